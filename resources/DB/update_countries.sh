@@ -0,0 +1,17 @@
+#!/usr/bin/env sh
+# Regenerates countries.txt from the Natural Earth 1:110m Admin 0 countries (public domain):
+# each line is an outer ring of a country, with its ISO 3166-1 alpha-2 code followed by
+# the (longitude,latitude) vertices.
+set -e
+
+cd "$(dirname "$0")"
+tmp="$(mktemp)"
+echo "# Natural Earth 1:110m Admin 0 countries (public domain), outer rings" > "$tmp"
+curl -fsSL "https://raw.githubusercontent.com/nvkelso/natural-earth-vector/master/geojson/ne_110m_admin_0_countries.geojson" |
+    jq -r '.features[]
+        | .properties.ISO_A2_EH as $code
+        | select($code | test("^[A-Z]{2}$"))
+        | .geometry
+        | (if .type == "Polygon" then [.coordinates] else .coordinates end)[]
+        | "\($code) " + (.[0][:-1] | map("\(.[0] * 100 | round / 100),\(.[1] * 100 | round / 100)") | join(" "))' >> "$tmp"
+mv "$tmp" countries.txt
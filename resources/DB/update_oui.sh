@@ -0,0 +1,12 @@
+#!/usr/bin/env sh
+# Regenerates oui.csv merging the IEEE registries of MAC address assignments:
+# MA-L (oui.csv), MA-M (mam.csv), and MA-S (oui36.csv).
+set -e
+
+cd "$(dirname "$0")"
+tmp="$(mktemp)"
+echo "Registry,Assignment,Organization Name,Organization Address" > "$tmp"
+for registry in oui mam oui36; do
+    curl -fsSL "https://standards-oui.ieee.org/$registry/$registry.csv" | tail -n +2 | tr -d '\r' >> "$tmp"
+done
+mv "$tmp" oui.csv
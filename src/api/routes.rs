@@ -0,0 +1,466 @@
+//! Module implementing the endpoints of the local API, which answer with JSON documents.
+
+use std::cmp::Reverse;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Local};
+use pcap::Device;
+use serde::Serialize;
+
+use crate::api::types::api_request::ApiRequest;
+use crate::api::types::api_state::ApiState;
+use crate::api::types::start_capture_body::StartCaptureBody;
+use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::networking::types::data_info::DataInfo;
+use crate::networking::types::data_info_host::DataInfoHost;
+use crate::networking::types::host::Host;
+use crate::networking::types::traffic_direction::TrafficDirection;
+use crate::report::get_report_entries::{get_all_app_entries, get_all_host_entries};
+use crate::{AppProtocol, ChartType, InfoTraffic};
+
+/// Path of the WebSocket streaming the per-second updates
+pub const UPDATES_PATH: &str = "/api/updates";
+
+/// Status code and JSON body of a response
+#[derive(Debug, PartialEq)]
+pub struct ApiResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+impl ApiResponse {
+    fn ok(body: &impl Serialize) -> Self {
+        Self::with_status(200, body)
+    }
+
+    pub fn error(status: u16, message: &str) -> Self {
+        #[derive(Serialize)]
+        struct Error<'a> {
+            error: &'a str,
+        }
+        Self::with_status(status, &Error { error: message })
+    }
+
+    fn with_status(status: u16, body: &impl Serialize) -> Self {
+        match serde_json::to_string(body) {
+            Ok(body) => ApiResponse { status, body },
+            Err(e) => Self::error(500, &e.to_string()),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Adapter {
+    name: String,
+    description: Option<String>,
+    addresses: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct Status<'a> {
+    running: bool,
+    adapters: &'a [String],
+}
+
+/// Traffic counters of the current capture
+#[derive(Serialize, Default, Clone, Copy, Debug, PartialEq)]
+pub struct Totals {
+    pub all_bytes: u128,
+    pub all_packets: u128,
+    pub sent_bytes: u128,
+    pub received_bytes: u128,
+    pub sent_packets: u128,
+    pub received_packets: u128,
+    pub dropped_packets: u32,
+}
+
+impl Totals {
+    pub fn new(info_traffic: &InfoTraffic) -> Self {
+        Totals {
+            all_bytes: info_traffic.all_bytes,
+            all_packets: info_traffic.all_packets,
+            sent_bytes: info_traffic.tot_sent_bytes,
+            received_bytes: info_traffic.tot_received_bytes,
+            sent_packets: info_traffic.tot_sent_packets,
+            received_packets: info_traffic.tot_received_packets,
+            dropped_packets: info_traffic.dropped_packets,
+        }
+    }
+}
+
+/// Traffic exchanged in the last interval (the same values plotted by the chart), and the totals
+#[derive(Serialize, Debug, PartialEq)]
+pub struct Update {
+    pub sent_bytes: u128,
+    pub received_bytes: u128,
+    pub sent_packets: u128,
+    pub received_packets: u128,
+    pub totals: Totals,
+}
+
+impl Update {
+    pub fn new(previous: &Totals, current: Totals) -> Self {
+        Update {
+            sent_bytes: current.sent_bytes.saturating_sub(previous.sent_bytes),
+            received_bytes: current
+                .received_bytes
+                .saturating_sub(previous.received_bytes),
+            sent_packets: current.sent_packets.saturating_sub(previous.sent_packets),
+            received_packets: current
+                .received_packets
+                .saturating_sub(previous.received_packets),
+            totals: current,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct HostEntry {
+    host: Host,
+    data: DataInfoHost,
+}
+
+#[derive(Serialize)]
+struct AppEntry {
+    app: AppProtocol,
+    data: DataInfo,
+}
+
+#[derive(Serialize)]
+struct Connection<'a> {
+    #[serde(flatten)]
+    key: &'a AddressPortPair,
+    app: AppProtocol,
+    direction: TrafficDirection,
+    interface: &'a str,
+    bytes: u128,
+    packets: u128,
+    first_seen: DateTime<Local>,
+    last_seen: DateTime<Local>,
+    icmp_types: Vec<(String, usize)>,
+}
+
+/// Returns the response to the given request
+pub fn handle_request(
+    method: &str,
+    path: &str,
+    body: &str,
+    api_state: &Arc<Mutex<ApiState>>,
+    info_traffic_mutex: &Arc<Mutex<InfoTraffic>>,
+) -> ApiResponse {
+    let expected_method = match path {
+        "/api/capture/start" | "/api/capture/stop" => "POST",
+        "/api/adapters" | "/api/status" | "/api/totals" | "/api/hosts" | "/api/apps"
+        | "/api/connections" => "GET",
+        _ => return ApiResponse::error(404, "Not found"),
+    };
+    if method != expected_method {
+        return ApiResponse::error(405, "Method not allowed");
+    }
+
+    match path {
+        "/api/adapters" => ApiResponse::ok(&get_adapters()),
+        "/api/status" => {
+            let api_state = api_state.lock().unwrap();
+            ApiResponse::ok(&Status {
+                running: api_state.is_capture_running,
+                adapters: &api_state.adapters,
+            })
+        }
+        "/api/capture/start" => start_capture(body, api_state),
+        "/api/capture/stop" => {
+            let mut api_state = api_state.lock().unwrap();
+            if !api_state.is_capture_running {
+                return ApiResponse::error(409, "No capture is running");
+            }
+            api_state.pending_requests.push(ApiRequest::Stop);
+            ApiResponse::with_status(
+                202,
+                &Status {
+                    running: true,
+                    adapters: &api_state.adapters,
+                },
+            )
+        }
+        "/api/totals" => ApiResponse::ok(&Totals::new(&info_traffic_mutex.lock().unwrap())),
+        "/api/hosts" => {
+            let hosts: Vec<HostEntry> =
+                get_all_host_entries(&info_traffic_mutex.lock().unwrap(), ChartType::Bytes)
+                    .into_iter()
+                    .map(|(host, data)| HostEntry { host, data })
+                    .collect();
+            ApiResponse::ok(&hosts)
+        }
+        "/api/apps" => {
+            let apps: Vec<AppEntry> =
+                get_all_app_entries(&info_traffic_mutex.lock().unwrap(), ChartType::Bytes)
+                    .into_iter()
+                    .map(|(app, data)| AppEntry { app, data })
+                    .collect();
+            ApiResponse::ok(&apps)
+        }
+        "/api/connections" => {
+            let info_traffic = info_traffic_mutex.lock().unwrap();
+            let mut connections: Vec<Connection> = info_traffic
+                .map
+                .iter()
+                .map(|(key, info)| Connection {
+                    key,
+                    app: info.app_protocol,
+                    direction: info.traffic_direction,
+                    interface: &info.interface,
+                    bytes: info.transmitted_bytes,
+                    packets: info.transmitted_packets,
+                    first_seen: info.initial_timestamp,
+                    last_seen: info.final_timestamp,
+                    icmp_types: info
+                        .icmp_types
+                        .iter()
+                        .map(|(icmp_type, count)| (icmp_type.to_string(), *count))
+                        .collect(),
+                })
+                .collect();
+            connections.sort_by_key(|connection| Reverse(connection.last_seen));
+            ApiResponse::ok(&connections)
+        }
+        _ => ApiResponse::error(404, "Not found"),
+    }
+}
+
+fn get_adapters() -> Vec<Adapter> {
+    Device::list()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|device| Adapter {
+            name: device.name,
+            description: device.desc,
+            addresses: device
+                .addresses
+                .iter()
+                .map(|address| address.addr.to_string())
+                .collect(),
+        })
+        .collect()
+}
+
+fn start_capture(body: &str, api_state: &Arc<Mutex<ApiState>>) -> ApiResponse {
+    let body: StartCaptureBody = if body.trim().is_empty() {
+        StartCaptureBody::default()
+    } else {
+        match serde_json::from_str(body) {
+            Ok(body) => body,
+            Err(e) => return ApiResponse::error(400, &e.to_string()),
+        }
+    };
+    let Some(filters) = body.to_filters() else {
+        return ApiResponse::error(400, "Invalid filters");
+    };
+    if let Some(adapter) = &body.adapter {
+        if !get_adapters().iter().any(|a| a.name.eq(adapter)) {
+            return ApiResponse::error(404, "Unknown adapter");
+        }
+    }
+
+    let mut api_state = api_state.lock().unwrap();
+    if api_state.is_capture_running
+        || api_state
+            .pending_requests
+            .iter()
+            .any(|request| matches!(request, ApiRequest::Start { .. }))
+    {
+        return ApiResponse::error(409, "A capture is already running");
+    }
+    api_state.pending_requests.push(ApiRequest::Start {
+        adapter: body.adapter,
+        filters: Box::new(filters),
+    });
+    ApiResponse::with_status(
+        202,
+        &Status {
+            running: false,
+            adapters: &api_state.adapters,
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
+    use crate::{IpVersion, Protocol};
+
+    use super::*;
+
+    fn shared_state() -> (Arc<Mutex<ApiState>>, Arc<Mutex<InfoTraffic>>) {
+        (
+            Arc::new(Mutex::new(ApiState::default())),
+            Arc::new(Mutex::new(InfoTraffic::new())),
+        )
+    }
+
+    #[test]
+    fn test_routing_errors() {
+        let (api_state, info_traffic) = shared_state();
+        let response = handle_request("GET", "/api/unknown", "", &api_state, &info_traffic);
+        assert_eq!(response.status, 404);
+        assert_eq!(response.body, "{\"error\":\"Not found\"}");
+        let response = handle_request("GET", "/api/capture/start", "", &api_state, &info_traffic);
+        assert_eq!(response.status, 405);
+        let response = handle_request("POST", "/api/totals", "", &api_state, &info_traffic);
+        assert_eq!(response.status, 405);
+    }
+
+    #[test]
+    fn test_start_and_stop_capture() {
+        let (api_state, info_traffic) = shared_state();
+
+        // nothing to stop
+        let response = handle_request("POST", "/api/capture/stop", "", &api_state, &info_traffic);
+        assert_eq!(response.status, 409);
+
+        // invalid requests are rejected
+        for body in [
+            "{",
+            "{\"unknown\": 1}",
+            "{\"ports\": \"not a port\"}",
+            "{\"protocols\": []}",
+        ] {
+            let response = handle_request(
+                "POST",
+                "/api/capture/start",
+                body,
+                &api_state,
+                &info_traffic,
+            );
+            assert_eq!(response.status, 400, "{body}");
+        }
+        assert!(api_state.lock().unwrap().pending_requests.is_empty());
+
+        let body =
+            "{\"ip_versions\": [\"IPv4\"], \"protocols\": [\"TCP\", \"UDP\"], \"ports\": \"443\"}";
+        let response = handle_request(
+            "POST",
+            "/api/capture/start",
+            body,
+            &api_state,
+            &info_traffic,
+        );
+        assert_eq!(response.status, 202);
+        // a second start request is refused
+        let response = handle_request("POST", "/api/capture/start", "", &api_state, &info_traffic);
+        assert_eq!(response.status, 409);
+
+        let requests = api_state.lock().unwrap().pending_requests.clone();
+        assert_eq!(requests.len(), 1);
+        let ApiRequest::Start { adapter, filters } = &requests[0] else {
+            panic!("expected a start request");
+        };
+        assert_eq!(adapter, &None);
+        assert_eq!(filters.ip_versions.len(), 1);
+        assert!(filters.ip_versions.contains(&IpVersion::IPv4));
+        assert_eq!(filters.protocols.len(), 2);
+        assert!(!filters.protocols.contains(&Protocol::ICMP));
+        assert_eq!(filters.port_str, "443");
+
+        // the GUI started the capture
+        {
+            let mut api_state = api_state.lock().unwrap();
+            api_state.pending_requests.clear();
+            api_state.is_capture_running = true;
+            api_state.adapters = vec!["eth0".to_string()];
+        }
+        let response = handle_request("GET", "/api/status", "", &api_state, &info_traffic);
+        assert_eq!(response.body, "{\"running\":true,\"adapters\":[\"eth0\"]}");
+        let response = handle_request("POST", "/api/capture/stop", "", &api_state, &info_traffic);
+        assert_eq!(response.status, 202);
+        assert!(matches!(
+            api_state.lock().unwrap().pending_requests[..],
+            [ApiRequest::Stop]
+        ));
+    }
+
+    #[test]
+    fn test_traffic_data() {
+        let (api_state, info_traffic) = shared_state();
+        {
+            let mut info_traffic = info_traffic.lock().unwrap();
+            info_traffic.tot_sent_bytes = 100;
+            info_traffic.tot_received_packets = 3;
+            info_traffic.app_protocols.insert(
+                AppProtocol::HTTPS,
+                DataInfo {
+                    incoming_packets: 3,
+                    outgoing_packets: 1,
+                    incoming_bytes: 300,
+                    outgoing_bytes: 100,
+                },
+            );
+            info_traffic.map.insert(
+                AddressPortPair::new(
+                    "10.0.0.1".to_string(),
+                    Some(50000),
+                    "10.0.0.2".to_string(),
+                    Some(443),
+                    Protocol::TCP,
+                    "eth0".to_string(),
+                ),
+                InfoAddressPortPair {
+                    transmitted_bytes: 400,
+                    transmitted_packets: 4,
+                    app_protocol: AppProtocol::HTTPS,
+                    interface: "eth0".to_string(),
+                    ..InfoAddressPortPair::default()
+                },
+            );
+        }
+
+        let response = handle_request("GET", "/api/totals", "", &api_state, &info_traffic);
+        assert_eq!(response.status, 200);
+        let totals: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+        assert_eq!(totals["sent_bytes"], 100);
+        assert_eq!(totals["received_packets"], 3);
+
+        let response = handle_request("GET", "/api/apps", "", &api_state, &info_traffic);
+        let apps: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+        assert_eq!(apps[0]["app"], "HTTPS");
+        assert_eq!(apps[0]["data"]["incoming_bytes"], 300);
+
+        let response = handle_request("GET", "/api/hosts", "", &api_state, &info_traffic);
+        assert_eq!(response.body, "[]");
+
+        let response = handle_request("GET", "/api/connections", "", &api_state, &info_traffic);
+        let connections: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+        assert_eq!(connections[0]["address2"], "10.0.0.2");
+        assert_eq!(connections[0]["port2"], 443);
+        assert_eq!(connections[0]["protocol"], "TCP");
+        assert_eq!(connections[0]["bytes"], 400);
+        assert_eq!(connections[0]["interface"], "eth0");
+    }
+
+    #[test]
+    fn test_update() {
+        let previous = Totals {
+            sent_bytes: 100,
+            received_bytes: 1000,
+            sent_packets: 1,
+            received_packets: 10,
+            ..Totals::default()
+        };
+        let current = Totals {
+            sent_bytes: 150,
+            received_bytes: 1800,
+            sent_packets: 2,
+            received_packets: 15,
+            ..Totals::default()
+        };
+        let update = Update::new(&previous, current);
+        assert_eq!(update.sent_bytes, 50);
+        assert_eq!(update.received_bytes, 800);
+        assert_eq!(update.sent_packets, 1);
+        assert_eq!(update.received_packets, 5);
+        assert_eq!(update.totals, current);
+
+        // a new capture restarts the counters
+        let update = Update::new(&current, previous);
+        assert_eq!(update.sent_bytes, 0);
+    }
+}
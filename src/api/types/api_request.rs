@@ -0,0 +1,13 @@
+use crate::networking::types::filters::Filters;
+
+/// Request received by the local API which has to be executed by the GUI
+#[derive(Clone)]
+pub enum ApiRequest {
+    /// Start a capture on the given adapter (or on the selected one) with the given filters
+    Start {
+        adapter: Option<String>,
+        filters: Box<Filters>,
+    },
+    /// Stop the running capture and return to the initial page
+    Stop,
+}
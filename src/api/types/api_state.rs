@@ -0,0 +1,12 @@
+use crate::api::types::api_request::ApiRequest;
+
+/// State shared between the GUI and the thread serving the local API
+#[derive(Default)]
+pub struct ApiState {
+    /// Requests waiting to be executed by the GUI
+    pub pending_requests: Vec<ApiRequest>,
+    /// Whether a capture is currently running
+    pub is_capture_running: bool,
+    /// Names of the network adapters being analyzed
+    pub adapters: Vec<String>,
+}
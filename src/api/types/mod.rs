@@ -0,0 +1,3 @@
+pub mod api_request;
+pub mod api_state;
+pub mod start_capture_body;
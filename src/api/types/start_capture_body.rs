@@ -0,0 +1,46 @@
+use std::collections::HashSet;
+
+use serde::Deserialize;
+
+use crate::networking::types::filters::Filters;
+use crate::networking::types::ip_collection::AddressCollection;
+use crate::networking::types::port_collection::PortCollection;
+use crate::{IpVersion, Protocol};
+
+/// JSON body of the requests to start a capture; omitted fields keep their default value
+#[derive(Deserialize, Default, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct StartCaptureBody {
+    /// Name of the network adapter to analyze (if omitted, the one selected in the GUI)
+    pub adapter: Option<String>,
+    /// IP versions to keep (if omitted, all of them)
+    pub ip_versions: Option<HashSet<IpVersion>>,
+    /// Protocols to keep (if omitted, all of them)
+    pub protocols: Option<HashSet<Protocol>>,
+    /// IP addresses to keep, with the same syntax of the initial page
+    pub addresses: String,
+    /// Ports to keep, with the same syntax of the initial page
+    pub ports: String,
+}
+
+impl StartCaptureBody {
+    /// Returns the filters described by the body, or `None` if they aren't valid
+    pub fn to_filters(&self) -> Option<Filters> {
+        let mut filters = Filters::default();
+        if let Some(ip_versions) = &self.ip_versions {
+            filters.ip_versions = ip_versions.clone();
+        }
+        if let Some(protocols) = &self.protocols {
+            filters.protocols = protocols.clone();
+        }
+        filters.address_collection = AddressCollection::new(&self.addresses)?;
+        filters.address_str = self.addresses.clone();
+        filters.port_collection = PortCollection::new(&self.ports)?;
+        filters.port_str = self.ports.clone();
+        if filters.are_valid() {
+            Some(filters)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use crate::blocklists::types::blocklist_match::BlocklistMatch;
+use crate::blocklists::types::blocklists::Blocklists;
+use crate::InfoTraffic;
+
+/// Checks a remote address, and the name it resolved to (if any), against the blocklists.
+///
+/// The first match of each address is recorded, to be marked in the connections report and notified.
+pub fn check_blocklists(
+    info_traffic: &mut InfoTraffic,
+    blocklists: &Blocklists,
+    address: &str,
+    name: Option<&str>,
+) {
+    if blocklists.lists.is_empty() || info_traffic.blocklisted.contains_key(address) {
+        return;
+    }
+    if let Some(blocklist_match) = match_blocklists(blocklists, address, name) {
+        info_traffic
+            .blocklisted
+            .insert(address.to_string(), blocklist_match.clone());
+        info_traffic.blocklisted_last_interval.push(blocklist_match);
+    }
+}
+
+/// Checks again from scratch the given remote addresses, after the blocklists were reloaded or removed.
+///
+/// Matches that don't hold anymore are discarded; only the new (or changed) ones are notified.
+pub fn recheck_blocklists(
+    info_traffic: &mut InfoTraffic,
+    blocklists: &Blocklists,
+    addresses: &[(String, Option<String>)],
+) {
+    let previous = std::mem::take(&mut info_traffic.blocklisted);
+    for (address, name) in addresses {
+        if let Some(blocklist_match) = match_blocklists(blocklists, address, name.as_deref()) {
+            if previous.get(address) != Some(&blocklist_match) {
+                info_traffic
+                    .blocklisted_last_interval
+                    .push(blocklist_match.clone());
+            }
+            info_traffic
+                .blocklisted
+                .insert(address.clone(), blocklist_match);
+        }
+    }
+}
+
+fn match_blocklists(
+    blocklists: &Blocklists,
+    address: &str,
+    name: Option<&str>,
+) -> Option<BlocklistMatch> {
+    if let Some((list, entry)) = blocklists.match_address(address) {
+        Some(BlocklistMatch {
+            list,
+            entry,
+            address: address.to_string(),
+            name: None,
+        })
+    } else {
+        let (list, entry) = name.and_then(|name| blocklists.match_domain(name))?;
+        Some(BlocklistMatch {
+            list,
+            entry,
+            address: address.to_string(),
+            name: name.map(str::to_string),
+        })
+    }
+}
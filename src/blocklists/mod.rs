@@ -0,0 +1,2 @@
+pub mod blocklist_check;
+pub mod types;
@@ -0,0 +1,257 @@
+use std::collections::HashSet;
+use std::fs;
+use std::net::IpAddr;
+use std::path::Path;
+use std::time::SystemTime;
+
+/// Addresses, networks, and domains read from a blocklist file.
+///
+/// Plain-text lists (one entry per line, hosts files included) and CSV exports are supported:
+/// each line contributes its first IP address, CIDR network, domain, or URL host.
+#[derive(Default, Debug)]
+pub struct Blocklist {
+    /// Name of the list (the file name without extension)
+    pub name: String,
+    /// Path of the file
+    pub path: String,
+    /// Last modification time of the file when it was read; `None` if it couldn't be read
+    pub modified: Option<SystemTime>,
+    /// Size of the file when it was read
+    pub size: u64,
+    addresses: HashSet<IpAddr>,
+    networks: Vec<(IpAddr, u8)>,
+    domains: HashSet<String>,
+}
+
+impl Blocklist {
+    /// Reads the blocklist at the given path; the list is empty if the file couldn't be read
+    pub fn from_file(path: &str) -> Self {
+        let name = Path::new(path)
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let metadata = fs::metadata(path).ok();
+        let modified = metadata.as_ref().and_then(|m| m.modified().ok());
+        let content = if modified.is_some() {
+            fs::read(path)
+                .map(|bytes| String::from_utf8_lossy(&bytes).to_string())
+                .ok()
+        } else {
+            None
+        };
+        let mut blocklist = Self::parse(name, content.as_deref().unwrap_or_default());
+        blocklist.path = path.to_string();
+        blocklist.modified = content.and(modified);
+        blocklist.size = metadata.map_or(0, |m| m.len());
+        blocklist
+    }
+
+    fn parse(name: String, content: &str) -> Self {
+        let mut blocklist = Blocklist {
+            name,
+            ..Blocklist::default()
+        };
+        for line in content.lines() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.starts_with(';') || line.starts_with('!') {
+                continue;
+            }
+            for field in line.split(|c: char| c == ',' || c == ';' || c.is_whitespace()) {
+                if blocklist.add_entry(field.trim_matches(|c| c == '"' || c == '\'')) {
+                    break;
+                }
+            }
+        }
+        blocklist
+    }
+
+    /// Adds the entry to the list, returning false if it's not a valid one
+    fn add_entry(&mut self, entry: &str) -> bool {
+        // URLs: keep only the host
+        let entry = match entry.split_once("://") {
+            Some((_, rest)) => rest.split(['/', '?']).next().unwrap_or_default(),
+            None => entry,
+        };
+        // host and port
+        let entry = match entry.split_once(':') {
+            Some((host, port)) if !port.contains(':') && port.parse::<u16>().is_ok() => host,
+            _ => entry,
+        };
+        let entry = entry.trim_start_matches('[').trim_end_matches(']');
+
+        if let Ok(address) = entry.parse::<IpAddr>() {
+            // sink addresses of hosts files
+            if address.is_unspecified() || address.is_loopback() {
+                return false;
+            }
+            self.addresses.insert(address);
+        } else if let Some((address, prefix)) = entry.split_once('/') {
+            let (Ok(address), Ok(prefix)) = (address.parse::<IpAddr>(), prefix.parse::<u8>())
+            else {
+                return false;
+            };
+            if prefix > if address.is_ipv4() { 32 } else { 128 } {
+                return false;
+            }
+            self.networks.push((address, prefix));
+        } else if is_domain(entry) {
+            let domain = entry.trim_start_matches("*.").trim_end_matches('.');
+            self.domains.insert(domain.to_lowercase());
+        } else {
+            return false;
+        }
+        true
+    }
+
+    /// Number of entries of the list
+    pub fn num_entries(&self) -> usize {
+        self.addresses.len() + self.networks.len() + self.domains.len()
+    }
+
+    /// Returns the entry matching the address, if any
+    pub fn match_address(&self, address: &IpAddr) -> Option<String> {
+        if self.addresses.contains(address) {
+            return Some(address.to_string());
+        }
+        self.networks
+            .iter()
+            .find(|(network, prefix)| is_in_network(address, network, *prefix))
+            .map(|(network, prefix)| format!("{network}/{prefix}"))
+    }
+
+    /// Returns the entry matching the domain or one of its parent domains, if any
+    pub fn match_domain(&self, domain: &str) -> Option<String> {
+        let domain = domain.trim_end_matches('.').to_lowercase();
+        let mut suffix = domain.as_str();
+        loop {
+            if self.domains.contains(suffix) {
+                return Some(suffix.to_string());
+            }
+            suffix = suffix.split_once('.')?.1;
+        }
+    }
+}
+
+fn is_domain(entry: &str) -> bool {
+    let entry = entry.trim_start_matches("*.").trim_end_matches('.');
+    let Some((_, tld)) = entry.rsplit_once('.') else {
+        return false;
+    };
+    tld.len() >= 2
+        && tld.chars().all(char::is_alphabetic)
+        && !entry.starts_with(['-', '.'])
+        && entry
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '.' || c == '_')
+}
+
+fn is_in_network(address: &IpAddr, network: &IpAddr, prefix: u8) -> bool {
+    match (address, network) {
+        (IpAddr::V4(address), IpAddr::V4(network)) => {
+            let mask = u32::MAX.checked_shl(32 - u32::from(prefix)).unwrap_or(0);
+            u32::from(*address) & mask == u32::from(*network) & mask
+        }
+        (IpAddr::V6(address), IpAddr::V6(network)) => {
+            let mask = u128::MAX.checked_shl(128 - u32::from(prefix)).unwrap_or(0);
+            u128::from(*address) & mask == u128::from(*network) & mask
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    fn ip(address: &str) -> IpAddr {
+        address.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_plain_list() {
+        let blocklist = Blocklist::parse(
+            "firehol_level1".to_string(),
+            "# FireHOL level 1\n\
+            ; another comment\n\
+            1.2.3.4\n\
+            10.20.0.0/16 # inline comment\n\
+            2001:db8::/32\n\
+            \n\
+            not an entry\n\
+            0.0.0.0 ads.example.com\n\
+            127.0.0.1 localhost\n",
+        );
+        assert_eq!(blocklist.num_entries(), 4);
+        assert_eq!(
+            blocklist.match_address(&ip("1.2.3.4")),
+            Some("1.2.3.4".to_string())
+        );
+        assert_eq!(
+            blocklist.match_address(&ip("10.20.255.1")),
+            Some("10.20.0.0/16".to_string())
+        );
+        assert_eq!(
+            blocklist.match_address(&ip("2001:db8:1::1")),
+            Some("2001:db8::/32".to_string())
+        );
+        assert_eq!(blocklist.match_address(&ip("10.21.0.1")), None);
+        assert_eq!(blocklist.match_address(&ip("0.0.0.0")), None);
+        assert_eq!(
+            blocklist.match_domain("ADS.example.com."),
+            Some("ads.example.com".to_string())
+        );
+        assert_eq!(blocklist.match_domain("localhost"), None);
+    }
+
+    #[test]
+    fn test_parse_csv_export() {
+        let blocklist = Blocklist::parse(
+            "urlhaus".to_string(),
+            "# id,dateadded,url,url_status,threat,urlhaus_link\n\
+            \"1\",\"2024-01-01 10:00:00\",\"http://malware.example.org:8080/bin.sh\",\"online\",\"malware_download\",\"https://urlhaus.abuse.ch/url/1/\"\n\
+            \"2\",\"2024-01-01 11:00:00\",\"http://[2001:db8::5]/x\",\"online\",\"malware_download\",\"https://urlhaus.abuse.ch/url/2/\"\n\
+            first_seen_utc;dst_ip;dst_port\n\
+            2024-01-02 08:00:00;5.6.7.8;443\n",
+        );
+        assert_eq!(blocklist.num_entries(), 3);
+        assert_eq!(
+            blocklist.match_domain("cdn.malware.example.org"),
+            Some("malware.example.org".to_string())
+        );
+        assert_eq!(blocklist.match_domain("example.org"), None);
+        // only the first entry of each line is considered
+        assert_eq!(blocklist.match_domain("urlhaus.abuse.ch"), None);
+        assert!(blocklist.match_address(&ip("2001:db8::5")).is_some());
+        assert!(blocklist.match_address(&ip("5.6.7.8")).is_some());
+    }
+
+    #[test]
+    fn test_network_boundaries() {
+        assert!(is_in_network(&ip("192.168.1.255"), &ip("192.168.1.0"), 24));
+        assert!(!is_in_network(&ip("192.168.2.0"), &ip("192.168.1.0"), 24));
+        assert!(is_in_network(&ip("8.8.8.8"), &ip("0.0.0.0"), 0));
+        assert!(is_in_network(&ip("8.8.8.8"), &ip("8.8.8.8"), 32));
+        assert!(!is_in_network(&ip("8.8.8.9"), &ip("8.8.8.8"), 32));
+        assert!(!is_in_network(&ip("::ffff:8.8.8.8"), &ip("8.8.8.8"), 32));
+    }
+
+    #[test]
+    fn test_from_file() {
+        let path = format!("{}/test_blocklist.txt", env!("CARGO_MANIFEST_DIR"));
+        let mut file = fs::File::create(&path).unwrap();
+        writeln!(file, "evil.example.net\n9.9.9.9").unwrap();
+        drop(file);
+
+        let blocklist = Blocklist::from_file(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(blocklist.name, "test_blocklist");
+        assert!(blocklist.modified.is_some());
+        assert_eq!(blocklist.num_entries(), 2);
+
+        let missing = Blocklist::from_file(&path);
+        assert!(missing.modified.is_none());
+        assert_eq!(missing.num_entries(), 0);
+    }
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// Remote address matching an entry of a blocklist
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct BlocklistMatch {
+    /// Name of the matching blocklist
+    pub list: String,
+    /// Entry of the blocklist matching the address or its name
+    pub entry: String,
+    /// Remote address
+    pub address: String,
+    /// Name of the remote address, if it was matched
+    pub name: Option<String>,
+}
@@ -0,0 +1,116 @@
+use std::fs;
+use std::net::IpAddr;
+
+use crate::blocklists::types::blocklist::Blocklist;
+
+/// Blocklists loaded by the user, checked against the remote addresses and their names
+#[derive(Default, Debug)]
+pub struct Blocklists {
+    pub lists: Vec<Blocklist>,
+}
+
+impl Blocklists {
+    pub fn from_paths(paths: &[String]) -> Self {
+        Blocklists {
+            lists: paths
+                .iter()
+                .map(|path| Blocklist::from_file(path))
+                .collect(),
+        }
+    }
+
+    /// Reads again the lists whose file changed (modification time or size)
+    /// since the last time they were read.
+    ///
+    /// It returns true if at least one list was reloaded
+    pub fn reload_changed(&mut self) -> bool {
+        let mut reloaded = false;
+        for list in &mut self.lists {
+            let metadata = fs::metadata(&list.path).ok();
+            let modified = metadata.as_ref().and_then(|m| m.modified().ok());
+            let size = metadata.map_or(0, |m| m.len());
+            if modified != list.modified || size != list.size {
+                *list = Blocklist::from_file(&list.path);
+                reloaded = true;
+            }
+        }
+        reloaded
+    }
+
+    /// Returns the name of the first list matching the address, together with the matching entry
+    pub fn match_address(&self, address: &str) -> Option<(String, String)> {
+        let address = address.parse::<IpAddr>().ok()?;
+        self.lists.iter().find_map(|list| {
+            list.match_address(&address)
+                .map(|entry| (list.name.clone(), entry))
+        })
+    }
+
+    /// Returns the name of the first list matching the domain, together with the matching entry
+    pub fn match_domain(&self, domain: &str) -> Option<(String, String)> {
+        self.lists.iter().find_map(|list| {
+            list.match_domain(domain)
+                .map(|entry| (list.name.clone(), entry))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::time::{Duration, SystemTime};
+
+    use super::*;
+
+    #[test]
+    fn test_reload_changed() {
+        let path = format!("{}/test_blocklists.txt", env!("CARGO_MANIFEST_DIR"));
+        fs::write(&path, "1.1.1.1\n").unwrap();
+        let mut blocklists = Blocklists::from_paths(std::slice::from_ref(&path));
+        assert!(!blocklists.reload_changed());
+        assert_eq!(
+            blocklists.match_address("1.1.1.1"),
+            Some(("test_blocklists".to_string(), "1.1.1.1".to_string()))
+        );
+        assert_eq!(blocklists.match_address("2.2.2.2"), None);
+
+        // set the modification time explicitly: filesystems may have a coarse resolution
+        fs::write(&path, "2.2.2.0/24\nbad.example.com\n").unwrap();
+        File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(SystemTime::now() + Duration::from_secs(10))
+            .unwrap();
+        assert!(blocklists.reload_changed());
+        assert_eq!(blocklists.match_address("1.1.1.1"), None);
+        assert_eq!(
+            blocklists.match_address("2.2.2.2"),
+            Some(("test_blocklists".to_string(), "2.2.2.0/24".to_string()))
+        );
+        assert_eq!(
+            blocklists.match_domain("www.bad.example.com"),
+            Some(("test_blocklists".to_string(), "bad.example.com".to_string()))
+        );
+
+        // a change of size is detected even if the modification time is the same
+        let modified = blocklists.lists[0].modified.unwrap();
+        fs::write(&path, "3.3.3.3\n").unwrap();
+        File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(modified)
+            .unwrap();
+        assert!(blocklists.reload_changed());
+        assert_eq!(
+            blocklists.match_address("3.3.3.3"),
+            Some(("test_blocklists".to_string(), "3.3.3.3".to_string()))
+        );
+
+        fs::remove_file(&path).unwrap();
+        assert!(blocklists.reload_changed());
+        assert!(blocklists.lists[0].modified.is_none());
+        assert_eq!(blocklists.match_domain("bad.example.com"), None);
+    }
+}
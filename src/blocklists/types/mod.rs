@@ -0,0 +1,3 @@
+pub mod blocklist;
+pub mod blocklist_match;
+pub mod blocklists;
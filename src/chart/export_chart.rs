@@ -0,0 +1,104 @@
+//! Module containing the functions to export the chart displayed in the overview page
+//! as a PNG or SVG image.
+
+use std::path::Path;
+use std::sync::Once;
+
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use plotters::style::register_font;
+use plotters_iced::Chart;
+
+use crate::gui::styles::style_constants::{SARASA_MONO_BOLD_BYTES, SARASA_MONO_BYTES};
+use crate::gui::styles::types::palette::to_rgb_color;
+use crate::TrafficChart;
+
+/// Size in pixels of the exported images
+const IMAGE_SIZE: (u32, u32) = (1200, 600);
+
+/// Format of the exported chart
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Png,
+    Svg,
+}
+
+/// Draws the chart as currently displayed (range, zoom, charted entity, and style)
+/// to an image file at the given path
+pub fn export_chart(
+    traffic_chart: &TrafficChart,
+    format: ImageFormat,
+    path: &Path,
+) -> Result<(), String> {
+    register_fonts();
+    let entity_chart;
+    let chart = if let Some(entity) = &traffic_chart.selected {
+        entity_chart = traffic_chart.entity_chart(entity);
+        &entity_chart
+    } else {
+        traffic_chart
+    };
+    match format {
+        ImageFormat::Png => draw(
+            chart,
+            BitMapBackend::new(path, IMAGE_SIZE).into_drawing_area(),
+        ),
+        ImageFormat::Svg => draw(chart, SVGBackend::new(path, IMAGE_SIZE).into_drawing_area()),
+    }
+}
+
+fn draw<DB: DrawingBackend>(
+    chart: &TrafficChart,
+    root: DrawingArea<DB, Shift>,
+) -> Result<(), String> {
+    let background = to_rgb_color(chart.style.get_palette().primary);
+    root.fill(&background).map_err(|e| e.to_string())?;
+    chart.build_chart(&None, ChartBuilder::on(&root));
+    root.present().map_err(|e| e.to_string())
+}
+
+/// Text is laid out by plotters outside of the GUI, so it needs the font data
+fn register_fonts() {
+    static REGISTER_FONTS: Once = Once::new();
+    REGISTER_FONTS.call_once(|| {
+        let _ = register_font("Sarasa Mono SC", FontStyle::Normal, SARASA_MONO_BYTES);
+        let _ = register_font("Sarasa Mono SC", FontStyle::Bold, SARASA_MONO_BOLD_BYTES);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::chart::manage_chart_data::update_charts_data;
+    use crate::{Language, RunTimeData, StyleType};
+
+    use super::*;
+
+    #[test]
+    fn test_export_chart() {
+        let mut traffic_chart = TrafficChart::new(StyleType::default(), Language::default());
+        let mut runtime_data = RunTimeData::new();
+        for _ in 0..5 {
+            runtime_data.tot_received_bytes += 1_500;
+            runtime_data.tot_sent_bytes += 400;
+            update_charts_data(&mut runtime_data, &mut traffic_chart, false);
+        }
+
+        let svg_path = format!("{}/test_chart.svg", env!("CARGO_MANIFEST_DIR"));
+        let png_path = format!("{}/test_chart.png", env!("CARGO_MANIFEST_DIR"));
+        let svg_result = export_chart(&traffic_chart, ImageFormat::Svg, Path::new(&svg_path));
+        let png_result = export_chart(&traffic_chart, ImageFormat::Png, Path::new(&png_path));
+        let svg = fs::read_to_string(&svg_path).unwrap_or_default();
+        let png = fs::read(&png_path).unwrap_or_default();
+        let _ = fs::remove_file(&svg_path);
+        let _ = fs::remove_file(&png_path);
+
+        assert_eq!(svg_result, Ok(()));
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("width=\"1200\""));
+        assert!(svg.contains("Incoming"));
+        assert_eq!(png_result, Ok(()));
+        assert!(png.starts_with(b"\x89PNG"));
+    }
+}
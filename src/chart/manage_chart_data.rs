@@ -1,14 +1,35 @@
-use std::collections::VecDeque;
-
-use crate::{RunTimeData, TrafficChart};
+use crate::chart::types::chart_range::ChartRange;
+use crate::chart::types::entity_history::update_history;
+use crate::networking::types::data_info::DataInfo;
+use crate::networking::types::traffic_direction::TrafficDirection;
+use crate::{InfoTraffic, RunTimeData, TrafficChart};
 
 /// This function is invoked every second by the application subscription
 ///
-/// It updates data (packets and bytes per second) to be displayed in the chart of gui run page
-pub fn update_charts_data(runtime_data: &mut RunTimeData, traffic_chart: &mut TrafficChart) {
+/// It updates data (packets and bytes per second) to be displayed in the chart of gui run page,
+/// rolling up the one-second samples into 10-second and one-minute averages
+///
+/// Time intervals in which the capture is paused are recorded, so that the chart can highlight them
+pub fn update_charts_data(
+    runtime_data: &mut RunTimeData,
+    traffic_chart: &mut TrafficChart,
+    paused: bool,
+) {
     let tot_seconds = traffic_chart.ticks;
     traffic_chart.ticks += 1;
 
+    // update paused time intervals
+    while traffic_chart
+        .paused_ticks
+        .front()
+        .is_some_and(|tick| tot_seconds - tick >= ChartRange::OneDay.seconds())
+    {
+        traffic_chart.paused_ticks.pop_front();
+    }
+    if paused {
+        traffic_chart.paused_ticks.push_back(tot_seconds);
+    }
+
     let sent_bytes_entry = runtime_data.tot_sent_bytes - runtime_data.tot_sent_bytes_prev;
     let received_bytes_entry =
         runtime_data.tot_received_bytes - runtime_data.tot_received_bytes_prev;
@@ -16,51 +37,66 @@ pub fn update_charts_data(runtime_data: &mut RunTimeData, traffic_chart: &mut Tr
     let received_packets_entry =
         runtime_data.tot_received_packets - runtime_data.tot_received_packets_prev;
 
-    // update sent bytes traffic data
-    if traffic_chart.sent_bytes.len() >= 30 {
-        traffic_chart.sent_bytes.pop_front();
-    }
-    traffic_chart.sent_bytes.push_back((
+    traffic_chart.seconds.push(
         tot_seconds,
         -<u128 as TryInto<i64>>::try_into(sent_bytes_entry).unwrap(),
-    ));
-    traffic_chart.min_sent_bytes = get_min(&traffic_chart.sent_bytes);
+        received_bytes_entry.try_into().unwrap(),
+        -<u128 as TryInto<i64>>::try_into(sent_packets_entry).unwrap(),
+        received_packets_entry.try_into().unwrap(),
+    );
     runtime_data.tot_sent_bytes_prev = runtime_data.tot_sent_bytes;
-    // update received bytes traffic data
-    if traffic_chart.received_bytes.len() >= 30 {
-        traffic_chart.received_bytes.pop_front();
-    }
-    traffic_chart
-        .received_bytes
-        .push_back((tot_seconds, received_bytes_entry.try_into().unwrap()));
-    traffic_chart.max_received_bytes = get_max(&traffic_chart.received_bytes);
     runtime_data.tot_received_bytes_prev = runtime_data.tot_received_bytes;
+    runtime_data.tot_sent_packets_prev = runtime_data.tot_sent_packets;
+    runtime_data.tot_received_packets_prev = runtime_data.tot_received_packets;
 
-    // update sent packets traffic data
-    if traffic_chart.sent_packets.len() >= 30 {
-        traffic_chart.sent_packets.pop_front();
+    // roll up the samples at the end of each 10-second and one-minute interval
+    if traffic_chart.ticks % traffic_chart.ten_seconds.resolution == 0 {
+        traffic_chart
+            .ten_seconds
+            .roll_up(&traffic_chart.seconds, tot_seconds);
     }
-    traffic_chart.sent_packets.push_back((
-        tot_seconds,
-        -<u128 as TryInto<i64>>::try_into(sent_packets_entry).unwrap(),
-    ));
-    traffic_chart.min_sent_packets = get_min(&traffic_chart.sent_packets);
-    runtime_data.tot_sent_packets_prev = runtime_data.tot_sent_packets;
-    // update received packets traffic data
-    if traffic_chart.received_packets.len() >= 30 {
-        traffic_chart.received_packets.pop_front();
+    if traffic_chart.ticks % traffic_chart.minutes.resolution == 0 {
+        traffic_chart
+            .minutes
+            .roll_up(&traffic_chart.ten_seconds, tot_seconds);
+    }
+}
+
+/// This function is invoked every second, after `update_charts_data`
+///
+/// It updates the traffic history of each host, application protocol, and connection,
+/// used to chart their traffic individually
+pub fn update_entities_data(traffic_chart: &mut TrafficChart, info_traffic: &InfoTraffic) {
+    let tick = traffic_chart.ticks.saturating_sub(1);
+    let entities = &mut traffic_chart.entities;
+    for (host, data_info_host) in &info_traffic.hosts {
+        update_history(&mut entities.hosts, host, data_info_host.data_info, tick);
+    }
+    for (app, data_info) in &info_traffic.app_protocols {
+        update_history(&mut entities.apps, app, *data_info, tick);
+    }
+    for (key, info) in &info_traffic.map {
+        let data_info = if info.traffic_direction.eq(&TrafficDirection::Outgoing) {
+            DataInfo {
+                outgoing_packets: info.transmitted_packets,
+                outgoing_bytes: info.transmitted_bytes,
+                ..DataInfo::default()
+            }
+        } else {
+            DataInfo {
+                incoming_packets: info.transmitted_packets,
+                incoming_bytes: info.transmitted_bytes,
+                ..DataInfo::default()
+            }
+        };
+        update_history(&mut entities.connections, key, data_info, tick);
     }
-    traffic_chart
-        .received_packets
-        .push_back((tot_seconds, received_packets_entry.try_into().unwrap()));
-    traffic_chart.max_received_packets = get_max(&traffic_chart.received_packets);
-    runtime_data.tot_received_packets_prev = runtime_data.tot_received_packets;
 }
 
 /// Finds the minimum y value to be displayed in chart
-fn get_min(deque: &VecDeque<(u32, i64)>) -> i64 {
+pub fn get_min<'a>(samples: impl IntoIterator<Item = &'a (u32, i64)>) -> i64 {
     let mut min = 0;
-    for (_, x) in deque {
+    for (_, x) in samples {
         if *x < min {
             min = *x;
         }
@@ -69,9 +105,9 @@ fn get_min(deque: &VecDeque<(u32, i64)>) -> i64 {
 }
 
 /// Finds the maximum y value to be displayed in chart
-fn get_max(deque: &VecDeque<(u32, i64)>) -> i64 {
+pub fn get_max<'a>(samples: impl IntoIterator<Item = &'a (u32, i64)>) -> i64 {
     let mut max = 0;
-    for (_, x) in deque {
+    for (_, x) in samples {
         if *x > max {
             max = *x;
         }
@@ -152,20 +188,13 @@ fn test_chart_data_updates() {
         ]);
         let tot_sent = 1000 * 28 + 500;
         let tot_received = 21000 * 28 + 1000;
-        let mut traffic_chart = TrafficChart {
-            ticks: 29,
-            sent_bytes: sent.clone(),
-            received_bytes: received.clone(),
-            sent_packets: sent.clone(),
-            received_packets: received.clone(),
-            min_sent_bytes: -1000,
-            max_received_bytes: 21000,
-            min_sent_packets: -1000,
-            max_received_packets: 21000,
-            language: Language::default(),
-            chart_type: ChartType::Packets,
-            style: StyleType::default(),
-        };
+        let mut traffic_chart = TrafficChart::new(StyleType::default(), Language::default());
+        traffic_chart.ticks = 29;
+        traffic_chart.seconds.sent_bytes = sent.clone();
+        traffic_chart.seconds.received_bytes = received.clone();
+        traffic_chart.seconds.sent_packets = sent.clone();
+        traffic_chart.seconds.received_packets = received.clone();
+        traffic_chart.chart_type = ChartType::Packets;
         let mut runtime_data = RunTimeData {
             all_bytes: 0,
             all_packets: 0,
@@ -180,15 +209,20 @@ fn test_chart_data_updates() {
             tot_received_packets_prev: tot_received,
             logged_notifications: Default::default(),
             tot_emitted_notifications: 0,
+            last_filters_change: None,
+            packets_alert: Default::default(),
+            bytes_alert: Default::default(),
+            rules_state: Vec::new(),
+            adapters_accounted: Default::default(),
         };
 
         assert_eq!(get_min(&sent), -1000);
         assert_eq!(get_max(&received), 21000);
 
-        update_charts_data(&mut runtime_data, &mut traffic_chart);
+        update_charts_data(&mut runtime_data, &mut traffic_chart, false);
 
-        assert_eq!(get_min(&traffic_chart.sent_packets), -3333);
-        assert_eq!(get_max(&traffic_chart.received_bytes), 21000);
+        assert_eq!(get_min(&traffic_chart.seconds.sent_packets), -3333);
+        assert_eq!(get_max(&traffic_chart.seconds.received_bytes), 21000);
 
         // runtime_data correctly updated?
         assert_eq!(runtime_data.tot_sent_bytes_prev, tot_sent + 1111);
@@ -207,50 +241,105 @@ fn test_chart_data_updates() {
 
         // traffic_chart correctly updated?
         assert_eq!(traffic_chart.ticks, 30);
-        assert_eq!(traffic_chart.min_sent_bytes, -1111);
-        assert_eq!(traffic_chart.min_sent_packets, -3333);
-        assert_eq!(traffic_chart.max_received_bytes, 21000);
-        assert_eq!(traffic_chart.max_received_packets, 21000);
-        assert_eq!(traffic_chart.sent_bytes, sent_bytes);
-        assert_eq!(traffic_chart.received_packets, received_packets);
-        assert_eq!(traffic_chart.sent_packets, sent_packets);
-        assert_eq!(traffic_chart.received_bytes, received_bytes);
+        assert_eq!(traffic_chart.seconds.sent_bytes, sent_bytes);
+        assert_eq!(traffic_chart.seconds.received_packets, received_packets);
+        assert_eq!(traffic_chart.seconds.sent_packets, sent_packets);
+        assert_eq!(traffic_chart.seconds.received_bytes, received_bytes);
+        // the last 10 seconds are rolled up
+        assert_eq!(
+            traffic_chart.ten_seconds.sent_bytes,
+            VecDeque::from([(20, -1011)])
+        );
+        assert_eq!(
+            traffic_chart.ten_seconds.received_bytes,
+            VecDeque::from([(20, 19122)])
+        );
+        assert_eq!(
+            traffic_chart.ten_seconds.received_packets,
+            VecDeque::from([(20, 19344)])
+        );
+        assert!(traffic_chart.minutes.sent_bytes.is_empty());
 
         runtime_data.tot_sent_bytes += 99;
         runtime_data.tot_received_packets += 990;
         runtime_data.tot_received_bytes += 2;
-        update_charts_data(&mut runtime_data, &mut traffic_chart);
+        update_charts_data(&mut runtime_data, &mut traffic_chart, false);
         runtime_data.tot_sent_bytes += 77;
         runtime_data.tot_received_packets += 1;
         runtime_data.tot_sent_packets += 220;
-        update_charts_data(&mut runtime_data, &mut traffic_chart);
+        update_charts_data(&mut runtime_data, &mut traffic_chart, false);
 
-        sent_bytes.pop_front();
-        sent_bytes.pop_front();
+        // one-second samples are retained for 10 minutes
         sent_bytes.push_back((30, -99));
         sent_bytes.push_back((31, -77));
-        received_packets.pop_front();
-        received_packets.pop_front();
         received_packets.push_back((30, 990));
         received_packets.push_back((31, 1));
-        sent_packets.pop_front();
-        sent_packets.pop_front();
         sent_packets.push_back((30, 0));
         sent_packets.push_back((31, -220));
-        received_bytes.pop_front();
-        received_bytes.pop_front();
         received_bytes.push_back((30, 2));
         received_bytes.push_back((31, 0));
 
         // traffic_chart correctly updated?
         assert_eq!(traffic_chart.ticks, 32);
-        assert_eq!(traffic_chart.min_sent_bytes, -1111);
-        assert_eq!(traffic_chart.min_sent_packets, -3333);
-        assert_eq!(traffic_chart.max_received_bytes, 21000);
-        assert_eq!(traffic_chart.max_received_packets, 21000);
-        assert_eq!(traffic_chart.sent_bytes, sent_bytes);
-        assert_eq!(traffic_chart.received_packets, received_packets);
-        assert_eq!(traffic_chart.sent_packets, sent_packets);
-        assert_eq!(traffic_chart.received_bytes, received_bytes);
+        assert_eq!(get_min(&traffic_chart.seconds.sent_bytes), -1111);
+        assert_eq!(get_min(&traffic_chart.seconds.sent_packets), -3333);
+        assert_eq!(get_max(&traffic_chart.seconds.received_bytes), 21000);
+        assert_eq!(get_max(&traffic_chart.seconds.received_packets), 21000);
+        assert_eq!(traffic_chart.seconds.sent_bytes, sent_bytes);
+        assert_eq!(traffic_chart.seconds.received_packets, received_packets);
+        assert_eq!(traffic_chart.seconds.sent_packets, sent_packets);
+        assert_eq!(traffic_chart.seconds.received_bytes, received_bytes);
+        assert_eq!(traffic_chart.ten_seconds.sent_bytes.len(), 1);
+        assert!(traffic_chart.paused_ticks.is_empty());
+    }
+
+    #[test]
+    fn test_chart_paused_ticks() {
+        let mut traffic_chart = TrafficChart::new(StyleType::default(), Language::default());
+        let mut runtime_data = RunTimeData::new();
+
+        update_charts_data(&mut runtime_data, &mut traffic_chart, false);
+        update_charts_data(&mut runtime_data, &mut traffic_chart, true);
+        update_charts_data(&mut runtime_data, &mut traffic_chart, true);
+        update_charts_data(&mut runtime_data, &mut traffic_chart, false);
+        assert_eq!(traffic_chart.ticks, 4);
+        assert_eq!(traffic_chart.paused_ticks, VecDeque::from([1, 2]));
+        // the chart timeline keeps going while paused
+        assert_eq!(traffic_chart.seconds.received_bytes.len(), 4);
+        assert_eq!(traffic_chart.seconds.received_bytes[2], (2, 0));
+
+        // paused ticks older than the retained history are discarded
+        traffic_chart.ticks = 86_401;
+        update_charts_data(&mut runtime_data, &mut traffic_chart, false);
+        assert_eq!(traffic_chart.paused_ticks, VecDeque::from([2]));
+        update_charts_data(&mut runtime_data, &mut traffic_chart, false);
+        assert!(traffic_chart.paused_ticks.is_empty());
+    }
+
+    #[test]
+    fn test_chart_rollups() {
+        let mut traffic_chart = TrafficChart::new(StyleType::default(), Language::default());
+        let mut runtime_data = RunTimeData::new();
+
+        for tick in 0..120 {
+            // constant traffic in the first minute, alternating in the second one
+            runtime_data.tot_received_bytes += if tick < 60 || tick % 2 == 0 { 600 } else { 0 };
+            runtime_data.tot_sent_packets += 3;
+            update_charts_data(&mut runtime_data, &mut traffic_chart, false);
+        }
+
+        assert_eq!(traffic_chart.seconds.received_bytes.len(), 120);
+        assert_eq!(traffic_chart.ten_seconds.received_bytes.len(), 12);
+        assert_eq!(traffic_chart.ten_seconds.received_bytes[0], (0, 600));
+        assert_eq!(traffic_chart.ten_seconds.received_bytes[11], (110, 300));
+        assert_eq!(traffic_chart.ten_seconds.sent_packets[11], (110, -3));
+        assert_eq!(
+            traffic_chart.minutes.received_bytes,
+            VecDeque::from([(0, 600), (60, 300)])
+        );
+        assert_eq!(
+            traffic_chart.minutes.sent_packets,
+            VecDeque::from([(0, -3), (60, -3)])
+        );
     }
 }
@@ -1,2 +1,3 @@
+pub mod export_chart;
 pub mod manage_chart_data;
 pub mod types;
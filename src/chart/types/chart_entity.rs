@@ -0,0 +1,29 @@
+use std::fmt;
+
+use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::networking::types::host::Host;
+use crate::utils::formatted_strings::get_socket_address;
+use crate::AppProtocol;
+
+/// Enum representing the entities whose traffic can be charted individually.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ChartEntity {
+    Host(Host),
+    App(AppProtocol),
+    Connection(AddressPortPair),
+}
+
+impl fmt::Display for ChartEntity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChartEntity::Host(host) => write!(f, "{}", host.domain),
+            ChartEntity::App(app) => write!(f, "{app}"),
+            ChartEntity::Connection(key) => write!(
+                f,
+                "{} -> {}",
+                get_socket_address(&key.address1, key.port1),
+                get_socket_address(&key.address2, key.port2)
+            ),
+        }
+    }
+}
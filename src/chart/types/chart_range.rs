@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+/// Enum representing the possible time ranges displayed in the chart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ChartRange {
+    OneMinute,
+    TenMinutes,
+    OneHour,
+    OneDay,
+}
+
+impl ChartRange {
+    pub(crate) const ALL: [ChartRange; 4] = [
+        ChartRange::OneMinute,
+        ChartRange::TenMinutes,
+        ChartRange::OneHour,
+        ChartRange::OneDay,
+    ];
+
+    /// Number of seconds displayed
+    pub fn seconds(self) -> u32 {
+        match self {
+            ChartRange::OneMinute => 60,
+            ChartRange::TenMinutes => 600,
+            ChartRange::OneHour => 3_600,
+            ChartRange::OneDay => 86_400,
+        }
+    }
+
+    pub fn get_label(&self) -> &'static str {
+        match self {
+            ChartRange::OneMinute => "1m",
+            ChartRange::TenMinutes => "10m",
+            ChartRange::OneHour => "1h",
+            ChartRange::OneDay => "24h",
+        }
+    }
+}
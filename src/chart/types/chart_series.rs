@@ -0,0 +1,91 @@
+//! This module defines the `ChartSeries` struct, used to store the traffic samples of the chart
+//! at a given time resolution
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+/// Traffic samples of the chart at a given time resolution
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ChartSeries {
+    /// Number of seconds covered by each sample
+    pub resolution: u32,
+    /// Maximum number of samples retained
+    pub capacity: usize,
+    /// Sent bytes filtered per second and their time occurrence
+    pub sent_bytes: VecDeque<(u32, i64)>,
+    /// Received bytes filtered per second and their time occurrence
+    pub received_bytes: VecDeque<(u32, i64)>,
+    /// Sent packets filtered per second and their time occurrence
+    pub sent_packets: VecDeque<(u32, i64)>,
+    /// Received packets filtered per second and their time occurrence
+    pub received_packets: VecDeque<(u32, i64)>,
+}
+
+impl ChartSeries {
+    pub fn new(resolution: u32, capacity: usize) -> Self {
+        ChartSeries {
+            resolution,
+            capacity,
+            sent_bytes: VecDeque::default(),
+            received_bytes: VecDeque::default(),
+            sent_packets: VecDeque::default(),
+            received_packets: VecDeque::default(),
+        }
+    }
+
+    /// Number of seconds of history retained
+    pub fn retention(&self) -> u32 {
+        self.resolution * u32::try_from(self.capacity).unwrap_or(u32::MAX)
+    }
+
+    /// Appends a sample, discarding the oldest one if the capacity is reached
+    pub fn push(
+        &mut self,
+        tick: u32,
+        sent_bytes: i64,
+        received_bytes: i64,
+        sent_packets: i64,
+        received_packets: i64,
+    ) {
+        let capacity = self.capacity;
+        for (deque, value) in [
+            (&mut self.sent_bytes, sent_bytes),
+            (&mut self.received_bytes, received_bytes),
+            (&mut self.sent_packets, sent_packets),
+            (&mut self.received_packets, received_packets),
+        ] {
+            if deque.len() >= capacity {
+                deque.pop_front();
+            }
+            deque.push_back((tick, value));
+        }
+    }
+
+    /// Appends a sample averaging the samples of a finer series
+    /// in the interval of this resolution ending at the given tick
+    pub fn roll_up(&mut self, source: &ChartSeries, tick: u32) {
+        let start = (tick + 1).saturating_sub(self.resolution);
+        let resolution = i64::from(self.resolution);
+        let average = |deque: &VecDeque<(u32, i64)>| {
+            let sum: i64 = deque
+                .iter()
+                .rev()
+                .take_while(|(t, _)| *t >= start)
+                .map(|(_, value)| value)
+                .sum();
+            sum * i64::from(source.resolution) / resolution
+        };
+        let sent_bytes = average(&source.sent_bytes);
+        let received_bytes = average(&source.received_bytes);
+        let sent_packets = average(&source.sent_packets);
+        let received_packets = average(&source.received_packets);
+        self.push(
+            start,
+            sent_bytes,
+            received_bytes,
+            sent_packets,
+            received_packets,
+        );
+    }
+}
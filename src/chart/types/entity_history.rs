@@ -0,0 +1,161 @@
+//! This module defines the `EntityHistory` struct, used to chart the traffic of a single host,
+//! application protocol, or connection
+
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::chart::types::chart_entity::ChartEntity;
+use crate::chart::types::chart_range::ChartRange;
+use crate::chart::types::chart_series::ChartSeries;
+use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::networking::types::data_info::DataInfo;
+use crate::networking::types::host::Host;
+use crate::AppProtocol;
+
+/// Number of seconds of history retained for each entity
+pub const ENTITY_RETENTION: u32 = 600;
+
+/// Traffic exchanged by an entity in the last seconds
+#[derive(Clone, Default)]
+pub struct EntityHistory {
+    /// Data exchanged by the entity before the current time interval
+    prev: DataInfo,
+    /// Data exchanged by the entity in each time interval, omitting the intervals without traffic
+    samples: VecDeque<(u32, DataInfo)>,
+}
+
+impl EntityHistory {
+    /// Records the data exchanged by the entity in the time interval
+    fn update(&mut self, data_info: DataInfo, tick: u32) {
+        if data_info.tot_packets() > self.prev.tot_packets() {
+            self.samples.push_back((
+                tick,
+                DataInfo {
+                    incoming_packets: data_info.incoming_packets - self.prev.incoming_packets,
+                    outgoing_packets: data_info.outgoing_packets - self.prev.outgoing_packets,
+                    incoming_bytes: data_info.incoming_bytes - self.prev.incoming_bytes,
+                    outgoing_bytes: data_info.outgoing_bytes - self.prev.outgoing_bytes,
+                },
+            ));
+            self.prev = data_info;
+        }
+        while self
+            .samples
+            .front()
+            .is_some_and(|(t, _)| tick - t >= ENTITY_RETENTION)
+        {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Returns the one-second samples of the retained history, up to the given time interval
+    pub fn to_series(&self, ticks: u32) -> ChartSeries {
+        let to_i64 = |value: u128| i64::try_from(value).unwrap_or(i64::MAX);
+        // the whole history is made of one-second samples, whatever the displayed range
+        let mut series = ChartSeries::new(
+            1,
+            usize::try_from(ChartRange::OneDay.seconds()).unwrap_or(usize::MAX),
+        );
+        let first = ticks.saturating_sub(ENTITY_RETENTION);
+        let mut samples = self
+            .samples
+            .iter()
+            .skip_while(|(tick, _)| *tick < first)
+            .peekable();
+        for tick in first..ticks {
+            let data_info = samples
+                .next_if(|(t, _)| *t == tick)
+                .map(|(_, data_info)| *data_info)
+                .unwrap_or_default();
+            series.push(
+                tick,
+                -to_i64(data_info.outgoing_bytes),
+                to_i64(data_info.incoming_bytes),
+                -to_i64(data_info.outgoing_packets),
+                to_i64(data_info.incoming_packets),
+            );
+        }
+        series
+    }
+}
+
+/// Traffic history of the hosts, application protocols, and connections
+#[derive(Clone, Default)]
+pub struct EntitiesHistory {
+    pub hosts: HashMap<Host, EntityHistory>,
+    pub apps: HashMap<AppProtocol, EntityHistory>,
+    pub connections: HashMap<AddressPortPair, EntityHistory>,
+}
+
+impl EntitiesHistory {
+    pub fn get(&self, entity: &ChartEntity) -> Option<&EntityHistory> {
+        match entity {
+            ChartEntity::Host(host) => self.hosts.get(host),
+            ChartEntity::App(app) => self.apps.get(app),
+            ChartEntity::Connection(key) => self.connections.get(key),
+        }
+    }
+}
+
+/// Records the data exchanged by an entity in the time interval, without cloning its key
+/// unless it's seen for the first time
+pub fn update_history<K: Hash + Eq + Clone>(
+    histories: &mut HashMap<K, EntityHistory>,
+    key: &K,
+    data_info: DataInfo,
+    tick: u32,
+) {
+    if let Some(history) = histories.get_mut(key) {
+        history.update(data_info, tick);
+    } else {
+        let mut history = EntityHistory::default();
+        history.update(data_info, tick);
+        histories.insert(key.clone(), history);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data_info(incoming_bytes: u128, outgoing_bytes: u128) -> DataInfo {
+        DataInfo {
+            incoming_packets: incoming_bytes / 100,
+            outgoing_packets: outgoing_bytes / 100,
+            incoming_bytes,
+            outgoing_bytes,
+        }
+    }
+
+    #[test]
+    fn test_entity_history() {
+        let mut histories = HashMap::new();
+        let app = AppProtocol::HTTPS;
+        update_history(&mut histories, &app, data_info(1_000, 200), 3);
+        // no traffic in the interval
+        update_history(&mut histories, &app, data_info(1_000, 200), 4);
+        update_history(&mut histories, &app, data_info(1_500, 500), 5);
+
+        let history = &histories[&app];
+        assert_eq!(history.samples.len(), 2);
+        let series = history.to_series(7);
+        assert_eq!(series.received_bytes.len(), 7);
+        assert_eq!(
+            series.received_bytes,
+            VecDeque::from([(0, 0), (1, 0), (2, 0), (3, 1_000), (4, 0), (5, 500), (6, 0)])
+        );
+        assert_eq!(series.sent_bytes[5], (5, -300));
+        assert_eq!(series.received_packets[3], (3, 10));
+        assert_eq!(series.sent_packets[3], (3, -2));
+
+        // samples older than the retained history are discarded
+        update_history(&mut histories, &app, data_info(1_600, 500), 603);
+        let history = &histories[&app];
+        assert_eq!(history.samples.len(), 2);
+        let series = history.to_series(604);
+        assert_eq!(series.received_bytes.len(), 600);
+        assert_eq!(series.received_bytes.front(), Some(&(4, 0)));
+        assert_eq!(series.received_bytes[1], (5, 500));
+        assert_eq!(series.received_bytes.back(), Some(&(603, 100)));
+    }
+}
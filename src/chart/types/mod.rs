@@ -1,2 +1,7 @@
+pub mod chart_entity;
+pub mod chart_range;
+pub mod chart_series;
 pub mod chart_type;
+pub mod entity_history;
 pub mod traffic_chart;
+pub mod world_map;
@@ -3,11 +3,20 @@
 use std::collections::VecDeque;
 
 use iced::alignment::{Horizontal, Vertical};
+use iced::event::Status;
+use iced::mouse::Cursor;
+use iced::widget::canvas::Event;
 use iced::widget::{Column, Container};
-use iced::{Element, Renderer};
+use iced::{mouse, Element, Renderer};
 use plotters::prelude::*;
 use plotters_iced::{Chart, ChartBuilder, ChartWidget, DrawingBackend};
+use serde::{Deserialize, Serialize};
 
+use crate::chart::manage_chart_data::{get_max, get_min};
+use crate::chart::types::chart_entity::ChartEntity;
+use crate::chart::types::chart_range::ChartRange;
+use crate::chart::types::chart_series::ChartSeries;
+use crate::chart::types::entity_history::{EntitiesHistory, EntityHistory, ENTITY_RETENTION};
 use crate::gui::styles::style_constants::CHARTS_LINE_BORDER;
 use crate::gui::styles::types::palette::to_rgb_color;
 use crate::gui::types::message::Message;
@@ -16,30 +25,38 @@
 use crate::{ChartType, Language, StyleType};
 
 /// Struct defining the chart to be displayed in gui run page
+#[derive(Clone, Serialize, Deserialize)]
 pub struct TrafficChart {
     /// Current time interval number
     pub ticks: u32,
-    /// Sent bytes filtered and their time occurrence
-    pub sent_bytes: VecDeque<(u32, i64)>,
-    /// Received bytes filtered and their time occurrence
-    pub received_bytes: VecDeque<(u32, i64)>,
-    /// Sent packets filtered and their time occurrence
-    pub sent_packets: VecDeque<(u32, i64)>,
-    /// Received packets filtered and their time occurrence
-    pub received_packets: VecDeque<(u32, i64)>,
-    /// Minimum number of sent bytes per time interval (computed on last 30 intervals)
-    pub min_sent_bytes: i64,
-    /// Minimum number of received bytes per time interval (computed on last 30 intervals)
-    pub max_received_bytes: i64,
-    /// Minimum number of sent packets per time interval (computed on last 30 intervals)
-    pub min_sent_packets: i64,
-    /// Minimum number of received packets per time interval (computed on last 30 intervals)
-    pub max_received_packets: i64,
+    /// One-second samples of the last 10 minutes
+    pub seconds: ChartSeries,
+    /// 10-second averages of the last 2 hours
+    pub ten_seconds: ChartSeries,
+    /// One-minute averages of the last 24 hours
+    pub minutes: ChartSeries,
+    /// Time intervals in which the capture was paused (computed on the last 24 hours)
+    pub paused_ticks: VecDeque<u32>,
+    /// Time range selected
+    pub range: ChartRange,
+    /// Number of seconds displayed, changed zooming in and out
+    pub span: u32,
+    /// Number of seconds between the last time interval and the right edge of the chart,
+    /// changed panning
+    pub offset: u32,
+    /// Traffic history of the single hosts, application protocols, and connections
+    #[serde(skip)]
+    pub entities: EntitiesHistory,
+    /// Entity whose traffic is displayed instead of the total one
+    #[serde(skip)]
+    pub selected: Option<ChartEntity>,
     /// Language used for the chart legend
+    #[serde(skip)]
     pub language: Language,
     /// Packets or bytes
     pub chart_type: ChartType,
     /// Style of the chart
+    #[serde(skip)]
     pub style: StyleType,
 }
 
@@ -47,14 +64,15 @@ impl TrafficChart {
     pub fn new(style: StyleType, language: Language) -> Self {
         TrafficChart {
             ticks: 0,
-            sent_bytes: VecDeque::default(),
-            received_bytes: VecDeque::default(),
-            sent_packets: VecDeque::default(),
-            received_packets: VecDeque::default(),
-            min_sent_bytes: 0,
-            max_received_bytes: 0,
-            min_sent_packets: 0,
-            max_received_packets: 0,
+            seconds: ChartSeries::new(1, 600),
+            ten_seconds: ChartSeries::new(10, 720),
+            minutes: ChartSeries::new(60, 1_440),
+            paused_ticks: VecDeque::default(),
+            range: ChartRange::OneMinute,
+            span: ChartRange::OneMinute.seconds(),
+            offset: 0,
+            entities: EntitiesHistory::default(),
+            selected: None,
             language,
             chart_type: ChartType::Bytes,
             style,
@@ -62,12 +80,59 @@ pub fn new(style: StyleType, language: Language) -> Self {
     }
 
     pub fn view(&self) -> Element<Message, Renderer<StyleType>> {
+        if let Some(entity) = &self.selected {
+            return self.entity_view(entity);
+        }
         Container::new(Column::new().push(ChartWidget::new(self)))
             .align_x(Horizontal::Left)
             .align_y(Vertical::Bottom)
             .into()
     }
 
+    /// Chart of the traffic of a single entity
+    pub fn entity_view(
+        &self,
+        entity: &ChartEntity,
+    ) -> Element<'static, Message, Renderer<StyleType>> {
+        Container::new(Column::new().push(ChartWidget::new(self.entity_chart(entity))))
+            .align_x(Horizontal::Left)
+            .align_y(Vertical::Bottom)
+            .into()
+    }
+
+    /// Returns a chart with the same settings of this one, displaying the traffic of a single entity
+    pub fn entity_chart(&self, entity: &ChartEntity) -> TrafficChart {
+        let seconds = self.entities.get(entity).map_or_else(
+            || EntityHistory::default().to_series(self.ticks),
+            |history| history.to_series(self.ticks),
+        );
+        TrafficChart {
+            ticks: self.ticks,
+            seconds,
+            ten_seconds: ChartSeries::new(10, 0),
+            minutes: ChartSeries::new(60, 0),
+            paused_ticks: self.paused_ticks.clone(),
+            range: self.range,
+            span: self.span,
+            offset: self.offset,
+            entities: EntitiesHistory::default(),
+            selected: None,
+            language: self.language,
+            chart_type: self.chart_type,
+            style: self.style,
+        }
+    }
+
+    /// Displays the traffic of the given entity, or the total one.
+    ///
+    /// The history of single entities is shorter, so a longer range is reduced
+    pub fn select_entity(&mut self, entity: Option<ChartEntity>) {
+        if entity.is_some() && self.span > ENTITY_RETENTION {
+            self.change_range(ChartRange::TenMinutes);
+        }
+        self.selected = entity;
+    }
+
     pub fn change_kind(&mut self, kind: ChartType) {
         self.chart_type = kind;
     }
@@ -79,10 +144,88 @@ pub fn change_language(&mut self, language: Language) {
     pub fn change_style(&mut self, style: StyleType) {
         self.style = style;
     }
+
+    /// Displays the last interval of the selected range
+    pub fn change_range(&mut self, range: ChartRange) {
+        self.range = range;
+        self.span = range.seconds();
+        self.offset = 0;
+    }
+
+    /// Halves or doubles the number of seconds displayed
+    pub fn zoom(&mut self, zoom_in: bool) {
+        self.span = if zoom_in {
+            (self.span / 2).max(MIN_SPAN)
+        } else {
+            (self.span * 2).min(ChartRange::OneDay.seconds())
+        };
+        self.offset = self.offset.min(self.max_offset());
+    }
+
+    /// Moves the displayed interval back (positive seconds) or forth (negative seconds) in time
+    pub fn pan(&mut self, seconds: i64) {
+        let offset = (i64::from(self.offset) + seconds).clamp(0, i64::from(self.max_offset()));
+        self.offset = u32::try_from(offset).unwrap_or_default();
+    }
+
+    /// Maximum offset, so that the displayed interval doesn't exceed the retained history
+    fn max_offset(&self) -> u32 {
+        self.ticks
+            .saturating_sub(1)
+            .saturating_sub(self.span)
+            .min(ChartRange::OneDay.seconds() - self.span)
+    }
+
+    /// First and last time intervals displayed
+    pub fn displayed_interval(&self) -> (u32, u32) {
+        let last = self.ticks.saturating_sub(1).saturating_sub(self.offset);
+        (last.saturating_sub(self.span), last)
+    }
+
+    /// Finest series retaining the whole displayed interval
+    pub fn displayed_series(&self) -> &ChartSeries {
+        [&self.seconds, &self.ten_seconds, &self.minutes]
+            .into_iter()
+            .find(|series| series.retention() >= self.offset + self.span)
+            .unwrap_or(&self.minutes)
+    }
+
+    /// Incoming and outgoing samples in the displayed interval
+    fn displayed_samples(&self) -> (Vec<(u32, i64)>, Vec<(u32, i64)>) {
+        let (first, last) = self.displayed_interval();
+        let series = self.displayed_series();
+        let (incoming, outgoing) = if self.chart_type.eq(&ChartType::Packets) {
+            (&series.received_packets, &series.sent_packets)
+        } else {
+            (&series.received_bytes, &series.sent_bytes)
+        };
+        let displayed = |deque: &VecDeque<(u32, i64)>| {
+            deque
+                .iter()
+                .filter(|(tick, _)| (first..=last).contains(tick))
+                .copied()
+                .collect()
+        };
+        (displayed(incoming), displayed(outgoing))
+    }
+}
+
+/// Minimum number of seconds displayed
+const MIN_SPAN: u32 = 10;
+
+/// Formats a number of seconds since the start of the capture as `h:mm:ss` or `m:ss`
+fn get_formatted_tick(tick: u32) -> String {
+    let (hours, minutes, seconds) = (tick / 3_600, tick / 60 % 60, tick % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes}:{seconds:02}")
+    }
 }
 
 impl Chart<Message> for TrafficChart {
-    type State = ();
+    /// Horizontal position of the cursor while the chart is dragged
+    type State = Option<f32>;
 
     fn build_chart<DB: DrawingBackend>(
         &self,
@@ -94,8 +237,8 @@ fn build_chart<DB: DrawingBackend>(
         if self.ticks == 0 {
             return;
         }
-        let tot_seconds = self.ticks - 1;
-        let first_time_displayed = if self.ticks > 30 { self.ticks - 30 } else { 0 };
+        let (first_time_displayed, last_time_displayed) = self.displayed_interval();
+        let (incoming, outgoing) = self.displayed_samples();
 
         let colors = self.style.get_palette();
         let color_incoming = to_rgb_color(colors.secondary);
@@ -109,15 +252,10 @@ fn build_chart<DB: DrawingBackend>(
             .set_label_area_size(LabelAreaPosition::Left, 60)
             .set_label_area_size(LabelAreaPosition::Bottom, 50);
 
+        let (y_min, y_max) = (get_min(&outgoing), get_max(&incoming));
+
         let mut chart = chart_builder
-            .build_cartesian_2d(
-                first_time_displayed..tot_seconds,
-                if self.chart_type.eq(&ChartType::Packets) {
-                    self.min_sent_packets..self.max_received_packets
-                } else {
-                    self.min_sent_bytes..self.max_received_bytes
-                },
-            )
+            .build_cartesian_2d(first_time_displayed..last_time_displayed, y_min..y_max)
             .expect("Error drawing packets chart");
 
         // Mesh
@@ -129,6 +267,7 @@ fn build_chart<DB: DrawingBackend>(
                     .style(font_weight)
                     .color(&color_font),
             )
+            .x_label_formatter(&|tick| get_formatted_tick(*tick))
             .y_labels(7)
             .y_label_formatter(if self.chart_type.eq(&ChartType::Packets) {
                 &|packets| packets.abs().to_string()
@@ -138,19 +277,30 @@ fn build_chart<DB: DrawingBackend>(
             .draw()
             .unwrap();
 
+        // Time intervals in which the capture was paused
+        chart
+            .draw_series(
+                self.paused_ticks
+                    .iter()
+                    .filter(|tick| (first_time_displayed..=last_time_displayed).contains(tick))
+                    .map(|tick| {
+                        Rectangle::new(
+                            [
+                                (tick.saturating_sub(1).max(first_time_displayed), y_min),
+                                (*tick, y_max),
+                            ],
+                            color_font.mix(0.15).filled(),
+                        )
+                    }),
+            )
+            .expect("Error drawing graph");
+
         // Incoming series
         chart
             .draw_series(
-                AreaSeries::new(
-                    if self.chart_type.eq(&ChartType::Packets) {
-                        self.received_packets.iter().copied()
-                    } else {
-                        self.received_bytes.iter().copied()
-                    },
-                    0,
-                    color_incoming.mix(color_mix.into()),
-                )
-                .border_style(ShapeStyle::from(&color_incoming).stroke_width(CHARTS_LINE_BORDER)),
+                AreaSeries::new(incoming, 0, color_incoming.mix(color_mix.into())).border_style(
+                    ShapeStyle::from(&color_incoming).stroke_width(CHARTS_LINE_BORDER),
+                ),
             )
             .expect("Error drawing graph")
             .label(incoming_translation(self.language))
@@ -161,16 +311,9 @@ fn build_chart<DB: DrawingBackend>(
         // Outgoing series
         chart
             .draw_series(
-                AreaSeries::new(
-                    if self.chart_type.eq(&ChartType::Packets) {
-                        self.sent_packets.iter().copied()
-                    } else {
-                        self.sent_bytes.iter().copied()
-                    },
-                    0,
-                    color_outgoing.mix(color_mix.into()),
-                )
-                .border_style(ShapeStyle::from(&color_outgoing).stroke_width(CHARTS_LINE_BORDER)),
+                AreaSeries::new(outgoing, 0, color_outgoing.mix(color_mix.into())).border_style(
+                    ShapeStyle::from(&color_outgoing).stroke_width(CHARTS_LINE_BORDER),
+                ),
             )
             .expect("Error drawing graph")
             .label(outgoing_translation(self.language))
@@ -193,4 +336,137 @@ fn build_chart<DB: DrawingBackend>(
             .draw()
             .expect("Error drawing graph");
     }
+
+    /// Zooms in and out scrolling, and pans dragging the chart
+    fn update(
+        &self,
+        state: &mut Self::State,
+        event: Event,
+        bounds: iced::Rectangle,
+        cursor: Cursor,
+    ) -> (Status, Option<Message>) {
+        let Event::Mouse(mouse_event) = event else {
+            return (Status::Ignored, None);
+        };
+        match mouse_event {
+            mouse::Event::WheelScrolled { delta } if cursor.is_over(bounds) => {
+                let (mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. }) =
+                    delta;
+                if y == 0.0 {
+                    return (Status::Ignored, None);
+                }
+                (Status::Captured, Some(Message::ChartZoom(y > 0.0)))
+            }
+            mouse::Event::ButtonPressed(mouse::Button::Left) if cursor.is_over(bounds) => {
+                *state = cursor.position().map(|position| position.x);
+                (Status::Captured, None)
+            }
+            mouse::Event::CursorMoved { position } => {
+                let Some(origin) = *state else {
+                    return (Status::Ignored, None);
+                };
+                // width of the plotting area, without labels and margins
+                let width = (bounds.width - 90.0).max(1.0);
+                #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+                let seconds = ((position.x - origin) / width * self.span as f32) as i64;
+                if seconds == 0 {
+                    return (Status::Captured, None);
+                }
+                // keep the remainder to be added to the next movement
+                #[allow(clippy::cast_precision_loss)]
+                let moved = seconds as f32 * width / self.span as f32;
+                *state = Some(origin + moved);
+                (Status::Captured, Some(Message::ChartPan(seconds)))
+            }
+            mouse::Event::ButtonReleased(mouse::Button::Left) if state.is_some() => {
+                *state = None;
+                (Status::Captured, None)
+            }
+            _ => (Status::Ignored, None),
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Self::State,
+        bounds: iced::Rectangle,
+        cursor: Cursor,
+    ) -> mouse::Interaction {
+        if state.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(bounds) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::chart::types::chart_range::ChartRange;
+    use crate::{Language, StyleType, TrafficChart};
+
+    use super::get_formatted_tick;
+
+    #[test]
+    fn test_chart_zoom_and_pan() {
+        let mut traffic_chart = TrafficChart::new(StyleType::default(), Language::default());
+        traffic_chart.ticks = 7_200;
+        assert_eq!(traffic_chart.displayed_interval(), (7_139, 7_199));
+        assert_eq!(traffic_chart.displayed_series().resolution, 1);
+
+        traffic_chart.change_range(ChartRange::OneHour);
+        assert_eq!(traffic_chart.displayed_interval(), (3_599, 7_199));
+        assert_eq!(traffic_chart.displayed_series().resolution, 10);
+
+        // can't pan beyond the start of the capture
+        traffic_chart.pan(10_000);
+        assert_eq!(traffic_chart.offset, 3_599);
+        assert_eq!(traffic_chart.displayed_interval(), (0, 3_600));
+        traffic_chart.pan(-600);
+        assert_eq!(traffic_chart.displayed_interval(), (600, 4_200));
+
+        traffic_chart.zoom(false);
+        assert_eq!(traffic_chart.span, 7_200);
+        assert_eq!(traffic_chart.offset, 0);
+        assert_eq!(traffic_chart.displayed_series().resolution, 10);
+        traffic_chart.zoom(false);
+        assert_eq!(traffic_chart.displayed_series().resolution, 60);
+
+        for _ in 0..20 {
+            traffic_chart.zoom(true);
+        }
+        assert_eq!(traffic_chart.span, 10);
+        assert_eq!(traffic_chart.displayed_series().resolution, 1);
+        traffic_chart.pan(-5);
+        assert_eq!(traffic_chart.offset, 0);
+
+        traffic_chart.pan(5);
+        traffic_chart.change_range(ChartRange::OneMinute);
+        assert_eq!(traffic_chart.span, 60);
+        assert_eq!(traffic_chart.offset, 0);
+    }
+
+    #[test]
+    fn test_displayed_samples() {
+        let mut traffic_chart = TrafficChart::new(StyleType::default(), Language::default());
+        for tick in 0..100 {
+            traffic_chart.seconds.push(tick, -1, 2, -3, 4);
+        }
+        traffic_chart.ticks = 100;
+        traffic_chart.pan(20);
+
+        let (incoming, outgoing) = traffic_chart.displayed_samples();
+        assert_eq!(incoming.len(), 61);
+        assert_eq!(incoming.first(), Some(&(19, 2)));
+        assert_eq!(outgoing.last(), Some(&(79, -1)));
+    }
+
+    #[test]
+    fn test_get_formatted_tick() {
+        assert_eq!(get_formatted_tick(7), "0:07");
+        assert_eq!(get_formatted_tick(615), "10:15");
+        assert_eq!(get_formatted_tick(86_399), "23:59:59");
+    }
 }
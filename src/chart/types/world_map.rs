@@ -0,0 +1,563 @@
+//! This module defines the `WorldMap` struct, used to display the traffic of each country
+//! in gui map page
+
+use std::collections::HashMap;
+
+use iced::event::Status;
+use iced::mouse::Cursor;
+use iced::widget::canvas::Event;
+use iced::widget::Container;
+use iced::{mouse, Element, Length, Point, Rectangle, Renderer};
+use plotters::prelude::*;
+use plotters::style::text_anchor::{HPos, Pos, VPos};
+use plotters_iced::{Chart, ChartBuilder, ChartWidget, DrawingBackend};
+
+use crate::countries::map_data::{
+    get_country_coordinates, get_country_outline, CountryOutline, COUNTRY_OUTLINES,
+};
+use crate::countries::types::country::Country;
+use crate::gui::styles::types::palette::to_rgb_color;
+use crate::gui::types::message::Message;
+use crate::networking::types::data_info::DataInfo;
+use crate::networking::types::data_info_host::DataInfoHost;
+use crate::networking::types::host::Host;
+use crate::networking::types::search_parameters::SearchParameters;
+use crate::utils::formatted_strings::get_formatted_bytes_string_with_b;
+use crate::{ChartType, StyleType};
+
+/// Longitudes displayed in the map
+const LONGITUDES: (f64, f64) = (-180.0, 180.0);
+/// Latitudes displayed in the map (Antarctica is left out)
+const LATITUDES: (f64, f64) = (-60.0, 84.0);
+/// Width in pixels of the map
+pub const MAP_WIDTH: f32 = 1000.0;
+/// Height in pixels of the map, keeping the proportions of the displayed coordinates
+pub const MAP_HEIGHT: f32 = 400.0;
+/// Radius in pixels of the dots of the countries too small to be outlined
+const DOT_RADIUS: f64 = 3.0;
+/// Opacity of the country with the least traffic
+const MIN_OPACITY: f64 = 0.25;
+/// Opacity of the country with the most traffic
+const MAX_OPACITY: f64 = 0.95;
+
+/// Traffic exchanged with the hosts of a country
+#[derive(Clone, Copy)]
+pub struct CountryTraffic {
+    pub country: Country,
+    /// Approximate centroid of the country, as (longitude, latitude)
+    pub coordinates: (f64, f64),
+    /// Outline of the country (countries too small to be outlined are drawn as dots)
+    pub outline: Option<&'static CountryOutline>,
+    /// Incoming and outgoing packets and bytes
+    pub data_info: DataInfo,
+    /// Whether some of the hosts of the country are favorites
+    pub is_favorite: bool,
+}
+
+/// Struct defining the world map to be displayed in gui map page
+pub struct WorldMap {
+    /// Traffic of the countries, in descending order of traffic
+    pub countries: Vec<CountryTraffic>,
+    /// Packets or bytes
+    pub chart_type: ChartType,
+    /// Style of the map
+    pub style: StyleType,
+}
+
+impl WorldMap {
+    pub fn new(
+        hosts: &HashMap<Host, DataInfoHost>,
+        chart_type: ChartType,
+        style: StyleType,
+    ) -> Self {
+        let mut countries: HashMap<Country, CountryTraffic> = HashMap::new();
+        for (host, data_info_host) in hosts {
+            let Some(coordinates) = get_country_coordinates(host.country) else {
+                continue;
+            };
+            let country_traffic = countries
+                .entry(host.country)
+                .or_insert_with(|| CountryTraffic {
+                    country: host.country,
+                    coordinates,
+                    outline: get_country_outline(host.country),
+                    data_info: DataInfo::default(),
+                    is_favorite: false,
+                });
+            country_traffic.data_info += data_info_host.data_info;
+            country_traffic.is_favorite |= data_info_host.is_favorite;
+        }
+        let mut countries: Vec<CountryTraffic> = countries.into_values().collect();
+        countries.sort_by(|a, b| {
+            get_amount(b, chart_type)
+                .cmp(&get_amount(a, chart_type))
+                .then_with(|| a.country.to_string().cmp(&b.country.to_string()))
+        });
+        WorldMap {
+            countries,
+            chart_type,
+            style,
+        }
+    }
+
+    pub fn view(self) -> Element<'static, Message, Renderer<StyleType>> {
+        Container::new(
+            ChartWidget::new(self)
+                .width(Length::Fixed(MAP_WIDTH))
+                .height(Length::Fixed(MAP_HEIGHT)),
+        )
+        .into()
+    }
+
+    /// Opacity of the fill of a country, growing logarithmically with its traffic
+    fn opacity(&self, country_traffic: &CountryTraffic) -> f64 {
+        #[allow(clippy::cast_precision_loss)]
+        let to_log = |amount: u128| (1.0 + amount as f64).ln();
+        let max = self
+            .countries
+            .first()
+            .map_or(0, |first| get_amount(first, self.chart_type));
+        if max == 0 {
+            return MIN_OPACITY;
+        }
+        let ratio = to_log(get_amount(country_traffic, self.chart_type)) / to_log(max);
+        MIN_OPACITY + (MAX_OPACITY - MIN_OPACITY) * ratio
+    }
+
+    /// Returns the country with traffic at the given position, relative to the map
+    fn country_at(&self, width: f32, height: f32, position: Point) -> Option<Country> {
+        // dots are drawn over the outlines: check them first
+        let dot = self.countries.iter().find(|country_traffic| {
+            let (x, y) = project(country_traffic.coordinates, width, height);
+            let distance = (f64::from(position.x) - x).hypot(f64::from(position.y) - y);
+            country_traffic.outline.is_none() && distance <= DOT_RADIUS + 1.0
+        });
+        if let Some(country_traffic) = dot {
+            return Some(country_traffic.country);
+        }
+        let coordinates = unproject(position, width, height);
+        // the smallest country containing the position (enclaves are drawn over their surroundings)
+        self.countries
+            .iter()
+            .filter_map(|country_traffic| country_traffic.outline)
+            .filter(|outline| outline.contains(coordinates))
+            .min_by(|a, b| a.area.total_cmp(&b.area))
+            .map(|outline| outline.country)
+    }
+}
+
+/// Returns the packets or bytes exchanged with a country
+fn get_amount(country_traffic: &CountryTraffic, chart_type: ChartType) -> u128 {
+    if chart_type.eq(&ChartType::Packets) {
+        country_traffic.data_info.tot_packets()
+    } else {
+        country_traffic.data_info.tot_bytes()
+    }
+}
+
+/// Keeps the coordinates in the displayed area
+fn clamp((longitude, latitude): (f64, f64)) -> (f64, f64) {
+    (
+        longitude.clamp(LONGITUDES.0, LONGITUDES.1),
+        latitude.clamp(LATITUDES.0, LATITUDES.1),
+    )
+}
+
+/// Position in pixels of the given coordinates, in a map of the given size
+fn project(coordinates: (f64, f64), width: f32, height: f32) -> (f64, f64) {
+    let (longitude, latitude) = clamp(coordinates);
+    let x = (longitude - LONGITUDES.0) / (LONGITUDES.1 - LONGITUDES.0) * f64::from(width);
+    let y = (LATITUDES.1 - latitude) / (LATITUDES.1 - LATITUDES.0) * f64::from(height);
+    (x, y)
+}
+
+/// Coordinates of the given position in pixels, in a map of the given size
+fn unproject(position: Point, width: f32, height: f32) -> (f64, f64) {
+    let longitude =
+        LONGITUDES.0 + f64::from(position.x) / f64::from(width) * (LONGITUDES.1 - LONGITUDES.0);
+    let latitude =
+        LATITUDES.1 - f64::from(position.y) / f64::from(height) * (LATITUDES.1 - LATITUDES.0);
+    (longitude, latitude)
+}
+
+/// Vertices of a ring, kept in the displayed area
+fn clamp_ring(ring: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    ring.iter().map(|vertex| clamp(*vertex)).collect()
+}
+
+/// Vertices of a ring, closed to be drawn as a path
+fn close_ring(ring: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let mut path = clamp_ring(ring);
+    path.extend(path.first().copied());
+    path
+}
+
+impl Chart<Message> for WorldMap {
+    /// Country hovered
+    type State = Option<Country>;
+
+    fn build_chart<DB: DrawingBackend>(
+        &self,
+        state: &Self::State,
+        mut chart_builder: ChartBuilder<DB>,
+    ) {
+        let font_weight = self.style.get_font_weight();
+        let colors = self.style.get_palette();
+        let color_sea = to_rgb_color(colors.primary);
+        let color_land = to_rgb_color(colors.generate_buttons_color());
+        let color_marker = to_rgb_color(colors.secondary);
+        let color_starred = to_rgb_color(colors.starred);
+        let color_font = to_rgb_color(colors.text_body);
+
+        let mut chart = chart_builder
+            .margin(0)
+            .build_cartesian_2d(LONGITUDES.0..LONGITUDES.1, LATITUDES.0..LATITUDES.1)
+            .expect("Error drawing map");
+
+        // Countries, with their borders
+        chart
+            .draw_series(COUNTRY_OUTLINES.iter().flat_map(|outline| {
+                outline
+                    .rings
+                    .iter()
+                    .map(|ring| Polygon::new(clamp_ring(ring), color_land.filled()))
+            }))
+            .expect("Error drawing map");
+        chart
+            .draw_series(COUNTRY_OUTLINES.iter().flat_map(|outline| {
+                outline
+                    .rings
+                    .iter()
+                    .map(|ring| PathElement::new(close_ring(ring), color_sea.stroke_width(1)))
+            }))
+            .expect("Error drawing map");
+
+        // Countries with traffic, shaded according to the amount exchanged (larger ones first)
+        let mut outlined: Vec<(&CountryTraffic, &CountryOutline)> = self
+            .countries
+            .iter()
+            .filter_map(|country_traffic| Some((country_traffic, country_traffic.outline?)))
+            .collect();
+        outlined.sort_by(|(_, a), (_, b)| b.area.total_cmp(&a.area));
+        for (country_traffic, outline) in outlined {
+            let fill = color_marker.mix(self.opacity(country_traffic)).filled();
+            let border = if state.eq(&Some(country_traffic.country)) {
+                Some(color_font.stroke_width(2))
+            } else if country_traffic.is_favorite {
+                Some(color_starred.stroke_width(2))
+            } else {
+                None
+            };
+            chart
+                .draw_series(
+                    outline
+                        .rings
+                        .iter()
+                        .map(|ring| Polygon::new(clamp_ring(ring), fill)),
+                )
+                .expect("Error drawing map");
+            if let Some(border) = border {
+                chart
+                    .draw_series(
+                        outline
+                            .rings
+                            .iter()
+                            .map(|ring| PathElement::new(close_ring(ring), border)),
+                    )
+                    .expect("Error drawing map");
+            }
+        }
+
+        // Countries too small to be outlined
+        chart
+            .draw_series(
+                self.countries
+                    .iter()
+                    .filter(|country_traffic| country_traffic.outline.is_none())
+                    .flat_map(|country_traffic| {
+                        let coordinates = clamp(country_traffic.coordinates);
+                        let opacity = self.opacity(country_traffic);
+                        let mut dot = vec![
+                            Circle::new(
+                                coordinates,
+                                DOT_RADIUS,
+                                color_marker.mix(opacity).filled(),
+                            ),
+                            Circle::new(coordinates, DOT_RADIUS, color_marker.stroke_width(1)),
+                        ];
+                        if country_traffic.is_favorite {
+                            dot.push(Circle::new(
+                                coordinates,
+                                DOT_RADIUS + 3.0,
+                                color_starred.stroke_width(2),
+                            ));
+                        }
+                        if state.eq(&Some(country_traffic.country)) {
+                            dot.push(Circle::new(
+                                coordinates,
+                                DOT_RADIUS,
+                                color_font.stroke_width(2),
+                            ));
+                        }
+                        dot
+                    }),
+            )
+            .expect("Error drawing map");
+
+        // Label of the hovered country
+        if let Some(country_traffic) = self
+            .countries
+            .iter()
+            .find(|country_traffic| state.eq(&Some(country_traffic.country)))
+        {
+            let amount = get_amount(country_traffic, self.chart_type);
+            let label = format!(
+                "{}  {}",
+                country_traffic.country,
+                if self.chart_type.eq(&ChartType::Packets) {
+                    amount.to_string()
+                } else {
+                    get_formatted_bytes_string_with_b(amount)
+                }
+            );
+            let coordinates = clamp(country_traffic.coordinates);
+            // keep the label inside the map
+            let (h_pos, direction) = if coordinates.0 > 90.0 {
+                (HPos::Right, -1)
+            } else {
+                (HPos::Left, 1)
+            };
+            let offset = direction * 10;
+            chart
+                .draw_series(std::iter::once(
+                    EmptyElement::at(coordinates)
+                        + Text::new(
+                            label,
+                            (offset, 0),
+                            ("Sarasa Mono SC", 15)
+                                .into_font()
+                                .style(font_weight)
+                                .color(&color_font)
+                                .pos(Pos::new(h_pos, VPos::Center)),
+                        ),
+                ))
+                .expect("Error drawing map");
+        }
+    }
+
+    /// Highlights the hovered country, and inspects its hosts when clicked
+    fn update(
+        &self,
+        state: &mut Self::State,
+        event: Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> (Status, Option<Message>) {
+        let Event::Mouse(mouse_event) = event else {
+            return (Status::Ignored, None);
+        };
+        let hovered = cursor
+            .position_in(bounds)
+            .and_then(|position| self.country_at(bounds.width, bounds.height, position));
+        match mouse_event {
+            mouse::Event::CursorMoved { .. } => {
+                *state = hovered;
+                (Status::Ignored, None)
+            }
+            mouse::Event::ButtonPressed(mouse::Button::Left) => match hovered {
+                Some(country) => (
+                    Status::Captured,
+                    Some(Message::Search(SearchParameters {
+                        country: country.to_string(),
+                        ..SearchParameters::default()
+                    })),
+                ),
+                None => (Status::Ignored, None),
+            },
+            _ => (Status::Ignored, None),
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Self::State,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> mouse::Interaction {
+        if cursor
+            .position_in(bounds)
+            .and_then(|position| self.country_at(bounds.width, bounds.height, position))
+            .is_some()
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::networking::types::asn::Asn;
+
+    use super::*;
+
+    fn host(domain: &str, country: Country) -> Host {
+        Host {
+            domain: domain.to_string(),
+            asn: Asn::default(),
+            country,
+        }
+    }
+
+    fn data_info_host(bytes: u128, packets: u128, is_favorite: bool) -> DataInfoHost {
+        DataInfoHost {
+            data_info: DataInfo {
+                incoming_packets: packets,
+                outgoing_packets: 0,
+                incoming_bytes: bytes,
+                outgoing_bytes: 0,
+            },
+            is_favorite,
+            ..DataInfoHost::default()
+        }
+    }
+
+    #[test]
+    fn test_world_map_countries() {
+        let hosts = HashMap::from([
+            (host("a.it", Country::IT), data_info_host(5_000, 5, false)),
+            (host("b.it", Country::IT), data_info_host(1_000, 40, true)),
+            (host("a.us", Country::US), data_info_host(9_000, 10, false)),
+            (
+                host("local", Country::ZZ),
+                data_info_host(50_000, 100, false),
+            ),
+        ]);
+
+        let world_map = WorldMap::new(&hosts, ChartType::Bytes, StyleType::default());
+        // hosts of unknown country are left out
+        assert_eq!(world_map.countries.len(), 2);
+        assert_eq!(world_map.countries[0].country, Country::US);
+        assert!(!world_map.countries[0].is_favorite);
+        assert_eq!(world_map.countries[1].country, Country::IT);
+        assert_eq!(world_map.countries[1].data_info.tot_bytes(), 6_000);
+        assert_eq!(world_map.countries[1].data_info.tot_packets(), 45);
+        assert!(world_map.countries[1].is_favorite);
+        assert!((world_map.opacity(&world_map.countries[0]) - MAX_OPACITY).abs() < 1e-9);
+        assert!(world_map.opacity(&world_map.countries[1]) < MAX_OPACITY);
+        assert!(world_map.countries[0].outline.is_some());
+
+        let world_map = WorldMap::new(&hosts, ChartType::Packets, StyleType::default());
+        assert_eq!(world_map.countries[0].country, Country::IT);
+        assert_eq!(world_map.countries[1].country, Country::US);
+    }
+
+    #[test]
+    fn test_world_map_country_at() {
+        let hosts = HashMap::from([
+            (host("a.it", Country::IT), data_info_host(5_000, 5, false)),
+            (host("a.us", Country::US), data_info_host(9_000, 10, false)),
+            (host("a.sg", Country::SG), data_info_host(1_000, 1, false)),
+        ]);
+        let world_map = WorldMap::new(&hosts, ChartType::Bytes, StyleType::default());
+        let position = |coordinates| {
+            let (x, y) = project(coordinates, MAP_WIDTH, MAP_HEIGHT);
+            #[allow(clippy::cast_possible_truncation)]
+            Point::new(x as f32, y as f32)
+        };
+
+        // anywhere inside the outline of the country
+        for coordinates in [(9.2, 45.5), (16.0, 40.5), (-87.6, 41.9), (-100.0, 32.0)] {
+            assert!(world_map
+                .country_at(MAP_WIDTH, MAP_HEIGHT, position(coordinates))
+                .is_some());
+        }
+        // France, without traffic
+        assert_eq!(
+            world_map.country_at(MAP_WIDTH, MAP_HEIGHT, position((2.3, 47.0))),
+            None
+        );
+        // Singapore is too small to be outlined
+        assert!(world_map.countries[2].outline.is_none());
+        assert_eq!(
+            world_map.country_at(MAP_WIDTH, MAP_HEIGHT, position((103.8, 1.4))),
+            Some(Country::SG)
+        );
+
+        let (x, y) = project((12.6, 42.8), MAP_WIDTH, MAP_HEIGHT);
+        #[allow(clippy::cast_possible_truncation)]
+        let italy = Point::new(x as f32, y as f32);
+        assert_eq!(
+            world_map.country_at(MAP_WIDTH, MAP_HEIGHT, italy),
+            Some(Country::IT)
+        );
+        assert_eq!(
+            world_map.country_at(
+                MAP_WIDTH,
+                MAP_HEIGHT,
+                Point::new(italy.x - 2.0, italy.y - 2.0)
+            ),
+            Some(Country::IT)
+        );
+        // the Atlantic Ocean
+        assert_eq!(
+            world_map.country_at(MAP_WIDTH, MAP_HEIGHT, Point::new(400.0, 180.0)),
+            None
+        );
+        // the map is scaled with its bounds
+        assert_eq!(
+            world_map.country_at(MAP_WIDTH / 2.0, MAP_HEIGHT / 2.0, italy),
+            None
+        );
+        assert_eq!(
+            world_map.country_at(
+                MAP_WIDTH / 2.0,
+                MAP_HEIGHT / 2.0,
+                Point::new(italy.x / 2.0, italy.y / 2.0)
+            ),
+            Some(Country::IT)
+        );
+    }
+
+    #[test]
+    fn test_country_outlines() {
+        assert!(COUNTRY_OUTLINES.len() > 150);
+        assert!(COUNTRY_OUTLINES
+            .windows(2)
+            .all(|pair| pair[0].area >= pair[1].area));
+        let country_at = |coordinates| {
+            COUNTRY_OUTLINES
+                .iter()
+                .rev()
+                .find(|outline| outline.contains(coordinates))
+                .map(|outline| outline.country)
+        };
+        assert_eq!(country_at((12.5, 41.9)), Some(Country::IT));
+        assert_eq!(country_at((2.3, 47.0)), Some(Country::FR));
+        assert_eq!(country_at((-3.7, 40.4)), Some(Country::ES));
+        assert_eq!(country_at((-95.0, 38.0)), Some(Country::US));
+        assert_eq!(country_at((-47.9, -15.8)), Some(Country::BR));
+        assert_eq!(country_at((133.9, -23.7)), Some(Country::AU));
+        assert_eq!(country_at((138.0, 36.0)), Some(Country::JP));
+        assert_eq!(country_at((37.6, 55.7)), Some(Country::RU));
+        // Lesotho is surrounded by South Africa
+        assert_eq!(country_at((28.2, -29.6)), Some(Country::LS));
+        assert_eq!(country_at((25.0, -29.0)), Some(Country::ZA));
+        // the Atlantic Ocean
+        assert_eq!(country_at((-30.0, 30.0)), None);
+        assert!(get_country_outline(Country::SG).is_none());
+    }
+
+    #[test]
+    fn test_country_coordinates() {
+        for code in ["IT", "US", "JP", "BR", "AU", "ZA", "AQ"] {
+            let (longitude, latitude) = get_country_coordinates(Country::from_str(code)).unwrap();
+            assert!((-180.0..=180.0).contains(&longitude));
+            assert!((-90.0..=90.0).contains(&latitude));
+        }
+        assert_eq!(get_country_coordinates(Country::ZZ), None);
+        // Antarctica is drawn at the bottom edge of the map
+        assert_eq!(project((0.0, -75.0), MAP_WIDTH, MAP_HEIGHT), (500.0, 400.0));
+    }
+}
@@ -49,14 +49,32 @@ fn unknown_argument(arg: &str) {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::{HashMap, HashSet};
     use std::sync::{Arc, Mutex};
 
+    use chrono::NaiveDate;
     use serial_test::serial;
 
+    use crate::configs::types::config_baseline::{ConfigBaseline, ListeningPort};
+    use crate::configs::types::config_usage::{AdapterUsage, ConfigUsage};
+    use crate::countries::types::country::Country;
+    use crate::flow_export::types::flow_export_settings::FlowExportSettings;
+    use crate::flow_export::types::flow_protocol::FlowProtocol;
     use crate::gui::styles::types::custom_palette::ExtraStyles;
     use crate::gui::styles::types::gradient_type::GradientType;
-    use crate::notifications::types::notifications::Notifications;
-    use crate::{ConfigDevice, ConfigSettings, ConfigWindow, Language, Sniffer, StyleType};
+    use crate::notifications::types::alert_trigger::{AlertTrigger, TriggerMode};
+    use crate::notifications::types::notification_action::{NotificationAction, NotificationKind};
+    use crate::notifications::types::notification_rule::{
+        NotificationRule, RuleDirection, RuleScope,
+    };
+    use crate::notifications::types::notifications::{
+        FirstSeenNotification, Notifications, QuotaNotification,
+    };
+    use crate::notifications::types::sound::Sound;
+    use crate::{
+        ByteMultiple, ConfigDevice, ConfigSettings, ConfigWindow, Language, Protocol, Sniffer,
+        StyleType,
+    };
 
     use super::*;
 
@@ -72,6 +90,16 @@ fn test_restore_default_configs() {
                 scale_factor: 0.65,
                 mmdb_country: "countrymmdb".to_string(),
                 mmdb_asn: "asnmmdb".to_string(),
+                oui_db: "ouidb".to_string(),
+                history: true,
+                history_retention: 7,
+                metrics: true,
+                metrics_address: "0.0.0.0:9000".to_string(),
+                api: true,
+                api_address: "0.0.0.0:9001".to_string(),
+                api_token: "token".to_string(),
+                events: true,
+                events_output: "/tmp/events.jsonl".to_string(),
                 style_path: format!(
                     "{}/resources/themes/catppuccin.toml",
                     env!("CARGO_MANIFEST_DIR")
@@ -81,6 +109,42 @@ fn test_restore_default_configs() {
                     packets_notification: Default::default(),
                     bytes_notification: Default::default(),
                     favorite_notification: Default::default(),
+                    first_seen_notification: FirstSeenNotification::on(Sound::Pop),
+                    new_port_notification: Default::default(),
+                    blocklist_notification: Default::default(),
+                    scan_notification: Default::default(),
+                    quota_notification: QuotaNotification {
+                        cap: Some(20_000_000_000),
+                        cycle_start_day: 8,
+                        previous_cap: 20_000_000_000,
+                        ..QuotaNotification::default()
+                    },
+                    trigger: AlertTrigger {
+                        mode: TriggerMode::Consecutive,
+                        window: 3,
+                        cooldown: 30,
+                    },
+                },
+                notification_rules: vec![NotificationRule {
+                    scope: RuleScope::Asn,
+                    value: "13335".to_string(),
+                    direction: RuleDirection::Outgoing,
+                    threshold: 5_000_000,
+                    byte_multiple: ByteMultiple::MB,
+                    sound: Sound::Pop,
+                }],
+                notification_actions: vec![NotificationAction {
+                    kind: NotificationKind::All,
+                    command: "/usr/local/bin/page-oncall".to_string(),
+                    webhook: "https://soar.example.com/hooks/sniffnet".to_string(),
+                }],
+                blocklists: vec!["/etc/blocklists/feodo.txt".to_string()],
+                flow_export: FlowExportSettings {
+                    enabled: true,
+                    collector: "10.0.0.1:4739".to_string(),
+                    protocol: FlowProtocol::Ipfix,
+                    active_timeout: 120,
+                    inactive_timeout: 30,
                 },
                 style: StyleType::Custom(ExtraStyles::DraculaDark),
             },
@@ -91,6 +155,27 @@ fn test_restore_default_configs() {
                 position: (440, 99),
                 size: (452, 870),
             },
+            baseline: ConfigBaseline {
+                hosts: HashSet::from(["example.com".to_string()]),
+                countries: HashSet::from([Country::IT]),
+                asns: HashSet::from([137]),
+                listening_ports: HashSet::from([ListeningPort {
+                    port: 22,
+                    protocol: Protocol::TCP,
+                }]),
+                learning_time: 1800,
+            },
+            usage: ConfigUsage {
+                adapters: HashMap::from([(
+                    "wwan0".to_string(),
+                    AdapterUsage {
+                        cycle_start: NaiveDate::from_ymd_opt(2024, 5, 8).unwrap(),
+                        sent_bytes: 1_200_000_000,
+                        received_bytes: 15_300_000_000,
+                        notified_level: 80,
+                    },
+                )]),
+            },
         };
         // we want to be sure that modified config is different from defaults
         assert_ne!(Configs::default(), modified_configs);
@@ -107,6 +192,7 @@ fn test_restore_default_configs() {
         Sniffer::new(
             &Arc::new(Mutex::new(Configs::default())),
             Arc::new(Mutex::new(Some(true))),
+            None,
         );
     }
 }
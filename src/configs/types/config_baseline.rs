@@ -0,0 +1,300 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::countries::types::country::Country;
+use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::networking::types::data_info_host::DataInfoHost;
+use crate::networking::types::host::Host;
+use crate::networking::types::traffic_direction::TrafficDirection;
+use crate::notifications::types::logged_notification::FirstSeenTarget;
+#[cfg(not(test))]
+use crate::SNIFFNET_LOWERCASE;
+use crate::{InfoTraffic, Protocol};
+
+/// Seconds of capture during which the baseline is learned without notifying the novelties
+pub const LEARNING_WINDOW: u64 = 60 * 60;
+
+/// Hosts, countries, ASNs and local listening ports observed in the past sessions;
+/// the novelties with respect to it are notified when they are first seen
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct ConfigBaseline {
+    pub hosts: HashSet<String>,
+    pub countries: HashSet<Country>,
+    pub asns: HashSet<u32>,
+    pub listening_ports: HashSet<ListeningPort>,
+    /// Seconds of capture spent learning the baseline, up to the learning window
+    #[serde(default)]
+    pub learning_time: u64,
+}
+
+/// Local port which accepted incoming connections
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ListeningPort {
+    pub port: u16,
+    pub protocol: Protocol,
+}
+
+impl ConfigBaseline {
+    const FILE_NAME: &'static str = "baseline";
+    #[cfg(not(test))]
+    pub fn load() -> Self {
+        if let Ok(baseline) = confy::load::<ConfigBaseline>(SNIFFNET_LOWERCASE, Self::FILE_NAME) {
+            baseline
+        } else {
+            confy::store(
+                SNIFFNET_LOWERCASE,
+                Self::FILE_NAME,
+                ConfigBaseline::default(),
+            )
+            .unwrap_or(());
+            ConfigBaseline::default()
+        }
+    }
+
+    #[cfg(not(test))]
+    pub fn store(self) {
+        confy::store(SNIFFNET_LOWERCASE, Self::FILE_NAME, self).unwrap_or(());
+    }
+
+    /// Whether the baseline is still being learned, so that its novelties are not notified
+    pub fn is_learning(&self) -> bool {
+        self.learning_time < LEARNING_WINDOW
+    }
+
+    /// Accounts a second of capture to the learning window
+    pub fn advance_learning(&mut self) {
+        if self.is_learning() {
+            self.learning_time += 1;
+        }
+    }
+
+    /// Adds to the baseline the hosts exchanging data, returning the ones
+    /// whose domain, country, or ASN wasn't part of it yet
+    pub fn learn_targets(
+        &mut self,
+        info_traffic: &InfoTraffic,
+    ) -> Vec<(FirstSeenTarget, Host, DataInfoHost)> {
+        let mut first_seen = Vec::new();
+        for (host, data_info_host) in &info_traffic.hosts {
+            if !host.domain.is_empty() && self.hosts.insert(host.domain.clone()) {
+                first_seen.push((FirstSeenTarget::Host, host.clone(), data_info_host.clone()));
+            }
+            // unknown countries and ASNs are not part of the baseline
+            if host.country.ne(&Country::ZZ) && self.countries.insert(host.country) {
+                first_seen.push((
+                    FirstSeenTarget::Country,
+                    host.clone(),
+                    data_info_host.clone(),
+                ));
+            }
+            if host.asn.number != 0 && self.asns.insert(host.asn.number) {
+                first_seen.push((FirstSeenTarget::Asn, host.clone(), data_info_host.clone()));
+            }
+        }
+        first_seen
+    }
+
+    /// Adds to the baseline the local ports accepting incoming connections, returning the ones
+    /// which weren't part of it yet together with the address of the remote peer.
+    ///
+    /// A connection is considered incoming if the remote peer sent the first packet,
+    /// and accepted if the local host replied to it.
+    pub fn learn_listening_ports(
+        &mut self,
+        info_traffic: &InfoTraffic,
+    ) -> Vec<(ListeningPort, String)> {
+        let mut first_seen = Vec::new();
+        for (key, info) in &info_traffic.map {
+            let (Some(remote_port), Some(local_port)) = (key.port1, key.port2) else {
+                continue;
+            };
+            if info.traffic_direction.ne(&TrafficDirection::Incoming) {
+                continue;
+            }
+            let reply_key = AddressPortPair::new(
+                key.address2.clone(),
+                Some(local_port),
+                key.address1.clone(),
+                Some(remote_port),
+                key.protocol,
+                key.interface.clone(),
+            );
+            let Some(reply) = info_traffic.map.get(&reply_key) else {
+                continue;
+            };
+            if reply.initial_timestamp < info.initial_timestamp {
+                // the local host started the connection
+                continue;
+            }
+            let listening_port = ListeningPort {
+                port: local_port,
+                protocol: key.protocol,
+            };
+            if self.listening_ports.insert(listening_port) {
+                first_seen.push((listening_port, key.address1.clone()));
+            }
+        }
+        first_seen
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Local};
+
+    use crate::networking::types::asn::Asn;
+    use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
+
+    use super::*;
+
+    impl ConfigBaseline {
+        pub fn test_path() -> String {
+            format!("{}/{}.toml", env!("CARGO_MANIFEST_DIR"), Self::FILE_NAME)
+        }
+
+        pub fn load() -> Self {
+            confy::load_path::<ConfigBaseline>(ConfigBaseline::test_path())
+                .unwrap_or_else(|_| ConfigBaseline::default())
+        }
+
+        pub fn store(self) {
+            confy::store_path(ConfigBaseline::test_path(), self).unwrap_or(());
+        }
+    }
+
+    fn host(domain: &str, asn: u32, country: Country) -> Host {
+        Host {
+            domain: domain.to_string(),
+            asn: Asn {
+                number: asn,
+                name: String::new(),
+            },
+            country,
+        }
+    }
+
+    fn insert_flow(
+        info_traffic: &mut InfoTraffic,
+        source: (&str, u16),
+        destination: (&str, u16),
+        traffic_direction: TrafficDirection,
+        seconds_ago: i64,
+    ) {
+        info_traffic.map.insert(
+            AddressPortPair::new(
+                source.0.to_string(),
+                Some(source.1),
+                destination.0.to_string(),
+                Some(destination.1),
+                Protocol::TCP,
+                "eth0".to_string(),
+            ),
+            InfoAddressPortPair {
+                traffic_direction,
+                initial_timestamp: Local::now() - Duration::seconds(seconds_ago),
+                ..InfoAddressPortPair::default()
+            },
+        );
+    }
+
+    #[test]
+    fn test_learn_targets() {
+        let mut baseline = ConfigBaseline {
+            hosts: HashSet::from(["known.com".to_string()]),
+            countries: HashSet::from([Country::US]),
+            asns: HashSet::from([15169]),
+            ..ConfigBaseline::default()
+        };
+        let mut info_traffic = InfoTraffic::new();
+        info_traffic.hosts.insert(
+            host("known.com", 15169, Country::US),
+            DataInfoHost::default(),
+        );
+        assert!(baseline.learn_targets(&info_traffic).is_empty());
+
+        info_traffic
+            .hosts
+            .insert(host("new.it", 12874, Country::IT), DataInfoHost::default());
+        info_traffic
+            .hosts
+            .insert(host("", 0, Country::ZZ), DataInfoHost::default());
+        let mut first_seen: Vec<FirstSeenTarget> = baseline
+            .learn_targets(&info_traffic)
+            .into_iter()
+            .map(|(target, host, _)| {
+                assert_eq!(host.domain, "new.it");
+                target
+            })
+            .collect();
+        first_seen.sort_by_key(|target| format!("{target:?}"));
+        assert_eq!(
+            first_seen,
+            vec![
+                FirstSeenTarget::Asn,
+                FirstSeenTarget::Country,
+                FirstSeenTarget::Host
+            ]
+        );
+        assert!(baseline.countries.contains(&Country::IT));
+        assert!(!baseline.countries.contains(&Country::ZZ));
+
+        // already part of the baseline
+        assert!(baseline.learn_targets(&info_traffic).is_empty());
+    }
+
+    #[test]
+    fn test_learn_listening_ports() {
+        let mut baseline = ConfigBaseline::default();
+        let mut info_traffic = InfoTraffic::new();
+        // connection accepted on the local port 22
+        insert_flow(
+            &mut info_traffic,
+            ("10.0.0.5", 50000),
+            ("192.168.1.2", 22),
+            TrafficDirection::Incoming,
+            5,
+        );
+        insert_flow(
+            &mut info_traffic,
+            ("192.168.1.2", 22),
+            ("10.0.0.5", 50000),
+            TrafficDirection::Outgoing,
+            4,
+        );
+        // connection started by the local host
+        insert_flow(
+            &mut info_traffic,
+            ("192.168.1.2", 40000),
+            ("1.1.1.1", 443),
+            TrafficDirection::Outgoing,
+            5,
+        );
+        insert_flow(
+            &mut info_traffic,
+            ("1.1.1.1", 443),
+            ("192.168.1.2", 40000),
+            TrafficDirection::Incoming,
+            4,
+        );
+        // connection attempt without reply
+        insert_flow(
+            &mut info_traffic,
+            ("10.0.0.7", 50001),
+            ("192.168.1.2", 23),
+            TrafficDirection::Incoming,
+            3,
+        );
+
+        let ssh = ListeningPort {
+            port: 22,
+            protocol: Protocol::TCP,
+        };
+        assert_eq!(
+            baseline.learn_listening_ports(&info_traffic),
+            vec![(ssh, "10.0.0.5".to_string())]
+        );
+        assert_eq!(baseline.listening_ports, HashSet::from([ssh]));
+        assert!(baseline.learn_listening_ports(&info_traffic).is_empty());
+    }
+}
@@ -3,8 +3,14 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::flow_export::types::flow_export_settings::FlowExportSettings;
 use crate::gui::styles::types::gradient_type::GradientType;
+use crate::notifications::types::notification_action::NotificationAction;
+use crate::notifications::types::notification_rule::NotificationRule;
 use crate::notifications::types::notifications::Notifications;
+use crate::secondary_threads::serve_api::DEFAULT_API_ADDRESS;
+use crate::secondary_threads::serve_metrics::DEFAULT_METRICS_ADDRESS;
+use crate::secondary_threads::write_events::STDOUT_OUTPUT;
 #[cfg(not(test))]
 use crate::SNIFFNET_LOWERCASE;
 use crate::{Language, StyleType};
@@ -16,8 +22,24 @@ pub struct ConfigSettings {
     pub scale_factor: f64,
     pub mmdb_country: String,
     pub mmdb_asn: String,
+    pub oui_db: String,
+    pub history: bool,
+    /// Number of days the traffic history is kept for
+    pub history_retention: u16,
+    pub metrics: bool,
+    pub metrics_address: String,
+    pub api: bool,
+    pub api_address: String,
+    /// Token required to authenticate to the local API, generated at the first launch
+    pub api_token: String,
+    pub events: bool,
+    pub events_output: String,
     pub style_path: String,
     pub notifications: Notifications,
+    pub notification_rules: Vec<NotificationRule>,
+    pub notification_actions: Vec<NotificationAction>,
+    pub blocklists: Vec<String>,
+    pub flow_export: FlowExportSettings,
     // StyleType should be last in order to deserialize as a table properly
     pub style: StyleType,
 }
@@ -54,8 +76,22 @@ fn default() -> Self {
             scale_factor: 1.0,
             mmdb_country: String::new(),
             mmdb_asn: String::new(),
+            oui_db: String::new(),
+            history: false,
+            history_retention: 30,
+            metrics: false,
+            metrics_address: DEFAULT_METRICS_ADDRESS.to_string(),
+            api: false,
+            api_address: DEFAULT_API_ADDRESS.to_string(),
+            api_token: String::new(),
+            events: false,
+            events_output: STDOUT_OUTPUT.to_string(),
             style_path: String::new(),
             notifications: Notifications::default(),
+            notification_rules: Vec::new(),
+            notification_actions: Vec::new(),
+            blocklists: Vec::new(),
+            flow_export: FlowExportSettings::default(),
             style: StyleType::default(),
         }
     }
@@ -0,0 +1,282 @@
+use std::collections::HashMap;
+
+use chrono::{Datelike, Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::networking::types::data_info::DataInfo;
+#[cfg(not(test))]
+use crate::SNIFFNET_LOWERCASE;
+
+/// Percentages of the data cap whose reaching is notified
+pub const QUOTA_LEVELS: [u8; 3] = [80, 90, 100];
+
+/// Data exchanged by each network adapter in the current billing cycle,
+/// accumulated across the sessions
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct ConfigUsage {
+    pub adapters: HashMap<String, AdapterUsage>,
+}
+
+/// Data exchanged by a network adapter in a billing cycle
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct AdapterUsage {
+    /// First day of the billing cycle
+    pub cycle_start: NaiveDate,
+    pub sent_bytes: u64,
+    pub received_bytes: u64,
+    /// Highest percentage of the cap already notified in the billing cycle
+    pub notified_level: u8,
+}
+
+impl AdapterUsage {
+    fn new(cycle_start: NaiveDate) -> Self {
+        AdapterUsage {
+            cycle_start,
+            sent_bytes: 0,
+            received_bytes: 0,
+            notified_level: 0,
+        }
+    }
+
+    pub fn tot_bytes(&self) -> u64 {
+        self.sent_bytes.saturating_add(self.received_bytes)
+    }
+
+    /// Percentage of the cap used, rounded down
+    pub fn percentage(&self, cap: u64) -> u64 {
+        if cap == 0 {
+            return 100;
+        }
+        u64::try_from(u128::from(self.tot_bytes()) * 100 / u128::from(cap)).unwrap_or(u64::MAX)
+    }
+}
+
+impl ConfigUsage {
+    const FILE_NAME: &'static str = "usage";
+    #[cfg(not(test))]
+    pub fn load() -> Self {
+        if let Ok(usage) = confy::load::<ConfigUsage>(SNIFFNET_LOWERCASE, Self::FILE_NAME) {
+            usage
+        } else {
+            confy::store(SNIFFNET_LOWERCASE, Self::FILE_NAME, ConfigUsage::default()).unwrap_or(());
+            ConfigUsage::default()
+        }
+    }
+
+    #[cfg(not(test))]
+    pub fn store(self) {
+        confy::store(SNIFFNET_LOWERCASE, Self::FILE_NAME, self).unwrap_or(());
+    }
+
+    /// Adds to the usage of each adapter the data it exchanged since the last call,
+    /// starting over when a new billing cycle begins
+    pub fn record(
+        &mut self,
+        adapters: &HashMap<String, DataInfo>,
+        accounted: &mut HashMap<String, DataInfo>,
+        cycle_start_day: u8,
+        today: NaiveDate,
+    ) {
+        let cycle_start = get_cycle_start(today, cycle_start_day);
+        for (adapter, data_info) in adapters {
+            let previous = accounted
+                .insert(adapter.clone(), *data_info)
+                .unwrap_or_default();
+            // counters going backwards started over (e.g. the capture has been reset)
+            let sent = data_info
+                .outgoing_bytes
+                .checked_sub(previous.outgoing_bytes)
+                .unwrap_or(data_info.outgoing_bytes);
+            let received = data_info
+                .incoming_bytes
+                .checked_sub(previous.incoming_bytes)
+                .unwrap_or(data_info.incoming_bytes);
+            let usage = self.current_usage_mut(adapter, cycle_start);
+            usage.sent_bytes = usage
+                .sent_bytes
+                .saturating_add(u64::try_from(sent).unwrap_or(u64::MAX));
+            usage.received_bytes = usage
+                .received_bytes
+                .saturating_add(u64::try_from(received).unwrap_or(u64::MAX));
+        }
+    }
+
+    /// Returns the usage of the adapter in the current billing cycle
+    pub fn get_usage(&self, adapter: &str, cycle_start_day: u8, today: NaiveDate) -> AdapterUsage {
+        let cycle_start = get_cycle_start(today, cycle_start_day);
+        match self.adapters.get(adapter) {
+            Some(usage) if usage.cycle_start == cycle_start => *usage,
+            _ => AdapterUsage::new(cycle_start),
+        }
+    }
+
+    /// Returns the highest level of the cap reached by the adapter which wasn't notified yet
+    /// in the current billing cycle.
+    ///
+    /// Levels are notified again if the usage falls below them because the cap was raised.
+    pub fn check_quota(
+        &mut self,
+        adapter: &str,
+        cap: u64,
+        cycle_start_day: u8,
+        today: NaiveDate,
+    ) -> Option<(u8, AdapterUsage)> {
+        let cycle_start = get_cycle_start(today, cycle_start_day);
+        let usage = self.current_usage_mut(adapter, cycle_start);
+        let percentage = usage.percentage(cap);
+        let reached = QUOTA_LEVELS
+            .into_iter()
+            .filter(|level| u64::from(*level) <= percentage)
+            .max()
+            .unwrap_or(0);
+        let notify = reached > usage.notified_level;
+        usage.notified_level = reached;
+        if notify {
+            Some((reached, *usage))
+        } else {
+            None
+        }
+    }
+
+    fn current_usage_mut(&mut self, adapter: &str, cycle_start: NaiveDate) -> &mut AdapterUsage {
+        let usage = self
+            .adapters
+            .entry(adapter.to_string())
+            .or_insert_with(|| AdapterUsage::new(cycle_start));
+        if usage.cycle_start != cycle_start {
+            *usage = AdapterUsage::new(cycle_start);
+        }
+        usage
+    }
+}
+
+/// Returns the first day of the billing cycle including the given date
+pub fn get_cycle_start(today: NaiveDate, cycle_start_day: u8) -> NaiveDate {
+    // days after the 28th don't exist in every month
+    let day = u32::from(cycle_start_day.clamp(1, 28));
+    let this_month = today.with_day(day).unwrap_or(today);
+    if today.day() >= day {
+        this_month
+    } else {
+        this_month
+            .checked_sub_months(Months::new(1))
+            .unwrap_or(this_month)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl ConfigUsage {
+        pub fn test_path() -> String {
+            format!("{}/{}.toml", env!("CARGO_MANIFEST_DIR"), Self::FILE_NAME)
+        }
+
+        pub fn load() -> Self {
+            confy::load_path::<ConfigUsage>(ConfigUsage::test_path())
+                .unwrap_or_else(|_| ConfigUsage::default())
+        }
+
+        pub fn store(self) {
+            confy::store_path(ConfigUsage::test_path(), self).unwrap_or(());
+        }
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn data_info(outgoing_bytes: u128, incoming_bytes: u128) -> DataInfo {
+        DataInfo {
+            outgoing_bytes,
+            incoming_bytes,
+            ..DataInfo::default()
+        }
+    }
+
+    #[test]
+    fn test_get_cycle_start() {
+        assert_eq!(get_cycle_start(date(2024, 3, 15), 1), date(2024, 3, 1));
+        assert_eq!(get_cycle_start(date(2024, 3, 15), 15), date(2024, 3, 15));
+        assert_eq!(get_cycle_start(date(2024, 3, 14), 15), date(2024, 2, 15));
+        assert_eq!(get_cycle_start(date(2024, 1, 3), 10), date(2023, 12, 10));
+        // days after the 28th are clamped
+        assert_eq!(get_cycle_start(date(2024, 3, 1), 31), date(2024, 2, 28));
+        assert_eq!(get_cycle_start(date(2024, 3, 1), 0), date(2024, 3, 1));
+    }
+
+    #[test]
+    fn test_record_usage() {
+        let mut usage = ConfigUsage::default();
+        let mut accounted = HashMap::new();
+        let today = date(2024, 5, 20);
+        let mut adapters = HashMap::from([("wwan0".to_string(), data_info(100, 1_000))]);
+        usage.record(&adapters, &mut accounted, 5, today);
+        adapters.insert("wwan0".to_string(), data_info(150, 1_500));
+        adapters.insert("eth0".to_string(), data_info(10, 20));
+        usage.record(&adapters, &mut accounted, 5, today);
+        assert_eq!(
+            usage.get_usage("wwan0", 5, today),
+            AdapterUsage {
+                cycle_start: date(2024, 5, 5),
+                sent_bytes: 150,
+                received_bytes: 1_500,
+                notified_level: 0,
+            }
+        );
+        assert_eq!(usage.get_usage("eth0", 5, today).tot_bytes(), 30);
+
+        // a new capture starts counting from zero, the usage is kept
+        let mut accounted = HashMap::new();
+        adapters = HashMap::from([("wwan0".to_string(), data_info(50, 50))]);
+        usage.record(&adapters, &mut accounted, 5, today);
+        assert_eq!(usage.get_usage("wwan0", 5, today).tot_bytes(), 1_750);
+
+        // a new billing cycle starts over
+        let next_cycle = date(2024, 6, 5);
+        assert_eq!(usage.get_usage("wwan0", 5, next_cycle).tot_bytes(), 0);
+        adapters.insert("wwan0".to_string(), data_info(60, 50));
+        usage.record(&adapters, &mut accounted, 5, next_cycle);
+        assert_eq!(usage.get_usage("wwan0", 5, next_cycle).tot_bytes(), 10);
+        assert_eq!(usage.get_usage("wwan0", 5, today).tot_bytes(), 0);
+        // also when the counters start over without resetting what was accounted
+        adapters.insert("wwan0".to_string(), data_info(20, 10));
+        usage.record(&adapters, &mut accounted, 5, next_cycle);
+        assert_eq!(usage.get_usage("wwan0", 5, next_cycle).tot_bytes(), 40);
+    }
+
+    #[test]
+    fn test_check_quota() {
+        let today = date(2024, 5, 20);
+        let cycle_start = get_cycle_start(today, 1);
+        let mut usage = ConfigUsage {
+            adapters: HashMap::from([(
+                "wwan0".to_string(),
+                AdapterUsage {
+                    sent_bytes: 100,
+                    received_bytes: 700,
+                    ..AdapterUsage::new(cycle_start)
+                },
+            )]),
+        };
+        assert_eq!(
+            usage.check_quota("wwan0", 1_000, 1, today),
+            Some((80, usage.adapters["wwan0"]))
+        );
+        assert_eq!(usage.check_quota("wwan0", 1_000, 1, today), None);
+
+        // levels skipped are notified together
+        usage.adapters.get_mut("wwan0").unwrap().received_bytes = 1_200;
+        assert_eq!(usage.check_quota("wwan0", 1_000, 1, today).unwrap().0, 100);
+        assert_eq!(usage.check_quota("wwan0", 1_000, 1, today), None);
+
+        // the cap has been raised
+        assert_eq!(usage.check_quota("wwan0", 10_000, 1, today), None);
+        assert_eq!(usage.adapters["wwan0"].notified_level, 0);
+        assert_eq!(usage.check_quota("wwan0", 1_400, 1, today).unwrap().0, 90);
+
+        // nothing exchanged yet
+        assert_eq!(usage.check_quota("eth0", 1_000, 1, today), None);
+    }
+}
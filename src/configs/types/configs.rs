@@ -1,3 +1,5 @@
+use crate::configs::types::config_baseline::ConfigBaseline;
+use crate::configs::types::config_usage::ConfigUsage;
 use crate::{ConfigDevice, ConfigSettings, ConfigWindow};
 
 #[derive(Default, Clone, PartialEq, Debug)]
@@ -5,6 +7,8 @@ pub struct Configs {
     pub settings: ConfigSettings,
     pub device: ConfigDevice,
     pub window: ConfigWindow,
+    pub baseline: ConfigBaseline,
+    pub usage: ConfigUsage,
 }
 
 impl Configs {
@@ -13,6 +17,8 @@ pub fn load() -> Self {
             settings: ConfigSettings::load(),
             device: ConfigDevice::load(),
             window: ConfigWindow::load(),
+            baseline: ConfigBaseline::load(),
+            usage: ConfigUsage::load(),
         }
     }
 
@@ -20,5 +26,7 @@ pub fn store(self) {
         self.settings.store();
         self.device.store();
         self.window.store();
+        self.baseline.store();
+        self.usage.store();
     }
 }
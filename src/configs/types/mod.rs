@@ -1,4 +1,6 @@
+pub mod config_baseline;
 pub mod config_device;
 pub mod config_settings;
+pub mod config_usage;
 pub mod config_window;
 pub mod configs;
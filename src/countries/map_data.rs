@@ -0,0 +1,354 @@
+//! Module containing the geographic data used to draw the world map.
+//!
+//! The outlines of the countries are bundled in `resources/DB/countries.txt`,
+//! which can be regenerated with `resources/DB/update_countries.sh`.
+
+use once_cell::sync::Lazy;
+
+use crate::countries::types::country::Country;
+
+/// Outlines of the countries: each line is a ring, with the country code followed by its vertices
+const COUNTRIES_DB: &str = include_str!("../../resources/DB/countries.txt");
+
+/// Outlines of the countries, in descending order of area
+pub static COUNTRY_OUTLINES: Lazy<Vec<CountryOutline>> =
+    Lazy::new(|| parse_country_outlines(COUNTRIES_DB));
+
+/// Simplified outline of a country
+pub struct CountryOutline {
+    pub country: Country,
+    /// Polygons of the country, as (longitude, latitude) vertices
+    pub rings: Vec<Vec<(f64, f64)>>,
+    /// Area of the polygons, in square degrees
+    pub area: f64,
+}
+
+impl CountryOutline {
+    /// Whether the given (longitude, latitude) coordinates are inside the country
+    pub fn contains(&self, (longitude, latitude): (f64, f64)) -> bool {
+        self.rings.iter().any(|ring| {
+            // even-odd rule: count the edges crossed by a ray going east
+            let mut inside = false;
+            for (i, &(x1, y1)) in ring.iter().enumerate() {
+                let (x2, y2) = ring[(i + 1) % ring.len()];
+                if (y1 > latitude) != (y2 > latitude)
+                    && longitude < x1 + (latitude - y1) * (x2 - x1) / (y2 - y1)
+                {
+                    inside = !inside;
+                }
+            }
+            inside
+        })
+    }
+}
+
+/// Returns the outline of the country, if it's large enough to be drawn
+pub fn get_country_outline(country: Country) -> Option<&'static CountryOutline> {
+    COUNTRY_OUTLINES
+        .iter()
+        .find(|outline| outline.country.eq(&country))
+}
+
+fn parse_country_outlines(db: &str) -> Vec<CountryOutline> {
+    let mut outlines: Vec<CountryOutline> = Vec::new();
+    for line in db.lines().filter(|line| !line.starts_with('#')) {
+        let mut fields = line.split_whitespace();
+        let Some(code) = fields.next() else {
+            continue;
+        };
+        let country = Country::from_str(code);
+        let ring: Vec<(f64, f64)> = fields
+            .filter_map(|vertex| {
+                let (longitude, latitude) = vertex.split_once(',')?;
+                Some((longitude.parse().ok()?, latitude.parse().ok()?))
+            })
+            .collect();
+        if country.eq(&Country::ZZ) || ring.len() < 3 {
+            continue;
+        }
+        let area = get_ring_area(&ring);
+        if let Some(outline) = outlines
+            .iter_mut()
+            .find(|outline| outline.country.eq(&country))
+        {
+            outline.rings.push(ring);
+            outline.area += area;
+        } else {
+            outlines.push(CountryOutline {
+                country,
+                rings: vec![ring],
+                area,
+            });
+        }
+    }
+    outlines.sort_by(|a, b| b.area.total_cmp(&a.area));
+    outlines
+}
+
+/// Area of a ring, in square degrees (shoelace formula)
+fn get_ring_area(ring: &[(f64, f64)]) -> f64 {
+    let mut double_area = 0.0;
+    for (i, &(x1, y1)) in ring.iter().enumerate() {
+        let (x2, y2) = ring[(i + 1) % ring.len()];
+        double_area += x1 * y2 - x2 * y1;
+    }
+    (double_area / 2.0).abs()
+}
+
+/// Returns the approximate centroid of the country, as (longitude, latitude)
+pub fn get_country_coordinates(country: Country) -> Option<(f64, f64)> {
+    #![allow(clippy::too_many_lines)]
+    let coordinates = match country {
+        Country::AD => (1.6, 42.5),
+        Country::AE => (54.0, 24.0),
+        Country::AF => (66.0, 33.9),
+        Country::AG => (-61.8, 17.1),
+        Country::AI => (-63.1, 18.2),
+        Country::AL => (20.0, 41.1),
+        Country::AM => (45.0, 40.1),
+        Country::AO => (17.9, -11.2),
+        Country::AQ => (0.0, -75.0),
+        Country::AR => (-64.0, -34.0),
+        Country::AS => (-170.7, -14.3),
+        Country::AT => (14.6, 47.6),
+        Country::AU => (134.0, -25.5),
+        Country::AW => (-70.0, 12.5),
+        Country::AX => (20.0, 60.2),
+        Country::AZ => (47.6, 40.3),
+        Country::BA => (17.8, 44.2),
+        Country::BB => (-59.5, 13.2),
+        Country::BD => (90.3, 23.7),
+        Country::BE => (4.5, 50.6),
+        Country::BF => (-1.6, 12.3),
+        Country::BG => (25.2, 42.7),
+        Country::BH => (50.6, 26.0),
+        Country::BI => (29.9, -3.4),
+        Country::BJ => (2.3, 9.3),
+        Country::BL => (-62.8, 17.9),
+        Country::BM => (-64.8, 32.3),
+        Country::BN => (114.7, 4.5),
+        Country::BO => (-64.7, -16.7),
+        Country::BQ => (-68.3, 12.2),
+        Country::BR => (-52.0, -10.0),
+        Country::BS => (-77.4, 24.7),
+        Country::BT => (90.4, 27.4),
+        Country::BV => (3.4, -54.4),
+        Country::BW => (24.7, -22.3),
+        Country::BY => (28.0, 53.7),
+        Country::BZ => (-88.7, 17.2),
+        Country::CA => (-100.0, 58.0),
+        Country::CC => (96.8, -12.2),
+        Country::CD => (23.6, -2.9),
+        Country::CF => (20.9, 6.6),
+        Country::CG => (15.2, -0.8),
+        Country::CH => (8.2, 46.8),
+        Country::CI => (-5.5, 7.6),
+        Country::CK => (-159.8, -21.2),
+        Country::CL => (-71.0, -35.0),
+        Country::CM => (12.7, 5.7),
+        Country::CN => (103.8, 35.0),
+        Country::CO => (-73.1, 3.9),
+        Country::CR => (-84.2, 9.9),
+        Country::CU => (-79.0, 21.6),
+        Country::CV => (-23.6, 15.1),
+        Country::CW => (-69.0, 12.2),
+        Country::CX => (105.7, -10.5),
+        Country::CY => (33.1, 35.0),
+        Country::CZ => (15.3, 49.8),
+        Country::DE => (10.4, 51.1),
+        Country::DJ => (42.6, 11.8),
+        Country::DK => (9.5, 56.0),
+        Country::DM => (-61.4, 15.4),
+        Country::DO => (-70.5, 18.9),
+        Country::DZ => (2.6, 28.2),
+        Country::EC => (-78.4, -1.5),
+        Country::EE => (25.5, 58.7),
+        Country::EG => (29.9, 26.5),
+        Country::EH => (-12.9, 24.6),
+        Country::ER => (38.8, 15.4),
+        Country::ES => (-3.6, 40.2),
+        Country::ET => (39.6, 8.6),
+        Country::FI => (26.3, 64.5),
+        Country::FJ => (178.1, -17.8),
+        Country::FK => (-59.4, -51.7),
+        Country::FM => (158.2, 6.9),
+        Country::FO => (-6.9, 62.0),
+        Country::FR => (2.3, 46.6),
+        Country::GA => (11.6, -0.6),
+        Country::GB => (-2.9, 54.1),
+        Country::GD => (-61.7, 12.1),
+        Country::GE => (43.5, 42.2),
+        Country::GF => (-53.2, 4.0),
+        Country::GG => (-2.6, 49.5),
+        Country::GH => (-1.0, 7.9),
+        Country::GI => (-5.4, 36.1),
+        Country::GL => (-41.0, 72.0),
+        Country::GM => (-15.4, 13.4),
+        Country::GN => (-10.9, 10.4),
+        Country::GP => (-61.5, 16.2),
+        Country::GQ => (10.4, 1.6),
+        Country::GR => (22.0, 39.1),
+        Country::GS => (-36.6, -54.4),
+        Country::GT => (-90.4, 15.7),
+        Country::GU => (144.8, 13.4),
+        Country::GW => (-15.1, 12.0),
+        Country::GY => (-58.9, 4.8),
+        Country::HK => (114.2, 22.4),
+        Country::HM => (73.5, -53.1),
+        Country::HN => (-86.6, 14.8),
+        Country::HR => (16.4, 45.1),
+        Country::HT => (-72.7, 19.0),
+        Country::HU => (19.4, 47.2),
+        Country::ID => (117.3, -2.2),
+        Country::IE => (-8.1, 53.2),
+        Country::IL => (34.9, 31.4),
+        Country::IM => (-4.5, 54.2),
+        Country::IN => (79.6, 22.9),
+        Country::IO => (72.4, -7.3),
+        Country::IQ => (43.7, 33.0),
+        Country::IR => (54.3, 32.6),
+        Country::IS => (-18.6, 65.0),
+        Country::IT => (12.6, 42.8),
+        Country::JE => (-2.1, 49.2),
+        Country::JM => (-77.3, 18.1),
+        Country::JO => (36.8, 31.3),
+        Country::JP => (138.0, 37.5),
+        Country::KE => (37.9, 0.5),
+        Country::KG => (74.6, 41.5),
+        Country::KH => (104.9, 12.7),
+        Country::KI => (173.0, 1.4),
+        Country::KM => (43.3, -11.9),
+        Country::KN => (-62.7, 17.3),
+        Country::KP => (127.2, 40.2),
+        Country::KR => (127.8, 36.4),
+        Country::KW => (47.6, 29.3),
+        Country::KY => (-81.2, 19.3),
+        Country::KZ => (67.3, 48.2),
+        Country::LA => (103.8, 18.5),
+        Country::LB => (35.9, 33.9),
+        Country::LC => (-61.0, 13.9),
+        Country::LI => (9.5, 47.1),
+        Country::LK => (80.7, 7.6),
+        Country::LR => (-9.3, 6.4),
+        Country::LS => (28.2, -29.6),
+        Country::LT => (23.9, 55.3),
+        Country::LU => (6.1, 49.8),
+        Country::LV => (24.9, 56.9),
+        Country::LY => (17.3, 27.0),
+        Country::MA => (-6.3, 31.9),
+        Country::MC => (7.4, 43.7),
+        Country::MD => (28.5, 47.2),
+        Country::ME => (19.3, 42.8),
+        Country::MF => (-63.1, 18.1),
+        Country::MG => (46.7, -19.4),
+        Country::MH => (171.2, 7.1),
+        Country::MK => (21.7, 41.6),
+        Country::ML => (-3.5, 17.3),
+        Country::MM => (96.5, 21.0),
+        Country::MN => (103.1, 46.8),
+        Country::MO => (113.5, 22.2),
+        Country::MP => (145.7, 15.2),
+        Country::MQ => (-61.0, 14.6),
+        Country::MR => (-10.3, 20.3),
+        Country::MS => (-62.2, 16.7),
+        Country::MT => (14.4, 35.9),
+        Country::MU => (57.6, -20.3),
+        Country::MV => (73.5, 3.2),
+        Country::MW => (34.3, -13.2),
+        Country::MX => (-102.5, 23.9),
+        Country::MY => (109.7, 3.8),
+        Country::MZ => (35.5, -17.3),
+        Country::NA => (17.2, -22.1),
+        Country::NC => (165.7, -21.3),
+        Country::NE => (9.4, 17.4),
+        Country::NF => (168.0, -29.0),
+        Country::NG => (8.1, 9.6),
+        Country::NI => (-85.0, 12.9),
+        Country::NL => (5.6, 52.1),
+        Country::NO => (10.0, 62.0),
+        Country::NP => (84.0, 28.3),
+        Country::NR => (166.9, -0.5),
+        Country::NU => (-169.9, -19.0),
+        Country::NZ => (172.5, -41.8),
+        Country::OM => (56.1, 20.6),
+        Country::PA => (-80.1, 8.5),
+        Country::PE => (-75.0, -9.2),
+        Country::PF => (-149.4, -17.6),
+        Country::PG => (145.2, -6.5),
+        Country::PH => (122.9, 11.8),
+        Country::PK => (69.4, 29.9),
+        Country::PL => (19.4, 52.1),
+        Country::PM => (-56.3, 46.9),
+        Country::PN => (-128.3, -24.4),
+        Country::PR => (-66.5, 18.2),
+        Country::PS => (35.2, 31.9),
+        Country::PT => (-8.2, 39.6),
+        Country::PW => (134.6, 7.5),
+        Country::PY => (-58.4, -23.2),
+        Country::QA => (51.2, 25.3),
+        Country::RE => (55.5, -21.1),
+        Country::RO => (25.0, 45.9),
+        Country::RS => (20.8, 44.2),
+        Country::RU => (96.7, 61.9),
+        Country::RW => (29.9, -2.0),
+        Country::SA => (44.5, 24.2),
+        Country::SB => (160.0, -9.6),
+        Country::SC => (55.5, -4.6),
+        Country::SD => (30.0, 15.9),
+        Country::SE => (16.7, 62.8),
+        Country::SG => (103.8, 1.4),
+        Country::SH => (-5.7, -15.9),
+        Country::SI => (14.8, 46.1),
+        Country::SJ => (16.0, 78.5),
+        Country::SK => (19.5, 48.7),
+        Country::SL => (-11.8, 8.6),
+        Country::SM => (12.5, 43.9),
+        Country::SN => (-14.5, 14.4),
+        Country::SO => (46.2, 5.2),
+        Country::SR => (-55.9, 4.1),
+        Country::SS => (30.3, 7.3),
+        Country::ST => (6.6, 0.4),
+        Country::SV => (-88.9, 13.7),
+        Country::SX => (-63.1, 18.0),
+        Country::SY => (38.5, 35.0),
+        Country::SZ => (31.5, -26.5),
+        Country::TC => (-71.8, 21.8),
+        Country::TD => (18.7, 15.4),
+        Country::TF => (69.3, -49.3),
+        Country::TG => (0.9, 8.5),
+        Country::TH => (101.0, 15.1),
+        Country::TJ => (71.0, 38.9),
+        Country::TK => (-171.9, -9.2),
+        Country::TL => (125.9, -8.8),
+        Country::TM => (59.4, 39.1),
+        Country::TN => (9.6, 34.1),
+        Country::TO => (-175.2, -21.2),
+        Country::TR => (35.2, 39.0),
+        Country::TT => (-61.3, 10.5),
+        Country::TV => (179.2, -8.5),
+        Country::TW => (121.0, 23.7),
+        Country::TZ => (34.8, -6.3),
+        Country::UA => (31.4, 49.0),
+        Country::UG => (32.4, 1.3),
+        Country::UM => (166.6, 19.3),
+        Country::US => (-98.6, 39.8),
+        Country::UY => (-56.0, -32.8),
+        Country::UZ => (63.1, 41.4),
+        Country::VA => (12.5, 41.9),
+        Country::VC => (-61.2, 13.2),
+        Country::VE => (-66.2, 7.1),
+        Country::VG => (-64.6, 18.4),
+        Country::VI => (-64.8, 17.9),
+        Country::VN => (105.8, 16.0),
+        Country::VU => (167.7, -16.2),
+        Country::WF => (-177.2, -13.8),
+        Country::WS => (-172.1, -13.8),
+        Country::YE => (47.6, 15.9),
+        Country::YT => (45.1, -12.8),
+        Country::ZA => (25.1, -29.0),
+        Country::ZM => (27.8, -13.5),
+        Country::ZW => (29.9, -19.0),
+        Country::ZZ => return None,
+    };
+    Some(coordinates)
+}
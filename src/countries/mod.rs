@@ -1,3 +1,4 @@
 pub mod country_utils;
 pub mod flags_pictures;
+pub mod map_data;
 pub mod types;
@@ -0,0 +1,241 @@
+//! Module defining the `EventTracker` struct, which compares the successive states
+//! of the collected traffic to produce the events of the JSON stream.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Duration, Local};
+
+use crate::api::routes::{Totals, Update};
+use crate::events::types::event::Event;
+use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::notifications::types::logged_notification::LoggedNotification;
+use crate::InfoTraffic;
+
+/// Seconds without packets after which a connection is considered ended
+const FLOW_INACTIVE_TIMEOUT: i64 = 15;
+
+/// State of a connection at the time of the last check
+struct TrackedFlow {
+    last_seen: DateTime<Local>,
+    is_ended: bool,
+}
+
+#[derive(Default)]
+pub struct EventTracker {
+    flows: HashMap<AddressPortPair, TrackedFlow>,
+    resolved_addresses: HashSet<String>,
+    previous_totals: Totals,
+}
+
+impl EventTracker {
+    /// Returns the events occurred since the last call, followed by the traffic totals.
+    ///
+    /// If `flush` is true, all the connections not ended yet are reported as ended.
+    pub fn get_events(
+        &mut self,
+        info_traffic: &InfoTraffic,
+        notifications: Vec<LoggedNotification>,
+        now: DateTime<Local>,
+        flush: bool,
+    ) -> Vec<Event> {
+        let mut events = Vec::new();
+
+        for (key, info) in &info_traffic.map {
+            // the connection is new, or it exchanged packets again after it ended
+            let is_started = self.flows.get(key).map_or(true, |flow| {
+                flow.is_ended && info.final_timestamp > flow.last_seen
+            });
+            let flow = self.flows.entry(key.clone()).or_insert(TrackedFlow {
+                last_seen: info.final_timestamp,
+                is_ended: false,
+            });
+            if is_started {
+                flow.is_ended = false;
+                events.push(Event::FlowStarted {
+                    timestamp: now,
+                    key: key.clone(),
+                    app: info.app_protocol,
+                    direction: info.traffic_direction,
+                    interface: info.interface.clone(),
+                });
+            }
+            flow.last_seen = info.final_timestamp;
+            if !flow.is_ended
+                && (flush || now - info.final_timestamp >= Duration::seconds(FLOW_INACTIVE_TIMEOUT))
+            {
+                flow.is_ended = true;
+                events.push(Event::FlowEnded {
+                    timestamp: now,
+                    key: key.clone(),
+                    app: info.app_protocol,
+                    bytes: info.transmitted_bytes,
+                    packets: info.transmitted_packets,
+                    first_seen: info.initial_timestamp,
+                    last_seen: info.final_timestamp,
+                });
+            }
+        }
+
+        for (address, (r_dns, host)) in &info_traffic.addresses_resolved {
+            if self.resolved_addresses.insert(address.clone()) {
+                events.push(Event::HostResolved {
+                    timestamp: now,
+                    address: address.clone(),
+                    r_dns: r_dns.clone(),
+                    host: host.clone(),
+                });
+            }
+        }
+
+        events.extend(
+            notifications
+                .into_iter()
+                .map(|notification| Event::Notification {
+                    timestamp: now,
+                    notification,
+                }),
+        );
+
+        let totals = Totals::new(info_traffic);
+        events.push(Event::Totals {
+            timestamp: now,
+            update: Update::new(&self.previous_totals, totals),
+        });
+        self.previous_totals = totals;
+
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::countries::types::country::Country;
+    use crate::networking::types::asn::Asn;
+    use crate::networking::types::host::Host;
+    use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
+    use crate::notifications::types::logged_notification::PacketsThresholdExceeded;
+    use crate::Protocol;
+
+    use super::*;
+
+    fn key() -> AddressPortPair {
+        AddressPortPair::new(
+            "192.168.1.2".to_string(),
+            Some(55555),
+            "1.1.1.1".to_string(),
+            Some(443),
+            Protocol::TCP,
+            "eth0".to_string(),
+        )
+    }
+
+    fn to_json(events: &[Event]) -> Vec<serde_json::Value> {
+        events
+            .iter()
+            .map(|event| serde_json::to_value(event).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_flow_events() {
+        let mut tracker = EventTracker::default();
+        let start = Local::now();
+        let mut info_traffic = InfoTraffic::new();
+        info_traffic.map.insert(
+            key(),
+            InfoAddressPortPair {
+                transmitted_bytes: 100,
+                transmitted_packets: 1,
+                initial_timestamp: start,
+                final_timestamp: start,
+                interface: "eth0".to_string(),
+                ..InfoAddressPortPair::default()
+            },
+        );
+
+        let events = to_json(&tracker.get_events(&info_traffic, Vec::new(), start, false));
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0]["event"], "flow_started");
+        assert_eq!(events[0]["address2"], "1.1.1.1");
+        assert_eq!(events[0]["port2"], 443);
+        assert_eq!(events[0]["interface"], "eth0");
+        assert_eq!(events[1]["event"], "totals");
+
+        // nothing new while the connection is active
+        let now = start + Duration::seconds(5);
+        let events = to_json(&tracker.get_events(&info_traffic, Vec::new(), now, false));
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0]["event"], "totals");
+
+        // the connection ends after the inactive timeout
+        let now = start + Duration::seconds(FLOW_INACTIVE_TIMEOUT);
+        let events = to_json(&tracker.get_events(&info_traffic, Vec::new(), now, false));
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0]["event"], "flow_ended");
+        assert_eq!(events[0]["bytes"], 100);
+        assert_eq!(events[0]["packets"], 1);
+        let events = to_json(&tracker.get_events(&info_traffic, Vec::new(), now, true));
+        assert_eq!(events.len(), 1);
+
+        // new packets on the same address:port pair start a new connection
+        let info = info_traffic.map.get_mut(&key()).unwrap();
+        info.final_timestamp = now;
+        info.transmitted_packets = 2;
+        let events = to_json(&tracker.get_events(&info_traffic, Vec::new(), now, false));
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0]["event"], "flow_started");
+
+        // when flushing, the active connections are ended
+        let events = to_json(&tracker.get_events(&info_traffic, Vec::new(), now, true));
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0]["event"], "flow_ended");
+        assert_eq!(events[0]["packets"], 2);
+    }
+
+    #[test]
+    fn test_host_notification_and_totals_events() {
+        let mut tracker = EventTracker::default();
+        let mut info_traffic = InfoTraffic::new();
+        info_traffic.addresses_resolved.insert(
+            "1.1.1.1".to_string(),
+            (
+                "one.one.one.one".to_string(),
+                Host {
+                    domain: "one.one".to_string(),
+                    asn: Asn {
+                        number: 13335,
+                        name: "CLOUDFLARENET".to_string(),
+                    },
+                    country: Country::US,
+                },
+            ),
+        );
+        info_traffic.tot_sent_bytes = 300;
+        info_traffic.tot_received_packets = 4;
+
+        let notification = LoggedNotification::PacketsThresholdExceeded(PacketsThresholdExceeded {
+            threshold: 3,
+            incoming: 4,
+            outgoing: 0,
+            timestamp: Local::now(),
+        });
+        let events =
+            to_json(&tracker.get_events(&info_traffic, vec![notification], Local::now(), false));
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0]["event"], "host_resolved");
+        assert_eq!(events[0]["address"], "1.1.1.1");
+        assert_eq!(events[0]["r_dns"], "one.one.one.one");
+        assert_eq!(events[0]["host"]["country"], "US");
+        assert_eq!(events[1]["event"], "notification");
+        assert_eq!(events[2]["event"], "totals");
+        assert_eq!(events[2]["sent_bytes"], 300);
+        assert_eq!(events[2]["received_packets"], 4);
+
+        // hosts are reported once, totals are the traffic of the last interval
+        info_traffic.tot_sent_bytes = 500;
+        let events = to_json(&tracker.get_events(&info_traffic, Vec::new(), Local::now(), false));
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0]["sent_bytes"], 200);
+        assert_eq!(events[0]["totals"]["sent_bytes"], 500);
+    }
+}
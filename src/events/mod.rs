@@ -0,0 +1,2 @@
+pub mod event_tracker;
+pub mod types;
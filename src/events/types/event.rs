@@ -0,0 +1,53 @@
+use chrono::{DateTime, Local};
+use serde::Serialize;
+
+use crate::api::routes::Update;
+use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::networking::types::host::Host;
+use crate::networking::types::traffic_direction::TrafficDirection;
+use crate::notifications::types::logged_notification::LoggedNotification;
+use crate::AppProtocol;
+
+/// Event written as a line of the JSON stream
+#[derive(Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    /// A connection has been observed for the first time (or again after it ended)
+    FlowStarted {
+        timestamp: DateTime<Local>,
+        #[serde(flatten)]
+        key: AddressPortPair,
+        app: AppProtocol,
+        direction: TrafficDirection,
+        interface: String,
+    },
+    /// A connection has been inactive for a while, or the capture has been interrupted
+    FlowEnded {
+        timestamp: DateTime<Local>,
+        #[serde(flatten)]
+        key: AddressPortPair,
+        app: AppProtocol,
+        bytes: u128,
+        packets: u128,
+        first_seen: DateTime<Local>,
+        last_seen: DateTime<Local>,
+    },
+    /// The reverse DNS lookup of an address has been completed
+    HostResolved {
+        timestamp: DateTime<Local>,
+        address: String,
+        r_dns: String,
+        host: Host,
+    },
+    /// A notification has been emitted
+    Notification {
+        timestamp: DateTime<Local>,
+        notification: LoggedNotification,
+    },
+    /// Traffic exchanged in the last second
+    Totals {
+        timestamp: DateTime<Local>,
+        #[serde(flatten)]
+        update: Update,
+    },
+}
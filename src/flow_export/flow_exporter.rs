@@ -0,0 +1,416 @@
+//! Module defining the `FlowExporter` struct, which decides when the observed flows have to be
+//! exported and encodes them as NetFlow v9 or IPFIX packets.
+
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+use chrono::{DateTime, Duration, Local};
+
+use crate::flow_export::types::flow_export_settings::FlowExportSettings;
+use crate::flow_export::types::flow_protocol::FlowProtocol;
+use crate::flow_export::types::flow_record::FlowRecord;
+use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
+
+/// Maximum number of data records in a single export packet, to stay below the usual MTU
+const MAX_RECORDS_PER_PACKET: usize = 20;
+
+/// ID of the template describing flows between IPv4 addresses
+const TEMPLATE_ID_V4: u16 = 256;
+/// ID of the template describing flows between IPv6 addresses
+const TEMPLATE_ID_V6: u16 = 257;
+
+/// Information elements (ID and length) shared by the two templates, after the addresses:
+/// source port, destination port, protocol, bytes, and packets
+const COMMON_FIELDS: [(u16, u16); 5] = [(7, 2), (11, 2), (4, 1), (1, 8), (2, 8)];
+
+/// Counters of a flow at the time of its last export
+struct ExportedFlow {
+    bytes: u128,
+    packets: u128,
+    /// Time of the first packet not exported yet (None if everything has already been exported)
+    pending_since: Option<DateTime<Local>>,
+}
+
+pub struct FlowExporter {
+    protocol: FlowProtocol,
+    active_timeout: Duration,
+    inactive_timeout: Duration,
+    flows: HashMap<AddressPortPair, ExportedFlow>,
+    /// Time at which the exporter started, used as system boot time in NetFlow v9 packets
+    start_time: DateTime<Local>,
+    /// Packets sent so far (NetFlow v9) or data records sent so far (IPFIX)
+    sequence: u32,
+}
+
+impl FlowExporter {
+    pub fn new(settings: &FlowExportSettings) -> Self {
+        FlowExporter {
+            protocol: settings.protocol,
+            active_timeout: Duration::seconds(
+                i64::try_from(settings.active_timeout).unwrap_or(i64::MAX / 1000),
+            ),
+            inactive_timeout: Duration::seconds(
+                i64::try_from(settings.inactive_timeout).unwrap_or(i64::MAX / 1000),
+            ),
+            flows: HashMap::new(),
+            start_time: Local::now(),
+            sequence: 0,
+        }
+    }
+
+    /// Returns the records of the flows which have to be exported at the given time:
+    /// the ones idle for longer than the inactive timeout, and the ones whose oldest
+    /// data not exported yet is older than the active timeout.
+    ///
+    /// If `flush` is true, all the data not exported yet is returned.
+    pub fn get_expired_records(
+        &mut self,
+        map: &HashMap<AddressPortPair, InfoAddressPortPair>,
+        now: DateTime<Local>,
+        flush: bool,
+    ) -> Vec<FlowRecord> {
+        let mut records = Vec::new();
+        for (key, info) in map {
+            let flow = self.flows.entry(key.clone()).or_insert(ExportedFlow {
+                bytes: 0,
+                packets: 0,
+                pending_since: Some(info.initial_timestamp),
+            });
+            if info.transmitted_packets <= flow.packets {
+                continue;
+            }
+            let pending_since = *flow.pending_since.get_or_insert(info.final_timestamp);
+            let is_inactive = now - info.final_timestamp >= self.inactive_timeout;
+            let is_active_expired = now - pending_since >= self.active_timeout;
+            if !flush && !is_inactive && !is_active_expired {
+                continue;
+            }
+            if let Some(record) = FlowRecord::new(
+                key,
+                info.transmitted_bytes.saturating_sub(flow.bytes),
+                info.transmitted_packets - flow.packets,
+                pending_since,
+                info.final_timestamp,
+            ) {
+                records.push(record);
+            }
+            flow.bytes = info.transmitted_bytes;
+            flow.packets = info.transmitted_packets;
+            flow.pending_since = None;
+        }
+        records.sort_by_key(|record| record.start);
+        records
+    }
+
+    /// Encodes the given records as a sequence of export packets, each including the templates
+    pub fn encode(&mut self, records: &[FlowRecord], now: DateTime<Local>) -> Vec<Vec<u8>> {
+        records
+            .chunks(MAX_RECORDS_PER_PACKET)
+            .map(|chunk| self.encode_packet(chunk, now))
+            .collect()
+    }
+
+    fn encode_packet(&mut self, records: &[FlowRecord], now: DateTime<Local>) -> Vec<u8> {
+        let (records_v4, records_v6): (Vec<&FlowRecord>, Vec<&FlowRecord>) = records
+            .iter()
+            .partition(|record| record.source.is_ipv4() && record.destination.is_ipv4());
+
+        let mut body = self.encode_template_set();
+        for (template_id, records) in [(TEMPLATE_ID_V4, records_v4), (TEMPLATE_ID_V6, records_v6)] {
+            if !records.is_empty() {
+                body.extend(self.encode_data_set(template_id, &records));
+            }
+        }
+
+        let export_time = u32::try_from(now.timestamp()).unwrap_or_default();
+        let mut packet = Vec::new();
+        packet.extend(self.protocol.get_version().to_be_bytes());
+        match self.protocol {
+            FlowProtocol::NetFlowV9 => {
+                // the two templates plus the data records
+                let count = u16::try_from(records.len() + 2).unwrap_or(u16::MAX);
+                packet.extend(count.to_be_bytes());
+                packet.extend(self.get_uptime(now).to_be_bytes());
+                packet.extend(export_time.to_be_bytes());
+                packet.extend(self.sequence.to_be_bytes());
+                self.sequence = self.sequence.wrapping_add(1);
+            }
+            FlowProtocol::Ipfix => {
+                let length = u16::try_from(16 + body.len()).unwrap_or(u16::MAX);
+                packet.extend(length.to_be_bytes());
+                packet.extend(export_time.to_be_bytes());
+                packet.extend(self.sequence.to_be_bytes());
+                self.sequence = self
+                    .sequence
+                    .wrapping_add(u32::try_from(records.len()).unwrap_or_default());
+            }
+        }
+        // source ID (NetFlow v9) or observation domain ID (IPFIX)
+        packet.extend(0_u32.to_be_bytes());
+        packet.extend(body);
+        packet
+    }
+
+    fn encode_template_set(&self) -> Vec<u8> {
+        let timestamp_fields: [(u16, u16); 2] = match self.protocol {
+            // FIRST_SWITCHED and LAST_SWITCHED, relative to the system uptime
+            FlowProtocol::NetFlowV9 => [(22, 4), (21, 4)],
+            // flowStartMilliseconds and flowEndMilliseconds
+            FlowProtocol::Ipfix => [(152, 8), (153, 8)],
+        };
+        let mut content = Vec::new();
+        for (template_id, address_fields) in [
+            (TEMPLATE_ID_V4, [(8, 4), (12, 4)]),
+            (TEMPLATE_ID_V6, [(27, 16), (28, 16)]),
+        ] {
+            let fields: Vec<(u16, u16)> = address_fields
+                .into_iter()
+                .chain(COMMON_FIELDS)
+                .chain(timestamp_fields)
+                .collect();
+            content.extend(template_id.to_be_bytes());
+            content.extend(
+                u16::try_from(fields.len())
+                    .unwrap_or_default()
+                    .to_be_bytes(),
+            );
+            for (id, length) in fields {
+                content.extend(id.to_be_bytes());
+                content.extend(length.to_be_bytes());
+            }
+        }
+        let set_id: u16 = match self.protocol {
+            FlowProtocol::NetFlowV9 => 0,
+            FlowProtocol::Ipfix => 2,
+        };
+        encode_set(set_id, content)
+    }
+
+    fn encode_data_set(&self, template_id: u16, records: &[&FlowRecord]) -> Vec<u8> {
+        let mut content = Vec::new();
+        for record in records {
+            match (record.source, record.destination) {
+                (IpAddr::V4(source), IpAddr::V4(destination)) => {
+                    content.extend(source.octets());
+                    content.extend(destination.octets());
+                }
+                (source, destination) => {
+                    content.extend(to_ipv6_octets(source));
+                    content.extend(to_ipv6_octets(destination));
+                }
+            }
+            content.extend(record.source_port.to_be_bytes());
+            content.extend(record.destination_port.to_be_bytes());
+            content.push(record.protocol);
+            content.extend(record.bytes.to_be_bytes());
+            content.extend(record.packets.to_be_bytes());
+            match self.protocol {
+                FlowProtocol::NetFlowV9 => {
+                    content.extend(self.get_uptime(record.start).to_be_bytes());
+                    content.extend(self.get_uptime(record.end).to_be_bytes());
+                }
+                FlowProtocol::Ipfix => {
+                    for time in [record.start, record.end] {
+                        let millis = u64::try_from(time.timestamp_millis()).unwrap_or_default();
+                        content.extend(millis.to_be_bytes());
+                    }
+                }
+            }
+        }
+        encode_set(template_id, content)
+    }
+
+    /// Milliseconds elapsed from the start of the exporter to the given time
+    fn get_uptime(&self, time: DateTime<Local>) -> u32 {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let uptime = (time - self.start_time).num_milliseconds().max(0) as u32;
+        uptime
+    }
+}
+
+/// Prepends the set header (ID and length) to the given content, padding it to a multiple of 4 bytes
+fn encode_set(set_id: u16, mut content: Vec<u8>) -> Vec<u8> {
+    while (content.len() + 4) % 4 != 0 {
+        content.push(0);
+    }
+    let mut set = Vec::with_capacity(content.len() + 4);
+    set.extend(set_id.to_be_bytes());
+    set.extend(
+        u16::try_from(content.len() + 4)
+            .unwrap_or(u16::MAX)
+            .to_be_bytes(),
+    );
+    set.extend(content);
+    set
+}
+
+fn to_ipv6_octets(address: IpAddr) -> [u8; 16] {
+    match address {
+        IpAddr::V4(address) => address.to_ipv6_mapped().octets(),
+        IpAddr::V6(address) => address.octets(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Protocol;
+
+    use super::*;
+
+    fn settings(protocol: FlowProtocol) -> FlowExportSettings {
+        FlowExportSettings {
+            enabled: true,
+            collector: "127.0.0.1:2055".to_string(),
+            protocol,
+            active_timeout: 60,
+            inactive_timeout: 15,
+        }
+    }
+
+    fn flow(address1: &str, address2: &str) -> AddressPortPair {
+        AddressPortPair::new(
+            address1.to_string(),
+            Some(55555),
+            address2.to_string(),
+            Some(443),
+            Protocol::TCP,
+            "eth0".to_string(),
+        )
+    }
+
+    fn info(
+        bytes: u128,
+        packets: u128,
+        start: DateTime<Local>,
+        end: DateTime<Local>,
+    ) -> InfoAddressPortPair {
+        InfoAddressPortPair {
+            transmitted_bytes: bytes,
+            transmitted_packets: packets,
+            initial_timestamp: start,
+            final_timestamp: end,
+            ..InfoAddressPortPair::default()
+        }
+    }
+
+    fn read_u16(packet: &[u8], offset: usize) -> u16 {
+        u16::from_be_bytes([packet[offset], packet[offset + 1]])
+    }
+
+    #[test]
+    fn test_inactive_and_active_timeouts() {
+        let mut exporter = FlowExporter::new(&settings(FlowProtocol::Ipfix));
+        let t0 = Local::now();
+        let idle = flow("192.168.1.2", "1.1.1.1");
+        let busy = flow("192.168.1.2", "8.8.8.8");
+        let mut map = HashMap::from([
+            (idle.clone(), info(100, 1, t0, t0)),
+            (busy.clone(), info(1000, 10, t0, t0 + Duration::seconds(10))),
+        ]);
+
+        // nothing expired yet
+        let now = t0 + Duration::seconds(10);
+        assert!(exporter.get_expired_records(&map, now, false).is_empty());
+
+        // the idle flow exceeds the inactive timeout
+        let now = t0 + Duration::seconds(20);
+        map.get_mut(&busy).unwrap().final_timestamp = now;
+        let records = exporter.get_expired_records(&map, now, false);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].destination, "1.1.1.1".parse::<IpAddr>().unwrap());
+        assert_eq!(records[0].bytes, 100);
+        assert_eq!(records[0].protocol, 6);
+        assert_eq!(records[0].destination_port, 443);
+
+        // the busy flow exceeds the active timeout, and only its new data is exported next time
+        let now = t0 + Duration::seconds(61);
+        let busy_info = map.get_mut(&busy).unwrap();
+        busy_info.final_timestamp = now;
+        let records = exporter.get_expired_records(&map, now, false);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].bytes, 1000);
+        assert_eq!(records[0].packets, 10);
+        assert_eq!(records[0].start, t0);
+        assert_eq!(records[0].end, now);
+
+        let busy_info = map.get_mut(&busy).unwrap();
+        busy_info.transmitted_bytes = 1500;
+        busy_info.transmitted_packets = 15;
+        assert!(exporter.get_expired_records(&map, now, false).is_empty());
+        let records = exporter.get_expired_records(&map, now, true);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].bytes, 500);
+        assert_eq!(records[0].packets, 5);
+
+        // everything has been exported
+        assert!(exporter.get_expired_records(&map, now, true).is_empty());
+    }
+
+    #[test]
+    fn test_encode_ipfix() {
+        let mut exporter = FlowExporter::new(&settings(FlowProtocol::Ipfix));
+        let now = Local::now();
+        let map = HashMap::from([
+            (flow("10.0.0.1", "10.0.0.2"), info(300, 3, now, now)),
+            (flow("::1", "2001:db8::1"), info(200, 2, now, now)),
+        ]);
+        let records = exporter.get_expired_records(&map, now, true);
+        let packets = exporter.encode(&records, now);
+        assert_eq!(packets.len(), 1);
+        let packet = &packets[0];
+
+        assert_eq!(read_u16(packet, 0), 10);
+        assert_eq!(usize::from(read_u16(packet, 2)), packet.len());
+        // template set
+        assert_eq!(read_u16(packet, 16), 2);
+        let template_set_length = usize::from(read_u16(packet, 18));
+        assert_eq!(read_u16(packet, 20), TEMPLATE_ID_V4);
+        assert_eq!(read_u16(packet, 22), 9);
+        // data sets: one IPv4 record (45 bytes) and one IPv6 record (69 bytes), padded
+        let v4_set = 16 + template_set_length;
+        assert_eq!(read_u16(packet, v4_set), TEMPLATE_ID_V4);
+        assert_eq!(read_u16(packet, v4_set + 2), 52);
+        assert_eq!(&packet[v4_set + 4..v4_set + 8], &[10, 0, 0, 1]);
+        let v6_set = v4_set + 52;
+        assert_eq!(read_u16(packet, v6_set), TEMPLATE_ID_V6);
+        assert_eq!(read_u16(packet, v6_set + 2), 76);
+        assert_eq!(v6_set + 76, packet.len());
+
+        // the sequence number counts the data records
+        let packets = exporter.encode(&records, now);
+        assert_eq!(&packets[0][8..12], &2_u32.to_be_bytes());
+    }
+
+    #[test]
+    fn test_encode_netflow_v9() {
+        let mut exporter = FlowExporter::new(&settings(FlowProtocol::NetFlowV9));
+        let now = Local::now();
+        let map: HashMap<AddressPortPair, InfoAddressPortPair> = (0..25)
+            .map(|i| {
+                (
+                    flow(&format!("10.0.0.{i}"), "10.0.1.1"),
+                    info(100, 1, now, now),
+                )
+            })
+            .collect();
+        let records = exporter.get_expired_records(&map, now, true);
+        let packets = exporter.encode(&records, now);
+        assert_eq!(packets.len(), 2);
+
+        for (i, (packet, data_records)) in packets.iter().zip([20, 5]).enumerate() {
+            assert_eq!(read_u16(packet, 0), 9);
+            assert_eq!(read_u16(packet, 2), data_records + 2);
+            // the sequence number counts the packets
+            assert_eq!(&packet[12..16], &u32::try_from(i).unwrap().to_be_bytes());
+            // template set
+            assert_eq!(read_u16(packet, 20), 0);
+            let v4_set = 20 + usize::from(read_u16(packet, 22));
+            assert_eq!(read_u16(packet, v4_set), TEMPLATE_ID_V4);
+            // each IPv4 record is 37 bytes long
+            let set_length = usize::from(read_u16(packet, v4_set + 2));
+            assert_eq!(set_length, (4 + 37 * usize::from(data_records) + 3) / 4 * 4);
+            assert_eq!(v4_set + set_length, packet.len());
+        }
+    }
+}
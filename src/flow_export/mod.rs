@@ -0,0 +1,2 @@
+pub mod flow_exporter;
+pub mod types;
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+use crate::flow_export::types::flow_protocol::FlowProtocol;
+
+/// Used to contain the flow export configuration set by the user
+#[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
+pub struct FlowExportSettings {
+    /// Whether the flows are exported
+    pub enabled: bool,
+    /// Address (IP and UDP port) of the collector
+    pub collector: String,
+    /// Format of the exported records
+    pub protocol: FlowProtocol,
+    /// Seconds after which a long-lived flow is exported, even if still active
+    pub active_timeout: u64,
+    /// Seconds of inactivity after which a flow is exported
+    pub inactive_timeout: u64,
+}
+
+impl Default for FlowExportSettings {
+    fn default() -> Self {
+        FlowExportSettings {
+            enabled: false,
+            collector: "127.0.0.1:2055".to_string(),
+            protocol: FlowProtocol::default(),
+            active_timeout: 60,
+            inactive_timeout: 15,
+        }
+    }
+}
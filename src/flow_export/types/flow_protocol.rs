@@ -0,0 +1,34 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Format of the flow records sent to the collector
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default, Hash, Serialize, Deserialize)]
+pub enum FlowProtocol {
+    /// Cisco NetFlow version 9 (RFC 3954)
+    #[default]
+    NetFlowV9,
+    /// IP Flow Information Export (RFC 7011)
+    Ipfix,
+}
+
+impl FlowProtocol {
+    pub(crate) const ALL: [FlowProtocol; 2] = [FlowProtocol::NetFlowV9, FlowProtocol::Ipfix];
+
+    /// Version number carried in the header of the export packets
+    pub fn get_version(self) -> u16 {
+        match self {
+            FlowProtocol::NetFlowV9 => 9,
+            FlowProtocol::Ipfix => 10,
+        }
+    }
+}
+
+impl fmt::Display for FlowProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FlowProtocol::NetFlowV9 => write!(f, "NetFlow v9"),
+            FlowProtocol::Ipfix => write!(f, "IPFIX"),
+        }
+    }
+}
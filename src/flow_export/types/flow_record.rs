@@ -0,0 +1,58 @@
+use std::net::IpAddr;
+
+use chrono::{DateTime, Local};
+
+use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::Protocol;
+
+/// Portion of a flow (i.e., of an address:port pair) to be sent to the collector
+#[derive(Clone, Debug, PartialEq)]
+pub struct FlowRecord {
+    pub source: IpAddr,
+    pub destination: IpAddr,
+    /// Source port (0 if not applicable)
+    pub source_port: u16,
+    /// Destination port (0 if not applicable)
+    pub destination_port: u16,
+    /// IANA protocol number
+    pub protocol: u8,
+    /// Bytes exchanged since the last export of the flow
+    pub bytes: u64,
+    /// Packets exchanged since the last export of the flow
+    pub packets: u64,
+    /// Time of the first packet of this portion of the flow
+    pub start: DateTime<Local>,
+    /// Time of the last packet of this portion of the flow
+    pub end: DateTime<Local>,
+}
+
+impl FlowRecord {
+    /// Returns the record of the given flow, or `None` if its addresses aren't valid IPs
+    pub fn new(
+        key: &AddressPortPair,
+        bytes: u128,
+        packets: u128,
+        start: DateTime<Local>,
+        end: DateTime<Local>,
+    ) -> Option<Self> {
+        let source: IpAddr = key.address1.parse().ok()?;
+        let destination: IpAddr = key.address2.parse().ok()?;
+        let protocol = match (key.protocol, source) {
+            (Protocol::TCP, _) => 6,
+            (Protocol::UDP, _) => 17,
+            (Protocol::ICMP, IpAddr::V4(_)) => 1,
+            (Protocol::ICMP, IpAddr::V6(_)) => 58,
+        };
+        Some(FlowRecord {
+            source,
+            destination,
+            source_port: key.port1.unwrap_or_default(),
+            destination_port: key.port2.unwrap_or_default(),
+            protocol,
+            bytes: u64::try_from(bytes).unwrap_or(u64::MAX),
+            packets: u64::try_from(packets).unwrap_or(u64::MAX),
+            start,
+            end,
+        })
+    }
+}
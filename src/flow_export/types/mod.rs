@@ -0,0 +1,3 @@
+pub mod flow_export_settings;
+pub mod flow_protocol;
+pub mod flow_record;
@@ -13,13 +13,19 @@
 
 use crate::gui::components::footer::footer;
 use crate::gui::components::header::header;
-use crate::gui::components::modal::{get_clear_all_overlay, get_exit_overlay, Modal};
+use crate::gui::components::modal::{
+    get_clear_all_overlay, get_edit_filters_overlay, get_error_overlay, get_exit_overlay, Modal,
+};
 use crate::gui::components::types::my_modal::MyModal;
 use crate::gui::pages::connection_details_page::connection_details_page;
+use crate::gui::pages::dns_page::dns_page;
+use crate::gui::pages::history_page::history_page;
 use crate::gui::pages::initial_page::initial_page;
 use crate::gui::pages::inspect_page::inspect_page;
+use crate::gui::pages::map_page::map_page;
 use crate::gui::pages::notifications_page::notifications_page;
 use crate::gui::pages::overview_page::overview_page;
+use crate::gui::pages::session_comparison_page::session_comparison_page;
 use crate::gui::pages::settings_general_page::settings_general_page;
 use crate::gui::pages::settings_notifications_page::settings_notifications_page;
 use crate::gui::pages::settings_style_page::settings_style_page;
@@ -63,6 +69,7 @@ fn view(&self) -> Element<Message, Renderer<StyleType>> {
             style,
             language,
             color_gradient,
+            history,
             ..
         } = self.configs.lock().unwrap().settings;
         let font = style.get_extension().font;
@@ -72,6 +79,12 @@ fn view(&self) -> Element<Message, Renderer<StyleType>> {
             font,
             color_gradient,
             self.running_page.ne(&RunningPage::Init),
+            if self.opened_session.is_some() {
+                None
+            } else {
+                Some(*self.paused.lock().unwrap())
+            },
+            history,
             language,
             self.last_opened_setting,
         );
@@ -80,6 +93,8 @@ fn view(&self) -> Element<Message, Renderer<StyleType>> {
             RunningPage::Init => initial_page(self),
             RunningPage::Overview => overview_page(self),
             RunningPage::Inspect => inspect_page(self),
+            RunningPage::Map => map_page(self),
+            RunningPage::Dns => dns_page(self),
             RunningPage::Notifications => notifications_page(self),
         };
 
@@ -116,6 +131,18 @@ fn view(&self) -> Element<Message, Renderer<StyleType>> {
                         get_clear_all_overlay(color_gradient, font, font_headers, language)
                     }
                     MyModal::ConnectionDetails(key) => connection_details_page(self, key),
+                    MyModal::EditFilters => get_edit_filters_overlay(
+                        color_gradient,
+                        font,
+                        font_headers,
+                        language,
+                        &self.filters,
+                    ),
+                    MyModal::History => history_page(self),
+                    MyModal::SessionComparison => session_comparison_page(self),
+                    MyModal::Error(error) => {
+                        get_error_overlay(color_gradient, font, font_headers, language, error)
+                    }
                 };
 
                 Modal::new(content, overlay)
@@ -145,6 +172,8 @@ fn subscription(&self) -> Subscription<Message> {
                     KeyCode::Q => Some(Message::CloseRequested),
                     KeyCode::Comma => Some(Message::OpenLastSettings),
                     KeyCode::Backspace => Some(Message::ResetButtonPressed),
+                    KeyCode::P => Some(Message::PauseResume),
+                    KeyCode::S => Some(Message::SaveSession),
                     KeyCode::D => Some(Message::CtrlDPressed),
                     KeyCode::Left => Some(Message::ArrowPressed(false)),
                     KeyCode::Right => Some(Message::ArrowPressed(true)),
@@ -3,15 +3,21 @@
 use iced::alignment::{Horizontal, Vertical};
 use iced::widget::text::LineHeight;
 use iced::widget::tooltip::Position;
-use iced::widget::{button, Container, Row, Tooltip};
+use iced::widget::{button, Container, Row, Text, Tooltip};
 use iced::Length::FillPortion;
 use iced::{Alignment, Font, Length, Renderer};
 
+use crate::gui::components::types::my_modal::MyModal;
 use crate::gui::pages::types::settings_page::SettingsPage;
 use crate::gui::styles::container::ContainerType;
 use crate::gui::styles::types::gradient_type::GradientType;
 use crate::gui::types::message::Message;
 use crate::translations::translations::{quit_analysis_translation, settings_translation};
+use crate::translations::translations_3::{
+    compare_sessions_translation, pause_translation, resume_translation, save_session_translation,
+    traffic_history_translation,
+};
+use crate::utils::types::file_info::FileInfo;
 use crate::utils::types::icon::Icon;
 use crate::{Language, StyleType};
 
@@ -19,6 +25,8 @@ pub fn header(
     font: Font,
     color_gradient: GradientType,
     back_button: bool,
+    paused: Option<bool>,
+    history: bool,
     language: Language,
     last_opened_setting: SettingsPage,
 ) -> Container<'static, Message, Renderer<StyleType>> {
@@ -37,7 +45,17 @@ pub fn header(
             .width(Length::Fill)
             .align_items(Alignment::Center)
             .push(if back_button {
-                Container::new(get_button_reset(font, language))
+                let mut buttons = Row::new()
+                    .spacing(10)
+                    .push(get_button_reset(font, language))
+                    .push(get_button_compare(font, language));
+                // pause and save are only available for live captures
+                if let Some(paused) = paused {
+                    buttons = buttons
+                        .push(get_button_pause(font, language, paused))
+                        .push(get_button_save(font, language));
+                }
+                Container::new(buttons)
                     .width(FillPortion(1))
                     .align_x(Horizontal::Center)
             } else {
@@ -47,9 +65,16 @@ pub fn header(
             })
             .push(logo)
             .push(
-                Container::new(get_button_settings(font, language, last_opened_setting))
-                    .width(FillPortion(1))
-                    .align_x(Horizontal::Center),
+                Container::new(if history {
+                    Row::new()
+                        .spacing(10)
+                        .push(get_button_history(font, language))
+                        .push(get_button_settings(font, language, last_opened_setting))
+                } else {
+                    Row::new().push(get_button_settings(font, language, last_opened_setting))
+                })
+                .width(FillPortion(1))
+                .align_x(Horizontal::Center),
             ),
     )
     .height(Length::Fixed(95.0))
@@ -84,6 +109,115 @@ fn get_button_reset(
     .style(ContainerType::Tooltip)
 }
 
+fn get_button_pause(
+    font: Font,
+    language: Language,
+    paused: bool,
+) -> Tooltip<'static, Message, Renderer<StyleType>> {
+    let content = button(
+        Text::new(if paused { "▶" } else { "‖" })
+            .font(font)
+            .size(20)
+            .horizontal_alignment(Horizontal::Center)
+            .vertical_alignment(Vertical::Center),
+    )
+    .padding(0)
+    .height(Length::Fixed(40.0))
+    .width(Length::Fixed(60.0))
+    .on_press(Message::PauseResume);
+
+    Tooltip::new(
+        content,
+        if paused {
+            resume_translation(language)
+        } else {
+            pause_translation(language)
+        },
+        Position::Right,
+    )
+    .gap(5)
+    .font(font)
+    .style(ContainerType::Tooltip)
+}
+
+fn get_button_save(
+    font: Font,
+    language: Language,
+) -> Tooltip<'static, Message, Renderer<StyleType>> {
+    let content = button(
+        Icon::File
+            .to_text()
+            .size(20)
+            .horizontal_alignment(Horizontal::Center)
+            .vertical_alignment(Vertical::Center),
+    )
+    .padding(0)
+    .height(Length::Fixed(40.0))
+    .width(Length::Fixed(60.0))
+    .on_press(Message::SaveSession);
+
+    Tooltip::new(content, save_session_translation(language), Position::Right)
+        .gap(5)
+        .font(font)
+        .style(ContainerType::Tooltip)
+}
+
+fn get_button_compare(
+    font: Font,
+    language: Language,
+) -> Tooltip<'static, Message, Renderer<StyleType>> {
+    let content = button(
+        Icon::Inspect
+            .to_text()
+            .size(20)
+            .horizontal_alignment(Horizontal::Center)
+            .vertical_alignment(Vertical::Center),
+    )
+    .padding(0)
+    .height(Length::Fixed(40.0))
+    .width(Length::Fixed(60.0))
+    .on_press(Message::OpenFile(
+        String::new(),
+        FileInfo::Session,
+        Message::CompareSession,
+    ));
+
+    Tooltip::new(
+        content,
+        compare_sessions_translation(language),
+        Position::Right,
+    )
+    .gap(5)
+    .font(font)
+    .style(ContainerType::Tooltip)
+}
+
+fn get_button_history(
+    font: Font,
+    language: Language,
+) -> Tooltip<'static, Message, Renderer<StyleType>> {
+    let content = button(
+        Icon::Clock
+            .to_text()
+            .size(20)
+            .horizontal_alignment(Horizontal::Center)
+            .vertical_alignment(Vertical::Center),
+    )
+    .padding(0)
+    .height(Length::Fixed(40.0))
+    .width(Length::Fixed(60.0))
+    .on_press(Message::ShowModal(MyModal::History));
+
+    Tooltip::new(
+        content,
+        traffic_history_translation(language),
+        Position::Left,
+    )
+    .gap(5)
+    .font(font)
+    .style(ContainerType::Tooltip)
+}
+
 pub fn get_button_settings(
     font: Font,
     language: Language,
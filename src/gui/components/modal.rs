@@ -4,22 +4,34 @@
 use iced::advanced::widget::{self, Widget};
 use iced::advanced::{self, Clipboard, Shell};
 use iced::alignment::{Alignment, Horizontal, Vertical};
-use iced::widget::{button, horizontal_space, vertical_space, Column, Container, Row, Text};
+use iced::widget::tooltip::Position;
+use iced::widget::{
+    button, horizontal_space, vertical_space, Column, Container, Row, Text, Tooltip,
+};
 use iced::{
     event, mouse, BorderRadius, Color, Element, Event, Font, Length, Point, Rectangle, Renderer,
     Size,
 };
 
 use crate::gui::components::button::button_hide;
+use crate::gui::pages::initial_page::{
+    col_address_input, col_ip_buttons, col_port_input, col_protocol_buttons,
+};
 use crate::gui::styles::button::ButtonType;
 use crate::gui::styles::container::ContainerType;
 use crate::gui::styles::style_constants::FONT_SIZE_TITLE;
+use crate::gui::styles::text::TextType;
 use crate::gui::styles::types::gradient_type::GradientType;
 use crate::gui::types::message::Message;
+use crate::networking::types::filters::Filters;
 use crate::translations::translations::{
     ask_clear_all_translation, ask_quit_translation, clear_all_translation,
     quit_analysis_translation, yes_translation,
 };
+use crate::translations::translations_3::{
+    apply_translation, edit_filters_translation, error_translation,
+};
+use crate::utils::formatted_strings::get_invalid_filters_string;
 use crate::{Language, StyleType};
 
 pub fn get_exit_overlay(
@@ -86,6 +98,107 @@ pub fn get_clear_all_overlay(
         .style(ContainerType::Modal)
 }
 
+pub fn get_edit_filters_overlay(
+    color_gradient: GradientType,
+    font: Font,
+    font_headers: Font,
+    language: Language,
+    filters: &Filters,
+) -> Container<'static, Message, Renderer<StyleType>> {
+    let mut button_apply = button(
+        Text::new(apply_translation(language))
+            .font(font)
+            .vertical_alignment(Vertical::Center)
+            .horizontal_alignment(Horizontal::Center),
+    )
+    .padding(5)
+    .height(Length::Fixed(40.0))
+    .width(Length::Fixed(120.0))
+    .style(ButtonType::Gradient(color_gradient));
+    let tooltip = if filters.are_valid() {
+        button_apply = button_apply.on_press(Message::ApplyFilters);
+        String::new()
+    } else {
+        get_invalid_filters_string(filters, language)
+    };
+
+    let filters_col = Column::new()
+        .padding([0, 20])
+        .spacing(15)
+        .push(
+            Row::new()
+                .spacing(20)
+                .push(col_ip_buttons(&filters.ip_versions, font, language))
+                .push(col_protocol_buttons(&filters.protocols, font, language)),
+        )
+        .push(
+            Row::new()
+                .spacing(20)
+                .push(col_address_input(&filters.address_str, font, language))
+                .push(col_port_input(&filters.port_str, font, language)),
+        );
+
+    let content = Column::new()
+        .align_items(Alignment::Center)
+        .width(Length::Fill)
+        .push(get_modal_header(
+            font,
+            font_headers,
+            color_gradient,
+            language,
+            edit_filters_translation(language).to_string(),
+        ))
+        .push(vertical_space(Length::Fixed(20.0)))
+        .push(filters_col)
+        .push(
+            Row::new()
+                .height(Length::Fill)
+                .align_items(Alignment::Center)
+                .push(
+                    Tooltip::new(button_apply, tooltip, Position::FollowCursor)
+                        .font(font)
+                        .style(ContainerType::Tooltip),
+                ),
+        );
+
+    Container::new(content)
+        .height(Length::Fixed(300.0))
+        .width(Length::Fixed(800.0))
+        .style(ContainerType::Modal)
+}
+
+pub fn get_error_overlay(
+    color_gradient: GradientType,
+    font: Font,
+    font_headers: Font,
+    language: Language,
+    error: String,
+) -> Container<'static, Message, Renderer<StyleType>> {
+    let content = Column::new()
+        .align_items(Alignment::Center)
+        .width(Length::Fill)
+        .push(get_modal_header(
+            font,
+            font_headers,
+            color_gradient,
+            language,
+            error_translation(language).to_string(),
+        ))
+        .push(vertical_space(Length::Fixed(20.0)))
+        .push(
+            Text::new(error)
+                .style(TextType::Danger)
+                .horizontal_alignment(Horizontal::Center)
+                .font(font),
+        );
+
+    Container::new(content)
+        .height(Length::Fixed(160.0))
+        .width(Length::Fixed(450.0))
+        .padding([0, 0, 10, 0])
+        .style(ContainerType::Modal)
+}
+
 fn get_modal_header(
     font: Font,
     font_headers: Font,
@@ -9,4 +9,12 @@ pub enum MyModal {
     ClearAll,
     /// Connection details modal.
     ConnectionDetails(AddressPortPair),
+    /// Edit filters modal (while a capture is running).
+    EditFilters,
+    /// Traffic history modal.
+    History,
+    /// Session comparison modal.
+    SessionComparison,
+    /// Error modal, with the description of the error.
+    Error(String),
 }
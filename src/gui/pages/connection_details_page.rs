@@ -8,6 +8,7 @@
 use iced::Length::Fixed;
 use iced::{Alignment, Font, Length, Renderer};
 
+use crate::chart::types::chart_entity::ChartEntity;
 use crate::countries::country_utils::{get_computer_tooltip, get_flag_tooltip};
 use crate::countries::flags_pictures::FLAGS_WIDTH_BIG;
 use crate::gui::components::button::button_hide;
@@ -26,9 +27,10 @@
 use crate::networking::types::icmp_type::IcmpType;
 use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
 use crate::networking::types::traffic_direction::TrafficDirection;
+use crate::oui::vendor::get_vendor;
 use crate::translations::translations::{
     address_translation, application_protocol_translation, incoming_translation,
-    outgoing_translation, packets_translation, protocol_translation,
+    network_adapter_translation, outgoing_translation, packets_translation, protocol_translation,
     transport_protocol_translation,
 };
 use crate::translations::translations_2::{
@@ -36,7 +38,9 @@
     fqdn_translation, mac_address_translation, socket_address_translation, source_translation,
     transmitted_data_translation,
 };
-use crate::translations::translations_3::{copy_translation, messages_translation};
+use crate::translations::translations_3::{
+    copy_translation, messages_translation, vendor_translation,
+};
 use crate::utils::formatted_strings::{get_formatted_bytes_string_with_b, get_socket_address};
 use crate::utils::types::icon::Icon;
 use crate::{ConfigSettings, Language, Protocol, Sniffer, StyleType};
@@ -50,6 +54,10 @@ pub fn connection_details_page(
             sniffer.runtime_data.tot_sent_packets + sniffer.runtime_data.tot_received_packets,
             sniffer.timing_events.was_just_copy_ip(&key.address1),
             sniffer.timing_events.was_just_copy_ip(&key.address2),
+            sniffer.traffic_chart.ticks,
+            sniffer.traffic_chart.span,
+            sniffer.traffic_chart.offset,
+            sniffer.traffic_chart.chart_type,
         ),
         move |_| page_content(sniffer, &key),
     ))
@@ -108,7 +116,7 @@ fn page_content(
         host_info_col = get_host_info_col(&r_dns, &host, font, language);
         let host_info = host_info_option.unwrap_or_default();
         let flag = get_flag_tooltip(host.country, FLAGS_WIDTH_BIG, &host_info, language, font);
-        let computer = get_local_tooltip(sniffer, &address_to_lookup, key);
+        let computer = get_local_tooltip(sniffer, &address_to_lookup, key, &val.interface);
         if address_to_lookup.eq(&key.address1) {
             source_caption = source_caption.push(flag);
             dest_caption = dest_caption.push(computer);
@@ -137,6 +145,21 @@ fn page_content(
         &sniffer.timing_events,
     );
 
+    if let Some(vendor) = get_mac_vendor(&val.mac_address1, sniffer) {
+        source_col = source_col.push(TextType::highlighted_subtitle_with_desc(
+            vendor_translation(language),
+            &vendor,
+            font,
+        ));
+    }
+    if let Some(vendor) = get_mac_vendor(&val.mac_address2, sniffer) {
+        dest_col = dest_col.push(TextType::highlighted_subtitle_with_desc(
+            vendor_translation(language),
+            &vendor,
+            font,
+        ));
+    }
+
     if address_to_lookup.eq(&key.address1) {
         source_col = source_col.push(host_info_col);
     } else {
@@ -147,12 +170,26 @@ fn page_content(
 
     let content = assemble_widgets(col_info, source_col, dest_col);
 
-    Container::new(header_and_content.push(content))
+    let chart = Container::new(
+        sniffer
+            .traffic_chart
+            .entity_view(&ChartEntity::Connection(key.clone())),
+    )
+    .padding([0, 10, 10, 10])
+    .height(Length::Fixed(200.0));
+
+    Container::new(header_and_content.push(content).push(chart))
         .width(Length::Fixed(1000.0))
-        .height(Length::Fixed(500.0))
+        .height(Length::Fixed(700.0))
         .style(ContainerType::Modal)
 }
 
+fn get_mac_vendor(mac_address: &Option<String>, sniffer: &Sniffer) -> Option<String> {
+    mac_address
+        .as_deref()
+        .and_then(|mac| get_vendor(mac, &sniffer.oui_reader))
+}
+
 fn page_header(
     font: Font,
     font_headers: Font,
@@ -216,6 +253,12 @@ fn col_info(
             font,
         ));
 
+    ret_val = ret_val.push(TextType::highlighted_subtitle_with_desc(
+        network_adapter_translation(language),
+        &val.interface,
+        font,
+    ));
+
     if !is_icmp {
         ret_val = ret_val.push(TextType::highlighted_subtitle_with_desc(
             application_protocol_translation(language),
@@ -300,6 +343,7 @@ fn get_local_tooltip(
     sniffer: &Sniffer,
     address_to_lookup: &str,
     key: &AddressPortPair,
+    interface: &str,
 ) -> Tooltip<'static, Message, Renderer<StyleType>> {
     let ConfigSettings {
         style, language, ..
@@ -310,7 +354,12 @@ fn get_local_tooltip(
     } else {
         &key.address1
     };
-    let my_interface_addresses = &*sniffer.device.addresses.lock().unwrap();
+    // use the addresses of the adapter on which the connection was observed
+    let device = std::iter::once(&sniffer.device)
+        .chain(&sniffer.additional_devices)
+        .find(|device| device.name.eq(interface))
+        .unwrap_or(&sniffer.device);
+    let my_interface_addresses = &*device.addresses.lock().unwrap();
     get_computer_tooltip(
         is_my_address(local_address, my_interface_addresses),
         is_local_connection(local_address, my_interface_addresses),
@@ -0,0 +1,340 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::widget::scrollable::Direction;
+use iced::widget::tooltip::Position;
+use iced::widget::{button, vertical_space, Rule};
+use iced::widget::{lazy, Button, Column, Container, Row, Scrollable, Text, TextInput, Tooltip};
+use iced::{Alignment, Font, Length, Renderer};
+
+use crate::gui::components::tab::get_pages_tabs;
+use crate::gui::pages::inspect_page::get_change_page_row;
+use crate::gui::styles::container::ContainerType;
+use crate::gui::styles::scrollbar::ScrollbarType;
+use crate::gui::styles::style_constants::FONT_SIZE_TITLE;
+use crate::gui::styles::text::TextType;
+use crate::gui::styles::text_input::TextInputType;
+use crate::gui::types::message::Message;
+use crate::networking::types::dns_query::DnsQuery;
+use crate::networking::types::search_parameters::DnsSearchParameters;
+use crate::report::get_report_entries::get_searched_dns_queries;
+use crate::translations::translations_2::{
+    domain_name_translation, no_search_results_translation, search_filters_translation,
+};
+use crate::translations::translations_3::{
+    answers_translation, client_translation, latency_translation, rcode_translation,
+    record_type_translation, response_code_translation, time_translation,
+};
+use crate::utils::types::icon::Icon;
+use crate::{ConfigSettings, Language, RunningPage, Sniffer, StyleType};
+
+/// Computes the body of gui DNS page
+pub fn dns_page(sniffer: &Sniffer) -> Container<Message, Renderer<StyleType>> {
+    let ConfigSettings {
+        style, language, ..
+    } = sniffer.configs.lock().unwrap().settings;
+    let font = style.get_extension().font;
+    let font_headers = style.get_extension().font_headers;
+
+    let mut body = Column::new()
+        .width(Length::Fill)
+        .padding(10)
+        .spacing(10)
+        .align_items(Alignment::Center);
+
+    let mut tab_and_body = Column::new().height(Length::Fill);
+
+    let tabs = get_pages_tabs(
+        RunningPage::Dns,
+        font,
+        font_headers,
+        language,
+        sniffer.unread_notifications,
+    );
+
+    tab_and_body = tab_and_body.push(tabs);
+
+    let report = lazy(
+        (
+            sniffer.runtime_data.tot_sent_packets + sniffer.runtime_data.tot_received_packets,
+            style,
+            language,
+            sniffer.dns_search.clone(),
+            sniffer.dns_page_number,
+        ),
+        move |_| lazy_dns_report(sniffer),
+    );
+
+    body = body
+        .push(
+            Container::new(filters_col(&sniffer.dns_search, font, language))
+                .height(Length::Fixed(125.0))
+                .width(Length::Fixed(1042.0))
+                .padding(10)
+                .style(ContainerType::BorderedRound),
+        )
+        .push(report);
+
+    Container::new(Column::new().push(tab_and_body.push(body))).height(Length::Fill)
+}
+
+fn lazy_dns_report(sniffer: &Sniffer) -> Container<'static, Message, Renderer<StyleType>> {
+    let ConfigSettings {
+        style, language, ..
+    } = sniffer.configs.lock().unwrap().settings;
+    let font = style.get_extension().font;
+
+    let (search_results, results_number) = get_searched_dns_queries(sniffer);
+
+    let mut col_report = Column::new()
+        .height(Length::Fill)
+        .width(Length::Fill)
+        .align_items(Alignment::Center);
+
+    let mut scroll_report = Column::new();
+    let start_entry_num = (sniffer.dns_page_number - 1) * 20 + 1;
+    let end_entry_num = start_entry_num + search_results.len() - 1;
+    for query in search_results {
+        scroll_report = scroll_report.push(dns_query_row(&query, font, language));
+    }
+    if results_number > 0 {
+        col_report = col_report
+            .push(
+                Text::new(format!(
+                    "  {:<12}  {:<24}  {:<6}{:<9}{:>10}{:>9}   {}",
+                    time_translation(language),
+                    client_translation(language),
+                    record_type_translation(language),
+                    rcode_translation(language),
+                    latency_translation(language),
+                    answers_translation(language),
+                    domain_name_translation(language)
+                ))
+                .vertical_alignment(Vertical::Center)
+                .height(Length::FillPortion(2))
+                .font(font),
+            )
+            .push(Rule::horizontal(5))
+            .push(
+                Scrollable::new(scroll_report)
+                    .height(Length::FillPortion(15))
+                    .width(Length::Fill)
+                    .direction(Direction::Both {
+                        vertical: ScrollbarType::properties(),
+                        horizontal: ScrollbarType::properties(),
+                    }),
+            )
+            .push(Rule::horizontal(5))
+            .push(get_change_page_row(
+                font,
+                language,
+                sniffer.dns_page_number,
+                start_entry_num,
+                end_entry_num,
+                results_number,
+            ));
+    } else {
+        col_report = col_report.push(
+            Column::new()
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .padding(20)
+                .align_items(Alignment::Center)
+                .push(vertical_space(Length::FillPortion(1)))
+                .push(Icon::Funnel.to_text().size(60))
+                .push(vertical_space(Length::Fixed(15.0)))
+                .push(Text::new(no_search_results_translation(language)).font(font))
+                .push(vertical_space(Length::FillPortion(2))),
+        );
+    }
+
+    Container::new(col_report)
+        .align_y(Vertical::Center)
+        .align_x(Horizontal::Center)
+        .padding([10, 7, 7, 7])
+        .width(Length::Fixed(1042.0))
+        .style(ContainerType::BorderedRound)
+}
+
+fn dns_query_row(
+    query: &DnsQuery,
+    font: Font,
+    language: Language,
+) -> Tooltip<'static, Message, Renderer<StyleType>> {
+    let text_type = match &query.response {
+        Some(response) if response.response_code != 0 => TextType::Danger,
+        _ => TextType::Standard,
+    };
+    let answers = match &query.response {
+        Some(response) if !response.answers.is_empty() => response.answers.join("\n   "),
+        _ => "-".to_string(),
+    };
+    let tooltip = format!(
+        "{}: {}\n{}:\n   {answers}",
+        domain_name_translation(language),
+        query.name,
+        answers_translation(language),
+    );
+
+    Tooltip::new(
+        Text::new(format!("  {query}  "))
+            .style(text_type)
+            .font(font),
+        tooltip,
+        Position::FollowCursor,
+    )
+    .font(font)
+    .style(ContainerType::Tooltip)
+}
+
+fn filters_col(
+    search_params: &DnsSearchParameters,
+    font: Font,
+    language: Language,
+) -> Column<'static, Message, Renderer<StyleType>> {
+    let mut title_row = Row::new().spacing(10).align_items(Alignment::Center).push(
+        Text::new(search_filters_translation(language))
+            .font(font)
+            .style(TextType::Title)
+            .size(FONT_SIZE_TITLE),
+    );
+    if search_params.is_some_filter_active() {
+        title_row = title_row.push(button_clear_filter(DnsSearchParameters::default(), font));
+    }
+
+    let params = search_params.clone();
+    let client_filter = filter_input(
+        &search_params.client,
+        client_translation(language),
+        200.0,
+        DnsSearchParameters {
+            client: String::new(),
+            ..search_params.clone()
+        },
+        move |client| DnsSearchParameters {
+            client,
+            ..params.clone()
+        },
+        font,
+    );
+    let params = search_params.clone();
+    let name_filter = filter_input(
+        &search_params.name,
+        domain_name_translation(language),
+        200.0,
+        DnsSearchParameters {
+            name: String::new(),
+            ..search_params.clone()
+        },
+        move |name| DnsSearchParameters {
+            name,
+            ..params.clone()
+        },
+        font,
+    );
+    let params = search_params.clone();
+    let type_filter = filter_input(
+        &search_params.query_type,
+        record_type_translation(language),
+        60.0,
+        DnsSearchParameters {
+            query_type: String::new(),
+            ..search_params.clone()
+        },
+        move |query_type| DnsSearchParameters {
+            query_type,
+            ..params.clone()
+        },
+        font,
+    );
+    let params = search_params.clone();
+    let response_code_filter = filter_input(
+        &search_params.response_code,
+        response_code_translation(language),
+        90.0,
+        DnsSearchParameters {
+            response_code: String::new(),
+            ..search_params.clone()
+        },
+        move |response_code| DnsSearchParameters {
+            response_code,
+            ..params.clone()
+        },
+        font,
+    );
+
+    Column::new()
+        .spacing(3)
+        .push(title_row)
+        .push(vertical_space(Length::Fixed(10.0)))
+        .push(
+            Row::new()
+                .align_items(Alignment::Center)
+                .spacing(10)
+                .push(client_filter)
+                .push(name_filter),
+        )
+        .push(
+            Row::new()
+                .align_items(Alignment::Center)
+                .spacing(10)
+                .push(type_filter)
+                .push(response_code_filter),
+        )
+}
+
+fn filter_input(
+    filter_value: &str,
+    caption: &str,
+    width: f32,
+    cleared_search_params: DnsSearchParameters,
+    new_search_params: impl Fn(String) -> DnsSearchParameters + 'static,
+    font: Font,
+) -> Container<'static, Message, Renderer<StyleType>> {
+    let is_filter_active = !filter_value.is_empty();
+
+    let input = TextInput::new("-", filter_value)
+        .on_input(move |new_value| {
+            Message::DnsSearch(new_search_params(new_value.trim().to_string()))
+        })
+        .padding([0, 5])
+        .font(font)
+        .width(Length::Fixed(width))
+        .style(if is_filter_active {
+            TextInputType::Badge
+        } else {
+            TextInputType::Standard
+        });
+
+    let mut content = Row::new()
+        .spacing(5)
+        .push(Text::new(format!("{caption}:")).font(font))
+        .push(input);
+
+    if is_filter_active {
+        content = content.push(button_clear_filter(cleared_search_params, font));
+    }
+
+    Container::new(content)
+        .padding(5)
+        .style(if is_filter_active {
+            ContainerType::Badge
+        } else {
+            ContainerType::Neutral
+        })
+}
+
+fn button_clear_filter(
+    new_search_parameters: DnsSearchParameters,
+    font: Font,
+) -> Button<'static, Message, Renderer<StyleType>> {
+    button(
+        Text::new("×")
+            .font(font)
+            .vertical_alignment(Vertical::Center)
+            .horizontal_alignment(Horizontal::Center)
+            .size(15),
+    )
+    .padding(2)
+    .height(Length::Fixed(20.0))
+    .width(Length::Fixed(20.0))
+    .on_press(Message::DnsSearch(new_search_parameters))
+}
@@ -0,0 +1,270 @@
+use iced::alignment::{Horizontal, Vertical};
+use iced::widget::scrollable::Direction;
+use iced::widget::{
+    button, horizontal_space, vertical_space, Button, Column, Container, Row, Rule, Scrollable,
+    Text, TextInput,
+};
+use iced::Length::Fixed;
+use iced::{Alignment, Font, Length, Renderer};
+
+use crate::gui::components::button::button_hide;
+use crate::gui::styles::button::ButtonType;
+use crate::gui::styles::container::ContainerType;
+use crate::gui::styles::scrollbar::ScrollbarType;
+use crate::gui::styles::style_constants::FONT_SIZE_TITLE;
+use crate::gui::styles::text::TextType;
+use crate::gui::styles::text_input::TextInputType;
+use crate::gui::styles::types::gradient_type::GradientType;
+use crate::gui::types::message::Message;
+use crate::history::types::history_entry::HistoryEntry;
+use crate::history::types::history_group::HistoryGroup;
+use crate::history::types::history_query::HistoryQuery;
+use crate::translations::translations::packets_translation;
+use crate::translations::translations_2::no_search_results_translation;
+use crate::translations::translations_3::{
+    from_translation, group_by_translation, local_address_translation, search_translation,
+    to_translation, traffic_history_translation,
+};
+use crate::utils::formatted_strings::get_formatted_bytes_string_with_b;
+use crate::{ConfigSettings, Language, Sniffer, StyleType};
+
+pub fn history_page(sniffer: &Sniffer) -> Container<Message, Renderer<StyleType>> {
+    let ConfigSettings {
+        style,
+        language,
+        color_gradient,
+        ..
+    } = sniffer.configs.lock().unwrap().settings;
+    let font = style.get_extension().font;
+    let font_headers = style.get_extension().font_headers;
+
+    let content = Column::new()
+        .width(Length::Fill)
+        .align_items(Alignment::Center)
+        .push(page_header(font, font_headers, color_gradient, language))
+        .push(vertical_space(Fixed(15.0)))
+        .push(query_row(&sniffer.history_query, font, language))
+        .push(Rule::horizontal(25))
+        .push(results_col(&sniffer.history_results, font, language));
+
+    Container::new(content)
+        .width(Length::Fixed(800.0))
+        .height(Length::Fixed(500.0))
+        .style(ContainerType::Modal)
+}
+
+fn page_header(
+    font: Font,
+    font_headers: Font,
+    color_gradient: GradientType,
+    language: Language,
+) -> Container<'static, Message, Renderer<StyleType>> {
+    Container::new(
+        Row::new()
+            .push(horizontal_space(Length::FillPortion(1)))
+            .push(
+                Text::new(traffic_history_translation(language))
+                    .font(font_headers)
+                    .size(FONT_SIZE_TITLE)
+                    .width(Length::FillPortion(6))
+                    .horizontal_alignment(Horizontal::Center),
+            )
+            .push(
+                Container::new(button_hide(Message::HideModal, language, font))
+                    .width(Length::FillPortion(1))
+                    .align_x(Horizontal::Center),
+            ),
+    )
+    .align_x(Horizontal::Center)
+    .align_y(Vertical::Center)
+    .height(Fixed(40.0))
+    .width(Length::Fill)
+    .style(ContainerType::Gradient(color_gradient))
+}
+
+fn query_row(
+    query: &HistoryQuery,
+    font: Font,
+    language: Language,
+) -> Column<'static, Message, Renderer<StyleType>> {
+    let query_from = query.clone();
+    let query_to = query.clone();
+    let is_valid = query.get_interval().is_some();
+
+    let interval_row = Row::new()
+        .spacing(10)
+        .align_items(Alignment::Center)
+        .push(Text::new(format!("{}:", from_translation(language))).font(font))
+        .push(
+            TextInput::new("YYYY-MM-DD HH:MM", &query.from)
+                .on_input(move |from| {
+                    Message::UpdateHistoryQuery(HistoryQuery {
+                        from,
+                        ..query_from.clone()
+                    })
+                })
+                .padding([0, 5])
+                .font(font)
+                .width(Length::Fixed(150.0))
+                .style(if is_valid {
+                    TextInputType::Standard
+                } else {
+                    TextInputType::Error
+                }),
+        )
+        .push(Text::new(format!("{}:", to_translation(language))).font(font))
+        .push(
+            TextInput::new("YYYY-MM-DD HH:MM", &query.to)
+                .on_input(move |to| {
+                    Message::UpdateHistoryQuery(HistoryQuery {
+                        to,
+                        ..query_to.clone()
+                    })
+                })
+                .padding([0, 5])
+                .font(font)
+                .width(Length::Fixed(150.0))
+                .style(if is_valid {
+                    TextInputType::Standard
+                } else {
+                    TextInputType::Error
+                }),
+        )
+        .push(horizontal_space(Fixed(10.0)))
+        .push(
+            button(
+                Text::new(search_translation(language))
+                    .font(font)
+                    .vertical_alignment(Vertical::Center)
+                    .horizontal_alignment(Horizontal::Center),
+            )
+            .padding(5)
+            .height(Length::Fixed(30.0))
+            .width(Length::Fixed(90.0))
+            .on_press(Message::SearchHistory),
+        );
+
+    let mut group_row = Row::new().spacing(10).align_items(Alignment::Center).push(
+        Text::new(format!("{}:", group_by_translation(language)))
+            .style(TextType::Subtitle)
+            .font(font),
+    );
+    for group in HistoryGroup::ALL {
+        let is_active = query.group.eq(&group);
+        group_row = group_row.push(
+            Button::new(
+                Text::new(group.get_label(language).to_owned())
+                    .width(Length::Fill)
+                    .horizontal_alignment(Horizontal::Center)
+                    .vertical_alignment(Vertical::Center)
+                    .font(font),
+            )
+            .width(Length::Fixed(120.0))
+            .height(Length::Fixed(30.0))
+            .style(if is_active {
+                ButtonType::BorderedRoundSelected
+            } else {
+                ButtonType::BorderedRound
+            })
+            .on_press(Message::UpdateHistoryQuery(HistoryQuery {
+                group,
+                ..query.clone()
+            })),
+        );
+    }
+
+    let mut column = Column::new()
+        .spacing(10)
+        .align_items(Alignment::Center)
+        .push(interval_row)
+        .push(group_row);
+
+    if query.group.eq(&HistoryGroup::Flows) {
+        let query_address = query.clone();
+        column = column.push(
+            Row::new()
+                .spacing(10)
+                .align_items(Alignment::Center)
+                .push(Text::new(format!("{}:", local_address_translation(language))).font(font))
+                .push(
+                    TextInput::new("", &query.local_address)
+                        .on_input(move |local_address| {
+                            Message::UpdateHistoryQuery(HistoryQuery {
+                                local_address,
+                                ..query_address.clone()
+                            })
+                        })
+                        .padding([0, 5])
+                        .font(font)
+                        .width(Length::Fixed(250.0))
+                        .style(TextInputType::Standard),
+                ),
+        );
+    }
+
+    column
+}
+
+fn results_col(
+    results: &Option<Result<Vec<HistoryEntry>, String>>,
+    font: Font,
+    language: Language,
+) -> Container<'static, Message, Renderer<StyleType>> {
+    let content = match results {
+        None => Column::new(),
+        Some(Err(error)) => {
+            Column::new().push(Text::new(error.clone()).style(TextType::Danger).font(font))
+        }
+        Some(Ok(entries)) if entries.is_empty() => {
+            Column::new().push(Text::new(no_search_results_translation(language)).font(font))
+        }
+        Some(Ok(entries)) => {
+            let mut entries_col = Column::new().spacing(5).padding([0, 20, 10, 0]);
+            for entry in entries {
+                entries_col = entries_col.push(entry_row(entry, font, language));
+            }
+            Column::new().push(
+                Scrollable::new(entries_col)
+                    .direction(Direction::Vertical(ScrollbarType::properties())),
+            )
+        }
+    };
+
+    Container::new(content)
+        .padding([0, 0, 10, 30])
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(Horizontal::Center)
+}
+
+fn entry_row(
+    entry: &HistoryEntry,
+    font: Font,
+    language: Language,
+) -> Row<'static, Message, Renderer<StyleType>> {
+    Row::new()
+        .spacing(10)
+        .push(
+            Text::new(entry.label.clone())
+                .font(font)
+                .width(Length::Fill),
+        )
+        .push(
+            Text::new(get_formatted_bytes_string_with_b(
+                entry.data_info.tot_bytes(),
+            ))
+            .font(font)
+            .width(Length::Fixed(100.0))
+            .horizontal_alignment(Horizontal::Right),
+        )
+        .push(
+            Text::new(format!(
+                "{} {}",
+                entry.data_info.tot_packets(),
+                packets_translation(language)
+            ))
+            .font(font)
+            .width(Length::Fixed(150.0))
+            .horizontal_alignment(Horizontal::Right),
+        )
+}
@@ -9,11 +9,11 @@
 use iced::widget::text::Shaping;
 use iced::widget::tooltip::Position;
 use iced::widget::{
-    button, horizontal_space, vertical_space, Button, Column, Container, Row, Rule, Scrollable,
-    Text, TextInput, Tooltip,
+    button, horizontal_space, vertical_space, Button, Checkbox, Column, Container, Row, Rule,
+    Scrollable, Text, TextInput, Tooltip,
 };
 use iced::Length::FillPortion;
-use iced::{alignment, Font, Length, Renderer};
+use iced::{alignment, Alignment, Font, Length, Renderer};
 use pcap::Device;
 
 use crate::gui::styles::button::ButtonType;
@@ -32,8 +32,11 @@
     address_translation, addresses_translation, choose_adapters_translation,
     ip_version_translation, protocol_translation, select_filters_translation, start_translation,
 };
-use crate::translations::translations_3::port_translation;
+use crate::translations::translations_3::{
+    also_analyze_adapter_translation, port_translation, session_from_file_translation,
+};
 use crate::utils::formatted_strings::get_invalid_filters_string;
+use crate::utils::types::file_info::FileInfo;
 use crate::utils::types::icon::Icon;
 use crate::{ConfigSettings, IpVersion, Language, Protocol, StyleType};
 
@@ -85,12 +88,18 @@ pub fn initial_page(sniffer: &Sniffer) -> Container<Message, Renderer<StyleType>
         )
         .push(Rule::horizontal(40))
         .push(
-            Container::new(button_start(
-                font,
-                language,
-                color_gradient,
-                &sniffer.filters,
-            ))
+            Container::new(
+                Row::new()
+                    .spacing(20)
+                    .align_items(Alignment::Center)
+                    .push(button_start(
+                        font,
+                        language,
+                        color_gradient,
+                        &sniffer.filters,
+                    ))
+                    .push(button_open_session(font, language)),
+            )
             .width(Length::Fill)
             .height(Length::Fill)
             .align_y(Vertical::Center)
@@ -107,7 +116,7 @@ pub fn initial_page(sniffer: &Sniffer) -> Container<Message, Renderer<StyleType>
     Container::new(body).height(Length::Fill)
 }
 
-fn col_ip_buttons(
+pub fn col_ip_buttons(
     active_ip_filters: &HashSet<IpVersion>,
     font: Font,
     language: Language,
@@ -147,7 +156,7 @@ fn col_ip_buttons(
         .push(buttons_row)
 }
 
-fn col_protocol_buttons(
+pub fn col_protocol_buttons(
     active_protocol_filters: &HashSet<Protocol>,
     font: Font,
     language: Language,
@@ -188,7 +197,7 @@ fn col_protocol_buttons(
         .push(buttons_row)
 }
 
-fn col_address_input(
+pub fn col_address_input(
     value: &String,
     font: Font,
     language: Language,
@@ -223,7 +232,7 @@ fn col_address_input(
         .push(input_row)
 }
 
-fn col_port_input(
+pub fn col_port_input(
     value: &String,
     font: Font,
     language: Language,
@@ -293,6 +302,36 @@ fn button_start(
         .style(ContainerType::Tooltip)
 }
 
+fn button_open_session(
+    font: Font,
+    language: Language,
+) -> Tooltip<'static, Message, Renderer<StyleType>> {
+    let content = button(
+        Icon::File
+            .to_text()
+            .size(25)
+            .horizontal_alignment(alignment::Horizontal::Center)
+            .vertical_alignment(alignment::Vertical::Center),
+    )
+    .padding(10)
+    .height(Length::Fixed(80.0))
+    .width(Length::Fixed(80.0))
+    .on_press(Message::OpenFile(
+        String::new(),
+        FileInfo::Session,
+        Message::OpenSession,
+    ));
+
+    Tooltip::new(
+        content,
+        session_from_file_translation(language),
+        Position::Top,
+    )
+    .gap(5)
+    .font(font)
+    .style(ContainerType::Tooltip)
+}
+
 fn get_col_adapter(sniffer: &Sniffer, font: Font) -> Column<Message, Renderer<StyleType>> {
     let ConfigSettings { language, .. } = sniffer.configs.lock().unwrap().settings;
 
@@ -345,17 +384,39 @@ fn get_col_adapter(sniffer: &Sniffer, font: Font) -> Column<Message, Renderer<St
                 |scroll_adapters, adapter| {
                     let name = adapter.0.clone();
                     let description = adapter.1.clone();
-                    scroll_adapters.push(
+                    let is_main = name == sniffer.device.name;
+                    let is_additional = sniffer
+                        .additional_devices
+                        .iter()
+                        .any(|device| device.name == name);
+                    let mut row = Row::new().spacing(10).align_items(Alignment::Center).push(
                         Button::new(Text::new(description).font(font))
                             .padding([20, 30])
                             .width(Length::Fill)
-                            .style(if name == sniffer.device.name {
+                            .style(if is_main || is_additional {
                                 ButtonType::BorderedRoundSelected
                             } else {
                                 ButtonType::BorderedRound
                             })
-                            .on_press(Message::AdapterSelection(name)),
-                    )
+                            .on_press(Message::AdapterSelection(name.clone())),
+                    );
+                    row = row.push(if is_main {
+                        Container::new(horizontal_space(Length::Fixed(18.0)))
+                    } else {
+                        Container::new(
+                            Tooltip::new(
+                                Checkbox::new("", is_additional, move |toggled| {
+                                    Message::AdditionalAdapterSelection(name.clone(), toggled)
+                                })
+                                .size(18),
+                                also_analyze_adapter_translation(language),
+                                Position::Left,
+                            )
+                            .font(font)
+                            .style(ContainerType::Tooltip),
+                        )
+                    });
+                    scroll_adapters.push(row)
                 },
             ))
             .direction(Direction::Vertical(ScrollbarType::properties())),
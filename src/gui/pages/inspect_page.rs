@@ -1,8 +1,9 @@
 use iced::alignment::{Horizontal, Vertical};
 use iced::widget::scrollable::Direction;
+use iced::widget::tooltip::Position;
 use iced::widget::{button, horizontal_space, vertical_space, Rule};
 use iced::widget::{
-    lazy, Button, Checkbox, Column, Container, PickList, Row, Scrollable, Text, TextInput,
+    lazy, Button, Checkbox, Column, Container, PickList, Row, Scrollable, Text, TextInput, Tooltip,
 };
 use iced::{alignment, Alignment, Font, Length, Renderer};
 
@@ -18,12 +19,15 @@
 use crate::networking::types::search_parameters::{FilterInputType, SearchParameters};
 use crate::networking::types::traffic_direction::TrafficDirection;
 use crate::report::get_report_entries::get_searched_entries;
-use crate::translations::translations::{address_translation, application_protocol_translation};
+use crate::translations::translations::{
+    address_translation, application_protocol_translation, network_adapter_translation,
+};
 use crate::translations::translations_2::{
     administrative_entity_translation, country_translation, domain_name_translation,
     no_search_results_translation, only_show_favorites_translation, search_filters_translation,
     showing_results_translation, sort_by_translation,
 };
+use crate::translations::translations_3::blocklist_matched_translation;
 use crate::utils::types::icon::Icon;
 use crate::{ConfigSettings, Language, ReportSortType, RunningPage, Sniffer, StyleType};
 
@@ -132,7 +136,7 @@ fn lazy_report(sniffer: &Sniffer) -> Container<'static, Message, Renderer<StyleT
         } else {
             TextType::Incoming
         };
-        let entry_row = Row::new()
+        let mut entry_row = Row::new()
             .align_items(Alignment::Center)
             .push(
                 Text::new(format!("  {}{}  ", report_entry.key, report_entry.val))
@@ -141,6 +145,19 @@ fn lazy_report(sniffer: &Sniffer) -> Container<'static, Message, Renderer<StyleT
             )
             .push(report_entry.tooltip)
             .push(Text::new("  "));
+        if let Some(blocklist) = report_entry.blocklist {
+            entry_row = entry_row
+                .push(
+                    Tooltip::new(
+                        Icon::Forbidden.to_text().style(TextType::Danger),
+                        format!("{}: {blocklist}", blocklist_matched_translation(language)),
+                        Position::FollowCursor,
+                    )
+                    .font(font)
+                    .style(ContainerType::Tooltip),
+                )
+                .push(Text::new("  "));
+        }
 
         scroll_report = scroll_report.push(
             button(entry_row)
@@ -290,6 +307,14 @@ fn filters_col(
                     120.0,
                     search_params.clone(),
                     font,
+                ))
+                .push(filter_input(
+                    FilterInputType::Interface,
+                    &search_params.interface,
+                    network_adapter_translation(language),
+                    120.0,
+                    search_params.clone(),
+                    font,
                 )),
         )
 }
@@ -326,6 +351,10 @@ fn filter_input(
                 address: String::new(),
                 ..search_params.clone()
             },
+            FilterInputType::Interface => SearchParameters {
+                interface: String::new(),
+                ..search_params.clone()
+            },
         },
         font,
     );
@@ -353,6 +382,10 @@ fn filter_input(
                     address: new_value.trim().to_string(),
                     ..search_params.clone()
                 },
+                FilterInputType::Interface => SearchParameters {
+                    interface: new_value.trim().to_string(),
+                    ..search_params.clone()
+                },
             })
         })
         .padding([0, 5])
@@ -400,7 +433,7 @@ fn get_button_change_page(increment: bool) -> Button<'static, Message, Renderer<
     .on_press(Message::UpdatePageNumber(increment))
 }
 
-fn get_change_page_row(
+pub fn get_change_page_row(
     font: Font,
     language: Language,
     page_number: usize,
@@ -0,0 +1,156 @@
+//! Module defining the map page of the application.
+//!
+//! It displays a world map with the traffic exchanged with each country.
+
+use iced::alignment::{Horizontal, Vertical};
+use iced::widget::{horizontal_space, lazy, Button, Column, Container, Row, Text};
+use iced::{Alignment, Font, Length, Renderer};
+
+use crate::chart::types::world_map::WorldMap;
+use crate::gui::components::tab::get_pages_tabs;
+use crate::gui::styles::button::ButtonType;
+use crate::gui::styles::container::ContainerType;
+use crate::gui::styles::style_constants::FONT_SIZE_TITLE;
+use crate::gui::styles::text::TextType;
+use crate::gui::types::message::Message;
+use crate::translations::translations::{bytes_chart_translation, packets_chart_translation};
+use crate::translations::translations_3::{
+    favorite_countries_translation, inspect_country_translation, traffic_by_country_translation,
+};
+use crate::utils::types::icon::Icon;
+use crate::{ChartType, ConfigSettings, Language, RunningPage, Sniffer, StyleType};
+
+/// Computes the body of gui map page
+pub fn map_page(sniffer: &Sniffer) -> Container<Message, Renderer<StyleType>> {
+    let ConfigSettings {
+        style, language, ..
+    } = sniffer.configs.lock().unwrap().settings;
+    let font = style.get_extension().font;
+    let font_headers = style.get_extension().font_headers;
+
+    let mut body = Column::new()
+        .width(Length::Fill)
+        .padding(10)
+        .spacing(10)
+        .align_items(Alignment::Center);
+
+    let mut tab_and_body = Column::new().height(Length::Fill);
+
+    let tabs = get_pages_tabs(
+        RunningPage::Map,
+        font,
+        font_headers,
+        language,
+        sniffer.unread_notifications,
+    );
+
+    tab_and_body = tab_and_body.push(tabs);
+
+    let map = lazy(
+        (
+            sniffer.runtime_data.tot_sent_packets + sniffer.runtime_data.tot_received_packets,
+            style,
+            language,
+            sniffer.traffic_chart.chart_type,
+            sniffer.info_traffic.lock().unwrap().favorite_hosts.len(),
+        ),
+        move |_| lazy_map(sniffer),
+    );
+
+    body = body.push(map);
+
+    Container::new(Column::new().push(tab_and_body.push(body))).height(Length::Fill)
+}
+
+fn lazy_map(sniffer: &Sniffer) -> Container<'static, Message, Renderer<StyleType>> {
+    let ConfigSettings {
+        style, language, ..
+    } = sniffer.configs.lock().unwrap().settings;
+    let font = style.get_extension().font;
+    let chart_type = sniffer.traffic_chart.chart_type;
+
+    let world_map = WorldMap::new(
+        &sniffer.info_traffic.lock().unwrap().hosts,
+        chart_type,
+        style,
+    );
+
+    let chart_info_string = format!(
+        "({})",
+        if chart_type.eq(&ChartType::Packets) {
+            packets_chart_translation(language)
+        } else {
+            bytes_chart_translation(language)
+        }
+    );
+
+    let header = Row::new()
+        .padding([0, 0, 10, 0])
+        .spacing(10)
+        .align_items(Alignment::Center)
+        .push(
+            Text::new(traffic_by_country_translation(language))
+                .font(font)
+                .style(TextType::Title)
+                .size(FONT_SIZE_TITLE),
+        )
+        .push(
+            Text::new(chart_info_string)
+                .style(TextType::Subtitle)
+                .font(font),
+        )
+        .push(horizontal_space(Length::Fill))
+        .push(row_chart_type(language, font, chart_type));
+
+    let legend = Row::new()
+        .padding([10, 0, 0, 0])
+        .spacing(10)
+        .align_items(Alignment::Center)
+        .push(Icon::Star.to_text().size(20).style(TextType::Starred))
+        .push(Text::new(favorite_countries_translation(language)).font(font))
+        .push(horizontal_space(Length::Fill))
+        .push(
+            Text::new(inspect_country_translation(language))
+                .style(TextType::Subtitle)
+                .font(font),
+        );
+
+    Container::new(
+        Column::new()
+            .align_items(Alignment::Center)
+            .push(header)
+            .push(world_map.view())
+            .push(legend),
+    )
+    .width(Length::Fixed(1042.0))
+    .padding(20)
+    .style(ContainerType::BorderedRound)
+}
+
+fn row_chart_type(
+    language: Language,
+    font: Font,
+    chart_type: ChartType,
+) -> Row<'static, Message, Renderer<StyleType>> {
+    let mut ret_val = Row::new().spacing(5).align_items(Alignment::Center);
+    for option in ChartType::ALL {
+        let is_active = chart_type.eq(&option);
+        ret_val = ret_val.push(
+            Button::new(
+                Text::new(option.get_label(language).to_owned())
+                    .horizontal_alignment(Horizontal::Center)
+                    .vertical_alignment(Vertical::Center)
+                    .font(font),
+            )
+            .padding([0, 10])
+            .height(Length::Fixed(25.0))
+            .style(if is_active {
+                ButtonType::BorderedRoundSelected
+            } else {
+                ButtonType::BorderedRound
+            })
+            .on_press(Message::ChartSelection(option)),
+        );
+    }
+    ret_val
+}
@@ -1,8 +1,12 @@
 pub mod connection_details_page;
+pub mod dns_page;
+pub mod history_page;
 pub mod initial_page;
 pub mod inspect_page;
+pub mod map_page;
 pub mod notifications_page;
 pub mod overview_page;
+pub mod session_comparison_page;
 pub mod settings_general_page;
 pub mod settings_notifications_page;
 pub mod settings_style_page;
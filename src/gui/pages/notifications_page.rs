@@ -1,8 +1,8 @@
 use iced::alignment::{Horizontal, Vertical};
 use iced::widget::scrollable::Direction;
 use iced::widget::tooltip::Position;
-use iced::widget::{button, vertical_space};
-use iced::widget::{lazy, Column, Container, Row, Scrollable, Text, Tooltip};
+use iced::widget::{button, horizontal_space, vertical_space};
+use iced::widget::{lazy, Column, Container, PickList, Row, Scrollable, Text, TextInput, Tooltip};
 use iced::Length::FillPortion;
 use iced::{Alignment, Font, Length, Renderer};
 
@@ -11,23 +11,38 @@
 use crate::gui::components::header::get_button_settings;
 use crate::gui::components::tab::get_pages_tabs;
 use crate::gui::components::types::my_modal::MyModal;
+use crate::gui::pages::inspect_page::get_change_page_row;
+use crate::gui::pages::settings_notifications_page::get_rule_description;
 use crate::gui::pages::types::settings_page::SettingsPage;
 use crate::gui::styles::container::ContainerType;
 use crate::gui::styles::scrollbar::ScrollbarType;
 use crate::gui::styles::style_constants::FONT_SIZE_FOOTER;
 use crate::gui::styles::text::TextType;
 use crate::gui::types::message::Message;
+use crate::networking::types::scan_detector::ScanKind;
+use crate::notifications::notification_log::ExportFormat;
 use crate::notifications::types::logged_notification::{
-    BytesThresholdExceeded, FavoriteTransmitted, LoggedNotification, PacketsThresholdExceeded,
+    ActionFailed, AlertEnded, BlocklistMatched, BytesThresholdExceeded, FavoriteTransmitted,
+    FirstSeen, FirstSeenTarget, LoggedNotification, NewListeningPort, PacketsThresholdExceeded,
+    QuotaReached, RuleThresholdExceeded, ScanDetected, ThresholdAlert,
 };
+use crate::notifications::types::notification_action::NotificationKind;
+use crate::notifications::types::notification_filter::NotificationFilter;
 use crate::translations::translations::{
     bytes_exceeded_translation, bytes_exceeded_value_translation, clear_all_translation,
     favorite_transmitted_translation, incoming_translation, no_notifications_received_translation,
-    no_notifications_set_translation, only_last_30_translation, outgoing_translation,
-    packets_exceeded_translation, packets_exceeded_value_translation, per_second_translation,
-    threshold_translation,
+    no_notifications_set_translation, outgoing_translation, packets_exceeded_translation,
+    packets_exceeded_value_translation, per_second_translation, threshold_translation,
 };
-use crate::utils::formatted_strings::get_formatted_bytes_string_with_b;
+use crate::translations::translations_2::no_search_results_translation;
+use crate::translations::translations_3::{
+    action_failed_translation, alert_ended_translation, blocklist_matched_translation,
+    data_cap_translation, data_usage_translation, export_notifications_translation,
+    host_sweep_translation, new_asn_translation, new_country_translation, new_host_translation,
+    new_listening_port_translation, port_scan_translation, quota_reached_translation,
+    rule_exceeded_translation, scan_targets_translation, search_translation, syn_flood_translation,
+};
+use crate::utils::formatted_strings::{get_formatted_bytes_string_with_b, get_formatted_timestamp};
 use crate::utils::types::icon::Icon;
 use crate::{ConfigSettings, Language, RunningPage, Sniffer, StyleType};
 
@@ -58,44 +73,75 @@ pub fn notifications_page(sniffer: &Sniffer) -> Container<Message, Renderer<Styl
         .push(tabs)
         .push(vertical_space(Length::Fixed(15.0)));
 
+    // notifications of the log (also of the previous sessions) matching the filter
+    let no_records =
+        sniffer.notifications_results_number == 0 && !sniffer.notification_filter.is_active();
+
     if notifications.packets_notification.threshold.is_none()
         && notifications.bytes_notification.threshold.is_none()
         && !notifications.favorite_notification.notify_on_favorite
-        && sniffer.runtime_data.logged_notifications.is_empty()
+        && !notifications.first_seen_notification.notify_on_first_seen
+        && !notifications.new_port_notification.notify_on_new_port
+        && !notifications.scan_notification.notify_on_scan
+        && notifications.quota_notification.cap.is_none()
+        && (!notifications.blocklist_notification.notify_on_match
+            || sniffer
+                .configs
+                .lock()
+                .unwrap()
+                .settings
+                .blocklists
+                .is_empty())
+        && sniffer
+            .configs
+            .lock()
+            .unwrap()
+            .settings
+            .notification_rules
+            .is_empty()
+        && no_records
     {
         let body = body_no_notifications_set(font, language);
         tab_and_body = tab_and_body.push(body);
-    } else if sniffer.runtime_data.logged_notifications.is_empty() {
+    } else if no_records {
         let body = body_no_notifications_received(font, language, &sniffer.waiting);
         tab_and_body = tab_and_body.push(body);
     } else {
+        tab_and_body = tab_and_body
+            .push(filter_row(&sniffer.notification_filter, language, font))
+            .push(vertical_space(Length::Fixed(10.0)));
         let logged_notifications = lazy(
             (
                 sniffer.runtime_data.tot_emitted_notifications,
-                sniffer.runtime_data.logged_notifications.len(),
+                sniffer.notifications_results_number,
+                sniffer.notifications_page_number,
+                sniffer.notification_filter.clone(),
                 language,
                 style,
             ),
             move |_| lazy_logged_notifications(sniffer),
         );
+        let mut notifications_col = Column::new().align_items(Alignment::Center).push(
+            Scrollable::new(logged_notifications)
+                .height(Length::FillPortion(15))
+                .direction(Direction::Vertical(ScrollbarType::properties())),
+        );
+        if sniffer.notifications_results_number > 0 {
+            let start_entry_num = (sniffer.notifications_page_number - 1) * 20 + 1;
+            let end_entry_num = start_entry_num + sniffer.notification_records.len() - 1;
+            notifications_col = notifications_col.push(get_change_page_row(
+                font,
+                language,
+                sniffer.notifications_page_number,
+                start_entry_num,
+                end_entry_num,
+                sniffer.notifications_results_number,
+            ));
+        }
         let body_row = Row::new()
             .width(Length::Fill)
-            .push(
-                Container::new(if sniffer.runtime_data.logged_notifications.len() < 30 {
-                    Text::new("")
-                } else {
-                    Text::new(only_last_30_translation(language)).font(font)
-                })
-                .padding(10)
-                .width(Length::FillPortion(1))
-                .height(Length::Fill)
-                .align_x(Horizontal::Center)
-                .align_y(Vertical::Center),
-            )
-            .push(
-                Scrollable::new(logged_notifications)
-                    .direction(Direction::Vertical(ScrollbarType::properties())),
-            )
+            .push(horizontal_space(Length::FillPortion(1)))
+            .push(notifications_col)
             .push(
                 Container::new(get_button_clear_all(font, language))
                     .width(Length::FillPortion(1))
@@ -188,12 +234,9 @@ fn packets_notification_log(
             Column::new()
                 .spacing(7)
                 .width(Length::Fixed(250.0))
-                .push(
-                    Row::new()
-                        .spacing(5)
-                        .push(Icon::Clock.to_text())
-                        .push(Text::new(logged_notification.timestamp).font(font)),
-                )
+                .push(Row::new().spacing(5).push(Icon::Clock.to_text()).push(
+                    Text::new(get_formatted_timestamp(logged_notification.timestamp)).font(font),
+                ))
                 .push(
                     Text::new(packets_exceeded_translation(language))
                         .style(TextType::Title)
@@ -267,12 +310,9 @@ fn bytes_notification_log(
             Column::new()
                 .spacing(7)
                 .width(Length::Fixed(250.0))
-                .push(
-                    Row::new()
-                        .spacing(5)
-                        .push(Icon::Clock.to_text())
-                        .push(Text::new(logged_notification.timestamp).font(font)),
-                )
+                .push(Row::new().spacing(5).push(Icon::Clock.to_text()).push(
+                    Text::new(get_formatted_timestamp(logged_notification.timestamp)).font(font),
+                ))
                 .push(
                     Text::new(bytes_exceeded_translation(language))
                         .style(TextType::Title)
@@ -307,6 +347,133 @@ fn bytes_notification_log(
         .style(ContainerType::BorderedRound)
 }
 
+fn rule_notification_log(
+    logged_notification: RuleThresholdExceeded,
+    language: Language,
+    font: Font,
+) -> Container<'static, Message, Renderer<StyleType>> {
+    let mut incoming_str = " - ".to_string();
+    incoming_str.push_str(incoming_translation(language));
+    incoming_str.push_str(": ");
+    incoming_str.push_str(&get_formatted_bytes_string_with_b(u128::from(
+        logged_notification.incoming,
+    )));
+    let mut outgoing_str = " - ".to_string();
+    outgoing_str.push_str(outgoing_translation(language));
+    outgoing_str.push_str(": ");
+    outgoing_str.push_str(&get_formatted_bytes_string_with_b(u128::from(
+        logged_notification.outgoing,
+    )));
+    let content = Row::new()
+        .spacing(30)
+        .align_items(Alignment::Center)
+        .height(Length::Fill)
+        .push(
+            Tooltip::new(
+                Icon::BytesThreshold.to_text().size(80),
+                rule_exceeded_translation(language),
+                Position::FollowCursor,
+            )
+            .font(font)
+            .style(ContainerType::Tooltip),
+        )
+        .push(
+            Column::new()
+                .spacing(7)
+                .width(Length::Fixed(250.0))
+                .push(Row::new().spacing(5).push(Icon::Clock.to_text()).push(
+                    Text::new(get_formatted_timestamp(logged_notification.timestamp)).font(font),
+                ))
+                .push(
+                    Text::new(rule_exceeded_translation(language))
+                        .style(TextType::Title)
+                        .font(font),
+                )
+                .push(
+                    Text::new(get_rule_description(&logged_notification.rule, language))
+                        .size(FONT_SIZE_FOOTER)
+                        .style(TextType::Subtitle)
+                        .font(font),
+                ),
+        )
+        .push(
+            Column::new()
+                .spacing(7)
+                .push(
+                    Text::new(bytes_exceeded_value_translation(
+                        language,
+                        &get_formatted_bytes_string_with_b(
+                            u128::from(logged_notification.incoming)
+                                + u128::from(logged_notification.outgoing),
+                        ),
+                    ))
+                    .font(font),
+                )
+                .push(Text::new(incoming_str).font(font))
+                .push(Text::new(outgoing_str).font(font)),
+        );
+    Container::new(content)
+        .height(Length::Fixed(120.0))
+        .width(Length::Fixed(800.0))
+        .padding(10)
+        .style(ContainerType::BorderedRound)
+}
+
+fn alert_ended_log(
+    logged_notification: AlertEnded,
+    language: Language,
+    font: Font,
+) -> Container<'static, Message, Renderer<StyleType>> {
+    let (icon, description) = match &logged_notification.alert {
+        ThresholdAlert::Packets { threshold } => (
+            Icon::PacketsThreshold,
+            format!(
+                "{}: {threshold} {}",
+                threshold_translation(language),
+                per_second_translation(language)
+            ),
+        ),
+        ThresholdAlert::Bytes { threshold } => (
+            Icon::BytesThreshold,
+            format!(
+                "{}: {} {}",
+                threshold_translation(language),
+                get_formatted_bytes_string_with_b(u128::from(*threshold)),
+                per_second_translation(language)
+            ),
+        ),
+        ThresholdAlert::Rule(rule) => (Icon::BytesThreshold, get_rule_description(rule, language)),
+    };
+    let content = Row::new()
+        .spacing(30)
+        .align_items(Alignment::Center)
+        .height(Length::Fill)
+        .push(
+            Container::new(icon.to_text().size(40).style(TextType::Subtitle))
+                .width(Length::Fixed(80.0))
+                .align_x(Horizontal::Center),
+        )
+        .push(
+            Column::new()
+                .spacing(7)
+                .push(Row::new().spacing(5).push(Icon::Clock.to_text()).push(
+                    Text::new(get_formatted_timestamp(logged_notification.timestamp)).font(font),
+                ))
+                .push(Text::new(alert_ended_translation(language)).font(font))
+                .push(
+                    Text::new(description)
+                        .size(FONT_SIZE_FOOTER)
+                        .style(TextType::Subtitle)
+                        .font(font),
+                ),
+        );
+    Container::new(content)
+        .height(Length::Fixed(90.0))
+        .width(Length::Fixed(800.0))
+        .padding(10)
+        .style(ContainerType::BorderedRound)
+}
+
 fn favorite_notification_log(
     logged_notification: FavoriteTransmitted,
     language: Language,
@@ -349,12 +516,9 @@ fn favorite_notification_log(
             Column::new()
                 .width(Length::Fixed(250.0))
                 .spacing(7)
-                .push(
-                    Row::new()
-                        .spacing(5)
-                        .push(Icon::Clock.to_text())
-                        .push(Text::new(logged_notification.timestamp).font(font)),
-                )
+                .push(Row::new().spacing(5).push(Icon::Clock.to_text()).push(
+                    Text::new(get_formatted_timestamp(logged_notification.timestamp)).font(font),
+                ))
                 .push(
                     Text::new(favorite_transmitted_translation(language))
                         .style(TextType::Title)
@@ -374,6 +538,423 @@ fn favorite_notification_log(
         .style(ContainerType::BorderedRound)
 }
 
+fn first_seen_log(
+    logged_notification: FirstSeen,
+    language: Language,
+    font: Font,
+) -> Container<'static, Message, Renderer<StyleType>> {
+    let country = logged_notification.host.country;
+    let asn = &logged_notification.host.asn;
+
+    let mut domain_asn_str = logged_notification.host.domain;
+    if !asn.name.is_empty() {
+        domain_asn_str.push_str(&format!(" - {}", asn.name));
+    }
+
+    let row_flag_details = Row::new()
+        .align_items(Alignment::Center)
+        .spacing(5)
+        .push(get_flag_tooltip(
+            country,
+            FLAGS_WIDTH_BIG,
+            &logged_notification.data_info_host,
+            language,
+            font,
+        ))
+        .push(Text::new(domain_asn_str).font(font));
+
+    let title = match logged_notification.target {
+        FirstSeenTarget::Host => new_host_translation(language),
+        FirstSeenTarget::Country => new_country_translation(language),
+        FirstSeenTarget::Asn => new_asn_translation(language),
+    };
+
+    let content = Row::new()
+        .spacing(30)
+        .align_items(Alignment::Center)
+        .height(Length::Fill)
+        .push(
+            Tooltip::new(
+                Icon::Globe.to_text().size(80),
+                title,
+                Position::FollowCursor,
+            )
+            .font(font)
+            .style(ContainerType::Tooltip),
+        )
+        .push(
+            Column::new()
+                .width(Length::Fixed(250.0))
+                .spacing(7)
+                .push(Row::new().spacing(5).push(Icon::Clock.to_text()).push(
+                    Text::new(get_formatted_timestamp(logged_notification.timestamp)).font(font),
+                ))
+                .push(Text::new(title).style(TextType::Title).font(font)),
+        )
+        .push(
+            Column::new()
+                .spacing(7)
+                .width(Length::Fill)
+                .push(row_flag_details),
+        );
+    Container::new(content)
+        .height(Length::Fixed(120.0))
+        .width(Length::Fixed(800.0))
+        .padding(10)
+        .style(ContainerType::BorderedRound)
+}
+
+fn new_listening_port_log(
+    logged_notification: NewListeningPort,
+    language: Language,
+    font: Font,
+) -> Container<'static, Message, Renderer<StyleType>> {
+    let listening_port = logged_notification.listening_port;
+    let content = Row::new()
+        .spacing(30)
+        .align_items(Alignment::Center)
+        .height(Length::Fill)
+        .push(
+            Tooltip::new(
+                Icon::Warning.to_text().size(80),
+                new_listening_port_translation(language),
+                Position::FollowCursor,
+            )
+            .font(font)
+            .style(ContainerType::Tooltip),
+        )
+        .push(
+            Column::new()
+                .width(Length::Fixed(250.0))
+                .spacing(7)
+                .push(Row::new().spacing(5).push(Icon::Clock.to_text()).push(
+                    Text::new(get_formatted_timestamp(logged_notification.timestamp)).font(font),
+                ))
+                .push(
+                    Text::new(new_listening_port_translation(language))
+                        .style(TextType::Title)
+                        .font(font),
+                ),
+        )
+        .push(
+            Column::new()
+                .spacing(7)
+                .width(Length::Fill)
+                .push(
+                    Text::new(format!(
+                        "{} {}",
+                        listening_port.protocol, listening_port.port
+                    ))
+                    .font(font),
+                )
+                .push(
+                    Text::new(format!("← {}", logged_notification.remote_address))
+                        .size(FONT_SIZE_FOOTER)
+                        .style(TextType::Subtitle)
+                        .font(font),
+                ),
+        );
+    Container::new(content)
+        .height(Length::Fixed(120.0))
+        .width(Length::Fixed(800.0))
+        .padding(10)
+        .style(ContainerType::BorderedRound)
+}
+
+fn blocklist_matched_log(
+    logged_notification: BlocklistMatched,
+    language: Language,
+    font: Font,
+) -> Container<'static, Message, Renderer<StyleType>> {
+    let blocklist_match = logged_notification.blocklist_match;
+    let matched_str = match blocklist_match.name {
+        Some(name) => format!("{} ({name})", blocklist_match.address),
+        None => blocklist_match.address,
+    };
+    let content = Row::new()
+        .spacing(30)
+        .align_items(Alignment::Center)
+        .height(Length::Fill)
+        .push(
+            Tooltip::new(
+                Icon::Forbidden.to_text().size(80).style(TextType::Danger),
+                blocklist_matched_translation(language),
+                Position::FollowCursor,
+            )
+            .font(font)
+            .style(ContainerType::Tooltip),
+        )
+        .push(
+            Column::new()
+                .width(Length::Fixed(250.0))
+                .spacing(7)
+                .push(Row::new().spacing(5).push(Icon::Clock.to_text()).push(
+                    Text::new(get_formatted_timestamp(logged_notification.timestamp)).font(font),
+                ))
+                .push(
+                    Text::new(blocklist_matched_translation(language))
+                        .style(TextType::Danger)
+                        .font(font),
+                ),
+        )
+        .push(
+            Column::new()
+                .spacing(7)
+                .width(Length::Fill)
+                .push(Text::new(matched_str).font(font))
+                .push(
+                    Text::new(format!(
+                        "{}: {}",
+                        blocklist_match.list, blocklist_match.entry
+                    ))
+                    .size(FONT_SIZE_FOOTER)
+                    .style(TextType::Subtitle)
+                    .font(font),
+                ),
+        );
+    Container::new(content)
+        .height(Length::Fixed(120.0))
+        .width(Length::Fixed(800.0))
+        .padding(10)
+        .style(ContainerType::BorderedRound)
+}
+
+fn scan_detected_log(
+    logged_notification: ScanDetected,
+    language: Language,
+    font: Font,
+) -> Container<'static, Message, Renderer<StyleType>> {
+    let scan = logged_notification.scan;
+    let title = match scan.kind {
+        ScanKind::PortScan => port_scan_translation(language),
+        ScanKind::HostSweep => host_sweep_translation(language),
+        ScanKind::SynFlood => syn_flood_translation(language),
+    };
+    let content = Row::new()
+        .spacing(30)
+        .align_items(Alignment::Center)
+        .height(Length::Fill)
+        .push(
+            Tooltip::new(
+                Icon::Warning.to_text().size(80).style(TextType::Danger),
+                title,
+                Position::FollowCursor,
+            )
+            .font(font)
+            .style(ContainerType::Tooltip),
+        )
+        .push(
+            Column::new()
+                .width(Length::Fixed(250.0))
+                .spacing(7)
+                .push(Row::new().spacing(5).push(Icon::Clock.to_text()).push(
+                    Text::new(get_formatted_timestamp(logged_notification.timestamp)).font(font),
+                ))
+                .push(Text::new(title).style(TextType::Danger).font(font)),
+        )
+        .push(
+            Column::new()
+                .spacing(7)
+                .width(Length::Fill)
+                .push(Text::new(format!("{} → {}", scan.offenders(), scan.target)).font(font))
+                .push(
+                    Text::new(scan_targets_translation(
+                        language,
+                        scan.kind,
+                        scan.count,
+                        (scan.end - scan.start).num_seconds(),
+                    ))
+                    .size(FONT_SIZE_FOOTER)
+                    .style(TextType::Subtitle)
+                    .font(font),
+                ),
+        );
+    Container::new(content)
+        .height(Length::Fixed(120.0))
+        .width(Length::Fixed(800.0))
+        .padding(10)
+        .style(ContainerType::BorderedRound)
+}
+
+fn quota_reached_log(
+    logged_notification: QuotaReached,
+    language: Language,
+    font: Font,
+) -> Container<'static, Message, Renderer<StyleType>> {
+    let title = quota_reached_translation(language, logged_notification.level);
+    let usage_str = format!(
+        "{} / {}",
+        get_formatted_bytes_string_with_b(u128::from(logged_notification.used)),
+        get_formatted_bytes_string_with_b(u128::from(logged_notification.cap))
+    );
+    let content = Row::new()
+        .spacing(30)
+        .align_items(Alignment::Center)
+        .height(Length::Fill)
+        .push(
+            Tooltip::new(
+                Icon::BytesThreshold.to_text().size(80),
+                data_cap_translation(language),
+                Position::FollowCursor,
+            )
+            .font(font)
+            .style(ContainerType::Tooltip),
+        )
+        .push(
+            Column::new()
+                .width(Length::Fixed(250.0))
+                .spacing(7)
+                .push(Row::new().spacing(5).push(Icon::Clock.to_text()).push(
+                    Text::new(get_formatted_timestamp(logged_notification.timestamp)).font(font),
+                ))
+                .push(
+                    Text::new(title)
+                        .style(if logged_notification.level >= 100 {
+                            TextType::Danger
+                        } else {
+                            TextType::Title
+                        })
+                        .font(font),
+                ),
+        )
+        .push(
+            Column::new()
+                .spacing(7)
+                .width(Length::Fill)
+                .push(Text::new(logged_notification.adapter).font(font))
+                .push(Text::new(usage_str).font(font))
+                .push(
+                    Text::new(data_usage_translation(
+                        language,
+                        &logged_notification.cycle_start.to_string(),
+                    ))
+                    .size(FONT_SIZE_FOOTER)
+                    .style(TextType::Subtitle)
+                    .font(font),
+                ),
+        );
+    Container::new(content)
+        .height(Length::Fixed(120.0))
+        .width(Length::Fixed(800.0))
+        .padding(10)
+        .style(ContainerType::BorderedRound)
+}
+
+fn action_failed_log(
+    logged_notification: ActionFailed,
+    language: Language,
+    font: Font,
+) -> Container<'static, Message, Renderer<StyleType>> {
+    let content = Row::new()
+        .spacing(30)
+        .align_items(Alignment::Center)
+        .height(Length::Fill)
+        .push(
+            Container::new(Icon::Warning.to_text().size(40).style(TextType::Danger))
+                .width(Length::Fixed(80.0))
+                .align_x(Horizontal::Center),
+        )
+        .push(
+            Column::new()
+                .spacing(7)
+                .push(Row::new().spacing(5).push(Icon::Clock.to_text()).push(
+                    Text::new(get_formatted_timestamp(logged_notification.timestamp)).font(font),
+                ))
+                .push(
+                    Text::new(action_failed_translation(language))
+                        .style(TextType::Danger)
+                        .font(font),
+                )
+                .push(
+                    Text::new(format!(
+                        "{}: {}",
+                        logged_notification.action, logged_notification.error
+                    ))
+                    .size(FONT_SIZE_FOOTER)
+                    .style(TextType::Subtitle)
+                    .font(font),
+                ),
+        );
+    Container::new(content)
+        .height(Length::Fixed(90.0))
+        .width(Length::Fixed(800.0))
+        .padding(10)
+        .style(ContainerType::BorderedRound)
+}
+
+fn filter_row(
+    filter: &NotificationFilter,
+    language: Language,
+    font: Font,
+) -> Row<'static, Message, Renderer<StyleType>> {
+    let current = filter.clone();
+    let search_input = TextInput::new(search_translation(language), &filter.search)
+        .on_input(move |search| {
+            Message::UpdateNotificationFilter(NotificationFilter {
+                search,
+                ..current.clone()
+            })
+        })
+        .padding([0, 5])
+        .font(font)
+        .width(Length::Fixed(300.0));
+
+    let current = filter.clone();
+    let kind_picklist = PickList::new(&NotificationKind::ALL[..], Some(filter.kind), move |kind| {
+        Message::UpdateNotificationFilter(NotificationFilter {
+            kind,
+            ..current.clone()
+        })
+    })
+    .padding([3, 7])
+    .font(font);
+
+    let mut ret_val = Row::new()
+        .spacing(10)
+        .align_items(Alignment::Center)
+        .push(search_input)
+        .push(kind_picklist);
+    if filter.is_active() {
+        ret_val = ret_val.push(
+            button(
+                Text::new("×")
+                    .font(font)
+                    .horizontal_alignment(Horizontal::Center)
+                    .vertical_alignment(Vertical::Center),
+            )
+            .padding(0)
+            .height(Length::Fixed(25.0))
+            .width(Length::Fixed(25.0))
+            .on_press(Message::UpdateNotificationFilter(
+                NotificationFilter::default(),
+            )),
+        );
+    }
+    ret_val = ret_val.push(horizontal_space(Length::Fixed(20.0)));
+    for (label, format) in [("CSV", ExportFormat::Csv), ("JSON", ExportFormat::Json)] {
+        ret_val = ret_val.push(
+            Tooltip::new(
+                button(
+                    Text::new(label)
+                        .font(font)
+                        .horizontal_alignment(Horizontal::Center)
+                        .vertical_alignment(Vertical::Center),
+                )
+                .padding(5)
+                .height(Length::Fixed(30.0))
+                .width(Length::Fixed(60.0))
+                .on_press(Message::ExportNotifications(format)),
+                export_notifications_translation(language),
+                Position::Top,
+            )
+            .gap(5)
+            .font(font)
+            .style(ContainerType::Tooltip),
+        );
+    }
+    ret_val
+}
+
 fn get_button_clear_all(
     font: Font,
     language: Language,
@@ -407,7 +988,11 @@ fn lazy_logged_notifications(sniffer: &Sniffer) -> Column<'static, Message, Rend
         .spacing(10)
         .align_items(Alignment::Center);
 
-    for logged_notification in &sniffer.runtime_data.logged_notifications {
+    for logged_notification in sniffer
+        .notification_records
+        .iter()
+        .map(|record| &record.notification)
+    {
         ret_val = ret_val.push(match logged_notification {
             LoggedNotification::PacketsThresholdExceeded(packet_threshold_exceeded) => {
                 packets_notification_log(packet_threshold_exceeded.clone(), language, font)
@@ -418,7 +1003,38 @@ fn lazy_logged_notifications(sniffer: &Sniffer) -> Column<'static, Message, Rend
             LoggedNotification::FavoriteTransmitted(favorite_transmitted) => {
                 favorite_notification_log(favorite_transmitted.clone(), language, font)
             }
+            LoggedNotification::RuleThresholdExceeded(rule_threshold_exceeded) => {
+                rule_notification_log(rule_threshold_exceeded.clone(), language, font)
+            }
+            LoggedNotification::AlertEnded(alert_ended) => {
+                alert_ended_log(alert_ended.clone(), language, font)
+            }
+            LoggedNotification::FirstSeen(first_seen) => {
+                first_seen_log(first_seen.clone(), language, font)
+            }
+            LoggedNotification::NewListeningPort(new_listening_port) => {
+                new_listening_port_log(new_listening_port.clone(), language, font)
+            }
+            LoggedNotification::BlocklistMatched(blocklist_matched) => {
+                blocklist_matched_log(blocklist_matched.clone(), language, font)
+            }
+            LoggedNotification::ScanDetected(scan_detected) => {
+                scan_detected_log(scan_detected.clone(), language, font)
+            }
+            LoggedNotification::QuotaReached(quota_reached) => {
+                quota_reached_log(quota_reached.clone(), language, font)
+            }
+            LoggedNotification::ActionFailed(action_failed) => {
+                action_failed_log(action_failed.clone(), language, font)
+            }
         });
     }
+    if sniffer.notification_records.is_empty() {
+        ret_val = ret_val.push(
+            Text::new(no_search_results_translation(language))
+                .font(font)
+                .horizontal_alignment(Horizontal::Center),
+        );
+    }
     ret_val
 }
@@ -3,6 +3,9 @@
 //! It contains elements to display traffic statistics: chart, detailed connections data
 //! and overall statistics about the filtered traffic.
 
+use std::path::Path;
+
+use chrono::{DateTime, Local};
 use iced::alignment::{Horizontal, Vertical};
 use iced::widget::scrollable::Direction;
 use iced::widget::tooltip::Position;
@@ -13,9 +16,13 @@
 use iced::Length::{Fill, FillPortion, Fixed};
 use iced::{Alignment, Font, Length, Renderer};
 
+use crate::chart::export_chart::ImageFormat;
+use crate::chart::types::chart_entity::ChartEntity;
+use crate::chart::types::chart_range::ChartRange;
 use crate::countries::country_utils::get_flag_tooltip;
 use crate::countries::flags_pictures::FLAGS_WIDTH_BIG;
 use crate::gui::components::tab::get_pages_tabs;
+use crate::gui::components::types::my_modal::MyModal;
 use crate::gui::styles::button::ButtonType;
 use crate::gui::styles::container::ContainerType;
 use crate::gui::styles::rule::RuleType;
@@ -23,12 +30,15 @@
 use crate::gui::styles::style_constants::FONT_SIZE_TITLE;
 use crate::gui::styles::text::TextType;
 use crate::gui::types::message::Message;
+use crate::gui::types::session_snapshot::OpenedSession;
 use crate::gui::types::sniffer::Sniffer;
 use crate::networking::types::data_info::DataInfo;
+use crate::networking::types::data_info_host::DataInfoHost;
 use crate::networking::types::filters::Filters;
 use crate::networking::types::host::Host;
 use crate::networking::types::my_device::MyDevice;
 use crate::networking::types::search_parameters::SearchParameters;
+use crate::oui::vendor::get_vendor;
 use crate::report::get_report_entries::{get_app_entries, get_host_entries};
 use crate::translations::translations::{
     active_filters_translation, application_protocol_translation, bytes_chart_translation,
@@ -41,7 +51,12 @@
     data_representation_translation, dropped_packets_translation, host_translation,
     only_top_30_hosts_translation,
 };
-use crate::translations::translations_3::unsupported_link_type_translation;
+use crate::translations::translations_3::{
+    analysis_paused_translation, data_usage_translation, edit_filters_translation,
+    export_chart_translation, filters_changed_translation, other_adapters_translation,
+    read_only_session_translation, session_file_translation, show_in_chart_translation,
+    show_total_traffic_translation, unsupported_link_type_translation,
+};
 use crate::utils::formatted_strings::{
     get_active_filters_string, get_formatted_bytes_string_with_b, get_percentage_string,
 };
@@ -74,8 +89,14 @@ pub fn overview_page(sniffer: &Sniffer) -> Container<Message, Renderer<StyleType
             }
             (observed, 0) => {
                 //no packets have been filtered but some have been observed
-                body =
-                    body_no_observed(&sniffer.filters, observed, font, language, &sniffer.waiting);
+                body = body_no_observed(
+                    &sniffer.active_filters.lock().unwrap(),
+                    sniffer.runtime_data.last_filters_change,
+                    observed,
+                    font,
+                    language,
+                    &sniffer.waiting,
+                );
             }
             (_observed, filtered) => {
                 //observed > filtered > 0 || observed = filtered > 0
@@ -91,7 +112,15 @@ pub fn overview_page(sniffer: &Sniffer) -> Container<Message, Renderer<StyleType
                 let container_chart = container_chart(sniffer, font);
 
                 let container_info = lazy(
-                    (total, style, language, sniffer.traffic_chart.chart_type),
+                    (
+                        total,
+                        style,
+                        language,
+                        sniffer.traffic_chart.chart_type,
+                        *sniffer.paused.lock().unwrap(),
+                        sniffer.runtime_data.last_filters_change,
+                        sniffer.opened_session.is_some(),
+                    ),
                     move |_| lazy_col_info(total, filtered, dropped, sniffer),
                 );
 
@@ -103,6 +132,7 @@ pub fn overview_page(sniffer: &Sniffer) -> Container<Message, Renderer<StyleType
                         style,
                         language,
                         sniffer.traffic_chart.chart_type,
+                        sniffer.traffic_chart.selected.clone(),
                     ),
                     move |_| lazy_row_report(sniffer),
                 );
@@ -182,6 +212,7 @@ fn body_no_packets(
 
 fn body_no_observed(
     filters: &Filters,
+    last_filters_change: Option<DateTime<Local>>,
     observed: u128,
     font: Font,
     language: Language,
@@ -198,7 +229,14 @@ fn body_no_observed(
         .align_items(Alignment::Center)
         .push(vertical_space(FillPortion(1)))
         .push(Icon::Funnel.to_text().size(60))
-        .push(get_active_filters_col(filters, language, font, true))
+        .push(get_active_filters_col(
+            filters,
+            last_filters_change,
+            language,
+            font,
+            true,
+            true,
+        ))
         .push(Rule::horizontal(20))
         .push(tot_packets_text)
         .push(Text::new(waiting.to_owned()).font(font).size(50))
@@ -250,6 +288,24 @@ fn lazy_row_report(sniffer: &Sniffer) -> Container<'static, Message, Renderer<St
         .align_x(Horizontal::Center)
 }
 
+/// Returns the ASN name of a remote host, or the MAC vendor of a host in the local network
+fn get_host_subtitle(host: &Host, data_info_host: &DataInfoHost, sniffer: &Sniffer) -> String {
+    let subtitle = if host.asn.name.is_empty() {
+        data_info_host
+            .mac_address
+            .as_deref()
+            .and_then(|mac| get_vendor(mac, &sniffer.oui_reader))
+            .unwrap_or_default()
+    } else {
+        host.asn.name.clone()
+    };
+    if subtitle.is_empty() {
+        subtitle
+    } else {
+        format!(" - {subtitle}")
+    }
+}
+
 fn col_host(width: f32, sniffer: &Sniffer) -> Column<'static, Message, Renderer<StyleType>> {
     let ConfigSettings {
         style, language, ..
@@ -278,14 +334,7 @@ fn col_host(width: f32, sniffer: &Sniffer) -> Column<'static, Message, Renderer<
             .push(
                 Row::new()
                     .push(Text::new(host.domain.clone()).font(font))
-                    .push(
-                        Text::new(if host.asn.name.is_empty() {
-                            String::new()
-                        } else {
-                            format!(" - {}", host.asn.name)
-                        })
-                        .font(font),
-                    )
+                    .push(Text::new(get_host_subtitle(host, data_info_host, sniffer)).font(font))
                     .push(horizontal_space(Length::FillPortion(1)))
                     .push(
                         Text::new(if chart_type.eq(&ChartType::Packets) {
@@ -294,7 +343,14 @@ fn col_host(width: f32, sniffer: &Sniffer) -> Column<'static, Message, Renderer<
                             get_formatted_bytes_string_with_b(data_info_host.data_info.tot_bytes())
                         })
                         .font(font),
-                    ),
+                    )
+                    .push(horizontal_space(Length::Fixed(5.0)))
+                    .push(get_chart_button(
+                        ChartEntity::Host(host.clone()),
+                        sniffer.traffic_chart.selected.as_ref(),
+                        font,
+                        language,
+                    )),
             )
             .push(get_bars(incoming_bar_len, outgoing_bar_len));
 
@@ -396,7 +452,14 @@ fn col_app(width: f32, sniffer: &Sniffer) -> Column<'static, Message, Renderer<S
                             get_formatted_bytes_string_with_b(data_info.tot_bytes())
                         })
                         .font(font),
-                    ),
+                    )
+                    .push(horizontal_space(Length::Fixed(5.0)))
+                    .push(get_chart_button(
+                        ChartEntity::App(*app),
+                        sniffer.traffic_chart.selected.as_ref(),
+                        font,
+                        language,
+                    )),
             )
             .push(get_bars(incoming_bar_len, outgoing_bar_len));
 
@@ -429,7 +492,17 @@ fn lazy_col_info(
     } = sniffer.configs.lock().unwrap().settings;
     let font = style.get_extension().font;
 
-    let col_device = col_device(language, font, &sniffer.device);
+    let col_device = if let Some(opened_session) = &sniffer.opened_session {
+        col_session(language, font, opened_session)
+    } else {
+        col_device(
+            language,
+            font,
+            &sniffer.device,
+            &sniffer.additional_devices,
+            *sniffer.paused.lock().unwrap(),
+        )
+    };
 
     let col_data_representation =
         col_data_representation(language, font, sniffer.traffic_chart.chart_type);
@@ -477,25 +550,45 @@ fn container_chart(sniffer: &Sniffer, font: Font) -> Container<Message, Renderer
     });
     chart_info_string.push(')');
 
+    let mut header = Row::new()
+        .padding([10, 0, 15, 0])
+        .spacing(10)
+        .align_items(Alignment::Center)
+        .push(
+            traffic_rate_translation(language)
+                .font(font)
+                .style(TextType::Title)
+                .size(FONT_SIZE_TITLE),
+        )
+        .push(
+            Text::new(chart_info_string)
+                .style(TextType::Subtitle)
+                .font(font),
+        );
+    if let Some(entity) = &traffic_chart.selected {
+        header = header
+            .push(Text::new(entity.to_string()).font(font))
+            .push(get_chart_button(
+                entity.clone(),
+                Some(entity),
+                font,
+                language,
+            ));
+    }
+
     Container::new(
         Column::new()
             .align_items(Alignment::Center)
             .push(
-                Row::new()
-                    .padding([10, 0, 15, 0])
-                    .spacing(10)
-                    .align_items(Alignment::Center)
-                    .push(
-                        traffic_rate_translation(language)
-                            .font(font)
-                            .style(TextType::Title)
-                            .size(FONT_SIZE_TITLE),
-                    )
-                    .push(
-                        Text::new(chart_info_string)
-                            .style(TextType::Subtitle)
-                            .font(font),
-                    ),
+                header
+                    .push(horizontal_space(Length::Fixed(20.0)))
+                    .push(row_chart_range(
+                        font,
+                        traffic_chart.range,
+                        traffic_chart.span,
+                    ))
+                    .push(horizontal_space(Length::Fixed(20.0)))
+                    .push(row_export_chart(font, language)),
             )
             .push(traffic_chart.view()),
     )
@@ -505,10 +598,68 @@ fn container_chart(sniffer: &Sniffer, font: Font) -> Container<Message, Renderer
     .style(ContainerType::BorderedRound)
 }
 
+fn row_chart_range(
+    font: Font,
+    range: ChartRange,
+    span: u32,
+) -> Row<'static, Message, Renderer<StyleType>> {
+    let mut ret_val = Row::new().spacing(5).align_items(Alignment::Center);
+    for option in ChartRange::ALL {
+        // no range is highlighted after zooming
+        let is_active = range.eq(&option) && span == option.seconds();
+        ret_val = ret_val.push(
+            Button::new(
+                Text::new(option.get_label())
+                    .horizontal_alignment(Horizontal::Center)
+                    .vertical_alignment(Vertical::Center)
+                    .font(font),
+            )
+            .padding(0)
+            .width(Length::Fixed(45.0))
+            .height(Length::Fixed(25.0))
+            .style(if is_active {
+                ButtonType::BorderedRoundSelected
+            } else {
+                ButtonType::BorderedRound
+            })
+            .on_press(Message::ChartRangeSelection(option)),
+        );
+    }
+    ret_val
+}
+
+fn row_export_chart(font: Font, language: Language) -> Row<'static, Message, Renderer<StyleType>> {
+    let mut ret_val = Row::new().spacing(5).align_items(Alignment::Center);
+    for (label, format) in [("PNG", ImageFormat::Png), ("SVG", ImageFormat::Svg)] {
+        ret_val = ret_val.push(
+            Tooltip::new(
+                button(
+                    Text::new(label)
+                        .font(font)
+                        .horizontal_alignment(Horizontal::Center)
+                        .vertical_alignment(Vertical::Center),
+                )
+                .padding(0)
+                .height(Length::Fixed(25.0))
+                .width(Length::Fixed(45.0))
+                .on_press(Message::ExportChart(format)),
+                export_chart_translation(language),
+                Position::Top,
+            )
+            .gap(5)
+            .font(font)
+            .style(ContainerType::Tooltip),
+        );
+    }
+    ret_val
+}
+
 fn col_device(
     language: Language,
     font: Font,
     device: &MyDevice,
+    additional_devices: &[MyDevice],
+    paused: bool,
 ) -> Column<'static, Message, Renderer<StyleType>> {
     let link_type = device.link_type;
     #[cfg(not(target_os = "windows"))]
@@ -516,14 +667,69 @@ fn col_device(
     #[cfg(target_os = "windows")]
     let adapter_info = device.desc.as_ref().unwrap_or(&device.name);
 
-    Column::new()
+    let mut ret_val = Column::new()
         .spacing(10)
         .push(TextType::highlighted_subtitle_with_desc(
             network_adapter_translation(language),
             adapter_info,
             font,
         ))
-        .push(link_type.link_type_col(language, font))
+        .push(link_type.link_type_col(language, font));
+    if !additional_devices.is_empty() {
+        let other_adapters_info = additional_devices
+            .iter()
+            .map(|device| device.name.clone())
+            .collect::<Vec<String>>()
+            .join("\n   ");
+        ret_val = ret_val.push(TextType::highlighted_subtitle_with_desc(
+            other_adapters_translation(language),
+            &other_adapters_info,
+            font,
+        ));
+    }
+    if paused {
+        ret_val = ret_val.push(
+            Text::new(analysis_paused_translation(language))
+                .style(TextType::Danger)
+                .font(font),
+        );
+    }
+    ret_val
+}
+
+fn col_session(
+    language: Language,
+    font: Font,
+    opened_session: &OpenedSession,
+) -> Column<'static, Message, Renderer<StyleType>> {
+    let file_name = Path::new(&opened_session.path)
+        .file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_string();
+
+    Column::new()
+        .spacing(10)
+        .push(TextType::highlighted_subtitle_with_desc(
+            session_file_translation(language),
+            &file_name,
+            font,
+        ))
+        .push(TextType::highlighted_subtitle_with_desc(
+            network_adapter_translation(language),
+            &opened_session.adapters.join("\n   "),
+            font,
+        ))
+        .push(
+            Text::new(read_only_session_translation(
+                language,
+                &opened_session
+                    .timestamp
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string(),
+            ))
+            .font(font),
+        )
 }
 
 fn col_data_representation(
@@ -571,7 +777,7 @@ fn col_bytes_packets(
     let filtered_bytes =
         sniffer.runtime_data.tot_sent_bytes + sniffer.runtime_data.tot_received_bytes;
     let all_bytes = sniffer.runtime_data.all_bytes;
-    let filters = &sniffer.filters;
+    let filters = sniffer.active_filters.lock().unwrap().clone();
 
     let dropped_val = if dropped > 0 {
         format!(
@@ -592,9 +798,18 @@ fn col_bytes_packets(
         )
     };
 
-    Column::new()
-        .spacing(10)
-        .push(get_active_filters_col(filters, language, font, false))
+    let mut ret_val = Column::new().spacing(10).push(get_active_filters_col(
+        &filters,
+        sniffer.runtime_data.last_filters_change,
+        language,
+        font,
+        false,
+        sniffer.opened_session.is_none(),
+    ));
+    if sniffer.opened_session.is_none() {
+        ret_val = ret_val.push(col_data_usage(language, font, sniffer));
+    }
+    ret_val
         .push(TextType::highlighted_subtitle_with_desc(
             filtered_bytes_translation(language),
             &bytes_value,
@@ -616,6 +831,58 @@ fn col_bytes_packets(
         ))
 }
 
+/// Data used by the captured adapters in the billing cycle, compared to the data cap
+fn col_data_usage(
+    language: Language,
+    font: Font,
+    sniffer: &Sniffer,
+) -> Column<'static, Message, Renderer<StyleType>> {
+    const BAR_WIDTH: f32 = 300.0;
+    let configs = sniffer.configs.lock().unwrap();
+    let quota_notification = configs.settings.notifications.quota_notification;
+    let mut ret_val = Column::new().spacing(5);
+    let Some(cap) = quota_notification.cap else {
+        return ret_val;
+    };
+    let today = Local::now().date_naive();
+    for device in std::iter::once(&sniffer.device).chain(sniffer.additional_devices.iter()) {
+        let usage =
+            configs
+                .usage
+                .get_usage(&device.name, quota_notification.cycle_start_day, today);
+        let desc = format!(
+            "{}: {} / {} ({}%)",
+            device.name,
+            get_formatted_bytes_string_with_b(u128::from(usage.tot_bytes())),
+            get_formatted_bytes_string_with_b(u128::from(cap)),
+            usage.percentage(cap)
+        );
+        #[allow(clippy::cast_precision_loss)]
+        let (in_len, out_len) = if cap == 0 {
+            (0.0, 0.0)
+        } else {
+            (
+                BAR_WIDTH * usage.received_bytes as f32 / cap as f32,
+                BAR_WIDTH * usage.sent_bytes as f32 / cap as f32,
+            )
+        };
+        let in_len = in_len.min(BAR_WIDTH);
+        let out_len = out_len.min(BAR_WIDTH - in_len);
+        ret_val = ret_val
+            .push(TextType::highlighted_subtitle_with_desc(
+                &data_usage_translation(language, &usage.cycle_start.to_string()),
+                &desc,
+                font,
+            ))
+            .push(
+                Row::new()
+                    .push(horizontal_space(Length::Fixed(10.0)))
+                    .push(get_bars(in_len, out_len)),
+            );
+    }
+    ret_val
+}
+
 fn get_bars_length(
     tot_width: f32,
     chart_type: ChartType,
@@ -682,17 +949,64 @@ fn get_star_button(is_favorite: bool, host: Host) -> Button<'static, Message, Re
     .on_press(Message::AddOrRemoveFavorite(host, !is_favorite))
 }
 
+/// Button to chart the traffic of a single entity, or to return to the total traffic
+/// if the entity is already charted
+fn get_chart_button(
+    entity: ChartEntity,
+    selected: Option<&ChartEntity>,
+    font: Font,
+    language: Language,
+) -> Tooltip<'static, Message, Renderer<StyleType>> {
+    let is_selected = selected == Some(&entity);
+    Tooltip::new(
+        button(
+            Icon::Overview
+                .to_text()
+                .size(14)
+                .vertical_alignment(Vertical::Center)
+                .horizontal_alignment(Horizontal::Center),
+        )
+        .padding(0)
+        .height(Fixed(20.0))
+        .width(Fixed(20.0))
+        .style(if is_selected {
+            ButtonType::BorderedRoundSelected
+        } else {
+            ButtonType::BorderedRound
+        })
+        .on_press(Message::SelectChartEntity(if is_selected {
+            None
+        } else {
+            Some(entity)
+        })),
+        if is_selected {
+            show_total_traffic_translation(language)
+        } else {
+            show_in_chart_translation(language)
+        },
+        Position::FollowCursor,
+    )
+    .font(font)
+    .style(ContainerType::Tooltip)
+}
+
 fn get_active_filters_col(
     filters: &Filters,
+    last_filters_change: Option<DateTime<Local>>,
     language: Language,
     font: Font,
     show: bool,
+    editable: bool,
 ) -> Column<'static, Message, Renderer<StyleType>> {
-    let mut ret_val = Column::new().push(
+    let mut title_row = Row::new().spacing(10).align_items(Alignment::Center).push(
         Text::new(format!("{}:", active_filters_translation(language),))
             .font(font)
             .style(TextType::Subtitle),
     );
+    if editable {
+        title_row = title_row.push(button_edit_filters(font, language));
+    }
+    let mut ret_val = Column::new().push(title_row);
 
     if filters.none_active() {
         ret_val = ret_val.push(Text::new(format!("   {}", none_translation(language))).font(font));
@@ -721,5 +1035,37 @@ fn get_active_filters_col(
             )
         });
     }
+    if let Some(time) = last_filters_change {
+        ret_val = ret_val.push(
+            Text::new(filters_changed_translation(
+                language,
+                &time.format("%H:%M:%S").to_string(),
+            ))
+            .font(font),
+        );
+    }
     ret_val
 }
+
+fn button_edit_filters(
+    font: Font,
+    language: Language,
+) -> Tooltip<'static, Message, Renderer<StyleType>> {
+    Tooltip::new(
+        button(
+            Icon::Funnel
+                .to_text()
+                .size(12)
+                .vertical_alignment(Vertical::Center)
+                .horizontal_alignment(Horizontal::Center),
+        )
+        .padding(2)
+        .height(Fixed(20.0))
+        .width(Fixed(20.0))
+        .on_press(Message::ShowModal(MyModal::EditFilters)),
+        edit_filters_translation(language),
+        Position::FollowCursor,
+    )
+    .font(font)
+    .style(ContainerType::Tooltip)
+}
@@ -0,0 +1,261 @@
+use std::fmt::Display;
+use std::path::Path;
+
+use iced::alignment::{Horizontal, Vertical};
+use iced::widget::scrollable::Direction;
+use iced::widget::{horizontal_space, vertical_space, Column, Container, Row, Scrollable, Text};
+use iced::Length::Fixed;
+use iced::{Alignment, Font, Length, Renderer};
+
+use crate::gui::components::button::button_hide;
+use crate::gui::styles::container::ContainerType;
+use crate::gui::styles::scrollbar::ScrollbarType;
+use crate::gui::styles::style_constants::FONT_SIZE_TITLE;
+use crate::gui::styles::text::TextType;
+use crate::gui::styles::types::gradient_type::GradientType;
+use crate::gui::types::message::Message;
+use crate::networking::types::asn::Asn;
+use crate::networking::types::host::Host;
+use crate::report::types::session_comparison::{BytesDelta, SessionComparison};
+use crate::translations::translations::application_protocol_translation;
+use crate::translations::translations_2::{country_translation, host_translation};
+use crate::translations::translations_3::{
+    autonomous_system_translation, comparison_with_translation,
+};
+use crate::utils::formatted_strings::get_formatted_bytes_string_with_b;
+use crate::{ConfigSettings, Language, Sniffer, StyleType};
+
+pub fn session_comparison_page(sniffer: &Sniffer) -> Container<Message, Renderer<StyleType>> {
+    let ConfigSettings {
+        style,
+        language,
+        color_gradient,
+        ..
+    } = sniffer.configs.lock().unwrap().settings;
+    let font = style.get_extension().font;
+    let font_headers = style.get_extension().font_headers;
+
+    let Some(baseline) = &sniffer.baseline_session else {
+        return Container::new(Column::new());
+    };
+    let file_name = Path::new(&baseline.path)
+        .file_name()
+        .map_or(baseline.path.clone(), |name| {
+            name.to_string_lossy().to_string()
+        });
+
+    let mut content = Column::new()
+        .width(Length::Fill)
+        .align_items(Alignment::Center)
+        .push(page_header(
+            font,
+            font_headers,
+            color_gradient,
+            language,
+            &file_name,
+        ))
+        .push(vertical_space(Fixed(15.0)));
+
+    content = match &baseline.info_traffic {
+        Err(error) => content.push(
+            Text::new(format!("{}\n{error}", baseline.path))
+                .style(TextType::Danger)
+                .font(font),
+        ),
+        Ok(baseline_traffic) => {
+            let comparison =
+                SessionComparison::new(baseline_traffic, &sniffer.info_traffic.lock().unwrap());
+            content.push(comparison_grid(&comparison, font, language))
+        }
+    };
+
+    Container::new(content)
+        .width(Length::Fixed(1000.0))
+        .height(Length::Fixed(600.0))
+        .style(ContainerType::Modal)
+}
+
+fn page_header(
+    font: Font,
+    font_headers: Font,
+    color_gradient: GradientType,
+    language: Language,
+    file_name: &str,
+) -> Container<'static, Message, Renderer<StyleType>> {
+    Container::new(
+        Row::new()
+            .push(horizontal_space(Length::FillPortion(1)))
+            .push(
+                Text::new(comparison_with_translation(language, file_name))
+                    .font(font_headers)
+                    .size(FONT_SIZE_TITLE)
+                    .width(Length::FillPortion(6))
+                    .horizontal_alignment(Horizontal::Center),
+            )
+            .push(
+                Container::new(button_hide(Message::HideModal, language, font))
+                    .width(Length::FillPortion(1))
+                    .align_x(Horizontal::Center),
+            ),
+    )
+    .align_x(Horizontal::Center)
+    .align_y(Vertical::Center)
+    .height(Fixed(40.0))
+    .width(Length::Fill)
+    .style(ContainerType::Gradient(color_gradient))
+}
+
+fn comparison_grid(
+    comparison: &SessionComparison,
+    font: Font,
+    language: Language,
+) -> Column<'static, Message, Renderer<StyleType>> {
+    let mut hosts_col = Column::new().spacing(5).padding([0, 20, 10, 0]);
+    for (host, bytes) in &comparison.new_hosts {
+        hosts_col = hosts_col.push(host_row(host, *bytes, true, font));
+    }
+    for (host, bytes) in &comparison.disappeared_hosts {
+        hosts_col = hosts_col.push(host_row(host, *bytes, false, font));
+    }
+
+    let mut asns_col = Column::new().spacing(5).padding([0, 20, 10, 0]);
+    for asn in &comparison.new_asns {
+        asns_col = asns_col.push(asn_text(asn, true, font));
+    }
+    for asn in &comparison.disappeared_asns {
+        asns_col = asns_col.push(asn_text(asn, false, font));
+    }
+
+    let mut apps_col = Column::new().spacing(5).padding([0, 20, 10, 0]);
+    for delta in &comparison.apps {
+        apps_col = apps_col.push(delta_row(delta, font));
+    }
+
+    let mut countries_col = Column::new().spacing(5).padding([0, 20, 10, 0]);
+    for delta in &comparison.countries {
+        countries_col = countries_col.push(delta_row(delta, font));
+    }
+
+    Column::new()
+        .spacing(15)
+        .padding([0, 0, 10, 0])
+        .push(
+            Row::new()
+                .spacing(15)
+                .height(Length::FillPortion(1))
+                .push(section(host_translation(language), hosts_col, font))
+                .push(section(
+                    autonomous_system_translation(language),
+                    asns_col,
+                    font,
+                )),
+        )
+        .push(
+            Row::new()
+                .spacing(15)
+                .height(Length::FillPortion(1))
+                .push(section(
+                    application_protocol_translation(language),
+                    apps_col,
+                    font,
+                ))
+                .push(section(country_translation(language), countries_col, font)),
+        )
+}
+
+fn section(
+    title: &str,
+    entries: Column<'static, Message, Renderer<StyleType>>,
+    font: Font,
+) -> Column<'static, Message, Renderer<StyleType>> {
+    Column::new()
+        .spacing(10)
+        .padding([0, 0, 0, 30])
+        .width(Length::Fill)
+        .push(
+            Text::new(title.to_string())
+                .style(TextType::Subtitle)
+                .font(font),
+        )
+        .push(Scrollable::new(entries).direction(Direction::Vertical(ScrollbarType::properties())))
+}
+
+fn host_row(
+    host: &Host,
+    bytes: u128,
+    is_new: bool,
+    font: Font,
+) -> Row<'static, Message, Renderer<StyleType>> {
+    Row::new()
+        .spacing(10)
+        .push(
+            Text::new(format!(
+                "{} {} ({})",
+                if is_new { "+" } else { "−" },
+                host.domain,
+                host.country
+            ))
+            .style(if is_new {
+                TextType::Standard
+            } else {
+                TextType::Danger
+            })
+            .font(font)
+            .width(Length::Fill),
+        )
+        .push(
+            Text::new(get_formatted_bytes_string_with_b(bytes))
+                .font(font)
+                .width(Length::Fixed(100.0))
+                .horizontal_alignment(Horizontal::Right),
+        )
+}
+
+fn asn_text(asn: &Asn, is_new: bool, font: Font) -> Text<'static, Renderer<StyleType>> {
+    Text::new(format!(
+        "{} {} (ASN {})",
+        if is_new { "+" } else { "−" },
+        asn.name,
+        asn.number
+    ))
+    .style(if is_new {
+        TextType::Standard
+    } else {
+        TextType::Danger
+    })
+    .font(font)
+}
+
+fn delta_row<T: Display>(
+    delta: &BytesDelta<T>,
+    font: Font,
+) -> Row<'static, Message, Renderer<StyleType>> {
+    let percentage_style = match delta.get_percentage() {
+        Some(percentage) if percentage < 0.0 => TextType::Danger,
+        _ => TextType::Standard,
+    };
+    Row::new()
+        .spacing(10)
+        .push(
+            Text::new(delta.item.to_string())
+                .font(font)
+                .width(Length::Fill),
+        )
+        .push(
+            Text::new(format!(
+                "{} → {}",
+                get_formatted_bytes_string_with_b(delta.baseline_bytes),
+                get_formatted_bytes_string_with_b(delta.current_bytes)
+            ))
+            .font(font)
+            .width(Length::Fixed(180.0))
+            .horizontal_alignment(Horizontal::Right),
+        )
+        .push(
+            Text::new(delta.get_percentage_string())
+                .style(percentage_style)
+                .font(font)
+                .width(Length::Fixed(70.0))
+                .horizontal_alignment(Horizontal::Right),
+        )
+}
@@ -1,28 +1,42 @@
+use std::net::SocketAddr;
 use std::sync::Arc;
 
 use iced::advanced::widget::Text;
 use iced::alignment::{Horizontal, Vertical};
+use iced::widget::scrollable::Direction;
 use iced::widget::tooltip::Position;
 use iced::widget::{
-    button, horizontal_space, vertical_space, Column, Container, PickList, Row, Rule, Slider,
-    Tooltip,
+    button, vertical_space, Checkbox, Column, Container, PickList, Row, Rule, Scrollable, Slider,
+    TextInput, Tooltip,
 };
 use iced::Length::Fixed;
 use iced::{Alignment, Font, Length, Renderer};
 
+use crate::blocklists::types::blocklists::Blocklists;
+use crate::flow_export::types::flow_export_settings::FlowExportSettings;
+use crate::flow_export::types::flow_protocol::FlowProtocol;
 use crate::gui::components::button::button_open_file;
 use crate::gui::components::tab::get_settings_tabs;
 use crate::gui::pages::settings_notifications_page::settings_header;
 use crate::gui::pages::types::settings_page::SettingsPage;
 use crate::gui::styles::container::ContainerType;
+use crate::gui::styles::scrollbar::ScrollbarType;
 use crate::gui::styles::style_constants::FONT_SIZE_SUBTITLE;
 use crate::gui::styles::text::TextType;
+use crate::gui::styles::text_input::TextInputType;
 use crate::gui::types::message::Message;
 use crate::mmdb::types::mmdb_reader::MmdbReader;
+use crate::oui::types::oui_reader::OuiReader;
+use crate::secondary_threads::write_events::STDOUT_OUTPUT;
 use crate::translations::translations::language_translation;
 use crate::translations::translations_2::country_translation;
 use crate::translations::translations_3::{
-    mmdb_files_translation, params_not_editable_translation, zoom_translation,
+    api_token_translation, applied_at_next_launch_translation, blocklist_entries_translation,
+    blocklists_translation, enable_api_translation, events_stdout_translation,
+    export_flows_translation, expose_metrics_translation, history_retention_translation,
+    mac_vendors_translation, mmdb_files_translation, params_not_editable_translation,
+    store_history_translation, traffic_history_translation, write_events_translation,
+    zoom_translation,
 };
 use crate::utils::formatted_strings::get_path_termination_string;
 use crate::utils::types::file_info::FileInfo;
@@ -50,10 +64,13 @@ pub fn settings_general_page(sniffer: &Sniffer) -> Container<Message, Renderer<S
         ))
         .push(get_settings_tabs(SettingsPage::General, font, language))
         .push(vertical_space(Fixed(10.0)))
-        .push(column_all_general_setting(sniffer, font));
+        .push(
+            Scrollable::new(column_all_general_setting(sniffer, font))
+                .direction(Direction::Vertical(ScrollbarType::properties())),
+        );
 
     Container::new(content)
-        .height(Fixed(400.0))
+        .height(Fixed(490.0))
         .width(Fixed(800.0))
         .style(ContainerType::Modal)
 }
@@ -67,6 +84,17 @@ fn column_all_general_setting(
         scale_factor,
         mmdb_country,
         mmdb_asn,
+        oui_db,
+        history,
+        history_retention,
+        metrics,
+        metrics_address,
+        api,
+        api_address,
+        api_token,
+        events,
+        events_output,
+        flow_export,
         ..
     } = sniffer.configs.lock().unwrap().settings.clone();
 
@@ -75,7 +103,13 @@ fn column_all_general_setting(
     let mut column = Column::new()
         .align_items(Alignment::Center)
         .padding([5, 10])
-        .push(row_language_scale_factor(language, font, scale_factor))
+        .push(row_language_scale_factor(
+            language,
+            font,
+            scale_factor,
+            history,
+            history_retention,
+        ))
         .push(Rule::horizontal(25));
 
     if !is_editable {
@@ -92,12 +126,40 @@ fn column_all_general_setting(
         is_editable,
         language,
         font,
-        &mmdb_country,
-        &mmdb_asn,
+        [&mmdb_country, &mmdb_asn, &oui_db],
         &sniffer.country_mmdb_reader,
         &sniffer.asn_mmdb_reader,
+        &sniffer.oui_reader,
     ));
 
+    column = column
+        .push(Rule::horizontal(25))
+        .push(blocklists_settings(
+            language,
+            font,
+            &sniffer.blocklists.lock().unwrap(),
+        ))
+        .push(Rule::horizontal(25))
+        .push(metrics_row(
+            is_editable,
+            language,
+            font,
+            metrics,
+            &metrics_address,
+        ))
+        .push(vertical_space(Fixed(10.0)))
+        .push(flow_export_row(is_editable, language, font, &flow_export))
+        .push(vertical_space(Fixed(10.0)))
+        .push(events_row(
+            is_editable,
+            language,
+            font,
+            events,
+            &events_output,
+        ))
+        .push(vertical_space(Fixed(10.0)))
+        .push(api_row(language, font, api, &api_address, &api_token));
+
     column
 }
 
@@ -105,15 +167,17 @@ fn row_language_scale_factor(
     language: Language,
     font: Font,
     scale_factor: f64,
+    history: bool,
+    history_retention: u16,
 ) -> Row<'static, Message, Renderer<StyleType>> {
     Row::new()
         .align_items(Alignment::Start)
-        .height(Length::Fixed(90.0))
+        .height(Length::Fixed(120.0))
         .push(language_picklist(language, font))
         .push(Rule::vertical(25))
         .push(scale_factor_slider(language, font, scale_factor))
         .push(Rule::vertical(25))
-        .push(horizontal_space(Length::FillPortion(1)))
+        .push(history_checkbox(language, font, history, history_retention))
 }
 
 fn language_picklist(
@@ -201,15 +265,212 @@ fn scale_factor_slider(
     .align_y(Vertical::Center)
 }
 
+fn history_checkbox(
+    language: Language,
+    font: Font,
+    history: bool,
+    history_retention: u16,
+) -> Container<'static, Message, Renderer<StyleType>> {
+    Container::new(
+        Column::new()
+            .spacing(5)
+            .align_items(Alignment::Center)
+            .push(
+                Text::new(traffic_history_translation(language))
+                    .style(TextType::Subtitle)
+                    .size(FONT_SIZE_SUBTITLE)
+                    .font(font),
+            )
+            .push(
+                Checkbox::new(
+                    store_history_translation(language),
+                    history,
+                    Message::ToggleHistory,
+                )
+                .font(font)
+                .size(18),
+            )
+            .push(Text::new(history_retention_translation(language, history_retention)).font(font))
+            .push(
+                Slider::new(1..=365, history_retention, Message::ChangeHistoryRetention)
+                    .width(Fixed(150.0)),
+            ),
+    )
+    .width(Length::FillPortion(1))
+    .align_x(Horizontal::Center)
+    .align_y(Vertical::Center)
+}
+
+fn metrics_row(
+    is_editable: bool,
+    language: Language,
+    font: Font,
+    metrics: bool,
+    metrics_address: &str,
+) -> Row<'static, Message, Renderer<StyleType>> {
+    let is_valid = metrics_address.parse::<SocketAddr>().is_ok();
+
+    let checkbox = Checkbox::new(
+        expose_metrics_translation(language),
+        metrics,
+        Message::ToggleMetrics,
+    )
+    .font(font)
+    .size(18);
+
+    let mut address_input = TextInput::new("127.0.0.1:9284", metrics_address)
+        .padding([0, 5])
+        .font(font)
+        .width(Length::Fixed(180.0))
+        .style(if is_valid {
+            TextInputType::Standard
+        } else {
+            TextInputType::Error
+        });
+    if is_editable {
+        address_input = address_input.on_input(Message::MetricsAddress);
+    }
+
+    Row::new()
+        .spacing(10)
+        .align_items(Alignment::Center)
+        .push(checkbox)
+        .push(address_input)
+        .push(Text::new("/metrics").font(font))
+}
+
+fn events_row(
+    is_editable: bool,
+    language: Language,
+    font: Font,
+    events: bool,
+    events_output: &str,
+) -> Row<'static, Message, Renderer<StyleType>> {
+    let checkbox = Checkbox::new(
+        write_events_translation(language),
+        events,
+        Message::ToggleEvents,
+    )
+    .font(font)
+    .size(18);
+
+    let mut output_input = TextInput::new(STDOUT_OUTPUT, events_output)
+        .padding([0, 5])
+        .font(font)
+        .width(Length::Fixed(250.0))
+        .style(if events_output.is_empty() {
+            TextInputType::Error
+        } else {
+            TextInputType::Standard
+        });
+    if is_editable {
+        output_input = output_input.on_input(Message::EventsOutput);
+    }
+
+    Row::new()
+        .spacing(10)
+        .align_items(Alignment::Center)
+        .push(checkbox)
+        .push(output_input)
+        .push(Text::new(events_stdout_translation(language)).font(font))
+}
+
+fn api_row(
+    language: Language,
+    font: Font,
+    api: bool,
+    api_address: &str,
+    api_token: &str,
+) -> Row<'static, Message, Renderer<StyleType>> {
+    let is_valid = api_address.parse::<SocketAddr>().is_ok();
+
+    let checkbox = Checkbox::new(enable_api_translation(language), api, Message::ToggleApi)
+        .font(font)
+        .size(18);
+
+    let address_input = TextInput::new("127.0.0.1:9285", api_address)
+        .on_input(Message::ApiAddress)
+        .padding([0, 5])
+        .font(font)
+        .width(Length::Fixed(180.0))
+        .style(if is_valid {
+            TextInputType::Standard
+        } else {
+            TextInputType::Error
+        });
+
+    // read-only, to let users copy it into their clients
+    let token_input = TextInput::new("", api_token)
+        .padding([0, 5])
+        .font(font)
+        .width(Length::Fixed(300.0))
+        .style(TextInputType::Standard);
+
+    Row::new()
+        .spacing(10)
+        .align_items(Alignment::Center)
+        .push(checkbox)
+        .push(address_input)
+        .push(Text::new(format!("{}:", api_token_translation(language))).font(font))
+        .push(token_input)
+        .push(Text::new(applied_at_next_launch_translation(language)).font(font))
+}
+
+fn flow_export_row(
+    is_editable: bool,
+    language: Language,
+    font: Font,
+    flow_export: &FlowExportSettings,
+) -> Row<'static, Message, Renderer<StyleType>> {
+    let is_valid = flow_export.collector.parse::<SocketAddr>().is_ok();
+
+    let checkbox = Checkbox::new(
+        export_flows_translation(language),
+        flow_export.enabled,
+        Message::ToggleFlowExport,
+    )
+    .font(font)
+    .size(18);
+
+    let mut collector_input = TextInput::new("127.0.0.1:2055", &flow_export.collector)
+        .padding([0, 5])
+        .font(font)
+        .width(Length::Fixed(180.0))
+        .style(if is_valid {
+            TextInputType::Standard
+        } else {
+            TextInputType::Error
+        });
+    if is_editable {
+        collector_input = collector_input.on_input(Message::FlowCollector);
+    }
+
+    let protocol_picklist = PickList::new(
+        &FlowProtocol::ALL[..],
+        Some(flow_export.protocol),
+        Message::FlowProtocolSelection,
+    )
+    .padding([3, 7])
+    .font(font);
+
+    Row::new()
+        .spacing(10)
+        .align_items(Alignment::Center)
+        .push(checkbox)
+        .push(collector_input)
+        .push(protocol_picklist)
+}
+
 fn mmdb_settings(
     is_editable: bool,
     language: Language,
     font: Font,
-    country_path: &str,
-    asn_path: &str,
+    paths: [&str; 3],
     country_reader: &Arc<MmdbReader>,
     asn_reader: &Arc<MmdbReader>,
+    oui_reader: &Arc<OuiReader>,
 ) -> Column<'static, Message, Renderer<StyleType>> {
+    let [country_path, asn_path, oui_path] = paths;
     Column::new()
         .spacing(5)
         .align_items(Alignment::Center)
@@ -219,43 +480,113 @@ fn mmdb_settings(
                 .style(TextType::Subtitle)
                 .size(FONT_SIZE_SUBTITLE),
         )
-        .push(mmdb_selection_row(
+        .push(database_selection_row(
             is_editable,
             font,
             Message::CustomCountryDb,
             country_path,
-            country_reader,
+            matches!(**country_reader, MmdbReader::Default(_)),
+            FileInfo::Database,
             country_translation(language),
             language,
         ))
-        .push(mmdb_selection_row(
+        .push(database_selection_row(
             is_editable,
             font,
             Message::CustomAsnDb,
             asn_path,
-            asn_reader,
+            matches!(**asn_reader, MmdbReader::Default(_)),
+            FileInfo::Database,
             "ASN",
             language,
         ))
+        .push(database_selection_row(
+            is_editable,
+            font,
+            Message::CustomOuiDb,
+            oui_path,
+            matches!(**oui_reader, OuiReader::Default(_)),
+            FileInfo::OuiDatabase,
+            mac_vendors_translation(language),
+            language,
+        ))
+}
+
+fn blocklists_settings(
+    language: Language,
+    font: Font,
+    blocklists: &Blocklists,
+) -> Column<'static, Message, Renderer<StyleType>> {
+    let mut column = Column::new()
+        .spacing(5)
+        .align_items(Alignment::Center)
+        .push(
+            Text::new(blocklists_translation(language))
+                .font(font)
+                .style(TextType::Subtitle)
+                .size(FONT_SIZE_SUBTITLE),
+        );
+    for (i, blocklist) in blocklists.lists.iter().enumerate() {
+        // lists can be changed at any time: the ones not readable are highlighted
+        let is_error = blocklist.modified.is_none();
+        column = column.push(
+            Row::new()
+                .spacing(5)
+                .align_items(Alignment::Center)
+                .push(
+                    Text::new(get_path_termination_string(&blocklist.path, 30))
+                        .font(font)
+                        .style(if is_error {
+                            TextType::Danger
+                        } else {
+                            TextType::Standard
+                        }),
+                )
+                .push(
+                    Text::new(blocklist_entries_translation(
+                        language,
+                        blocklist.num_entries(),
+                    ))
+                    .font(font)
+                    .style(TextType::Subtitle),
+                )
+                .push(
+                    button(
+                        Text::new("×")
+                            .font(font)
+                            .vertical_alignment(Vertical::Center)
+                            .horizontal_alignment(Horizontal::Center)
+                            .size(15),
+                    )
+                    .padding(2)
+                    .height(Fixed(20.0))
+                    .width(Fixed(20.0))
+                    .on_press(Message::RemoveBlocklist(i)),
+                ),
+        );
+    }
+    column.push(button_open_file(
+        String::new(),
+        FileInfo::Blocklist,
+        language,
+        font,
+        true,
+        Message::AddBlocklist,
+    ))
 }
 
-fn mmdb_selection_row(
+#[allow(clippy::too_many_arguments)]
+fn database_selection_row(
     is_editable: bool,
     font: Font,
     message: fn(String) -> Message,
     custom_path: &str,
-    mmdb_reader: &Arc<MmdbReader>,
+    is_default_loaded: bool,
+    file_info: FileInfo,
     caption: &str,
     language: Language,
 ) -> Row<'static, Message, Renderer<StyleType>> {
-    let is_error = if custom_path.is_empty() {
-        false
-    } else {
-        match **mmdb_reader {
-            MmdbReader::Default(_) => true,
-            MmdbReader::Custom(_) => false,
-        }
-    };
+    let is_error = !custom_path.is_empty() && is_default_loaded;
 
     Row::new()
         .align_items(Alignment::Center)
@@ -272,7 +603,7 @@ fn mmdb_selection_row(
         .push(if custom_path.is_empty() {
             button_open_file(
                 custom_path.to_owned(),
-                FileInfo::Database,
+                file_info,
                 language,
                 font,
                 is_editable,
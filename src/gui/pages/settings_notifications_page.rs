@@ -1,12 +1,15 @@
 use iced::alignment::{Horizontal, Vertical};
 use iced::widget::scrollable::Direction;
+use iced::widget::tooltip::Position;
 use iced::widget::{
-    horizontal_space, vertical_space, Checkbox, Column, Container, Row, Scrollable, Text, TextInput,
+    button, horizontal_space, vertical_space, Checkbox, Column, Container, PickList, Row,
+    Scrollable, Text, TextInput, Tooltip,
 };
 use iced::widget::{Button, Slider};
 use iced::Length::Fixed;
 use iced::{Alignment, Font, Length, Renderer};
 
+use crate::configs::types::config_baseline::{ConfigBaseline, LEARNING_WINDOW};
 use crate::gui::components::button::button_hide;
 use crate::gui::components::tab::get_settings_tabs;
 use crate::gui::pages::types::settings_page::SettingsPage;
@@ -15,10 +18,15 @@
 use crate::gui::styles::scrollbar::ScrollbarType;
 use crate::gui::styles::style_constants::{FONT_SIZE_FOOTER, FONT_SIZE_SUBTITLE, FONT_SIZE_TITLE};
 use crate::gui::styles::text::TextType;
+use crate::gui::styles::text_input::TextInputType;
 use crate::gui::styles::types::gradient_type::GradientType;
 use crate::gui::types::message::Message;
+use crate::notifications::types::alert_trigger::{AlertTrigger, TriggerMode};
+use crate::notifications::types::notification_action::{NotificationAction, NotificationKind};
+use crate::notifications::types::notification_rule::{NotificationRule, RuleDirection, RuleScope};
 use crate::notifications::types::notifications::{
-    BytesNotification, FavoriteNotification, Notification, PacketsNotification,
+    BlocklistNotification, BytesNotification, FavoriteNotification, FirstSeenNotification,
+    NewPortNotification, Notification, PacketsNotification, QuotaNotification, ScanNotification,
 };
 use crate::notifications::types::sound::Sound;
 use crate::translations::translations::{
@@ -27,6 +35,16 @@
     settings_translation, sound_translation, specify_multiples_translation, threshold_translation,
     volume_translation,
 };
+use crate::translations::translations_3::{
+    baseline_translation, blocklist_notification_translation, command_translation,
+    cooldown_translation, cycle_start_day_translation, data_cap_translation,
+    first_seen_notification_translation, learning_baseline_translation,
+    new_port_notification_translation, notification_actions_translation,
+    notification_rules_translation, notify_when_exceeded_for_translation,
+    quota_notification_translation, reset_baseline_translation, scan_notification_translation,
+    webhook_translation,
+};
+use crate::utils::formatted_strings::get_formatted_bytes_string_with_b;
 use crate::utils::types::icon::Icon;
 use crate::{ConfigSettings, Language, Sniffer, StyleType};
 
@@ -70,6 +88,7 @@ pub fn settings_notifications_page(sniffer: &Sniffer) -> Container<Message, Rend
         .align_items(Alignment::Center)
         .width(Length::Fill)
         .push(volume_slider(language, font, notifications.volume))
+        .push(alert_trigger_row(notifications.trigger, language, font))
         .push(
             Scrollable::new(
                 Column::new()
@@ -88,6 +107,53 @@ pub fn settings_notifications_page(sniffer: &Sniffer) -> Container<Message, Rend
                         notifications.favorite_notification,
                         language,
                         font,
+                    ))
+                    .push(get_blocklist_notify(
+                        notifications.blocklist_notification,
+                        language,
+                        font,
+                    ))
+                    .push(get_scan_notify(
+                        notifications.scan_notification,
+                        language,
+                        font,
+                    ))
+                    .push(get_quota_notify(
+                        notifications.quota_notification,
+                        language,
+                        font,
+                    ))
+                    .push(get_first_seen_notify(
+                        notifications.first_seen_notification,
+                        language,
+                        font,
+                    ))
+                    .push(get_new_port_notify(
+                        notifications.new_port_notification,
+                        language,
+                        font,
+                    ))
+                    .push(get_baseline_row(
+                        &sniffer.configs.lock().unwrap().baseline,
+                        language,
+                        font,
+                    ))
+                    .push(get_rules_notify(
+                        &sniffer.configs.lock().unwrap().settings.notification_rules,
+                        &sniffer.rule_draft,
+                        language,
+                        font,
+                    ))
+                    .push(get_actions_notify(
+                        &sniffer
+                            .configs
+                            .lock()
+                            .unwrap()
+                            .settings
+                            .notification_actions,
+                        &sniffer.action_draft,
+                        language,
+                        font,
                     )),
             )
             .direction(Direction::Vertical(ScrollbarType::properties())),
@@ -96,7 +162,7 @@ pub fn settings_notifications_page(sniffer: &Sniffer) -> Container<Message, Rend
     content = content.push(volume_notification_col);
 
     Container::new(content)
-        .height(Fixed(400.0))
+        .height(Fixed(490.0))
         .width(Fixed(800.0))
         .style(ContainerType::Modal)
 }
@@ -236,16 +302,64 @@ fn get_favorite_notify(
     language: Language,
     font: Font,
 ) -> Column<'static, Message, Renderer<StyleType>> {
-    let checkbox = Checkbox::new(
+    get_toggle_notify(
         favorite_notification_translation(language),
         favorite_notification.notify_on_favorite,
+        Notification::Favorite(FavoriteNotification::on(favorite_notification.sound)),
+        Notification::Favorite(FavoriteNotification::off(favorite_notification.sound)),
+        language,
+        font,
+    )
+}
+
+fn get_blocklist_notify(
+    blocklist_notification: BlocklistNotification,
+    language: Language,
+    font: Font,
+) -> Column<'static, Message, Renderer<StyleType>> {
+    get_toggle_notify(
+        blocklist_notification_translation(language),
+        blocklist_notification.notify_on_match,
+        Notification::Blocklist(BlocklistNotification::on(blocklist_notification.sound)),
+        Notification::Blocklist(BlocklistNotification::off(blocklist_notification.sound)),
+        language,
+        font,
+    )
+}
+
+fn get_scan_notify(
+    scan_notification: ScanNotification,
+    language: Language,
+    font: Font,
+) -> Column<'static, Message, Renderer<StyleType>> {
+    get_toggle_notify(
+        scan_notification_translation(language),
+        scan_notification.notify_on_scan,
+        Notification::Scan(ScanNotification::on(scan_notification.sound)),
+        Notification::Scan(ScanNotification::off(scan_notification.sound)),
+        language,
+        font,
+    )
+}
+
+fn get_quota_notify(
+    quota_notification: QuotaNotification,
+    language: Language,
+    font: Font,
+) -> Column<'static, Message, Renderer<StyleType>> {
+    let checkbox = Checkbox::new(
+        quota_notification_translation(language),
+        quota_notification.cap.is_some(),
         move |toggled| {
             Message::UpdateNotificationSettings(
-                if toggled {
-                    Notification::Favorite(FavoriteNotification::on(favorite_notification.sound))
-                } else {
-                    Notification::Favorite(FavoriteNotification::off(favorite_notification.sound))
-                },
+                Notification::Quota(QuotaNotification {
+                    cap: if toggled {
+                        Some(quota_notification.previous_cap)
+                    } else {
+                        None
+                    },
+                    ..quota_notification
+                }),
                 false,
             )
         },
@@ -255,29 +369,538 @@ fn get_favorite_notify(
 
     let mut ret_val = Column::new().spacing(5).push(checkbox);
 
-    if favorite_notification.notify_on_favorite {
+    if quota_notification.cap.is_some() {
+        let input_row = Row::new()
+            .push(horizontal_space(Fixed(50.0)))
+            .push(Text::new(format!("{}: ", data_cap_translation(language))).font(font))
+            .push(input_group_quota(quota_notification, font, language));
         let sound_row = Row::new()
             .push(horizontal_space(Fixed(50.0)))
             .push(sound_buttons(
-                Notification::Favorite(favorite_notification),
+                Notification::Quota(quota_notification),
                 font,
                 language,
             ));
+        ret_val = ret_val
+            .push(vertical_space(Fixed(5.0)))
+            .push(input_row)
+            .push(sound_row);
+    }
+    Column::new().padding(5).push(
+        Container::new(ret_val)
+            .padding(10)
+            .width(Fixed(700.0))
+            .style(ContainerType::BorderedRound),
+    )
+}
+
+fn get_first_seen_notify(
+    first_seen_notification: FirstSeenNotification,
+    language: Language,
+    font: Font,
+) -> Column<'static, Message, Renderer<StyleType>> {
+    get_toggle_notify(
+        first_seen_notification_translation(language),
+        first_seen_notification.notify_on_first_seen,
+        Notification::FirstSeen(FirstSeenNotification::on(first_seen_notification.sound)),
+        Notification::FirstSeen(FirstSeenNotification::off(first_seen_notification.sound)),
+        language,
+        font,
+    )
+}
+
+fn get_new_port_notify(
+    new_port_notification: NewPortNotification,
+    language: Language,
+    font: Font,
+) -> Column<'static, Message, Renderer<StyleType>> {
+    get_toggle_notify(
+        new_port_notification_translation(language),
+        new_port_notification.notify_on_new_port,
+        Notification::NewPort(NewPortNotification::on(new_port_notification.sound)),
+        Notification::NewPort(NewPortNotification::off(new_port_notification.sound)),
+        language,
+        font,
+    )
+}
+
+/// Size of the baseline of first seen targets and listening ports, and button to reset it
+fn get_baseline_row(
+    baseline: &ConfigBaseline,
+    language: Language,
+    font: Font,
+) -> Column<'static, Message, Renderer<StyleType>> {
+    let status = if baseline.is_learning() {
+        learning_baseline_translation(
+            language,
+            (LEARNING_WINDOW - baseline.learning_time).div_ceil(60),
+        )
+    } else {
+        String::new()
+    };
+    let reset_button = button(
+        Icon::Bin
+            .to_text()
+            .size(15)
+            .horizontal_alignment(Horizontal::Center)
+            .vertical_alignment(Vertical::Center),
+    )
+    .padding(0)
+    .height(Fixed(25.0))
+    .width(Fixed(25.0))
+    .on_press(Message::ResetBaseline);
+    let description = Column::new()
+        .spacing(2)
+        .width(Length::Fill)
+        .push(
+            Text::new(baseline_translation(
+                language,
+                baseline.hosts.len(),
+                baseline.countries.len(),
+                baseline.asns.len(),
+                baseline.listening_ports.len(),
+            ))
+            .font(font),
+        )
+        .push(
+            Text::new(status)
+                .size(FONT_SIZE_FOOTER)
+                .style(TextType::Subtitle)
+                .font(font),
+        );
+    let content = Row::new()
+        .spacing(10)
+        .align_items(Alignment::Center)
+        .push(description)
+        .push(
+            Tooltip::new(
+                reset_button,
+                reset_baseline_translation(language),
+                Position::Top,
+            )
+            .gap(5)
+            .font(font)
+            .style(ContainerType::Tooltip),
+        );
+    Column::new().padding(5).push(
+        Container::new(content)
+            .padding(10)
+            .width(Fixed(700.0))
+            .style(ContainerType::BorderedRound),
+    )
+}
+
+/// Checkbox to enable a notification without a threshold, and its sound buttons if enabled
+fn get_toggle_notify(
+    label: &'static str,
+    is_on: bool,
+    notification_on: Notification,
+    notification_off: Notification,
+    language: Language,
+    font: Font,
+) -> Column<'static, Message, Renderer<StyleType>> {
+    let checkbox = Checkbox::new(label, is_on, move |toggled| {
+        Message::UpdateNotificationSettings(
+            if toggled {
+                notification_on
+            } else {
+                notification_off
+            },
+            false,
+        )
+    })
+    .size(18)
+    .font(font);
+
+    let mut ret_val = Column::new().spacing(5).push(checkbox);
+
+    if is_on {
+        let sound_row = Row::new()
+            .push(horizontal_space(Fixed(50.0)))
+            .push(sound_buttons(notification_on, font, language));
         ret_val = ret_val.push(vertical_space(Fixed(5.0))).push(sound_row);
-        Column::new().padding(5).push(
-            Container::new(ret_val)
-                .padding(10)
-                .width(Fixed(700.0))
-                .style(ContainerType::BorderedRound),
+    }
+    Column::new().padding(5).push(
+        Container::new(ret_val)
+            .padding(10)
+            .width(Fixed(700.0))
+            .style(ContainerType::BorderedRound),
+    )
+}
+
+fn alert_trigger_row(
+    trigger: AlertTrigger,
+    language: Language,
+    font: Font,
+) -> Row<'static, Message, Renderer<StyleType>> {
+    let window_str = trigger.window.to_string();
+    let window_input = TextInput::new("1", &window_str)
+        .on_input(move |value| {
+            Message::UpdateAlertTrigger(AlertTrigger {
+                window: if value.is_empty() {
+                    1
+                } else {
+                    value.parse().unwrap_or(trigger.window).clamp(1, 60)
+                },
+                ..trigger
+            })
+        })
+        .padding([0, 5])
+        .font(font)
+        .width(Length::Fixed(40.0));
+
+    let mode_list_str = TriggerMode::all_strings(language);
+    let mode_picklist = PickList::new(
+        mode_list_str.clone(),
+        Some(trigger.mode.get_picklist_label(language)),
+        move |selected_str| {
+            let mode = if selected_str == *mode_list_str.first().unwrap_or(&"") {
+                TriggerMode::Consecutive
+            } else {
+                TriggerMode::Average
+            };
+            Message::UpdateAlertTrigger(AlertTrigger { mode, ..trigger })
+        },
+    )
+    .padding([3, 7])
+    .font(font);
+
+    let cooldown_str = trigger.cooldown.to_string();
+    let cooldown_input = TextInput::new("0", &cooldown_str)
+        .on_input(move |value| {
+            Message::UpdateAlertTrigger(AlertTrigger {
+                cooldown: if value.is_empty() {
+                    0
+                } else {
+                    value.parse().unwrap_or(trigger.cooldown)
+                },
+                ..trigger
+            })
+        })
+        .padding([0, 5])
+        .font(font)
+        .width(Length::Fixed(60.0));
+
+    Row::new()
+        .spacing(5)
+        .padding([0, 0, 10, 0])
+        .align_items(Alignment::Center)
+        .push(Text::new(notify_when_exceeded_for_translation(language)).font(font))
+        .push(window_input)
+        .push(mode_picklist)
+        .push(horizontal_space(Fixed(15.0)))
+        .push(Text::new(cooldown_translation(language)).font(font))
+        .push(cooldown_input)
+        .push(Text::new("s").font(font))
+}
+
+fn get_rules_notify(
+    notification_rules: &[NotificationRule],
+    rule_draft: &NotificationRule,
+    language: Language,
+    font: Font,
+) -> Column<'static, Message, Renderer<StyleType>> {
+    let mut ret_val = Column::new()
+        .spacing(5)
+        .push(Text::new(notification_rules_translation(language)).font(font));
+
+    for (i, rule) in notification_rules.iter().enumerate() {
+        let remove_button = button(
+            Icon::Bin
+                .to_text()
+                .size(15)
+                .horizontal_alignment(Horizontal::Center)
+                .vertical_alignment(Vertical::Center),
         )
+        .padding(0)
+        .height(Fixed(25.0))
+        .width(Fixed(25.0))
+        .on_press(Message::RemoveNotificationRule(i));
+        ret_val = ret_val.push(
+            Row::new()
+                .spacing(10)
+                .align_items(Alignment::Center)
+                .push(horizontal_space(Fixed(50.0)))
+                .push(remove_button)
+                .push(Text::new(get_rule_description(rule, language)).font(font)),
+        );
+    }
+
+    ret_val = ret_val
+        .push(vertical_space(Fixed(5.0)))
+        .push(input_row_rule(rule_draft, language, font));
+
+    Column::new().padding(5).push(
+        Container::new(ret_val)
+            .padding(10)
+            .width(Fixed(700.0))
+            .style(ContainerType::BorderedRound),
+    )
+}
+
+fn input_row_rule(
+    rule_draft: &NotificationRule,
+    language: Language,
+    font: Font,
+) -> Row<'static, Message, Renderer<StyleType>> {
+    let draft = rule_draft.clone();
+    let scope_picklist = PickList::new(&RuleScope::ALL[..], Some(draft.scope), move |scope| {
+        Message::UpdateRuleDraft(NotificationRule {
+            scope,
+            ..draft.clone()
+        })
+    })
+    .padding([3, 7])
+    .font(font);
+
+    let draft = rule_draft.clone();
+    let value_input = TextInput::new(
+        match rule_draft.scope {
+            RuleScope::Host => "example.com",
+            RuleScope::Asn => "13335",
+            RuleScope::Country => "IT",
+            RuleScope::App => "Telnet",
+            RuleScope::Port => "23",
+        },
+        &rule_draft.value,
+    )
+    .on_input(move |value| {
+        Message::UpdateRuleDraft(NotificationRule {
+            value,
+            ..draft.clone()
+        })
+    })
+    .padding([0, 5])
+    .font(font)
+    .width(Length::Fixed(120.0))
+    .style(if rule_draft.value.is_empty() || rule_draft.is_valid() {
+        TextInputType::Standard
     } else {
-        Column::new().padding(5).push(
-            Container::new(ret_val)
-                .padding(10)
-                .width(Fixed(700.0))
-                .style(ContainerType::BorderedRound),
+        TextInputType::Error
+    });
+
+    let draft = rule_draft.clone();
+    let direction_picklist = PickList::new(
+        &RuleDirection::ALL[..],
+        Some(draft.direction),
+        move |direction| {
+            Message::UpdateRuleDraft(NotificationRule {
+                direction,
+                ..draft.clone()
+            })
+        },
+    )
+    .padding([3, 7])
+    .font(font);
+
+    let draft = rule_draft.clone();
+    let mut curr_threshold_str =
+        (rule_draft.threshold / rule_draft.byte_multiple.get_multiplier()).to_string();
+    curr_threshold_str.push_str(rule_draft.byte_multiple.get_char());
+    let threshold_input = TextInput::new(
+        "0",
+        if curr_threshold_str == "0" {
+            ""
+        } else {
+            &curr_threshold_str
+        },
+    )
+    .on_input(move |value| {
+        let bytes_notification = BytesNotification::from(
+            &value,
+            Some(BytesNotification {
+                previous_threshold: draft.threshold,
+                byte_multiple: draft.byte_multiple,
+                ..BytesNotification::default()
+            }),
+        );
+        Message::UpdateRuleDraft(NotificationRule {
+            threshold: bytes_notification.previous_threshold,
+            byte_multiple: bytes_notification.byte_multiple,
+            ..draft.clone()
+        })
+    })
+    .padding([0, 5])
+    .font(font)
+    .width(Length::Fixed(70.0));
+
+    let draft = rule_draft.clone();
+    let sound_picklist = PickList::new(&Sound::ALL[..], Some(draft.sound), move |sound| {
+        Message::UpdateRuleDraft(NotificationRule {
+            sound,
+            ..draft.clone()
+        })
+    })
+    .padding([3, 7])
+    .font(font);
+
+    let mut add_button = button(
+        Text::new("+")
+            .font(font)
+            .horizontal_alignment(Horizontal::Center)
+            .vertical_alignment(Vertical::Center),
+    )
+    .padding(0)
+    .height(Fixed(25.0))
+    .width(Fixed(25.0));
+    if rule_draft.is_valid() {
+        add_button = add_button.on_press(Message::AddNotificationRule);
+    }
+
+    Row::new()
+        .spacing(5)
+        .align_items(Alignment::Center)
+        .push(horizontal_space(Fixed(15.0)))
+        .push(scope_picklist)
+        .push(value_input)
+        .push(direction_picklist)
+        .push(Text::new(">").font(font))
+        .push(threshold_input)
+        .push(
+            Text::new(per_second_translation(language))
+                .font(font)
+                .size(FONT_SIZE_FOOTER),
+        )
+        .push(sound_picklist)
+        .push(add_button)
+}
+
+fn get_actions_notify(
+    notification_actions: &[NotificationAction],
+    action_draft: &NotificationAction,
+    language: Language,
+    font: Font,
+) -> Column<'static, Message, Renderer<StyleType>> {
+    let mut ret_val = Column::new()
+        .spacing(5)
+        .push(Text::new(notification_actions_translation(language)).font(font));
+
+    for (i, action) in notification_actions.iter().enumerate() {
+        let remove_button = button(
+            Icon::Bin
+                .to_text()
+                .size(15)
+                .horizontal_alignment(Horizontal::Center)
+                .vertical_alignment(Vertical::Center),
         )
+        .padding(0)
+        .height(Fixed(25.0))
+        .width(Fixed(25.0))
+        .on_press(Message::RemoveNotificationAction(i));
+        let mut description = Column::new().spacing(2);
+        if !action.command.is_empty() {
+            description = description
+                .push(Text::new(format!("{}  $ {}", action.kind, action.command)).font(font));
+        }
+        if !action.webhook.is_empty() {
+            description = description
+                .push(Text::new(format!("{}  → {}", action.kind, action.webhook)).font(font));
+        }
+        ret_val = ret_val.push(
+            Row::new()
+                .spacing(10)
+                .align_items(Alignment::Center)
+                .push(horizontal_space(Fixed(50.0)))
+                .push(remove_button)
+                .push(description),
+        );
     }
+
+    ret_val = ret_val
+        .push(vertical_space(Fixed(5.0)))
+        .push(input_row_action(action_draft, language, font));
+
+    Column::new().padding(5).push(
+        Container::new(ret_val)
+            .padding(10)
+            .width(Fixed(700.0))
+            .style(ContainerType::BorderedRound),
+    )
+}
+
+fn input_row_action(
+    action_draft: &NotificationAction,
+    language: Language,
+    font: Font,
+) -> Row<'static, Message, Renderer<StyleType>> {
+    let draft = action_draft.clone();
+    let kind_picklist = PickList::new(
+        &NotificationKind::ACTIONABLE[..],
+        Some(draft.kind),
+        move |kind| {
+            Message::UpdateActionDraft(NotificationAction {
+                kind,
+                ..draft.clone()
+            })
+        },
+    )
+    .padding([3, 7])
+    .font(font);
+
+    let draft = action_draft.clone();
+    let command_input = TextInput::new(command_translation(language), &action_draft.command)
+        .on_input(move |command| {
+            Message::UpdateActionDraft(NotificationAction {
+                command,
+                ..draft.clone()
+            })
+        })
+        .padding([0, 5])
+        .font(font)
+        .width(Length::Fixed(230.0));
+
+    let draft = action_draft.clone();
+    let webhook_input = TextInput::new(webhook_translation(language), &action_draft.webhook)
+        .on_input(move |webhook| {
+            Message::UpdateActionDraft(NotificationAction {
+                webhook,
+                ..draft.clone()
+            })
+        })
+        .padding([0, 5])
+        .font(font)
+        .width(Length::Fixed(230.0))
+        .style(
+            if action_draft.webhook.is_empty() || action_draft.is_valid() {
+                TextInputType::Standard
+            } else {
+                TextInputType::Error
+            },
+        );
+
+    let mut add_button = button(
+        Text::new("+")
+            .font(font)
+            .horizontal_alignment(Horizontal::Center)
+            .vertical_alignment(Vertical::Center),
+    )
+    .padding(0)
+    .height(Fixed(25.0))
+    .width(Fixed(25.0));
+    if action_draft.is_valid() {
+        add_button = add_button.on_press(Message::AddNotificationAction);
+    }
+
+    Row::new()
+        .spacing(5)
+        .align_items(Alignment::Center)
+        .push(horizontal_space(Fixed(15.0)))
+        .push(kind_picklist)
+        .push(command_input)
+        .push(webhook_input)
+        .push(add_button)
+}
+
+/// Returns a short description of the rule, such as "ASN 13335 ↑ > 5 MB/s"
+pub fn get_rule_description(rule: &NotificationRule, language: Language) -> String {
+    format!(
+        "{} {} {} > {} {}",
+        rule.scope,
+        rule.value,
+        rule.direction,
+        get_formatted_bytes_string_with_b(u128::from(rule.threshold)),
+        per_second_translation(language)
+    )
 }
 
 fn input_group_packets(
@@ -361,6 +984,58 @@ fn input_group_bytes(
         .align_y(Vertical::Center)
 }
 
+fn input_group_quota(
+    quota_notification: QuotaNotification,
+    font: Font,
+    language: Language,
+) -> Container<'static, Message, Renderer<StyleType>> {
+    let mut curr_cap_str = (quota_notification.cap.unwrap()
+        / quota_notification.byte_multiple.get_multiplier())
+    .to_string();
+    curr_cap_str.push_str(quota_notification.byte_multiple.get_char());
+    let cycle_start_day_str = quota_notification.cycle_start_day.to_string();
+    let input_row = Row::new()
+        .spacing(10)
+        .align_items(Alignment::Center)
+        .push(
+            TextInput::new(
+                "0",
+                if curr_cap_str == "0" {
+                    ""
+                } else {
+                    &curr_cap_str
+                },
+            )
+            .on_input(move |value| {
+                let quota_notification = QuotaNotification::from(&value, Some(quota_notification));
+                Message::UpdateNotificationSettings(Notification::Quota(quota_notification), false)
+            })
+            .padding([0, 0, 0, 10])
+            .font(font)
+            .width(Length::Fixed(100.0)),
+        )
+        .push(
+            Text::new(cycle_start_day_translation(language))
+                .font(font)
+                .vertical_alignment(Vertical::Center),
+        )
+        .push(
+            TextInput::new("1", &cycle_start_day_str)
+                .on_input(move |value| {
+                    Message::UpdateNotificationSettings(
+                        Notification::Quota(quota_notification.with_cycle_start_day(&value)),
+                        false,
+                    )
+                })
+                .padding([0, 0, 0, 10])
+                .font(font)
+                .width(Length::Fixed(50.0)),
+        );
+    Container::new(input_row)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+}
+
 fn volume_slider(
     language: Language,
     font: Font,
@@ -410,6 +1085,11 @@ pub fn sound_buttons(
         Notification::Packets(n) => n.sound,
         Notification::Bytes(n) => n.sound,
         Notification::Favorite(n) => n.sound,
+        Notification::FirstSeen(n) => n.sound,
+        Notification::NewPort(n) => n.sound,
+        Notification::Blocklist(n) => n.sound,
+        Notification::Scan(n) => n.sound,
+        Notification::Quota(n) => n.sound,
     };
 
     let mut ret_val = Row::new()
@@ -427,6 +1107,17 @@ pub fn sound_buttons(
             Notification::Favorite(n) => {
                 Notification::Favorite(FavoriteNotification { sound: option, ..n })
             }
+            Notification::FirstSeen(n) => {
+                Notification::FirstSeen(FirstSeenNotification { sound: option, ..n })
+            }
+            Notification::NewPort(n) => {
+                Notification::NewPort(NewPortNotification { sound: option, ..n })
+            }
+            Notification::Blocklist(n) => {
+                Notification::Blocklist(BlocklistNotification { sound: option, ..n })
+            }
+            Notification::Scan(n) => Notification::Scan(ScanNotification { sound: option, ..n }),
+            Notification::Quota(n) => Notification::Quota(QuotaNotification { sound: option, ..n }),
         };
         ret_val = ret_val.push(
             Button::new(option.get_text(font))
@@ -116,7 +116,7 @@ pub fn settings_style_page(sniffer: &Sniffer) -> Container<Message, Renderer<Sty
     content = content.push(styles_scroll);
 
     Container::new(content)
-        .height(Length::Fixed(400.0))
+        .height(Length::Fixed(490.0))
         .width(Length::Fixed(800.0))
         .style(ContainerType::Modal)
 }
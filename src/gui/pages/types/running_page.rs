@@ -3,6 +3,7 @@
 use crate::gui::types::message::Message;
 use crate::translations::translations::{notifications_translation, overview_translation};
 use crate::translations::translations_2::inspect_translation;
+use crate::translations::translations_3::map_translation;
 use crate::utils::types::icon::Icon;
 use crate::{Language, StyleType};
 
@@ -15,14 +16,20 @@ pub enum RunningPage {
     Overview,
     /// Inspect page.
     Inspect,
+    /// World map page.
+    Map,
+    /// DNS queries page.
+    Dns,
     /// Notifications page.
     Notifications,
 }
 
 impl RunningPage {
-    pub const ALL: [RunningPage; 3] = [
+    pub const ALL: [RunningPage; 5] = [
         RunningPage::Overview,
         RunningPage::Inspect,
+        RunningPage::Map,
+        RunningPage::Dns,
         RunningPage::Notifications,
     ];
 
@@ -30,6 +37,8 @@ pub fn get_tab_label(&self, language: Language) -> &str {
         match self {
             RunningPage::Overview => overview_translation(language),
             RunningPage::Inspect => inspect_translation(language),
+            RunningPage::Map => map_translation(language),
+            RunningPage::Dns => "DNS",
             RunningPage::Notifications => notifications_translation(language),
             RunningPage::Init => "",
         }
@@ -38,7 +47,9 @@ pub fn get_tab_label(&self, language: Language) -> &str {
     pub fn next(self) -> Self {
         match self {
             RunningPage::Overview => RunningPage::Inspect,
-            RunningPage::Inspect => RunningPage::Notifications,
+            RunningPage::Inspect => RunningPage::Map,
+            RunningPage::Map => RunningPage::Dns,
+            RunningPage::Dns => RunningPage::Notifications,
             RunningPage::Notifications => RunningPage::Overview,
             RunningPage::Init => RunningPage::Init,
         }
@@ -48,7 +59,9 @@ pub fn previous(self) -> Self {
         match self {
             RunningPage::Overview => RunningPage::Notifications,
             RunningPage::Inspect => RunningPage::Overview,
-            RunningPage::Notifications => RunningPage::Inspect,
+            RunningPage::Map => RunningPage::Inspect,
+            RunningPage::Dns => RunningPage::Map,
+            RunningPage::Notifications => RunningPage::Dns,
             RunningPage::Init => RunningPage::Init,
         }
     }
@@ -57,6 +70,8 @@ pub fn icon(self) -> iced::advanced::widget::Text<'static, Renderer<StyleType>>
         match self {
             RunningPage::Overview => Icon::Overview,
             RunningPage::Inspect => Icon::Inspect,
+            RunningPage::Map => Icon::Globe,
+            RunningPage::Dns => Icon::Globe,
             RunningPage::Notifications => Icon::Notification,
             RunningPage::Init => Icon::Sniffnet,
         }
@@ -75,14 +90,18 @@ mod tests {
     #[test]
     fn test_previous_running_page() {
         assert_eq!(RunningPage::Overview.previous(), RunningPage::Notifications);
-        assert_eq!(RunningPage::Notifications.previous(), RunningPage::Inspect);
+        assert_eq!(RunningPage::Notifications.previous(), RunningPage::Dns);
+        assert_eq!(RunningPage::Dns.previous(), RunningPage::Map);
+        assert_eq!(RunningPage::Map.previous(), RunningPage::Inspect);
         assert_eq!(RunningPage::Inspect.previous(), RunningPage::Overview);
     }
 
     #[test]
     fn test_next_running_page() {
         assert_eq!(RunningPage::Overview.next(), RunningPage::Inspect);
-        assert_eq!(RunningPage::Inspect.next(), RunningPage::Notifications);
+        assert_eq!(RunningPage::Inspect.next(), RunningPage::Map);
+        assert_eq!(RunningPage::Map.next(), RunningPage::Dns);
+        assert_eq!(RunningPage::Dns.next(), RunningPage::Notifications);
         assert_eq!(RunningPage::Notifications.next(), RunningPage::Overview);
     }
 }
@@ -1,11 +1,21 @@
 use iced::font;
 
+use crate::chart::export_chart::ImageFormat;
+use crate::chart::types::chart_entity::ChartEntity;
+use crate::chart::types::chart_range::ChartRange;
+use crate::flow_export::types::flow_protocol::FlowProtocol;
 use crate::gui::components::types::my_modal::MyModal;
 use crate::gui::pages::types::running_page::RunningPage;
 use crate::gui::pages::types::settings_page::SettingsPage;
 use crate::gui::styles::types::gradient_type::GradientType;
+use crate::history::types::history_query::HistoryQuery;
 use crate::networking::types::host::Host;
-use crate::networking::types::search_parameters::SearchParameters;
+use crate::networking::types::search_parameters::{DnsSearchParameters, SearchParameters};
+use crate::notifications::notification_log::ExportFormat;
+use crate::notifications::types::alert_trigger::AlertTrigger;
+use crate::notifications::types::notification_action::NotificationAction;
+use crate::notifications::types::notification_filter::NotificationFilter;
+use crate::notifications::types::notification_rule::NotificationRule;
 use crate::notifications::types::notifications::Notification;
 use crate::utils::types::file_info::FileInfo;
 use crate::utils::types::web_page::WebPage;
@@ -20,6 +30,8 @@ pub enum Message {
     TickRun,
     /// Select adapter
     AdapterSelection(String),
+    /// Add or remove an adapter to be analyzed together with the selected one
+    AdditionalAdapterSelection(String, bool),
     /// Select IP filter
     IpVersionSelection(IpVersion, bool),
     /// Select protocol filter
@@ -30,6 +42,18 @@ pub enum Message {
     PortFilter(String),
     /// Select chart type to be displayed
     ChartSelection(ChartType),
+    /// Select time range to be displayed in the chart
+    ChartRangeSelection(ChartRange),
+    /// Zoom in (true) or out (false) the chart
+    ChartZoom(bool),
+    /// Move the chart back (positive) or forth (negative) by the given number of seconds
+    ChartPan(i64),
+    /// Chart the traffic of a single host, application protocol, or connection (None for the total traffic)
+    SelectChartEntity(Option<ChartEntity>),
+    /// Pick the file where to export the chart as an image
+    ExportChart(ImageFormat),
+    /// Export the chart as an image to the file at the given path
+    SaveChart(ImageFormat, String),
     /// Select report type to be displayed
     ReportSortSelection(ReportSortType),
     /// Adds or removes the given host into/from the favorites
@@ -40,6 +64,18 @@ pub enum Message {
     Start,
     /// Stop sniffing process and return to initial page
     Reset,
+    /// Apply the selected filters to the running capture
+    ApplyFilters,
+    /// Pause or resume the running capture, keeping the data collected so far
+    PauseResume,
+    /// Save a snapshot of the current session to a file
+    SaveSession,
+    /// The session snapshot has been written (or the file dialog has been dismissed), or an error occurred
+    SessionSaved(Result<(), String>),
+    /// Reopen the session snapshot saved in the given file, as a read-only session
+    OpenSession(String),
+    /// Compare the current session with the session snapshot saved in the given file
+    CompareSession(String),
     /// Change application style
     Style(StyleType),
     /// Deserialize a style from a path
@@ -62,8 +98,34 @@ pub enum Message {
     LanguageSelection(Language),
     /// Set packets notification
     UpdateNotificationSettings(Notification, bool),
+    /// Set how thresholds are evaluated over time
+    UpdateAlertTrigger(AlertTrigger),
+    /// The notification rule being composed has been updated
+    UpdateRuleDraft(NotificationRule),
+    /// Add the notification rule being composed
+    AddNotificationRule,
+    /// Remove the notification rule at the given index
+    RemoveNotificationRule(usize),
+    /// The notification action being composed has been updated
+    UpdateActionDraft(NotificationAction),
+    /// Add the notification action being composed
+    AddNotificationAction,
+    /// Remove the notification action at the given index
+    RemoveNotificationAction(usize),
+    /// Load the blocklist at the given path
+    AddBlocklist(String),
+    /// Remove the blocklist at the given index
+    RemoveBlocklist(usize),
     /// Clear all received notifications
     ClearAllNotifications,
+    /// Forget the targets and listening ports observed, restarting to learn them
+    ResetBaseline,
+    /// The search or the kind filter of the notifications has been updated
+    UpdateNotificationFilter(NotificationFilter),
+    /// Export the notifications of the persistent log matching the filter to a file
+    ExportNotifications(ExportFormat),
+    /// The notifications have been exported (or the file dialog has been dismissed), or an error occurred
+    NotificationsExported(Result<(), String>),
     /// Set notifications volume
     ChangeVolume(u8),
     /// Switch from a page to the next (previous) one if true (false), when the tab (shift+tab) key is pressed.
@@ -78,7 +140,9 @@ pub enum Message {
     CtrlDPressed,
     /// Update search parameters of inspect page
     Search(SearchParameters),
-    /// Update page result number in inspect
+    /// Update search parameters of DNS page
+    DnsSearch(DnsSearchParameters),
+    /// Update page result number in inspect or DNS page
     UpdatePageNumber(bool),
     /// Left (false) or Right (true) arrow key has been pressed
     ArrowPressed(bool),
@@ -98,6 +162,34 @@ pub enum Message {
     CustomCountryDb(String),
     /// The ASN MMDB custom path has been updated
     CustomAsnDb(String),
+    /// The OUI database custom path has been updated
+    CustomOuiDb(String),
+    /// Enables or disables the storage of the traffic history
+    ToggleHistory(bool),
+    /// Changes the number of days the traffic history is kept for
+    ChangeHistoryRetention(u16),
+    /// Enables or disables the metrics endpoint
+    ToggleMetrics(bool),
+    /// Changes the address the metrics endpoint is bound to
+    MetricsAddress(String),
+    /// Enables or disables the local API
+    ToggleApi(bool),
+    /// Changes the address the local API is bound to
+    ApiAddress(String),
+    /// Enables or disables the stream of JSON events
+    ToggleEvents(bool),
+    /// Changes the destination of the JSON events
+    EventsOutput(String),
+    /// Enables or disables the export of the flows to a collector
+    ToggleFlowExport(bool),
+    /// Changes the address of the flow collector
+    FlowCollector(String),
+    /// Changes the format of the exported flows
+    FlowProtocolSelection(FlowProtocol),
+    /// The parameters of the traffic history query have been updated
+    UpdateHistoryQuery(HistoryQuery),
+    /// Query the traffic history with the current parameters
+    SearchHistory,
     // /// The path for the output report has been updated
     // CustomReport(String),
     /// Save the configurations of the app and quit
@@ -1,4 +1,5 @@
 pub mod message;
 pub mod runtime_data;
+pub mod session_snapshot;
 pub mod sniffer;
 pub mod timing_events;
@@ -1,10 +1,17 @@
 //! Module defining the `RunTimeData` struct, useful to to generate chart and to display statistics about network traffic
 
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::networking::types::data_info::DataInfo;
+use crate::notifications::types::alert_trigger::AlertState;
 use crate::notifications::types::logged_notification::LoggedNotification;
+use crate::notifications::types::notification_rule::NotificationRule;
 
 /// Struct containing useful data to display statistics about network traffic and the relative notifications
+#[derive(Clone, Serialize, Deserialize)]
 pub struct RunTimeData {
     /// Total number of bytes (filtered and not filtered)
     pub all_bytes: u128,
@@ -32,6 +39,21 @@ pub struct RunTimeData {
     pub logged_notifications: VecDeque<LoggedNotification>,
     /// The total number of emitted notifications
     pub tot_emitted_notifications: usize,
+    /// Time of the last change of filters during the capture, if any
+    pub last_filters_change: Option<DateTime<Local>>,
+    /// State of the alert on the packets threshold
+    #[serde(skip)]
+    pub packets_alert: AlertState,
+    /// State of the alert on the bytes threshold
+    #[serde(skip)]
+    pub bytes_alert: AlertState,
+    /// Data exchanged by the traffic of each notification rule before the current time interval,
+    /// with the state of the relative alert
+    #[serde(skip)]
+    pub rules_state: Vec<(NotificationRule, DataInfo, AlertState)>,
+    /// Data exchanged by each network adapter already added to the usage of the billing cycle
+    #[serde(skip)]
+    pub adapters_accounted: HashMap<String, DataInfo>,
 }
 
 impl RunTimeData {
@@ -51,6 +73,11 @@ pub fn new() -> Self {
             tot_received_packets_prev: 0,
             logged_notifications: VecDeque::default(),
             tot_emitted_notifications: 0,
+            last_filters_change: None,
+            packets_alert: AlertState::default(),
+            bytes_alert: AlertState::default(),
+            rules_state: Vec::new(),
+            adapters_accounted: HashMap::new(),
         }
     }
 }
@@ -0,0 +1,90 @@
+//! Module defining the `SessionSnapshot` struct, used to save the aggregated state of a capture
+//! to a file and to reopen it later as a read-only session.
+
+use std::path::Path;
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::gui::types::runtime_data::RunTimeData;
+use crate::gui::types::sniffer::Sniffer;
+use crate::networking::types::filters::Filters;
+use crate::utils::formatted_strings::APP_VERSION;
+use crate::{InfoTraffic, TrafficChart};
+
+/// Aggregated state of a capture session
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SessionSnapshot {
+    /// Version of the application which saved the snapshot
+    pub version: String,
+    /// Time at which the snapshot was saved
+    pub timestamp: DateTime<Local>,
+    /// Names of the analyzed network adapters
+    pub adapters: Vec<String>,
+    /// Filters applied to the traffic when the snapshot was saved
+    pub filters: Filters,
+    /// Connections, hosts, application protocols, and DNS queries observed
+    pub info_traffic: InfoTraffic,
+    /// Traffic counters and logged notifications
+    pub runtime_data: RunTimeData,
+    /// Chart series
+    pub traffic_chart: TrafficChart,
+}
+
+impl SessionSnapshot {
+    /// Takes a snapshot of the current capture session
+    pub fn new(sniffer: &Sniffer) -> Self {
+        SessionSnapshot {
+            version: APP_VERSION.to_string(),
+            timestamp: Local::now(),
+            adapters: std::iter::once(&sniffer.device)
+                .chain(sniffer.additional_devices.iter())
+                .map(|device| device.name.clone())
+                .collect(),
+            filters: sniffer.active_filters.lock().unwrap().clone(),
+            info_traffic: sniffer.info_traffic.lock().unwrap().clone(),
+            runtime_data: sniffer.runtime_data.clone(),
+            traffic_chart: sniffer.traffic_chart.clone(),
+        }
+    }
+
+    /// Serializes the snapshot to a string
+    pub fn to_ron(&self) -> Result<String, String> {
+        ron::to_string(self).map_err(|e| e.to_string())
+    }
+
+    /// Reads a snapshot from the file at the given path
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        ron::from_str(&content).map_err(|e| e.to_string())
+    }
+}
+
+/// Session loaded from a snapshot file to be compared with the current one
+#[derive(Clone)]
+pub struct BaselineSession {
+    /// Path of the snapshot file
+    pub path: String,
+    /// Traffic observed in the baseline session, or the error occurred while reading the file
+    pub info_traffic: Result<InfoTraffic, String>,
+}
+
+impl BaselineSession {
+    /// Reads the baseline session from the file at the given path
+    pub fn from_file(path: String) -> Self {
+        let info_traffic =
+            SessionSnapshot::from_file(Path::new(&path)).map(|snapshot| snapshot.info_traffic);
+        BaselineSession { path, info_traffic }
+    }
+}
+
+/// Information about a session reopened from a snapshot file
+#[derive(Clone, Debug)]
+pub struct OpenedSession {
+    /// Path of the snapshot file
+    pub path: String,
+    /// Time at which the snapshot was saved
+    pub timestamp: DateTime<Local>,
+    /// Names of the network adapters analyzed in the session
+    pub adapters: Vec<String>,
+}
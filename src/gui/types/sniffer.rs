@@ -2,22 +2,37 @@
 //! to share data among the different threads.
 
 use std::collections::{HashSet, VecDeque};
-use std::path::PathBuf;
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, Sender};
 use std::sync::{Arc, Mutex};
 use std::thread;
 
+use chrono::Local;
 use iced::{window, Command};
 use pcap::Device;
 use rfd::FileHandle;
 
-use crate::chart::manage_chart_data::update_charts_data;
+use crate::api::types::api_request::ApiRequest;
+use crate::api::types::api_state::ApiState;
+use crate::blocklists::blocklist_check::recheck_blocklists;
+use crate::blocklists::types::blocklist::Blocklist;
+use crate::blocklists::types::blocklists::Blocklists;
+use crate::chart::export_chart::{export_chart, ImageFormat};
+use crate::chart::manage_chart_data::{update_charts_data, update_entities_data};
+use crate::configs::types::config_baseline::ConfigBaseline;
+use crate::flow_export::flow_exporter::FlowExporter;
 use crate::gui::components::types::my_modal::MyModal;
 use crate::gui::pages::types::running_page::RunningPage;
 use crate::gui::pages::types::settings_page::SettingsPage;
 use crate::gui::styles::types::custom_palette::{CustomPalette, ExtraStyles};
 use crate::gui::styles::types::palette::Palette;
 use crate::gui::types::message::Message;
+use crate::gui::types::session_snapshot::{BaselineSession, OpenedSession, SessionSnapshot};
 use crate::gui::types::timing_events::TimingEvents;
+use crate::history::history_db::{get_history_db_path, HistoryDb};
+use crate::history::types::history_entry::HistoryEntry;
+use crate::history::types::history_query::HistoryQuery;
 use crate::mmdb::asn::ASN_MMDB;
 use crate::mmdb::country::COUNTRY_MMDB;
 use crate::mmdb::types::mmdb_reader::MmdbReader;
@@ -28,13 +43,32 @@
 use crate::networking::types::my_device::MyDevice;
 use crate::networking::types::my_link_type::MyLinkType;
 use crate::networking::types::port_collection::PortCollection;
-use crate::networking::types::search_parameters::SearchParameters;
-use crate::notifications::notify_and_log::notify_and_log;
+use crate::networking::types::search_parameters::{DnsSearchParameters, SearchParameters};
+use crate::notifications::notification_log::{
+    export_records, read_log, ExportFormat, NotificationLog, NotificationRecord,
+};
+use crate::notifications::notify_and_log::{log_notification, notify_and_log};
+use crate::notifications::run_actions::{run_actions, spawn_actions_worker, ActionsJob};
+use crate::notifications::types::logged_notification::{ActionFailed, LoggedNotification};
+use crate::notifications::types::notification_action::NotificationAction;
+use crate::notifications::types::notification_filter::NotificationFilter;
+use crate::notifications::types::notification_rule::NotificationRule;
 use crate::notifications::types::notifications::Notification;
 use crate::notifications::types::sound::{play, Sound};
-use crate::report::get_report_entries::get_searched_entries;
+use crate::oui::types::oui_reader::OuiReader;
+use crate::oui::vendor::OUI_DB;
+use crate::report::get_report_entries::{get_searched_dns_queries, get_searched_entries};
 use crate::report::types::report_sort_type::ReportSortType;
+use crate::secondary_threads::export_flows::{connect_to_collector, export_flows};
 use crate::secondary_threads::parse_packets::parse_packets;
+use crate::secondary_threads::serve_metrics::serve_metrics;
+use crate::secondary_threads::store_configs::store_configs;
+use crate::secondary_threads::store_history::store_history;
+use crate::secondary_threads::write_events::{open_events_output, write_events};
+use crate::translations::translations_3::{
+    file_not_saved_translation, history_unavailable_translation, invalid_time_interval_translation,
+    metrics_unavailable_translation,
+};
 use crate::translations::types::language::Language;
 use crate::utils::types::file_info::FileInfo;
 use crate::utils::types::web_page::WebPage;
@@ -48,6 +82,8 @@ pub struct Sniffer {
     pub configs: Arc<Mutex<Configs>>,
     /// Capture number, incremented at every new run
     pub current_capture_id: Arc<Mutex<usize>>,
+    /// Whether the running capture is paused, shared with the capture thread
+    pub paused: Arc<Mutex<bool>>,
     /// Capture data updated by thread parsing packets
     pub info_traffic: Arc<Mutex<InfoTraffic>>,
     /// Reports if a newer release of the software is available on GitHub
@@ -56,8 +92,12 @@ pub struct Sniffer {
     pub runtime_data: RunTimeData,
     /// Network adapter to be analyzed
     pub device: MyDevice,
-    /// Active filters on the observed traffic
+    /// Other network adapters to be analyzed together with the main one
+    pub additional_devices: Vec<MyDevice>,
+    /// Filters selected by the user (in the initial page or in the edit filters modal)
     pub filters: Filters,
+    /// Filters currently applied to the observed traffic, shared with the capture thread
+    pub active_filters: Arc<Mutex<Filters>>,
     /// Signals if a pcap error occurred
     pub pcap_error: Option<String>,
     /// Waiting string
@@ -80,35 +120,82 @@ pub struct Sniffer {
     pub search: SearchParameters,
     /// Current page number of inspect search results
     pub page_number: usize,
+    /// Search parameters of DNS page
+    pub dns_search: DnsSearchParameters,
+    /// Current page number of DNS search results
+    pub dns_page_number: usize,
     /// MMDB reader for countries
     pub country_mmdb_reader: Arc<MmdbReader>,
     /// MMDB reader for ASN
     pub asn_mmdb_reader: Arc<MmdbReader>,
+    /// Reader for the MAC address vendors
+    pub oui_reader: Arc<OuiReader>,
     /// Time-related events
     pub timing_events: TimingEvents,
+    /// Parameters of the traffic history query
+    pub history_query: HistoryQuery,
+    /// Results of the last traffic history query (None if no query has been performed yet)
+    pub history_results: Option<Result<Vec<HistoryEntry>, String>>,
+    /// Session reopened from a snapshot file; None if the displayed session is a live capture
+    pub opened_session: Option<OpenedSession>,
+    /// Session the current one is compared with
+    pub baseline_session: Option<BaselineSession>,
+    /// State shared with the thread serving the local API
+    pub api_state: Arc<Mutex<ApiState>>,
+    /// Channel used to forward the emitted notifications to the stream of JSON events, if enabled
+    pub events_sender: Option<Sender<LoggedNotification>>,
+    /// Channel used to submit the notification actions to the thread running them
+    pub actions_sender: Sender<ActionsJob>,
+    /// Channel receiving the notification actions failed
+    pub action_failures: Receiver<ActionFailed>,
+    /// Notification rule being composed in the notifications settings
+    pub rule_draft: NotificationRule,
+    /// Notification action being composed in the notifications settings
+    pub action_draft: NotificationAction,
+    /// Log of the emitted notifications
+    pub notification_log: NotificationLog,
+    /// Search and kind filter of the notifications page
+    pub notification_filter: NotificationFilter,
+    /// Page of the notifications log shown in the notifications page
+    pub notification_records: Vec<NotificationRecord>,
+    /// Number of notifications of the log matching the filter
+    pub notifications_results_number: usize,
+    /// Page number of the notifications page
+    pub notifications_page_number: usize,
+    /// Blocklists checked against the remote addresses
+    pub blocklists: Arc<Mutex<Blocklists>>,
+    /// Listener of the metrics endpoint and its address, shared by the following captures
+    pub metrics_listener: Option<(String, Arc<TcpListener>)>,
 }
 
 impl Sniffer {
     pub fn new(
         configs: &Arc<Mutex<Configs>>,
         newer_release_available: Arc<Mutex<Option<bool>>>,
+        notification_log_path: Option<PathBuf>,
     ) -> Self {
         let ConfigSettings {
             style,
             language,
             mmdb_country,
             mmdb_asn,
+            oui_db,
+            blocklists,
             ..
         } = configs.lock().unwrap().settings.clone();
         let device = configs.lock().unwrap().device.to_my_device();
+        let (failures_sender, action_failures) = mpsc::channel();
         Self {
             configs: configs.clone(),
             current_capture_id: Arc::new(Mutex::new(0)),
+            paused: Arc::new(Mutex::new(false)),
             info_traffic: Arc::new(Mutex::new(InfoTraffic::new())),
             newer_release_available,
             runtime_data: RunTimeData::new(),
             device,
+            additional_devices: Vec::new(),
             filters: Filters::default(),
+            active_filters: Arc::new(Mutex::new(Filters::default())),
             pcap_error: None,
             waiting: ".".to_string(),
             traffic_chart: TrafficChart::new(style, language),
@@ -119,17 +206,42 @@ pub fn new(
             running_page: RunningPage::Init,
             unread_notifications: 0,
             search: SearchParameters::default(),
+            dns_search: DnsSearchParameters::default(),
+            dns_page_number: 1,
             page_number: 1,
             country_mmdb_reader: Arc::new(MmdbReader::from(&mmdb_country, COUNTRY_MMDB)),
             asn_mmdb_reader: Arc::new(MmdbReader::from(&mmdb_asn, ASN_MMDB)),
+            oui_reader: Arc::new(OuiReader::from(&oui_db, OUI_DB)),
             timing_events: TimingEvents::default(),
+            history_query: HistoryQuery::default(),
+            history_results: None,
+            opened_session: None,
+            baseline_session: None,
+            api_state: Arc::new(Mutex::new(ApiState::default())),
+            events_sender: None,
+            actions_sender: spawn_actions_worker(failures_sender),
+            action_failures,
+            rule_draft: NotificationRule::default(),
+            action_draft: NotificationAction::default(),
+            notification_log: NotificationLog::new(notification_log_path),
+            notification_filter: NotificationFilter::default(),
+            notification_records: Vec::new(),
+            notifications_results_number: 0,
+            notifications_page_number: 1,
+            blocklists: Arc::new(Mutex::new(Blocklists::from_paths(&blocklists))),
+            metrics_listener: None,
         }
     }
 
     pub fn update(&mut self, message: Message) -> Command<Message> {
         match message {
-            Message::TickRun => return self.refresh_data(),
+            Message::TickRun => {
+                return Command::batch([self.refresh_data(), self.handle_api_requests()])
+            }
             Message::AdapterSelection(name) => self.set_adapter(&name),
+            Message::AdditionalAdapterSelection(name, insert) => {
+                self.set_additional_adapter(&name, insert);
+            }
             Message::IpVersionSelection(version, insert) => {
                 if insert {
                     self.filters.ip_versions.insert(version);
@@ -157,9 +269,37 @@ pub fn update(&mut self, message: Message) -> Command<Message> {
                 self.filters.port_str = value;
             }
             Message::ChartSelection(unit) => self.traffic_chart.change_kind(unit),
+            Message::ChartRangeSelection(range) => self.traffic_chart.change_range(range),
+            Message::ChartZoom(zoom_in) => self.traffic_chart.zoom(zoom_in),
+            Message::ChartPan(seconds) => self.traffic_chart.pan(seconds),
+            Message::SelectChartEntity(entity) => self.traffic_chart.select_entity(entity),
+            Message::ExportChart(format) => return self.pick_chart_path(format),
+            Message::SaveChart(format, path) => {
+                if !path.is_empty() {
+                    if let Err(error) = export_chart(&self.traffic_chart, format, Path::new(&path))
+                    {
+                        let language = self.configs.lock().unwrap().settings.language;
+                        self.show_error(format!(
+                            "{}\n{path}: {error}",
+                            file_not_saved_translation(language)
+                        ));
+                    }
+                }
+            }
             Message::ReportSortSelection(sort) => self.report_sort_type = sort,
             Message::OpenWebPage(web_page) => Self::open_web(&web_page),
             Message::Start => self.start(),
+            Message::ApplyFilters => {
+                if self.filters.are_valid() {
+                    *self.active_filters.lock().unwrap() = self.filters.clone();
+                    self.runtime_data.last_filters_change = Some(Local::now());
+                    return self.update(Message::HideModal);
+                }
+            }
+            Message::PauseResume => self.pause_resume(),
+            Message::SaveSession => return self.save_session(),
+            Message::OpenSession(path) => self.open_session(path),
+            Message::CompareSession(path) => return self.compare_session(path),
             Message::Reset => return self.reset(),
             Message::Style(style) => {
                 self.configs.lock().unwrap().settings.style = style;
@@ -179,6 +319,10 @@ pub fn update(&mut self, message: Message) -> Command<Message> {
             Message::AddOrRemoveFavorite(host, add) => self.add_or_remove_favorite(&host, add),
             Message::ShowModal(modal) => {
                 if self.settings_page.is_none() && self.modal.is_none() {
+                    if modal.eq(&MyModal::EditFilters) {
+                        // start editing from the filters currently applied
+                        self.filters = self.active_filters.lock().unwrap().clone();
+                    }
                     self.modal = Some(modal);
                 }
             }
@@ -198,6 +342,7 @@ pub fn update(&mut self, message: Message) -> Command<Message> {
                 self.running_page = running_page;
                 if running_page.eq(&RunningPage::Notifications) {
                     self.unread_notifications = 0;
+                    self.load_notifications_page();
                 }
             }
             Message::LanguageSelection(language) => {
@@ -207,14 +352,96 @@ pub fn update(&mut self, message: Message) -> Command<Message> {
             Message::UpdateNotificationSettings(value, emit_sound) => {
                 self.update_notification_settings(value, emit_sound);
             }
+            Message::UpdateAlertTrigger(trigger) => {
+                self.configs.lock().unwrap().settings.notifications.trigger = trigger;
+            }
+            Message::UpdateRuleDraft(rule) => self.rule_draft = rule,
+            Message::AddNotificationRule => {
+                if self.rule_draft.is_valid() {
+                    let rule = NotificationRule {
+                        value: self.rule_draft.value.trim().to_string(),
+                        ..self.rule_draft.clone()
+                    };
+                    let notification_rules =
+                        &mut self.configs.lock().unwrap().settings.notification_rules;
+                    if !notification_rules.contains(&rule) {
+                        notification_rules.push(rule);
+                    }
+                    self.rule_draft.value = String::new();
+                }
+            }
+            Message::RemoveNotificationRule(index) => {
+                let notification_rules =
+                    &mut self.configs.lock().unwrap().settings.notification_rules;
+                if index < notification_rules.len() {
+                    notification_rules.remove(index);
+                }
+            }
+            Message::UpdateActionDraft(action) => self.action_draft = action,
+            Message::AddNotificationAction => {
+                if self.action_draft.is_valid() {
+                    let action = NotificationAction {
+                        command: self.action_draft.command.trim().to_string(),
+                        webhook: self.action_draft.webhook.trim().to_string(),
+                        ..self.action_draft
+                    };
+                    let notification_actions =
+                        &mut self.configs.lock().unwrap().settings.notification_actions;
+                    if !notification_actions.contains(&action) {
+                        notification_actions.push(action);
+                    }
+                    self.action_draft = NotificationAction::default();
+                }
+            }
+            Message::RemoveNotificationAction(index) => {
+                let notification_actions =
+                    &mut self.configs.lock().unwrap().settings.notification_actions;
+                if index < notification_actions.len() {
+                    notification_actions.remove(index);
+                }
+            }
+            Message::AddBlocklist(path) => {
+                let blocklists = &mut self.configs.lock().unwrap().settings.blocklists;
+                if !path.is_empty() && !blocklists.contains(&path) {
+                    self.blocklists
+                        .lock()
+                        .unwrap()
+                        .lists
+                        .push(Blocklist::from_file(&path));
+                    blocklists.push(path);
+                    self.check_known_addresses();
+                }
+            }
+            Message::RemoveBlocklist(index) => {
+                let blocklists = &mut self.configs.lock().unwrap().settings.blocklists;
+                if index < blocklists.len() {
+                    blocklists.remove(index);
+                    self.blocklists.lock().unwrap().lists.remove(index);
+                    self.check_known_addresses();
+                }
+            }
             Message::ChangeVolume(volume) => {
                 play(Sound::Pop, volume);
                 self.configs.lock().unwrap().settings.notifications.volume = volume;
             }
             Message::ClearAllNotifications => {
                 self.runtime_data.logged_notifications = VecDeque::new();
+                self.notification_log.clear_view();
+                self.notifications_page_number = 1;
+                self.load_notifications_page();
                 return self.update(Message::HideModal);
             }
+            Message::ResetBaseline => {
+                let baseline = ConfigBaseline::default();
+                self.configs.lock().unwrap().baseline = baseline.clone();
+                baseline.store();
+            }
+            Message::UpdateNotificationFilter(filter) => {
+                self.notification_filter = filter;
+                self.notifications_page_number = 1;
+                self.load_notifications_page();
+            }
+            Message::ExportNotifications(format) => return self.export_notifications(format),
             Message::SwitchPage(next) => {
                 // To prevent SwitchPage be triggered when using `Alt` + `Tab` to switch back,
                 // first check if user switch back just now, and ignore the request for a short time.
@@ -231,25 +458,46 @@ pub fn update(&mut self, message: Message) -> Command<Message> {
                 self.running_page = RunningPage::Inspect;
                 self.search = parameters;
             }
+            Message::DnsSearch(parameters) => {
+                self.dns_page_number = 1;
+                self.running_page = RunningPage::Dns;
+                self.dns_search = parameters;
+            }
             Message::UpdatePageNumber(increment) => {
+                let page_number = match self.running_page {
+                    RunningPage::Dns => &mut self.dns_page_number,
+                    RunningPage::Notifications => &mut self.notifications_page_number,
+                    _ => &mut self.page_number,
+                };
                 let new_page = if increment {
-                    self.page_number.checked_add(1)
+                    page_number.checked_add(1)
                 } else {
-                    self.page_number.checked_sub(1)
+                    page_number.checked_sub(1)
                 }
                 .unwrap();
-                self.page_number = new_page;
+                *page_number = new_page;
+                if self.running_page.eq(&RunningPage::Notifications) {
+                    self.load_notifications_page();
+                }
             }
             Message::ArrowPressed(increment) => {
-                if self.running_page.eq(&RunningPage::Inspect)
-                    && self.settings_page.is_none()
-                    && self.modal.is_none()
-                {
+                if self.settings_page.is_none() && self.modal.is_none() {
+                    let (page_number, results_number) = match self.running_page {
+                        RunningPage::Inspect => (self.page_number, get_searched_entries(self).1),
+                        RunningPage::Dns => {
+                            (self.dns_page_number, get_searched_dns_queries(self).1)
+                        }
+                        RunningPage::Notifications => (
+                            self.notifications_page_number,
+                            self.notifications_results_number,
+                        ),
+                        _ => return Command::none(),
+                    };
                     if increment {
-                        if self.page_number < (get_searched_entries(self).1 + 20 - 1) / 20 {
+                        if page_number < (results_number + 20 - 1) / 20 {
                             return self.update(Message::UpdatePageNumber(increment));
                         }
-                    } else if self.page_number > 1 {
+                    } else if page_number > 1 {
                         return self.update(Message::UpdatePageNumber(increment));
                     }
                 }
@@ -279,6 +527,62 @@ pub fn update(&mut self, message: Message) -> Command<Message> {
                 self.configs.lock().unwrap().settings.mmdb_asn = db.clone();
                 self.asn_mmdb_reader = Arc::new(MmdbReader::from(&db, ASN_MMDB));
             }
+            Message::CustomOuiDb(db) => {
+                self.configs.lock().unwrap().settings.oui_db = db.clone();
+                self.oui_reader = Arc::new(OuiReader::from(&db, OUI_DB));
+            }
+            Message::ToggleHistory(history) => {
+                if self.running_page.eq(&RunningPage::Init) {
+                    self.configs.lock().unwrap().settings.history = history;
+                }
+            }
+            Message::ChangeHistoryRetention(days) => {
+                if self.running_page.eq(&RunningPage::Init) {
+                    self.configs.lock().unwrap().settings.history_retention = days;
+                }
+            }
+            Message::ToggleMetrics(metrics) => {
+                if self.running_page.eq(&RunningPage::Init) {
+                    self.configs.lock().unwrap().settings.metrics = metrics;
+                }
+            }
+            Message::MetricsAddress(address) => {
+                if self.running_page.eq(&RunningPage::Init) {
+                    self.configs.lock().unwrap().settings.metrics_address = address;
+                }
+            }
+            // the local API lives as long as the app: its settings are applied at next launch
+            Message::ToggleApi(api) => self.configs.lock().unwrap().settings.api = api,
+            Message::ApiAddress(address) => {
+                self.configs.lock().unwrap().settings.api_address = address;
+            }
+            Message::ToggleEvents(events) => {
+                if self.running_page.eq(&RunningPage::Init) {
+                    self.configs.lock().unwrap().settings.events = events;
+                }
+            }
+            Message::EventsOutput(output) => {
+                if self.running_page.eq(&RunningPage::Init) {
+                    self.configs.lock().unwrap().settings.events_output = output;
+                }
+            }
+            Message::ToggleFlowExport(enabled) => {
+                if self.running_page.eq(&RunningPage::Init) {
+                    self.configs.lock().unwrap().settings.flow_export.enabled = enabled;
+                }
+            }
+            Message::FlowCollector(collector) => {
+                if self.running_page.eq(&RunningPage::Init) {
+                    self.configs.lock().unwrap().settings.flow_export.collector = collector;
+                }
+            }
+            Message::FlowProtocolSelection(protocol) => {
+                if self.running_page.eq(&RunningPage::Init) {
+                    self.configs.lock().unwrap().settings.flow_export.protocol = protocol;
+                }
+            }
+            Message::UpdateHistoryQuery(query) => self.history_query = query,
+            Message::SearchHistory => self.history_results = Some(self.search_history()),
             // Message::CustomReport(path) => {
             //     self.settings.output_path = path;
             // }
@@ -300,12 +604,37 @@ pub fn update(&mut self, message: Message) -> Command<Message> {
                     consumer_message,
                 );
             }
-            Message::TickInit | Message::FontLoaded(_) => {}
+            Message::TickInit => return self.handle_api_requests(),
+            Message::SessionSaved(result) | Message::NotificationsExported(result) => {
+                if let Err(error) = result {
+                    self.show_error(error);
+                }
+            }
+            Message::FontLoaded(_) => {}
         }
         Command::none()
     }
 
     fn refresh_data(&mut self) -> Command<Message> {
+        if self.opened_session.is_some() {
+            // the data of a reopened session never change
+            return Command::none();
+        }
+        // the data usage is accounted even if no packet was filtered, or the capture is paused
+        let adapters = self.info_traffic.lock().unwrap().adapters.clone();
+        let mut configs = self.configs.lock().unwrap();
+        let cycle_start_day = configs
+            .settings
+            .notifications
+            .quota_notification
+            .cycle_start_day;
+        configs.usage.record(
+            &adapters,
+            &mut self.runtime_data.adapters_accounted,
+            cycle_start_day,
+            Local::now().date_naive(),
+        );
+        drop(configs);
         let info_traffic_lock = self.info_traffic.lock().unwrap();
         self.runtime_data.all_packets = info_traffic_lock.all_packets;
         if info_traffic_lock.tot_received_packets + info_traffic_lock.tot_sent_packets == 0 {
@@ -319,17 +648,65 @@ fn refresh_data(&mut self) -> Command<Message> {
         self.runtime_data.tot_sent_bytes = info_traffic_lock.tot_sent_bytes;
         self.runtime_data.dropped_packets = info_traffic_lock.dropped_packets;
         drop(info_traffic_lock);
-        let emitted_notifications = notify_and_log(
-            &mut self.runtime_data,
-            self.configs.lock().unwrap().settings.notifications,
-            &self.info_traffic.clone(),
-        );
-        self.info_traffic.lock().unwrap().favorites_last_interval = HashSet::new();
-        self.runtime_data.tot_emitted_notifications += emitted_notifications;
-        if self.running_page.ne(&RunningPage::Notifications) {
-            self.unread_notifications += emitted_notifications;
+        if self.blocklists.lock().unwrap().reload_changed() {
+            self.check_known_addresses();
+        }
+        let paused = *self.paused.lock().unwrap();
+        if !paused {
+            let mut configs = self.configs.lock().unwrap();
+            let Configs {
+                settings,
+                baseline,
+                usage,
+                ..
+            } = &mut *configs;
+            let mut emitted = notify_and_log(
+                &mut self.runtime_data,
+                settings.notifications,
+                &settings.notification_rules,
+                baseline,
+                usage,
+                &self.info_traffic.clone(),
+            );
+            drop(configs);
+            // failures of the actions run for the previous notifications
+            for action_failed in self.action_failures.try_iter() {
+                let notification = LoggedNotification::ActionFailed(action_failed);
+                log_notification(&mut self.runtime_data, notification.clone());
+                emitted.push(notification);
+            }
+            self.info_traffic.lock().unwrap().favorites_last_interval = HashSet::new();
+            if let Some(events_sender) = &self.events_sender {
+                for notification in &emitted {
+                    // the receiver is gone if the output couldn't be written anymore
+                    let _ = events_sender.send(notification.clone());
+                }
+            }
+            let notification_actions = self
+                .configs
+                .lock()
+                .unwrap()
+                .settings
+                .notification_actions
+                .clone();
+            if !notification_actions.is_empty() {
+                let now = Local::now();
+                for notification in &emitted {
+                    run_actions(
+                        &self.actions_sender,
+                        &notification_actions,
+                        notification,
+                        now,
+                    );
+                }
+            }
+            let _ = self.notification_log.append(&emitted);
+            self.runtime_data.tot_emitted_notifications += emitted.len();
+            // the page shown is reloaded only when requested (also clicking its tab)
+            self.unread_notifications += emitted.len();
         }
-        update_charts_data(&mut self.runtime_data, &mut self.traffic_chart);
+        update_charts_data(&mut self.runtime_data, &mut self.traffic_chart, paused);
+        update_entities_data(&mut self.traffic_chart, &self.info_traffic.lock().unwrap());
 
         let current_device_name = self.device.name.clone();
         // update ConfigDevice stored if different from last sniffed device
@@ -339,7 +716,7 @@ fn refresh_data(&mut self) -> Command<Message> {
         }
         // waiting notifications
         if self.running_page.eq(&RunningPage::Notifications)
-            && self.runtime_data.logged_notifications.is_empty()
+            && self.notifications_results_number == 0
         {
             return self.update(Message::Waiting);
         }
@@ -365,41 +742,200 @@ fn open_web(web_page: &WebPage) {
     fn start(&mut self) {
         let current_device_name = &*self.device.name.clone();
         self.set_adapter(current_device_name);
-        let device = self.device.clone();
-        let (pcap_error, cap_result) = get_capture_result(&device);
-        self.pcap_error = pcap_error.clone();
+        for name in self
+            .additional_devices
+            .iter()
+            .map(|device| device.name.clone())
+            .collect::<Vec<String>>()
+        {
+            // refresh the addresses of the additional adapters as well
+            self.set_additional_adapter(&name, true);
+        }
+        let mut captures = Vec::new();
+        let mut pcap_errors = Vec::new();
+        for (i, device) in std::iter::once(&mut self.device)
+            .chain(self.additional_devices.iter_mut())
+            .enumerate()
+        {
+            match get_capture_result(device) {
+                (None, Some(cap)) => {
+                    device.link_type = MyLinkType::from_pcap_link_type(cap.get_datalink());
+                    captures.push((device.clone(), cap));
+                }
+                (pcap_error, _) => {
+                    let error = pcap_error.unwrap_or_default();
+                    pcap_errors.push(if i == 0 {
+                        error
+                    } else {
+                        format!("{}: {error}", device.name)
+                    });
+                }
+            }
+        }
+        self.pcap_error = if pcap_errors.is_empty() {
+            None
+        } else {
+            Some(pcap_errors.join("\n"))
+        };
         let info_traffic_mutex = self.info_traffic.clone();
         *info_traffic_mutex.lock().unwrap() = InfoTraffic::new();
         self.runtime_data = RunTimeData::new();
         let ConfigSettings {
-            style, language, ..
+            style,
+            language,
+            history,
+            history_retention,
+            metrics,
+            ..
         } = self.configs.lock().unwrap().settings;
         self.traffic_chart = TrafficChart::new(style, language);
         self.running_page = RunningPage::Overview;
+        *self.active_filters.lock().unwrap() = self.filters.clone();
+        *self.paused.lock().unwrap() = false;
+        self.events_sender = None;
 
-        if pcap_error.is_none() {
-            // no pcap error
-            let cap = cap_result.unwrap();
+        if self.pcap_error.is_some() {
+            return;
+        }
+        // no pcap error: start a capture thread for each adapter
+        for (device, cap) in captures {
+            let info_traffic_mutex = info_traffic_mutex.clone();
             let current_capture_id = self.current_capture_id.clone();
-            let filters = self.filters.clone();
+            let paused = self.paused.clone();
+            let filters = self.active_filters.clone();
             let country_mmdb_reader = self.country_mmdb_reader.clone();
             let asn_mmdb_reader = self.asn_mmdb_reader.clone();
-            self.device.link_type = MyLinkType::from_pcap_link_type(cap.get_datalink());
+            let blocklists = self.blocklists.clone();
             thread::Builder::new()
                 .name("thread_parse_packets".to_string())
                 .spawn(move || {
                     parse_packets(
                         &current_capture_id,
+                        &paused,
                         &device,
                         cap,
                         &filters,
                         &info_traffic_mutex,
                         &country_mmdb_reader,
                         &asn_mmdb_reader,
+                        &blocklists,
                     );
                 })
                 .unwrap();
         }
+        // expose the metrics endpoint, if enabled
+        if metrics {
+            let metrics_address = self
+                .configs
+                .lock()
+                .unwrap()
+                .settings
+                .metrics_address
+                .clone();
+            match self.get_metrics_listener(&metrics_address) {
+                Ok(listener) => {
+                    let current_capture_id = self.current_capture_id.clone();
+                    let info_traffic_mutex = info_traffic_mutex.clone();
+                    thread::Builder::new()
+                        .name("thread_serve_metrics".to_string())
+                        .spawn(move || {
+                            serve_metrics(&current_capture_id, &info_traffic_mutex, &listener);
+                        })
+                        .unwrap();
+                }
+                Err(error) => self.show_error(format!(
+                    "{}\n{metrics_address}: {error}",
+                    metrics_unavailable_translation(language)
+                )),
+            }
+        } else {
+            // the endpoint is closed as soon as the thread serving it is interrupted
+            self.metrics_listener = None;
+        }
+        // export the flows to the collector, if enabled
+        let flow_export = self.configs.lock().unwrap().settings.flow_export.clone();
+        if flow_export.enabled {
+            if let Ok(socket) = connect_to_collector(&flow_export.collector) {
+                let current_capture_id = self.current_capture_id.clone();
+                let info_traffic_mutex = info_traffic_mutex.clone();
+                let exporter = FlowExporter::new(&flow_export);
+                thread::Builder::new()
+                    .name("thread_export_flows".to_string())
+                    .spawn(move || {
+                        export_flows(&current_capture_id, &info_traffic_mutex, exporter, &socket);
+                    })
+                    .unwrap();
+            }
+        }
+        // write the events as JSON lines, if enabled
+        let (events, events_output) = {
+            let settings = &self.configs.lock().unwrap().settings;
+            (settings.events, settings.events_output.clone())
+        };
+        if events {
+            if let Ok(output) = open_events_output(&events_output) {
+                let current_capture_id = self.current_capture_id.clone();
+                let info_traffic_mutex = info_traffic_mutex.clone();
+                let (events_sender, events_receiver) = mpsc::channel();
+                self.events_sender = Some(events_sender);
+                thread::Builder::new()
+                    .name("thread_write_events".to_string())
+                    .spawn(move || {
+                        write_events(
+                            &current_capture_id,
+                            &info_traffic_mutex,
+                            &events_receiver,
+                            output,
+                        );
+                    })
+                    .unwrap();
+            }
+        }
+        // periodically store the configurations updated during the capture
+        let current_capture_id = self.current_capture_id.clone();
+        let configs = self.configs.clone();
+        thread::Builder::new()
+            .name("thread_store_configs".to_string())
+            .spawn(move || {
+                store_configs(&current_capture_id, &configs);
+            })
+            .unwrap();
+        // store the traffic history, if enabled
+        if let Some(path) = get_history_db_path().filter(|_| history) {
+            let current_capture_id = self.current_capture_id.clone();
+            thread::Builder::new()
+                .name("thread_store_history".to_string())
+                .spawn(move || {
+                    if let Ok(history_db) = HistoryDb::open(&path) {
+                        store_history(
+                            &current_capture_id,
+                            &info_traffic_mutex,
+                            history_db,
+                            history_retention,
+                        );
+                    }
+                })
+                .unwrap();
+        }
+    }
+
+    fn search_history(&self) -> Result<Vec<HistoryEntry>, String> {
+        let language = self.configs.lock().unwrap().settings.language;
+        let Some((from, to)) = self.history_query.get_interval() else {
+            return Err(invalid_time_interval_translation(language).to_string());
+        };
+        let path = get_history_db_path()
+            .ok_or_else(|| history_unavailable_translation(language).to_string())?;
+        HistoryDb::open(&path)
+            .and_then(|history_db| {
+                history_db.query(
+                    from,
+                    to,
+                    self.history_query.group,
+                    &self.history_query.local_address,
+                )
+            })
+            .map_err(|e| format!("{}: {e}", history_unavailable_translation(language)))
     }
 
     fn reset(&mut self) -> Command<Message> {
@@ -410,10 +946,20 @@ fn reset(&mut self) -> Command<Message> {
         self.unread_notifications = 0;
         self.search = SearchParameters::default();
         self.page_number = 1;
+        // the filters of a reopened session aren't inherited by the next capture
+        if self.opened_session.take().is_none() {
+            self.filters = self.active_filters.lock().unwrap().clone();
+        }
+        self.dns_search = DnsSearchParameters::default();
+        self.dns_page_number = 1;
+        self.baseline_session = None;
+        self.events_sender = None;
         self.update(Message::HideModal)
     }
 
     fn set_adapter(&mut self, name: &str) {
+        self.additional_devices
+            .retain(|device| device.name.ne(name));
         for dev in Device::list().expect("Error retrieving device list\r\n") {
             if dev.name.eq(&name) {
                 let mut addresses_mutex = self.device.addresses.lock().unwrap();
@@ -430,6 +976,25 @@ fn set_adapter(&mut self, name: &str) {
         }
     }
 
+    fn set_additional_adapter(&mut self, name: &str, insert: bool) {
+        self.additional_devices
+            .retain(|device| device.name.ne(name));
+        if !insert || self.device.name.eq(name) {
+            return;
+        }
+        for dev in Device::list().expect("Error retrieving device list\r\n") {
+            if dev.name.eq(&name) {
+                self.additional_devices.push(MyDevice {
+                    name: dev.name,
+                    desc: dev.desc,
+                    addresses: Arc::new(Mutex::new(dev.addresses)),
+                    link_type: MyLinkType::NotYetAssigned,
+                });
+                break;
+            }
+        }
+    }
+
     fn update_waiting_dots(&mut self) {
         if self.waiting.len() > 2 {
             self.waiting = String::new();
@@ -450,6 +1015,24 @@ fn add_or_remove_favorite(&mut self, host: &Host, add: bool) {
         drop(info_traffic);
     }
 
+    /// Checks again against the blocklists the remote addresses already observed, after the lists changed
+    fn check_known_addresses(&self) {
+        let mut info_traffic = self.info_traffic.lock().unwrap();
+        let blocklists = self.blocklists.lock().unwrap();
+        let addresses: Vec<(String, Option<String>)> = info_traffic
+            .addresses_resolved
+            .iter()
+            .map(|(address, (r_dns, _))| (address.clone(), Some(r_dns.clone())))
+            .chain(
+                info_traffic
+                    .addresses_waiting_resolution
+                    .keys()
+                    .map(|address| (address.clone(), None)),
+            )
+            .collect();
+        recheck_blocklists(&mut info_traffic, &blocklists, &addresses);
+    }
+
     fn close_settings(&mut self) {
         if let Some(page) = self.settings_page {
             self.last_opened_setting = page;
@@ -486,6 +1069,51 @@ fn update_notification_settings(&mut self, value: Notification, emit_sound: bool
                     .favorite_notification = favorite_notification;
                 favorite_notification.sound
             }
+            Notification::FirstSeen(first_seen_notification) => {
+                self.configs
+                    .lock()
+                    .unwrap()
+                    .settings
+                    .notifications
+                    .first_seen_notification = first_seen_notification;
+                first_seen_notification.sound
+            }
+            Notification::NewPort(new_port_notification) => {
+                self.configs
+                    .lock()
+                    .unwrap()
+                    .settings
+                    .notifications
+                    .new_port_notification = new_port_notification;
+                new_port_notification.sound
+            }
+            Notification::Blocklist(blocklist_notification) => {
+                self.configs
+                    .lock()
+                    .unwrap()
+                    .settings
+                    .notifications
+                    .blocklist_notification = blocklist_notification;
+                blocklist_notification.sound
+            }
+            Notification::Scan(scan_notification) => {
+                self.configs
+                    .lock()
+                    .unwrap()
+                    .settings
+                    .notifications
+                    .scan_notification = scan_notification;
+                scan_notification.sound
+            }
+            Notification::Quota(quota_notification) => {
+                self.configs
+                    .lock()
+                    .unwrap()
+                    .settings
+                    .notifications
+                    .quota_notification = quota_notification;
+                quota_notification.sound
+            }
         };
         if emit_sound {
             play(
@@ -506,7 +1134,11 @@ fn switch_page(&mut self, next: bool) {
                 }
             }
             (
-                RunningPage::Inspect | RunningPage::Notifications | RunningPage::Overview,
+                RunningPage::Inspect
+                | RunningPage::Map
+                | RunningPage::Dns
+                | RunningPage::Notifications
+                | RunningPage::Overview,
                 None,
                 true,
             ) => {
@@ -520,6 +1152,7 @@ fn switch_page(&mut self, next: bool) {
                     };
                     if self.running_page.eq(&RunningPage::Notifications) {
                         self.unread_notifications = 0;
+                        self.load_notifications_page();
                     }
                 }
             }
@@ -539,6 +1172,10 @@ fn shortcut_return(&mut self) -> Command<Message> {
             return self.update(Message::Reset);
         } else if self.modal.eq(&Some(MyModal::ClearAll)) {
             return self.update(Message::ClearAllNotifications);
+        } else if self.modal.eq(&Some(MyModal::EditFilters)) {
+            return self.update(Message::ApplyFilters);
+        } else if self.modal.eq(&Some(MyModal::History)) {
+            return self.update(Message::SearchHistory);
         }
         Command::none()
     }
@@ -552,10 +1189,186 @@ fn shortcut_esc(&mut self) -> Command<Message> {
         Command::none()
     }
 
+    fn pause_resume(&mut self) {
+        if self.is_capture_running() {
+            let mut paused = self.paused.lock().unwrap();
+            *paused = !*paused;
+        }
+    }
+
+    /// Returns true if a live capture (not a reopened session) is running without errors
+    fn is_capture_running(&self) -> bool {
+        self.running_page.ne(&RunningPage::Init)
+            && self.pcap_error.is_none()
+            && self.opened_session.is_none()
+    }
+
+    /// Executes the requests received by the local API,
+    /// and publishes the current state of the capture to it
+    fn handle_api_requests(&mut self) -> Command<Message> {
+        let pending_requests = std::mem::take(&mut self.api_state.lock().unwrap().pending_requests);
+        let mut commands = Vec::new();
+        for request in pending_requests {
+            match request {
+                ApiRequest::Start { adapter, filters } => {
+                    if self.is_capture_running() {
+                        continue;
+                    }
+                    if self.running_page.ne(&RunningPage::Init) {
+                        // close the reopened session or the failed capture
+                        commands.push(self.reset());
+                    }
+                    if let Some(name) = adapter {
+                        self.set_adapter(&name);
+                    }
+                    self.filters = *filters;
+                    commands.push(self.update(Message::Start));
+                }
+                ApiRequest::Stop => {
+                    if self.is_capture_running() {
+                        commands.push(self.update(Message::Reset));
+                    }
+                }
+            }
+        }
+
+        let mut api_state = self.api_state.lock().unwrap();
+        api_state.is_capture_running = self.is_capture_running();
+        api_state.adapters = if api_state.is_capture_running {
+            std::iter::once(&self.device)
+                .chain(self.additional_devices.iter())
+                .map(|device| device.name.clone())
+                .collect()
+        } else {
+            Vec::new()
+        };
+        drop(api_state);
+
+        Command::batch(commands)
+    }
+
+    fn save_session(&mut self) -> Command<Message> {
+        if !self.is_capture_running() {
+            return Command::none();
+        }
+        let language = self.configs.lock().unwrap().settings.language;
+        match SessionSnapshot::new(self).to_ron() {
+            Ok(content) => Command::perform(
+                Self::save_file(content, FileInfo::Session, language),
+                Message::SessionSaved,
+            ),
+            Err(error) => {
+                self.show_error(format!("{}\n{error}", file_not_saved_translation(language)));
+                Command::none()
+            }
+        }
+    }
+
+    /// Returns the listener of the metrics endpoint, binding it only if its address changed
+    /// (the thread serving the previous capture could still be holding the port)
+    fn get_metrics_listener(&mut self, address: &str) -> std::io::Result<Arc<TcpListener>> {
+        if let Some((bound_address, listener)) = &self.metrics_listener {
+            if bound_address == address {
+                return Ok(listener.clone());
+            }
+        }
+        self.metrics_listener = None;
+        let listener = Arc::new(TcpListener::bind(address)?);
+        self.metrics_listener = Some((address.to_string(), listener.clone()));
+        Ok(listener)
+    }
+
+    /// Reads from the log the page of notifications to show
+    fn load_notifications_page(&mut self) {
+        let (records, results_number) = self
+            .notification_log
+            .read_page(&self.notification_filter, self.notifications_page_number);
+        self.notifications_page_number = self
+            .notifications_page_number
+            .clamp(1, results_number.div_ceil(20).max(1));
+        self.notification_records = records;
+        self.notifications_results_number = results_number;
+    }
+
+    fn export_notifications(&self, format: ExportFormat) -> Command<Message> {
+        let Some(log_path) = self.notification_log.path() else {
+            return Command::none();
+        };
+        let language = self.configs.lock().unwrap().settings.language;
+        let records = read_log(log_path, &self.notification_filter);
+        let file_info = match format {
+            ExportFormat::Csv => FileInfo::NotificationsCsv,
+            ExportFormat::Json => FileInfo::NotificationsJson,
+        };
+        Command::perform(
+            Self::save_file(export_records(&records, format), file_info, language),
+            Message::NotificationsExported,
+        )
+    }
+
+    fn pick_chart_path(&self, format: ImageFormat) -> Command<Message> {
+        let language = self.configs.lock().unwrap().settings.language;
+        let file_info = match format {
+            ImageFormat::Png => FileInfo::ChartPng,
+            ImageFormat::Svg => FileInfo::ChartSvg,
+        };
+        Command::perform(Self::pick_save_path(file_info, language), move |path| {
+            Message::SaveChart(format, path)
+        })
+    }
+
+    fn open_session(&mut self, path: String) {
+        if path.is_empty() || self.running_page.ne(&RunningPage::Init) {
+            return;
+        }
+        let ConfigSettings {
+            style, language, ..
+        } = self.configs.lock().unwrap().settings;
+        self.running_page = RunningPage::Overview;
+        *self.paused.lock().unwrap() = false;
+        match SessionSnapshot::from_file(Path::new(&path)) {
+            Ok(snapshot) => {
+                self.pcap_error = None;
+                *self.info_traffic.lock().unwrap() = snapshot.info_traffic;
+                *self.active_filters.lock().unwrap() = snapshot.filters;
+                self.runtime_data = snapshot.runtime_data;
+                self.traffic_chart = snapshot.traffic_chart;
+                self.traffic_chart.change_style(style);
+                self.traffic_chart.change_language(language);
+                self.opened_session = Some(OpenedSession {
+                    path,
+                    timestamp: snapshot.timestamp,
+                    adapters: snapshot.adapters,
+                });
+            }
+            Err(error) => {
+                *self.info_traffic.lock().unwrap() = InfoTraffic::new();
+                self.runtime_data = RunTimeData::new();
+                self.traffic_chart = TrafficChart::new(style, language);
+                self.pcap_error = Some(format!("{path}\n{error}"));
+                self.opened_session = Some(OpenedSession {
+                    path,
+                    timestamp: Local::now(),
+                    adapters: Vec::new(),
+                });
+            }
+        }
+    }
+
+    fn compare_session(&mut self, path: String) -> Command<Message> {
+        if path.is_empty() || self.running_page.eq(&RunningPage::Init) {
+            return Command::none();
+        }
+        self.baseline_session = Some(BaselineSession::from_file(path));
+        self.update(Message::ShowModal(MyModal::SessionComparison))
+    }
+
     // also called when backspace key is pressed on a running state
     fn reset_button_pressed(&mut self) -> Command<Message> {
         if self.running_page.ne(&RunningPage::Init) {
-            return if self.info_traffic.lock().unwrap().all_packets == 0
+            // quitting a reopened session doesn't lose any data
+            return if (self.info_traffic.lock().unwrap().all_packets == 0
+                || self.opened_session.is_some())
                 && self.settings_page.is_none()
             {
                 self.update(Message::Reset)
@@ -585,7 +1398,7 @@ async fn open_file(old_file: String, file_info: FileInfo, language: Language) ->
         };
         let picked_file = rfd::AsyncFileDialog::new()
             .set_title(file_info.action_info(language))
-            .add_filter(file_info.get_extension(), &[file_info.get_extension()])
+            .add_filter(file_info.get_extension(), file_info.get_open_extensions())
             .set_directory(starting_directory)
             .pick_file()
             .await
@@ -593,14 +1406,50 @@ async fn open_file(old_file: String, file_info: FileInfo, language: Language) ->
 
         picked_file.path().to_string_lossy().to_string()
     }
+
+    async fn save_file(
+        content: String,
+        file_info: FileInfo,
+        language: Language,
+    ) -> Result<(), String> {
+        let path = Self::pick_save_path(file_info, language).await;
+        if path.is_empty() {
+            return Ok(());
+        }
+        std::fs::write(&path, content)
+            .map_err(|error| format!("{}\n{path}: {error}", file_not_saved_translation(language)))
+    }
+
+    /// Shows a modal describing the error, in place of the one currently displayed (if any)
+    pub fn show_error(&mut self, error: String) {
+        self.modal = Some(MyModal::Error(error));
+    }
+
+    async fn pick_save_path(file_info: FileInfo, language: Language) -> String {
+        let picked_file = rfd::AsyncFileDialog::new()
+            .set_title(file_info.action_info(language))
+            .add_filter(file_info.get_extension(), &[file_info.get_extension()])
+            .set_directory(std::env::var("HOME").unwrap_or_default())
+            .set_file_name(format!(
+                "sniffnet_{}.{}",
+                Local::now().format("%Y%m%d_%H%M%S"),
+                file_info.get_extension()
+            ))
+            .save_file()
+            .await;
+
+        picked_file
+            .map(|file| file.path().to_string_lossy().to_string())
+            .unwrap_or_default()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     #![allow(unused_must_use)]
 
-    use std::collections::{HashSet, VecDeque};
-    use std::fs::remove_file;
+    use std::collections::{HashMap, HashSet, VecDeque};
+    use std::fs::{remove_dir_all, remove_file};
     use std::ops::Sub;
     use std::path::Path;
     use std::sync::{Arc, Mutex};
@@ -608,23 +1457,54 @@ mod tests {
 
     use serial_test::{parallel, serial};
 
+    use crate::api::types::api_request::ApiRequest;
+    use crate::blocklists::types::blocklist_match::BlocklistMatch;
+    use crate::chart::export_chart::ImageFormat;
+    use crate::chart::types::chart_entity::ChartEntity;
+    use crate::chart::types::chart_range::ChartRange;
+    use crate::configs::types::config_baseline::{ConfigBaseline, LEARNING_WINDOW};
+    use crate::configs::types::config_usage::ConfigUsage;
     use crate::countries::types::country::Country;
+    use crate::flow_export::types::flow_export_settings::FlowExportSettings;
+    use crate::flow_export::types::flow_protocol::FlowProtocol;
     use crate::gui::components::types::my_modal::MyModal;
     use crate::gui::pages::types::settings_page::SettingsPage;
     use crate::gui::styles::types::custom_palette::ExtraStyles;
     use crate::gui::styles::types::gradient_type::GradientType;
     use crate::gui::types::message::Message;
+    use crate::gui::types::session_snapshot::SessionSnapshot;
+    use crate::history::types::history_group::HistoryGroup;
+    use crate::history::types::history_query::HistoryQuery;
+    use crate::networking::types::address_port_pair::AddressPortPair;
+    use crate::networking::types::asn::Asn;
+    use crate::networking::types::data_info::DataInfo;
+    use crate::networking::types::data_info_host::DataInfoHost;
     use crate::networking::types::host::Host;
+    use crate::networking::types::icmp_type::{IcmpType, IcmpTypeV4};
+    use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
+    use crate::networking::types::scan_probe::{ProbeKind, ScanProbe};
+    use crate::networking::types::search_parameters::SearchParameters;
+    use crate::notifications::notification_log::{get_test_log_path, read_log};
+    use crate::notifications::types::alert_trigger::{AlertTrigger, TriggerMode};
     use crate::notifications::types::logged_notification::{
-        LoggedNotification, PacketsThresholdExceeded,
+        BlocklistMatched, LoggedNotification, PacketsThresholdExceeded, RuleThresholdExceeded,
+    };
+    use crate::notifications::types::notification_action::{NotificationAction, NotificationKind};
+    use crate::notifications::types::notification_filter::NotificationFilter;
+    use crate::notifications::types::notification_rule::{
+        NotificationRule, RuleDirection, RuleScope,
     };
     use crate::notifications::types::notifications::{
-        BytesNotification, FavoriteNotification, Notification, Notifications, PacketsNotification,
+        BlocklistNotification, BytesNotification, FavoriteNotification, FirstSeenNotification,
+        NewPortNotification, Notification, Notifications, PacketsNotification, QuotaNotification,
+        ScanNotification,
     };
     use crate::notifications::types::sound::Sound;
+    use crate::report::get_report_entries::get_searched_entries;
+    use crate::translations::translations_3::invalid_time_interval_translation;
     use crate::{
-        ByteMultiple, ChartType, ConfigDevice, ConfigSettings, ConfigWindow, Configs, IpVersion,
-        Language, Protocol, ReportSortType, RunningPage, Sniffer, StyleType,
+        AppProtocol, ByteMultiple, ChartType, ConfigDevice, ConfigSettings, ConfigWindow, Configs,
+        IpVersion, Language, Protocol, ReportSortType, RunningPage, Sniffer, StyleType,
     };
 
     // tests using this will require the #[parallel] annotation
@@ -632,12 +1512,17 @@ fn new_sniffer() -> Sniffer {
         Sniffer::new(
             &Arc::new(Mutex::new(Configs::default())),
             Arc::new(Mutex::new(None)),
+            Some(get_test_log_path()),
         )
     }
 
     // tests using this will require the #[serial] annotation
     fn new_sniffer_with_configs(configs: Configs) -> Sniffer {
-        Sniffer::new(&Arc::new(Mutex::new(configs)), Arc::new(Mutex::new(None)))
+        Sniffer::new(
+            &Arc::new(Mutex::new(configs)),
+            Arc::new(Mutex::new(None)),
+            Some(get_test_log_path()),
+        )
     }
 
     // helpful to clean up files generated from tests
@@ -660,6 +1545,22 @@ fn drop(&mut self) {
             if window_path.exists() {
                 remove_file(ConfigWindow::test_path()).unwrap();
             }
+
+            let baseline_path_str = ConfigBaseline::test_path();
+            let baseline_path = Path::new(&baseline_path_str);
+            if baseline_path.exists() {
+                remove_file(ConfigBaseline::test_path()).unwrap();
+            }
+
+            let usage_path_str = ConfigUsage::test_path();
+            let usage_path = Path::new(&usage_path_str);
+            if usage_path.exists() {
+                remove_file(ConfigUsage::test_path()).unwrap();
+            }
+
+            if let Some(log_dir) = self.notification_log.path().and_then(Path::parent) {
+                let _ = remove_dir_all(log_dir);
+            }
         }
     }
 
@@ -717,39 +1618,163 @@ fn test_correctly_update_chart_kind() {
 
     #[test]
     #[parallel] // needed to not collide with other tests generating configs files
-    fn test_correctly_update_report_kind() {
+    fn test_correctly_update_chart_range() {
         let mut sniffer = new_sniffer();
-
-        assert_eq!(sniffer.report_sort_type, ReportSortType::MostRecent);
-        sniffer.update(Message::ReportSortSelection(ReportSortType::MostBytes));
-        assert_eq!(sniffer.report_sort_type, ReportSortType::MostBytes);
-        sniffer.update(Message::ReportSortSelection(ReportSortType::MostPackets));
-        assert_eq!(sniffer.report_sort_type, ReportSortType::MostPackets);
-        sniffer.update(Message::ReportSortSelection(ReportSortType::MostPackets));
-        assert_eq!(sniffer.report_sort_type, ReportSortType::MostPackets);
-        sniffer.update(Message::ReportSortSelection(ReportSortType::MostRecent));
-        assert_eq!(sniffer.report_sort_type, ReportSortType::MostRecent);
+        sniffer.traffic_chart.ticks = 1_000;
+
+        assert_eq!(sniffer.traffic_chart.range, ChartRange::OneMinute);
+        assert_eq!(sniffer.traffic_chart.span, 60);
+        sniffer.update(Message::ChartRangeSelection(ChartRange::TenMinutes));
+        assert_eq!(sniffer.traffic_chart.range, ChartRange::TenMinutes);
+        assert_eq!(sniffer.traffic_chart.span, 600);
+        sniffer.update(Message::ChartZoom(true));
+        assert_eq!(sniffer.traffic_chart.span, 300);
+        sniffer.update(Message::ChartPan(120));
+        assert_eq!(sniffer.traffic_chart.offset, 120);
+        sniffer.update(Message::ChartPan(-200));
+        assert_eq!(sniffer.traffic_chart.offset, 0);
+        sniffer.update(Message::ChartZoom(false));
+        sniffer.update(Message::ChartZoom(false));
+        assert_eq!(sniffer.traffic_chart.span, 1_200);
+        sniffer.update(Message::ChartRangeSelection(ChartRange::OneMinute));
+        assert_eq!(sniffer.traffic_chart.span, 60);
     }
 
     #[test]
     #[parallel] // needed to not collide with other tests generating configs files
-    fn test_correctly_update_style() {
+    fn test_chart_entity() {
         let mut sniffer = new_sniffer();
+        let host = Host {
+            domain: "example.com".to_string(),
+            ..Host::default()
+        };
+        let mut info_traffic = sniffer.info_traffic.lock().unwrap();
+        info_traffic.tot_received_packets = 3;
+        info_traffic.hosts.insert(
+            host.clone(),
+            DataInfoHost {
+                data_info: DataInfo {
+                    incoming_packets: 3,
+                    incoming_bytes: 1_500,
+                    ..DataInfo::default()
+                },
+                ..DataInfoHost::default()
+            },
+        );
+        drop(info_traffic);
+        sniffer.update(Message::TickRun);
 
-        sniffer.update(Message::Style(StyleType::MonAmour));
+        // the history of single entities is shorter
+        sniffer.update(Message::ChartRangeSelection(ChartRange::OneHour));
+        sniffer.update(Message::SelectChartEntity(Some(ChartEntity::Host(
+            host.clone(),
+        ))));
         assert_eq!(
-            sniffer.configs.lock().unwrap().settings.style,
-            StyleType::MonAmour
+            sniffer.traffic_chart.selected,
+            Some(ChartEntity::Host(host.clone()))
         );
-        sniffer.update(Message::Style(StyleType::Day));
+        assert_eq!(sniffer.traffic_chart.range, ChartRange::TenMinutes);
+
+        let host_chart = sniffer.traffic_chart.entity_chart(&ChartEntity::Host(host));
         assert_eq!(
-            sniffer.configs.lock().unwrap().settings.style,
-            StyleType::Day
+            host_chart.seconds.received_bytes,
+            VecDeque::from([(0, 1_500)])
         );
-        sniffer.update(Message::Style(StyleType::Night));
-        assert_eq!(
-            sniffer.configs.lock().unwrap().settings.style,
-            StyleType::Night
+        assert_eq!(host_chart.span, 600);
+        assert!(host_chart.selected.is_none());
+        let app_chart = sniffer
+            .traffic_chart
+            .entity_chart(&ChartEntity::App(AppProtocol::HTTPS));
+        assert_eq!(app_chart.seconds.received_bytes, VecDeque::from([(0, 0)]));
+
+        sniffer.update(Message::SelectChartEntity(None));
+        assert!(sniffer.traffic_chart.selected.is_none());
+        assert_eq!(sniffer.traffic_chart.range, ChartRange::TenMinutes);
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_get_metrics_listener() {
+        let mut sniffer = new_sniffer();
+        let listener = sniffer.get_metrics_listener("127.0.0.1:0").unwrap();
+        // the following captures share the listener instead of binding the address again
+        let shared = sniffer.get_metrics_listener("127.0.0.1:0").unwrap();
+        assert!(Arc::ptr_eq(&listener, &shared));
+        drop(shared);
+
+        // the address changed
+        let other = sniffer.get_metrics_listener("localhost:0").unwrap();
+        assert!(!Arc::ptr_eq(&listener, &other));
+        assert_eq!(Arc::strong_count(&listener), 1);
+
+        // the address is in use
+        let address = listener.local_addr().unwrap().to_string();
+        assert!(sniffer.get_metrics_listener(&address).is_err());
+        assert!(sniffer.metrics_listener.is_none());
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_save_chart() {
+        let mut sniffer = new_sniffer();
+        sniffer.info_traffic.lock().unwrap().tot_received_packets = 1;
+        sniffer.update(Message::TickRun);
+
+        let path = format!("{}/test_save_chart.svg", env!("CARGO_MANIFEST_DIR"));
+        sniffer.update(Message::SaveChart(ImageFormat::Svg, path.clone()));
+        let svg = std::fs::read_to_string(&path).unwrap_or_default();
+        remove_file(&path);
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(sniffer.modal, None);
+
+        // the file dialog was dismissed
+        sniffer.update(Message::SaveChart(ImageFormat::Png, String::new()));
+        assert_eq!(sniffer.modal, None);
+
+        // the image can't be written
+        let path = format!("{}/missing_dir/chart.svg", env!("CARGO_MANIFEST_DIR"));
+        sniffer.update(Message::SaveChart(ImageFormat::Svg, path.clone()));
+        assert!(matches!(
+            &sniffer.modal,
+            Some(MyModal::Error(error)) if error.contains(&path)
+        ));
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_correctly_update_report_kind() {
+        let mut sniffer = new_sniffer();
+
+        assert_eq!(sniffer.report_sort_type, ReportSortType::MostRecent);
+        sniffer.update(Message::ReportSortSelection(ReportSortType::MostBytes));
+        assert_eq!(sniffer.report_sort_type, ReportSortType::MostBytes);
+        sniffer.update(Message::ReportSortSelection(ReportSortType::MostPackets));
+        assert_eq!(sniffer.report_sort_type, ReportSortType::MostPackets);
+        sniffer.update(Message::ReportSortSelection(ReportSortType::MostPackets));
+        assert_eq!(sniffer.report_sort_type, ReportSortType::MostPackets);
+        sniffer.update(Message::ReportSortSelection(ReportSortType::MostRecent));
+        assert_eq!(sniffer.report_sort_type, ReportSortType::MostRecent);
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_correctly_update_style() {
+        let mut sniffer = new_sniffer();
+
+        sniffer.update(Message::Style(StyleType::MonAmour));
+        assert_eq!(
+            sniffer.configs.lock().unwrap().settings.style,
+            StyleType::MonAmour
+        );
+        sniffer.update(Message::Style(StyleType::Day));
+        assert_eq!(
+            sniffer.configs.lock().unwrap().settings.style,
+            StyleType::Day
+        );
+        sniffer.update(Message::Style(StyleType::Night));
+        assert_eq!(
+            sniffer.configs.lock().unwrap().settings.style,
+            StyleType::Night
         );
         sniffer.update(Message::Style(StyleType::DeepSea));
         assert_eq!(
@@ -1394,9 +2419,13 @@ fn test_clear_all_notifications() {
                     threshold: 0,
                     incoming: 0,
                     outgoing: 0,
-                    timestamp: "".to_string(),
+                    timestamp: chrono::Local::now(),
                 },
             )]);
+        let logged = Vec::from(sniffer.runtime_data.logged_notifications.clone());
+        sniffer.notification_log.append(&logged).unwrap();
+        sniffer.update(Message::ChangeRunningPage(RunningPage::Notifications));
+        assert_eq!(sniffer.notification_records.len(), 1);
 
         assert_eq!(sniffer.modal, None);
         sniffer.update(Message::ShowModal(MyModal::ClearAll));
@@ -1405,6 +2434,801 @@ fn test_clear_all_notifications() {
         sniffer.update(Message::ClearAllNotifications);
         assert_eq!(sniffer.modal, None);
         assert_eq!(sniffer.runtime_data.logged_notifications.len(), 0);
+        assert!(sniffer.notification_records.is_empty());
+        assert_eq!(sniffer.notifications_results_number, 0);
+        // the log history is kept
+        let log_path = sniffer.notification_log.path().unwrap();
+        assert_eq!(read_log(log_path, &NotificationFilter::default()).len(), 1);
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_notifications_forwarded_to_events() {
+        let mut sniffer = new_sniffer();
+        sniffer.running_page = RunningPage::Overview;
+        sniffer
+            .configs
+            .lock()
+            .unwrap()
+            .settings
+            .notifications
+            .packets_notification = PacketsNotification {
+            threshold: Some(3),
+            sound: Sound::None,
+            previous_threshold: 3,
+        };
+        let (events_sender, events_receiver) = std::sync::mpsc::channel();
+        sniffer.events_sender = Some(events_sender);
+        sniffer.info_traffic.lock().unwrap().tot_received_packets = 5;
+
+        sniffer.update(Message::TickRun);
+        assert_eq!(sniffer.runtime_data.logged_notifications.len(), 1);
+        let forwarded: Vec<LoggedNotification> = events_receiver.try_iter().collect();
+        assert!(matches!(
+            forwarded.as_slice(),
+            [LoggedNotification::PacketsThresholdExceeded(_)]
+        ));
+
+        // notifications aren't lost even if more than the ones kept in memory are emitted at once
+        sniffer
+            .info_traffic
+            .lock()
+            .unwrap()
+            .blocklisted_last_interval = (0..40)
+            .map(|i| BlocklistMatch {
+                list: "list.txt".to_string(),
+                entry: format!("203.0.113.{i}"),
+                address: format!("203.0.113.{i}"),
+                name: None,
+            })
+            .collect();
+        sniffer.info_traffic.lock().unwrap().tot_received_packets = 6;
+        sniffer.update(Message::TickRun);
+        assert_eq!(sniffer.runtime_data.logged_notifications.len(), 30);
+        assert_eq!(sniffer.runtime_data.tot_emitted_notifications, 42);
+        let forwarded: Vec<LoggedNotification> = events_receiver.try_iter().collect();
+        assert_eq!(forwarded.len(), 41);
+        assert!(matches!(
+            forwarded.first(),
+            Some(LoggedNotification::BlocklistMatched(BlocklistMatched { blocklist_match, .. }))
+                if blocklist_match.address == "203.0.113.0"
+        ));
+
+        // the stream of events ends with the capture
+        sniffer.update(Message::Reset);
+        assert!(sniffer.events_sender.is_none());
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_notification_rules() {
+        let mut sniffer = new_sniffer();
+        let rules = |sniffer: &Sniffer| {
+            sniffer
+                .configs
+                .lock()
+                .unwrap()
+                .settings
+                .notification_rules
+                .clone()
+        };
+
+        // invalid rules are not added
+        sniffer.update(Message::UpdateRuleDraft(NotificationRule {
+            scope: RuleScope::Port,
+            value: "telnet".to_string(),
+            ..NotificationRule::default()
+        }));
+        sniffer.update(Message::AddNotificationRule);
+        assert!(rules(&sniffer).is_empty());
+
+        let rule = NotificationRule {
+            scope: RuleScope::Port,
+            value: "23".to_string(),
+            direction: RuleDirection::Incoming,
+            threshold: 150,
+            sound: Sound::None,
+            ..NotificationRule::default()
+        };
+        sniffer.update(Message::UpdateRuleDraft(rule.clone()));
+        sniffer.update(Message::AddNotificationRule);
+        assert_eq!(rules(&sniffer), vec![rule.clone()]);
+        assert!(sniffer.rule_draft.value.is_empty());
+
+        // the rule is evaluated starting from the interval after it was added
+        sniffer.running_page = RunningPage::Overview;
+        let key = AddressPortPair::new(
+            "10.0.0.2".to_string(),
+            Some(23),
+            "192.168.1.2".to_string(),
+            Some(50000),
+            Protocol::TCP,
+            "eth0".to_string(),
+        );
+        let mut info_traffic = sniffer.info_traffic.lock().unwrap();
+        info_traffic.tot_received_packets = 1;
+        info_traffic.map.insert(
+            key.clone(),
+            InfoAddressPortPair {
+                transmitted_bytes: 1000,
+                transmitted_packets: 1,
+                ..InfoAddressPortPair::default()
+            },
+        );
+        drop(info_traffic);
+        sniffer.update(Message::TickRun);
+        assert!(sniffer.runtime_data.logged_notifications.is_empty());
+
+        let mut info_traffic = sniffer.info_traffic.lock().unwrap();
+        info_traffic.tot_received_packets = 2;
+        info_traffic.map.get_mut(&key).unwrap().transmitted_bytes = 1200;
+        drop(info_traffic);
+        sniffer.update(Message::TickRun);
+        assert!(matches!(
+            sniffer.runtime_data.logged_notifications.front(),
+            Some(LoggedNotification::RuleThresholdExceeded(
+                RuleThresholdExceeded {
+                    incoming: 200,
+                    outgoing: 0,
+                    ..
+                }
+            ))
+        ));
+
+        sniffer.update(Message::RemoveNotificationRule(0));
+        assert!(rules(&sniffer).is_empty());
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_notification_filter() {
+        let mut sniffer = new_sniffer();
+        let packets = LoggedNotification::PacketsThresholdExceeded(PacketsThresholdExceeded {
+            threshold: 10,
+            incoming: 25,
+            outgoing: 5,
+            timestamp: chrono::Local::now(),
+        });
+
+        assert_eq!(sniffer.notification_filter, NotificationFilter::default());
+        assert!(!sniffer.notification_filter.is_active());
+        assert!(sniffer.notification_filter.matches(&packets));
+
+        sniffer.update(Message::UpdateNotificationFilter(NotificationFilter {
+            search: "25 INCOMING".to_string(),
+            kind: NotificationKind::All,
+        }));
+        assert!(sniffer.notification_filter.is_active());
+        assert!(sniffer.notification_filter.matches(&packets));
+
+        sniffer.update(Message::UpdateNotificationFilter(NotificationFilter {
+            kind: NotificationKind::Bytes,
+            ..sniffer.notification_filter.clone()
+        }));
+        assert!(!sniffer.notification_filter.matches(&packets));
+
+        sniffer.update(Message::UpdateNotificationFilter(
+            NotificationFilter::default(),
+        ));
+        assert!(!sniffer.notification_filter.is_active());
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_notification_actions() {
+        let mut sniffer = new_sniffer();
+        let actions = |sniffer: &Sniffer| {
+            sniffer
+                .configs
+                .lock()
+                .unwrap()
+                .settings
+                .notification_actions
+                .clone()
+        };
+
+        // invalid actions are not added
+        sniffer.update(Message::UpdateActionDraft(NotificationAction {
+            webhook: "example.com/hook".to_string(),
+            ..NotificationAction::default()
+        }));
+        sniffer.update(Message::AddNotificationAction);
+        assert!(actions(&sniffer).is_empty());
+
+        sniffer.update(Message::UpdateActionDraft(NotificationAction {
+            kind: NotificationKind::Favorite,
+            command: " notify-send Sniffnet ".to_string(),
+            webhook: String::new(),
+        }));
+        sniffer.update(Message::AddNotificationAction);
+        // duplicates are ignored
+        sniffer.update(Message::UpdateActionDraft(NotificationAction {
+            kind: NotificationKind::Favorite,
+            command: "notify-send Sniffnet".to_string(),
+            webhook: String::new(),
+        }));
+        sniffer.update(Message::AddNotificationAction);
+        assert_eq!(
+            actions(&sniffer),
+            vec![NotificationAction {
+                kind: NotificationKind::Favorite,
+                command: "notify-send Sniffnet".to_string(),
+                webhook: String::new(),
+            }]
+        );
+        assert_eq!(sniffer.action_draft, NotificationAction::default());
+
+        sniffer.update(Message::RemoveNotificationAction(1));
+        assert_eq!(actions(&sniffer).len(), 1);
+        sniffer.update(Message::RemoveNotificationAction(0));
+        assert!(actions(&sniffer).is_empty());
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_blocklists() {
+        let mut sniffer = new_sniffer();
+        let path = format!("{}/test_sniffer_blocklist.txt", env!("CARGO_MANIFEST_DIR"));
+        std::fs::write(&path, "203.0.113.0/24\n").unwrap();
+        sniffer
+            .info_traffic
+            .lock()
+            .unwrap()
+            .addresses_waiting_resolution
+            .insert("203.0.113.7".to_string(), DataInfo::default());
+
+        // addresses already seen are checked as soon as the list is added
+        sniffer.update(Message::AddBlocklist(path.clone()));
+        sniffer.update(Message::AddBlocklist(path.clone()));
+        assert_eq!(
+            sniffer.configs.lock().unwrap().settings.blocklists,
+            vec![path.clone()]
+        );
+        assert_eq!(
+            sniffer.info_traffic.lock().unwrap().blocklisted["203.0.113.7"].entry,
+            "203.0.113.0/24"
+        );
+
+        sniffer.running_page = RunningPage::Overview;
+        sniffer.info_traffic.lock().unwrap().tot_received_packets = 1;
+        sniffer.update(Message::TickRun);
+        assert!(matches!(
+            sniffer.runtime_data.logged_notifications.front(),
+            Some(LoggedNotification::BlocklistMatched(_))
+        ));
+
+        sniffer.update(Message::RemoveBlocklist(0));
+        assert!(sniffer
+            .configs
+            .lock()
+            .unwrap()
+            .settings
+            .blocklists
+            .is_empty());
+        assert!(sniffer.blocklists.lock().unwrap().lists.is_empty());
+        // matches of the removed list don't stay marked
+        assert!(sniffer.info_traffic.lock().unwrap().blocklisted.is_empty());
+        remove_file(&path).unwrap();
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_scan_notification() {
+        let mut sniffer = new_sniffer();
+        sniffer.running_page = RunningPage::Overview;
+        let probe_time = chrono::Local::now() - chrono::Duration::seconds(10);
+        let mut info_traffic = sniffer.info_traffic.lock().unwrap();
+        info_traffic.tot_received_packets = 1;
+        for port in 1..=30 {
+            info_traffic.scan_detector.record(
+                ScanProbe {
+                    kind: ProbeKind::Attempt,
+                    offender: "10.0.0.9".to_string(),
+                    target: "10.0.0.2".to_string(),
+                    port,
+                    protocol: Protocol::TCP,
+                },
+                probe_time,
+            );
+        }
+        drop(info_traffic);
+        sniffer.update(Message::TickRun);
+        assert!(matches!(
+            sniffer.runtime_data.logged_notifications.front(),
+            Some(LoggedNotification::ScanDetected(_))
+        ));
+
+        // disabled notifications are not logged
+        sniffer.update(Message::ClearAllNotifications);
+        sniffer.update(Message::UpdateNotificationSettings(
+            Notification::Scan(ScanNotification::off(Sound::Gulp)),
+            false,
+        ));
+        let mut info_traffic = sniffer.info_traffic.lock().unwrap();
+        for port in 1..=30 {
+            info_traffic.scan_detector.record(
+                ScanProbe {
+                    kind: ProbeKind::Attempt,
+                    offender: "10.0.0.10".to_string(),
+                    target: "10.0.0.2".to_string(),
+                    port,
+                    protocol: Protocol::TCP,
+                },
+                probe_time,
+            );
+        }
+        drop(info_traffic);
+        sniffer.update(Message::TickRun);
+        assert!(sniffer.runtime_data.logged_notifications.is_empty());
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_first_seen_learning() {
+        let mut sniffer = new_sniffer();
+        sniffer.running_page = RunningPage::Overview;
+        sniffer.update(Message::UpdateNotificationSettings(
+            Notification::FirstSeen(FirstSeenNotification::on(Sound::None)),
+            false,
+        ));
+        let add_host = |sniffer: &mut Sniffer, domain: &str, asn: u32, country: Country| {
+            let mut info_traffic = sniffer.info_traffic.lock().unwrap();
+            info_traffic.tot_received_packets += 1;
+            info_traffic.hosts.insert(
+                Host {
+                    domain: domain.to_string(),
+                    asn: Asn {
+                        number: asn,
+                        name: String::new(),
+                    },
+                    country,
+                },
+                DataInfoHost::default(),
+            );
+        };
+
+        // targets are learned silently during the learning window
+        add_host(&mut sniffer, "first.com", 15169, Country::US);
+        sniffer.update(Message::TickRun);
+        assert!(sniffer.runtime_data.logged_notifications.is_empty());
+        assert!(sniffer
+            .configs
+            .lock()
+            .unwrap()
+            .baseline
+            .hosts
+            .contains("first.com"));
+        assert_eq!(sniffer.configs.lock().unwrap().baseline.learning_time, 1);
+
+        // and notified once it's over
+        sniffer.configs.lock().unwrap().baseline.learning_time = LEARNING_WINDOW;
+        add_host(&mut sniffer, "second.it", 15169, Country::IT);
+        sniffer.update(Message::TickRun);
+        assert_eq!(sniffer.runtime_data.logged_notifications.len(), 2);
+        assert!(sniffer
+            .runtime_data
+            .logged_notifications
+            .iter()
+            .all(|notification| matches!(notification, LoggedNotification::FirstSeen(_))));
+
+        // resetting the baseline restarts the learning
+        sniffer.update(Message::ResetBaseline);
+        assert_eq!(
+            sniffer.configs.lock().unwrap().baseline,
+            ConfigBaseline::default()
+        );
+        sniffer.update(Message::TickRun);
+        assert_eq!(sniffer.runtime_data.logged_notifications.len(), 2);
+        assert_eq!(sniffer.configs.lock().unwrap().baseline.hosts.len(), 2);
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_quota_notification() {
+        let mut sniffer = new_sniffer();
+        sniffer.running_page = RunningPage::Overview;
+        sniffer.device.name = "wwan0".to_string();
+        sniffer.update(Message::UpdateNotificationSettings(
+            Notification::Quota(QuotaNotification::from("1K", None)),
+            false,
+        ));
+        let mut info_traffic = sniffer.info_traffic.lock().unwrap();
+        info_traffic.tot_received_packets = 2;
+        info_traffic.adapters.insert(
+            "wwan0".to_string(),
+            DataInfo {
+                incoming_packets: 1,
+                outgoing_packets: 1,
+                incoming_bytes: 600,
+                outgoing_bytes: 250,
+            },
+        );
+        drop(info_traffic);
+        sniffer.update(Message::TickRun);
+        let today = chrono::Local::now().date_naive();
+        let usage = sniffer
+            .configs
+            .lock()
+            .unwrap()
+            .usage
+            .get_usage("wwan0", 1, today);
+        assert_eq!(usage.received_bytes, 600);
+        assert_eq!(usage.sent_bytes, 250);
+        assert!(matches!(
+            sniffer.runtime_data.logged_notifications.front(),
+            Some(LoggedNotification::QuotaReached(quota_reached)) if quota_reached.level == 80
+        ));
+
+        // the data already counted is not added again, and levels are notified only once
+        sniffer.update(Message::TickRun);
+        assert_eq!(sniffer.runtime_data.logged_notifications.len(), 1);
+        assert_eq!(
+            sniffer
+                .configs
+                .lock()
+                .unwrap()
+                .usage
+                .get_usage("wwan0", 1, today)
+                .tot_bytes(),
+            850
+        );
+
+        sniffer
+            .info_traffic
+            .lock()
+            .unwrap()
+            .adapters
+            .get_mut("wwan0")
+            .unwrap()
+            .incoming_bytes = 800;
+        sniffer.update(Message::TickRun);
+        assert_eq!(sniffer.runtime_data.logged_notifications.len(), 2);
+        assert!(matches!(
+            sniffer.runtime_data.logged_notifications.front(),
+            Some(LoggedNotification::QuotaReached(quota_reached)) if quota_reached.level == 100
+        ));
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_usage_without_filtered_packets() {
+        let mut sniffer = new_sniffer();
+        sniffer.device.name = "wwan0".to_string();
+        *sniffer.paused.lock().unwrap() = true;
+        // no packet passed the filters, but the adapter exchanged some data
+        sniffer.info_traffic.lock().unwrap().adapters.insert(
+            "wwan0".to_string(),
+            DataInfo {
+                incoming_packets: 3,
+                outgoing_packets: 1,
+                incoming_bytes: 900,
+                outgoing_bytes: 100,
+            },
+        );
+        sniffer.update(Message::TickRun);
+        let today = chrono::Local::now().date_naive();
+        let usage = sniffer
+            .configs
+            .lock()
+            .unwrap()
+            .usage
+            .get_usage("wwan0", 1, today);
+        assert_eq!(usage.received_bytes, 900);
+        assert_eq!(usage.sent_bytes, 100);
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_toggle_history_and_invalid_history_query() {
+        let mut sniffer = new_sniffer();
+        assert!(!sniffer.configs.lock().unwrap().settings.history);
+
+        sniffer.update(Message::ToggleHistory(true));
+        sniffer.update(Message::ChangeHistoryRetention(90));
+        assert!(sniffer.configs.lock().unwrap().settings.history);
+        assert_eq!(
+            sniffer.configs.lock().unwrap().settings.history_retention,
+            90
+        );
+
+        // the settings can't be changed while a capture is running
+        sniffer.running_page = RunningPage::Overview;
+        sniffer.update(Message::ToggleHistory(false));
+        sniffer.update(Message::ChangeHistoryRetention(1));
+        assert!(sniffer.configs.lock().unwrap().settings.history);
+        assert_eq!(
+            sniffer.configs.lock().unwrap().settings.history_retention,
+            90
+        );
+
+        // the history database is not even opened when the time interval is invalid
+        sniffer.update(Message::UpdateHistoryQuery(HistoryQuery {
+            from: "2023-12-01 15:00".to_string(),
+            to: "2023-12-01 14:00".to_string(),
+            group: HistoryGroup::Apps,
+            local_address: String::new(),
+        }));
+        assert!(sniffer.history_results.is_none());
+        sniffer.update(Message::SearchHistory);
+        assert!(matches!(
+            sniffer.history_results,
+            Some(Err(ref error)) if error == invalid_time_interval_translation(Language::EN)
+        ));
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_apply_filters_while_running() {
+        let mut sniffer = new_sniffer();
+        *sniffer.active_filters.lock().unwrap() = sniffer.filters.clone();
+        // selections made in the initial page are discarded when opening the modal
+        sniffer.update(Message::ProtocolSelection(Protocol::UDP, false));
+
+        sniffer.update(Message::ShowModal(MyModal::EditFilters));
+        assert_eq!(sniffer.modal, Some(MyModal::EditFilters));
+        assert_eq!(sniffer.filters.protocols, HashSet::from(Protocol::ALL));
+        sniffer.update(Message::ProtocolSelection(Protocol::UDP, false));
+        sniffer.update(Message::ProtocolSelection(Protocol::TCP, false));
+        // not applied yet
+        assert_eq!(
+            sniffer.active_filters.lock().unwrap().protocols,
+            HashSet::from(Protocol::ALL)
+        );
+        assert_eq!(sniffer.runtime_data.last_filters_change, None);
+
+        sniffer.update(Message::ApplyFilters);
+        assert_eq!(sniffer.modal, None);
+        assert_eq!(
+            sniffer.active_filters.lock().unwrap().protocols,
+            HashSet::from([Protocol::ICMP])
+        );
+        assert!(sniffer.runtime_data.last_filters_change.is_some());
+
+        // invalid filters are not applied
+        sniffer.update(Message::ShowModal(MyModal::EditFilters));
+        sniffer.update(Message::ProtocolSelection(Protocol::ICMP, false));
+        sniffer.update(Message::ApplyFilters);
+        assert_eq!(sniffer.modal, Some(MyModal::EditFilters));
+        assert_eq!(
+            sniffer.active_filters.lock().unwrap().protocols,
+            HashSet::from([Protocol::ICMP])
+        );
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_search_by_interface() {
+        let mut sniffer = new_sniffer();
+        for (i, interface) in ["eth0", "tun0", "docker0"].iter().enumerate() {
+            let key = AddressPortPair::new(
+                format!("10.0.0.{i}"),
+                Some(443),
+                "1.1.1.1".to_string(),
+                Some(55555),
+                Protocol::TCP,
+                (*interface).to_string(),
+            );
+            let info = InfoAddressPortPair {
+                interface: (*interface).to_string(),
+                ..InfoAddressPortPair::default()
+            };
+            sniffer.info_traffic.lock().unwrap().map.insert(key, info);
+        }
+
+        assert_eq!(get_searched_entries(&sniffer).1, 3);
+        sniffer.update(Message::Search(SearchParameters {
+            interface: "TUN".to_string(),
+            ..SearchParameters::default()
+        }));
+        let (entries, results_number) = get_searched_entries(&sniffer);
+        assert_eq!(results_number, 1);
+        assert_eq!(entries[0].val.interface, "tun0");
+        sniffer.update(Message::Search(SearchParameters {
+            interface: "0".to_string(),
+            ..SearchParameters::default()
+        }));
+        assert_eq!(get_searched_entries(&sniffer).1, 3);
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_file_not_saved_is_reported() {
+        let mut sniffer = new_sniffer();
+        sniffer.update(Message::SessionSaved(Ok(())));
+        assert_eq!(sniffer.modal, None);
+
+        // the error replaces any modal currently displayed
+        sniffer.modal = Some(MyModal::History);
+        sniffer.update(Message::SessionSaved(Err("permission denied".to_string())));
+        assert_eq!(
+            sniffer.modal,
+            Some(MyModal::Error("permission denied".to_string()))
+        );
+
+        sniffer.update(Message::HideModal);
+        sniffer.update(Message::NotificationsExported(Err("disk full".to_string())));
+        assert_eq!(sniffer.modal, Some(MyModal::Error("disk full".to_string())));
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_save_and_open_session() {
+        let mut sniffer = new_sniffer();
+        sniffer.running_page = RunningPage::Overview;
+        let key = AddressPortPair::new(
+            "10.0.0.1".to_string(),
+            None,
+            "1.1.1.1".to_string(),
+            None,
+            Protocol::ICMP,
+            "eth0".to_string(),
+        );
+        let info = InfoAddressPortPair {
+            transmitted_packets: 3,
+            transmitted_bytes: 250,
+            icmp_types: HashMap::from([(IcmpType::V4(IcmpTypeV4::EchoReply), 3)]),
+            interface: "eth0".to_string(),
+            ..InfoAddressPortPair::default()
+        };
+        sniffer
+            .info_traffic
+            .lock()
+            .unwrap()
+            .map
+            .insert(key.clone(), info);
+        sniffer.info_traffic.lock().unwrap().all_packets = 3;
+        sniffer.runtime_data.tot_received_packets = 3;
+        sniffer.runtime_data.tot_received_bytes = 250;
+        sniffer.traffic_chart.ticks = 5;
+        sniffer.traffic_chart.seconds.received_packets = VecDeque::from([(4, 3)]);
+
+        let path_string = format!("{}/test_session.ron", env!("CARGO_MANIFEST_DIR"));
+        let content = SessionSnapshot::new(&sniffer).to_ron().unwrap();
+        std::fs::write(&path_string, content).unwrap();
+
+        let mut reopened = new_sniffer();
+        reopened.update(Message::OpenSession(path_string.clone()));
+        remove_file(&path_string).unwrap();
+        assert_eq!(reopened.running_page, RunningPage::Overview);
+        assert_eq!(reopened.pcap_error, None);
+        assert_eq!(
+            reopened.opened_session.as_ref().unwrap().adapters,
+            vec![sniffer.device.name.clone()]
+        );
+        let info_traffic = reopened.info_traffic.lock().unwrap().clone();
+        let info = &info_traffic.map[&key];
+        assert_eq!(info.transmitted_bytes, 250);
+        assert_eq!(info.interface, "eth0");
+        assert_eq!(info.icmp_types[&IcmpType::V4(IcmpTypeV4::EchoReply)], 3);
+        assert_eq!(reopened.runtime_data.tot_received_bytes, 250);
+        assert_eq!(reopened.traffic_chart.ticks, 5);
+        assert_eq!(
+            reopened.traffic_chart.seconds.received_packets,
+            VecDeque::from([(4, 3)])
+        );
+
+        // the reopened session is read-only
+        reopened.update(Message::TickRun);
+        assert_eq!(reopened.traffic_chart.ticks, 5);
+        reopened.update(Message::PauseResume);
+        assert!(!*reopened.paused.lock().unwrap());
+
+        // quitting the session doesn't ask for confirmation
+        reopened.update(Message::ResetButtonPressed);
+        assert_eq!(reopened.running_page, RunningPage::Init);
+        assert!(reopened.opened_session.is_none());
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_open_invalid_session() {
+        let mut sniffer = new_sniffer();
+        let path_string = format!("{}/not_a_session.ron", env!("CARGO_MANIFEST_DIR"));
+        sniffer.update(Message::OpenSession(path_string));
+        assert_eq!(sniffer.running_page, RunningPage::Overview);
+        assert!(sniffer.pcap_error.is_some());
+        assert!(sniffer.opened_session.is_some());
+
+        // a dismissed file dialog has no effect
+        let mut sniffer = new_sniffer();
+        sniffer.update(Message::OpenSession(String::new()));
+        assert_eq!(sniffer.running_page, RunningPage::Init);
+        assert!(sniffer.opened_session.is_none());
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_handle_api_requests() {
+        let mut sniffer = new_sniffer();
+        sniffer.running_page = RunningPage::Overview;
+
+        // a capture is already running: the start request is discarded
+        sniffer
+            .api_state
+            .lock()
+            .unwrap()
+            .pending_requests
+            .push(ApiRequest::Start {
+                adapter: None,
+                filters: Box::default(),
+            });
+        sniffer.update(Message::TickRun);
+        let api_state = sniffer.api_state.lock().unwrap();
+        assert!(api_state.pending_requests.is_empty());
+        assert!(api_state.is_capture_running);
+        assert_eq!(api_state.adapters, vec![sniffer.device.name.clone()]);
+        drop(api_state);
+        assert_eq!(sniffer.running_page, RunningPage::Overview);
+
+        // the stop request brings back to the initial page
+        sniffer
+            .api_state
+            .lock()
+            .unwrap()
+            .pending_requests
+            .push(ApiRequest::Stop);
+        sniffer.update(Message::TickRun);
+        assert_eq!(sniffer.running_page, RunningPage::Init);
+        let api_state = sniffer.api_state.lock().unwrap();
+        assert!(api_state.pending_requests.is_empty());
+        assert!(!api_state.is_capture_running);
+        assert!(api_state.adapters.is_empty());
+        drop(api_state);
+
+        // nothing is running: the stop request is discarded
+        sniffer
+            .api_state
+            .lock()
+            .unwrap()
+            .pending_requests
+            .push(ApiRequest::Stop);
+        sniffer.update(Message::TickInit);
+        assert_eq!(sniffer.running_page, RunningPage::Init);
+        assert!(sniffer
+            .api_state
+            .lock()
+            .unwrap()
+            .pending_requests
+            .is_empty());
+    }
+
+    #[test]
+    #[parallel] // needed to not collide with other tests generating configs files
+    fn test_compare_session() {
+        let mut sniffer = new_sniffer();
+        let path_string = format!("{}/test_baseline_session.ron", env!("CARGO_MANIFEST_DIR"));
+        let content = SessionSnapshot::new(&sniffer).to_ron().unwrap();
+        std::fs::write(&path_string, content).unwrap();
+
+        // no comparison is possible before starting an analysis
+        sniffer.update(Message::CompareSession(path_string.clone()));
+        assert!(sniffer.baseline_session.is_none());
+        assert_eq!(sniffer.modal, None);
+
+        sniffer.running_page = RunningPage::Overview;
+        sniffer.update(Message::CompareSession(String::new()));
+        assert!(sniffer.baseline_session.is_none());
+
+        sniffer.update(Message::CompareSession(path_string.clone()));
+        remove_file(&path_string).unwrap();
+        assert_eq!(sniffer.modal, Some(MyModal::SessionComparison));
+        let baseline = sniffer.baseline_session.as_ref().unwrap();
+        assert_eq!(baseline.path, path_string);
+        assert!(baseline.info_traffic.is_ok());
+
+        // a file which isn't a session snapshot is reported
+        sniffer.update(Message::HideModal);
+        sniffer.update(Message::CompareSession(path_string));
+        assert_eq!(sniffer.modal, Some(MyModal::SessionComparison));
+        assert!(sniffer
+            .baseline_session
+            .as_ref()
+            .unwrap()
+            .info_traffic
+            .is_err());
+
+        sniffer.update(Message::Reset);
+        assert!(sniffer.baseline_session.is_none());
     }
 
     #[test]
@@ -1488,13 +3312,33 @@ fn test_config_settings() {
                 scale_factor: 1.0,
                 mmdb_country: "".to_string(),
                 mmdb_asn: "".to_string(),
+                oui_db: "".to_string(),
+                history: false,
+                history_retention: 30,
+                metrics: false,
+                metrics_address: "127.0.0.1:9284".to_string(),
+                api: false,
+                api_address: "127.0.0.1:9285".to_string(),
+                api_token: String::new(),
+                events: false,
+                events_output: "-".to_string(),
                 style_path: "".to_string(),
                 notifications: Notifications {
                     volume: 60,
                     packets_notification: Default::default(),
                     bytes_notification: Default::default(),
-                    favorite_notification: Default::default()
+                    favorite_notification: Default::default(),
+                    first_seen_notification: Default::default(),
+                    new_port_notification: Default::default(),
+                    blocklist_notification: Default::default(),
+                    scan_notification: Default::default(),
+                    quota_notification: Default::default(),
+                    trigger: Default::default()
                 },
+                notification_rules: Vec::new(),
+                notification_actions: Vec::new(),
+                blocklists: Vec::new(),
+                flow_export: FlowExportSettings::default(),
                 style: StyleType::Night
             }
         );
@@ -1505,6 +3349,56 @@ fn test_config_settings() {
         sniffer.update(Message::ChangeScaleFactor(0.65));
         sniffer.update(Message::CustomCountryDb("countrymmdb".to_string()));
         sniffer.update(Message::CustomAsnDb("asnmmdb".to_string()));
+        sniffer.update(Message::CustomOuiDb("ouidb".to_string()));
+        sniffer.update(Message::ToggleHistory(true));
+        sniffer.update(Message::ChangeHistoryRetention(7));
+        sniffer.update(Message::ToggleMetrics(true));
+        sniffer.update(Message::MetricsAddress("0.0.0.0:9000".to_string()));
+        sniffer.update(Message::ToggleApi(true));
+        sniffer.update(Message::ApiAddress("0.0.0.0:9001".to_string()));
+        sniffer.update(Message::ToggleEvents(true));
+        sniffer.update(Message::EventsOutput("/tmp/events.jsonl".to_string()));
+        sniffer.update(Message::UpdateAlertTrigger(AlertTrigger {
+            mode: TriggerMode::Average,
+            window: 5,
+            cooldown: 60,
+        }));
+        sniffer.update(Message::UpdateNotificationSettings(
+            Notification::FirstSeen(FirstSeenNotification::on(Sound::Swhoosh)),
+            false,
+        ));
+        sniffer.update(Message::UpdateNotificationSettings(
+            Notification::NewPort(NewPortNotification::on(Sound::None)),
+            false,
+        ));
+        sniffer.update(Message::UpdateNotificationSettings(
+            Notification::Blocklist(BlocklistNotification::off(Sound::Pop)),
+            false,
+        ));
+        sniffer.update(Message::UpdateNotificationSettings(
+            Notification::Scan(ScanNotification::on(Sound::Swhoosh)),
+            false,
+        ));
+        sniffer.update(Message::UpdateNotificationSettings(
+            Notification::Quota(QuotaNotification::from("2G", None).with_cycle_start_day("15")),
+            false,
+        ));
+        sniffer.update(Message::AddBlocklist("firehol_level1.netset".to_string()));
+        sniffer.update(Message::UpdateRuleDraft(NotificationRule {
+            scope: RuleScope::App,
+            value: " Telnet ".to_string(),
+            ..NotificationRule::default()
+        }));
+        sniffer.update(Message::AddNotificationRule);
+        sniffer.update(Message::UpdateActionDraft(NotificationAction {
+            kind: NotificationKind::Scan,
+            webhook: "http://127.0.0.1:8080/alerts ".to_string(),
+            ..NotificationAction::default()
+        }));
+        sniffer.update(Message::AddNotificationAction);
+        sniffer.update(Message::ToggleFlowExport(true));
+        sniffer.update(Message::FlowCollector("10.0.0.1:4739".to_string()));
+        sniffer.update(Message::FlowProtocolSelection(FlowProtocol::Ipfix));
         sniffer.update(Message::LoadStyle(format!(
             "{}/resources/themes/catppuccin.toml",
             env!("CARGO_MANIFEST_DIR")
@@ -1532,6 +3426,16 @@ fn test_config_settings() {
                 scale_factor: 0.65,
                 mmdb_country: "countrymmdb".to_string(),
                 mmdb_asn: "asnmmdb".to_string(),
+                oui_db: "ouidb".to_string(),
+                history: true,
+                history_retention: 7,
+                metrics: true,
+                metrics_address: "0.0.0.0:9000".to_string(),
+                api: true,
+                api_address: "0.0.0.0:9001".to_string(),
+                api_token: String::new(),
+                events: true,
+                events_output: "/tmp/events.jsonl".to_string(),
                 style_path: format!(
                     "{}/resources/themes/catppuccin.toml",
                     env!("CARGO_MANIFEST_DIR")
@@ -1540,7 +3444,39 @@ fn test_config_settings() {
                     volume: 100,
                     packets_notification: Default::default(),
                     bytes_notification: Default::default(),
-                    favorite_notification: Default::default()
+                    favorite_notification: Default::default(),
+                    first_seen_notification: FirstSeenNotification::on(Sound::Swhoosh),
+                    new_port_notification: NewPortNotification::on(Sound::None),
+                    blocklist_notification: BlocklistNotification::off(Sound::Pop),
+                    scan_notification: ScanNotification::on(Sound::Swhoosh),
+                    quota_notification: QuotaNotification {
+                        cap: Some(2_000_000_000),
+                        cycle_start_day: 15,
+                        previous_cap: 2_000_000_000,
+                        ..QuotaNotification::default()
+                    },
+                    trigger: AlertTrigger {
+                        mode: TriggerMode::Average,
+                        window: 5,
+                        cooldown: 60
+                    }
+                },
+                notification_rules: vec![NotificationRule {
+                    scope: RuleScope::App,
+                    value: "Telnet".to_string(),
+                    ..NotificationRule::default()
+                }],
+                notification_actions: vec![NotificationAction {
+                    kind: NotificationKind::Scan,
+                    command: String::new(),
+                    webhook: "http://127.0.0.1:8080/alerts".to_string(),
+                }],
+                blocklists: vec!["firehol_level1.netset".to_string()],
+                flow_export: FlowExportSettings {
+                    enabled: true,
+                    collector: "10.0.0.1:4739".to_string(),
+                    protocol: FlowProtocol::Ipfix,
+                    ..FlowExportSettings::default()
                 },
                 style: StyleType::Custom(ExtraStyles::DraculaDark)
             }
@@ -0,0 +1,548 @@
+//! Module defining the `HistoryDb` struct, used to store the traffic history in a SQLite database
+//! and to query it afterwards.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Local};
+use rusqlite::{params, Connection, Row};
+
+use crate::history::types::history_entry::HistoryEntry;
+use crate::history::types::history_group::HistoryGroup;
+use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::networking::types::data_info::DataInfo;
+use crate::networking::types::host::Host;
+use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
+use crate::networking::types::traffic_direction::TrafficDirection;
+use crate::{AppProtocol, SNIFFNET_LOWERCASE};
+
+/// Maximum number of entries returned by a query
+const MAX_ENTRIES: i64 = 100;
+
+/// Flow summaries are identified by the capture session, the adapter, and their address:port pair;
+/// ports are stored as -1 when not applicable (ICMP)
+const CREATE_TABLES: &str = "
+    CREATE TABLE IF NOT EXISTS flows (
+        session INTEGER NOT NULL,
+        interface TEXT NOT NULL,
+        protocol TEXT NOT NULL,
+        address1 TEXT NOT NULL,
+        port1 INTEGER NOT NULL,
+        address2 TEXT NOT NULL,
+        port2 INTEGER NOT NULL,
+        app TEXT NOT NULL,
+        outgoing INTEGER NOT NULL,
+        initial_timestamp INTEGER NOT NULL,
+        final_timestamp INTEGER NOT NULL,
+        packets INTEGER NOT NULL,
+        bytes INTEGER NOT NULL,
+        domain TEXT,
+        asn_number INTEGER,
+        asn_name TEXT,
+        country TEXT,
+        PRIMARY KEY (session, interface, protocol, address1, port1, address2, port2)
+    );
+    CREATE INDEX IF NOT EXISTS flows_final_timestamp ON flows (final_timestamp);
+    CREATE TABLE IF NOT EXISTS hosts (
+        timestamp INTEGER NOT NULL,
+        domain TEXT NOT NULL,
+        asn_number INTEGER NOT NULL,
+        asn_name TEXT NOT NULL,
+        country TEXT NOT NULL,
+        incoming_packets INTEGER NOT NULL,
+        outgoing_packets INTEGER NOT NULL,
+        incoming_bytes INTEGER NOT NULL,
+        outgoing_bytes INTEGER NOT NULL
+    );
+    CREATE INDEX IF NOT EXISTS hosts_timestamp ON hosts (timestamp);
+    CREATE TABLE IF NOT EXISTS apps (
+        timestamp INTEGER NOT NULL,
+        app TEXT NOT NULL,
+        incoming_packets INTEGER NOT NULL,
+        outgoing_packets INTEGER NOT NULL,
+        incoming_bytes INTEGER NOT NULL,
+        outgoing_bytes INTEGER NOT NULL
+    );
+    CREATE INDEX IF NOT EXISTS apps_timestamp ON apps (timestamp);
+";
+
+/// Value of the `auto_vacuum` pragma for incremental vacuum
+const AUTO_VACUUM_INCREMENTAL: i64 = 2;
+
+/// Totals of the data exchanged, in the order expected by the history queries
+const DATA_INFO_COLUMNS: &str = "SUM(incoming_packets), SUM(outgoing_packets), \
+    SUM(incoming_bytes), SUM(outgoing_bytes)";
+
+/// Traffic history stored on disk
+pub struct HistoryDb {
+    connection: Connection,
+}
+
+impl HistoryDb {
+    /// Opens the database at the given path, creating it if needed
+    pub fn open(path: &Path) -> rusqlite::Result<Self> {
+        let connection = Connection::open(path)?;
+        // let the space freed by pruning be returned to the filesystem
+        let auto_vacuum: i64 = connection.query_row("PRAGMA auto_vacuum", [], |row| row.get(0))?;
+        if auto_vacuum != AUTO_VACUUM_INCREMENTAL {
+            connection.execute_batch("PRAGMA auto_vacuum = INCREMENTAL; VACUUM;")?;
+        }
+        connection.execute_batch(CREATE_TABLES)?;
+        Ok(HistoryDb { connection })
+    }
+
+    /// Deletes the data stored before the given Unix timestamp, and releases the space it occupied
+    pub fn prune(&self, before: i64) -> rusqlite::Result<()> {
+        self.connection
+            .execute("DELETE FROM flows WHERE final_timestamp < ?1", [before])?;
+        self.connection
+            .execute("DELETE FROM hosts WHERE timestamp < ?1", [before])?;
+        self.connection
+            .execute("DELETE FROM apps WHERE timestamp < ?1", [before])?;
+        self.connection.execute_batch("PRAGMA incremental_vacuum;")
+    }
+
+    /// Stores the flows updated in the last interval and the data exchanged in the last interval
+    /// by each host and application protocol
+    pub fn store(
+        &mut self,
+        session: i64,
+        timestamp: DateTime<Local>,
+        flows: &[(AddressPortPair, InfoAddressPortPair, Option<Host>)],
+        hosts: &HashMap<Host, DataInfo>,
+        apps: &HashMap<AppProtocol, DataInfo>,
+    ) -> rusqlite::Result<()> {
+        let timestamp = timestamp.timestamp();
+        let transaction = self.connection.transaction()?;
+        {
+            let mut insert_flow = transaction.prepare_cached(
+                "INSERT INTO flows VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)
+                ON CONFLICT (session, interface, protocol, address1, port1, address2, port2) DO UPDATE SET
+                    final_timestamp = excluded.final_timestamp,
+                    packets = excluded.packets,
+                    bytes = excluded.bytes,
+                    domain = excluded.domain,
+                    asn_number = excluded.asn_number,
+                    asn_name = excluded.asn_name,
+                    country = excluded.country",
+            )?;
+            for (key, val, host) in flows {
+                insert_flow.execute(params![
+                    session,
+                    val.interface,
+                    key.protocol.to_string(),
+                    key.address1,
+                    key.port1.map_or(-1, i64::from),
+                    key.address2,
+                    key.port2.map_or(-1, i64::from),
+                    val.app_protocol.to_string(),
+                    val.traffic_direction.eq(&TrafficDirection::Outgoing),
+                    val.initial_timestamp.timestamp(),
+                    val.final_timestamp.timestamp(),
+                    to_sql_integer(val.transmitted_packets),
+                    to_sql_integer(val.transmitted_bytes),
+                    host.as_ref().map(|host| host.domain.clone()),
+                    host.as_ref().map(|host| host.asn.number),
+                    host.as_ref().map(|host| host.asn.name.clone()),
+                    host.as_ref().map(|host| host.country.to_string()),
+                ])?;
+            }
+
+            let mut insert_host = transaction
+                .prepare_cached("INSERT INTO hosts VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)")?;
+            for (host, data_info) in hosts {
+                insert_host.execute(params![
+                    timestamp,
+                    host.domain,
+                    host.asn.number,
+                    host.asn.name,
+                    host.country.to_string(),
+                    to_sql_integer(data_info.incoming_packets),
+                    to_sql_integer(data_info.outgoing_packets),
+                    to_sql_integer(data_info.incoming_bytes),
+                    to_sql_integer(data_info.outgoing_bytes),
+                ])?;
+            }
+
+            let mut insert_app =
+                transaction.prepare_cached("INSERT INTO apps VALUES (?1, ?2, ?3, ?4, ?5, ?6)")?;
+            for (app, data_info) in apps {
+                insert_app.execute(params![
+                    timestamp,
+                    app.to_string(),
+                    to_sql_integer(data_info.incoming_packets),
+                    to_sql_integer(data_info.outgoing_packets),
+                    to_sql_integer(data_info.incoming_bytes),
+                    to_sql_integer(data_info.outgoing_bytes),
+                ])?;
+            }
+        }
+        transaction.commit()
+    }
+
+    /// Returns the data exchanged in the given time interval (Unix timestamps),
+    /// aggregated according to the given criterion and sorted by number of bytes.
+    ///
+    /// The local address filter only applies to flows
+    pub fn query(
+        &self,
+        from: i64,
+        to: i64,
+        group: HistoryGroup,
+        local_address: &str,
+    ) -> rusqlite::Result<Vec<HistoryEntry>> {
+        let (label, table, group_columns) = match group {
+            HistoryGroup::Hosts => (
+                "domain || ' (' || country || ')'",
+                "hosts",
+                "domain, country",
+            ),
+            HistoryGroup::Asns => (
+                "CASE asn_number WHEN 0 THEN '?' ELSE asn_name || ' (ASN ' || asn_number || ')' END",
+                "hosts",
+                "asn_number, asn_name",
+            ),
+            HistoryGroup::Countries => ("country", "hosts", "country"),
+            HistoryGroup::Apps => ("app", "apps", "app"),
+            HistoryGroup::Flows => return self.query_flows(from, to, local_address),
+        };
+        let mut statement = self.connection.prepare(&format!(
+            "SELECT {label}, {DATA_INFO_COLUMNS} FROM {table}
+            WHERE timestamp > ?1 AND timestamp <= ?2
+            GROUP BY {group_columns}
+            ORDER BY SUM(incoming_bytes) + SUM(outgoing_bytes) DESC
+            LIMIT ?3"
+        ))?;
+        let entries = statement.query_map(params![from, to, MAX_ENTRIES], |row| {
+            Ok(HistoryEntry {
+                label: row.get(0)?,
+                data_info: get_data_info(row, 1)?,
+            })
+        })?;
+        entries.collect()
+    }
+
+    /// Returns the flows active in the given time interval (Unix timestamps) whose local address
+    /// contains the given string, sorted by number of bytes.
+    ///
+    /// The data of each flow is the total exchanged during its capture session
+    fn query_flows(
+        &self,
+        from: i64,
+        to: i64,
+        local_address: &str,
+    ) -> rusqlite::Result<Vec<HistoryEntry>> {
+        let mut statement = self.connection.prepare(
+            "SELECT protocol, address1, port1, address2, port2, app, domain, country, interface,
+                outgoing, packets, bytes
+            FROM flows
+            WHERE initial_timestamp <= ?2 AND final_timestamp > ?1
+                AND instr(CASE WHEN outgoing THEN address1 ELSE address2 END, ?3) > 0
+            ORDER BY bytes DESC
+            LIMIT ?4",
+        )?;
+        let entries = statement.query_map(
+            params![from, to, local_address.trim().to_lowercase(), MAX_ENTRIES],
+            |row| {
+                let endpoint = |address: usize, port: usize| -> rusqlite::Result<String> {
+                    let address: String = row.get(address)?;
+                    Ok(match row.get::<_, i64>(port)? {
+                        -1 => address,
+                        port => format!("{address}:{port}"),
+                    })
+                };
+                let mut label = format!(
+                    "{} {} → {} {} [{}]",
+                    row.get::<_, String>(0)?,
+                    endpoint(1, 2)?,
+                    endpoint(3, 4)?,
+                    row.get::<_, String>(5)?,
+                    row.get::<_, String>(8)?
+                );
+                if let (Some(domain), Some(country)) = (
+                    row.get::<_, Option<String>>(6)?,
+                    row.get::<_, Option<String>>(7)?,
+                ) {
+                    label.push_str(&format!(" {domain} ({country})"));
+                }
+                let packets = u128::try_from(row.get::<_, i64>(10)?).unwrap_or_default();
+                let bytes = u128::try_from(row.get::<_, i64>(11)?).unwrap_or_default();
+                let data_info = if row.get(9)? {
+                    DataInfo {
+                        outgoing_packets: packets,
+                        outgoing_bytes: bytes,
+                        ..DataInfo::default()
+                    }
+                } else {
+                    DataInfo {
+                        incoming_packets: packets,
+                        incoming_bytes: bytes,
+                        ..DataInfo::default()
+                    }
+                };
+                Ok(HistoryEntry { label, data_info })
+            },
+        )?;
+        entries.collect()
+    }
+}
+
+/// Returns the path of the traffic history database, in the same directory of the configuration files
+pub fn get_history_db_path() -> Option<PathBuf> {
+    let path = confy::get_configuration_file_path(SNIFFNET_LOWERCASE, "history").ok()?;
+    Some(path.with_extension("sqlite"))
+}
+
+fn to_sql_integer(value: u128) -> i64 {
+    i64::try_from(value).unwrap_or(i64::MAX)
+}
+
+fn get_data_info(row: &Row, first_column: usize) -> rusqlite::Result<DataInfo> {
+    let get_u128 = |column: usize| -> rusqlite::Result<u128> {
+        Ok(u128::try_from(row.get::<_, i64>(first_column + column)?).unwrap_or_default())
+    };
+    Ok(DataInfo {
+        incoming_packets: get_u128(0)?,
+        outgoing_packets: get_u128(1)?,
+        incoming_bytes: get_u128(2)?,
+        outgoing_bytes: get_u128(3)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use crate::countries::types::country::Country;
+    use crate::networking::types::asn::Asn;
+    use crate::Protocol;
+
+    use super::*;
+
+    fn host(domain: &str, asn_number: u32, asn_name: &str, country: Country) -> Host {
+        Host {
+            domain: domain.to_string(),
+            asn: Asn {
+                number: asn_number,
+                name: asn_name.to_string(),
+            },
+            country,
+        }
+    }
+
+    fn data_info(incoming_bytes: u128, outgoing_bytes: u128) -> DataInfo {
+        DataInfo {
+            incoming_packets: 1,
+            outgoing_packets: 1,
+            incoming_bytes,
+            outgoing_bytes,
+        }
+    }
+
+    fn test_db() -> HistoryDb {
+        let mut history_db = HistoryDb::open(Path::new(":memory:")).unwrap();
+        let google = host("google.com", 15169, "GOOGLE", Country::US);
+        let youtube = host("youtube.com", 15169, "GOOGLE", Country::US);
+        let local = host("raspberrypi", 0, "", Country::ZZ);
+        let interval_1 = Local.timestamp_opt(1000, 0).unwrap();
+        let interval_2 = Local.timestamp_opt(1060, 0).unwrap();
+        history_db
+            .store(
+                1,
+                interval_1,
+                &[],
+                &HashMap::from([
+                    (google.clone(), data_info(100, 10)),
+                    (local.clone(), data_info(500, 500)),
+                ]),
+                &HashMap::from([(AppProtocol::HTTPS, data_info(100, 10))]),
+            )
+            .unwrap();
+        history_db
+            .store(
+                1,
+                interval_2,
+                &[],
+                &HashMap::from([
+                    (google, data_info(1000, 100)),
+                    (youtube, data_info(5000, 0)),
+                ]),
+                &HashMap::from([
+                    (AppProtocol::HTTPS, data_info(6000, 100)),
+                    (AppProtocol::DNS, data_info(10, 10)),
+                ]),
+            )
+            .unwrap();
+        history_db
+    }
+
+    #[test]
+    fn test_query_hosts() {
+        let history_db = test_db();
+
+        let entries = history_db.query(0, 2000, HistoryGroup::Hosts, "").unwrap();
+        let labels: Vec<&str> = entries.iter().map(|e| e.label.as_str()).collect();
+        assert_eq!(
+            labels,
+            vec!["youtube.com (US)", "google.com (US)", "raspberrypi (?)"]
+        );
+        assert_eq!(entries[1].data_info.tot_bytes(), 1210);
+        assert_eq!(entries[1].data_info.tot_packets(), 4);
+
+        // only the first interval
+        let entries = history_db.query(0, 1000, HistoryGroup::Hosts, "").unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].label, "raspberrypi (?)");
+
+        // nothing stored in this interval
+        assert!(history_db
+            .query(2000, 3000, HistoryGroup::Hosts, "")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_query_asns_countries_apps() {
+        let history_db = test_db();
+
+        let asns = history_db.query(0, 2000, HistoryGroup::Asns, "").unwrap();
+        assert_eq!(asns[0].label, "GOOGLE (ASN 15169)");
+        assert_eq!(asns[0].data_info.tot_bytes(), 6210);
+        assert_eq!(asns[1].label, "?");
+
+        let countries = history_db
+            .query(0, 2000, HistoryGroup::Countries, "")
+            .unwrap();
+        assert_eq!(countries.len(), 2);
+        assert_eq!(countries[0].label, "US");
+
+        let apps = history_db
+            .query(1000, 2000, HistoryGroup::Apps, "")
+            .unwrap();
+        assert_eq!(apps.len(), 2);
+        assert_eq!(apps[0].label, "HTTPS");
+        assert_eq!(apps[0].data_info.tot_bytes(), 6100);
+    }
+
+    #[test]
+    fn test_store_flows() {
+        let mut history_db = HistoryDb::open(Path::new(":memory:")).unwrap();
+        let key = AddressPortPair::new(
+            "192.168.1.2".to_string(),
+            None,
+            "8.8.8.8".to_string(),
+            None,
+            Protocol::ICMP,
+            "eth0".to_string(),
+        );
+        let mut val = InfoAddressPortPair {
+            transmitted_packets: 1,
+            transmitted_bytes: 64,
+            interface: "eth0".to_string(),
+            ..InfoAddressPortPair::default()
+        };
+        let timestamp = Local.timestamp_opt(1000, 0).unwrap();
+        let flows = vec![(key.clone(), val.clone(), None)];
+        history_db
+            .store(1, timestamp, &flows, &HashMap::new(), &HashMap::new())
+            .unwrap();
+        val.transmitted_packets = 2;
+        val.transmitted_bytes = 128;
+        let flows = vec![(
+            key,
+            val,
+            Some(host("dns.google", 15169, "GOOGLE", Country::US)),
+        )];
+        history_db
+            .store(1, timestamp, &flows, &HashMap::new(), &HashMap::new())
+            .unwrap();
+
+        let (count, bytes, domain): (i64, i64, String) = history_db
+            .connection
+            .query_row(
+                "SELECT COUNT(*), SUM(bytes), MAX(domain) FROM flows",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .unwrap();
+        assert_eq!((count, bytes, domain.as_str()), (1, 128, "dns.google"));
+    }
+
+    #[test]
+    fn test_query_flows() {
+        let mut history_db = HistoryDb::open(Path::new(":memory:")).unwrap();
+        let flow = |address1: &str, port1: u16, address2: &str, interface: &str, bytes: u128| {
+            let key = AddressPortPair::new(
+                address1.to_string(),
+                Some(port1),
+                address2.to_string(),
+                Some(443),
+                Protocol::TCP,
+                interface.to_string(),
+            );
+            let val = InfoAddressPortPair {
+                transmitted_packets: 1,
+                transmitted_bytes: bytes,
+                traffic_direction: TrafficDirection::Outgoing,
+                initial_timestamp: Local.timestamp_opt(1000, 0).unwrap(),
+                final_timestamp: Local.timestamp_opt(1100, 0).unwrap(),
+                interface: interface.to_string(),
+                ..InfoAddressPortPair::default()
+            };
+            (key, val, None)
+        };
+        let flows = vec![
+            flow("192.168.1.2", 50000, "1.1.1.1", "eth0", 100),
+            // the same pair observed on another adapter
+            flow("192.168.1.2", 50000, "1.1.1.1", "tun0", 200),
+            flow("192.168.1.3", 50001, "1.1.1.1", "eth0", 300),
+        ];
+        history_db
+            .store(
+                1,
+                Local.timestamp_opt(1100, 0).unwrap(),
+                &flows,
+                &HashMap::new(),
+                &HashMap::new(),
+            )
+            .unwrap();
+
+        let entries = history_db.query(0, 2000, HistoryGroup::Flows, "").unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(
+            entries[0].label,
+            "TCP 192.168.1.3:50001 → 1.1.1.1:443 ? [eth0]"
+        );
+        assert_eq!(entries[0].data_info.outgoing_bytes, 300);
+
+        let entries = history_db
+            .query(0, 2000, HistoryGroup::Flows, " 192.168.1.2")
+            .unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(
+            entries[0].label,
+            "TCP 192.168.1.2:50000 → 1.1.1.1:443 ? [tun0]"
+        );
+
+        // flows not active in the interval
+        assert!(history_db
+            .query(1100, 2000, HistoryGroup::Flows, "")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_prune() {
+        let history_db = test_db();
+
+        history_db.prune(1030).unwrap();
+        let entries = history_db.query(0, 2000, HistoryGroup::Hosts, "").unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].label, "google.com (US)");
+        assert_eq!(entries[1].data_info.tot_bytes(), 1100);
+        assert!(history_db
+            .query(0, 1030, HistoryGroup::Apps, "")
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,2 @@
+pub mod history_db;
+pub mod types;
@@ -0,0 +1,10 @@
+use crate::networking::types::data_info::DataInfo;
+
+/// Entry of the traffic history, aggregated according to a `HistoryGroup`
+#[derive(Clone, Default)]
+pub struct HistoryEntry {
+    /// Host, ASN, country, or application protocol
+    pub label: String,
+    /// Data exchanged in the queried time interval
+    pub data_info: DataInfo,
+}
@@ -0,0 +1,35 @@
+use crate::translations::translations::application_protocol_translation;
+use crate::translations::translations_2::{country_translation, host_translation};
+use crate::translations::translations_3::flows_translation;
+use crate::Language;
+
+/// Criterion used to aggregate the traffic history
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default, Hash)]
+pub enum HistoryGroup {
+    #[default]
+    Hosts,
+    Asns,
+    Countries,
+    Apps,
+    Flows,
+}
+
+impl HistoryGroup {
+    pub(crate) const ALL: [HistoryGroup; 5] = [
+        HistoryGroup::Hosts,
+        HistoryGroup::Asns,
+        HistoryGroup::Countries,
+        HistoryGroup::Apps,
+        HistoryGroup::Flows,
+    ];
+
+    pub fn get_label(&self, language: Language) -> &str {
+        match self {
+            HistoryGroup::Hosts => host_translation(language),
+            HistoryGroup::Asns => "ASN",
+            HistoryGroup::Countries => country_translation(language),
+            HistoryGroup::Apps => application_protocol_translation(language),
+            HistoryGroup::Flows => flows_translation(language),
+        }
+    }
+}
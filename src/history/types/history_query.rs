@@ -0,0 +1,79 @@
+use chrono::{DateTime, Duration, Local, NaiveDateTime, TimeZone};
+
+use crate::history::types::history_group::HistoryGroup;
+
+/// Format of the time interval boundaries inserted by the user
+pub const HISTORY_TIME_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+/// Parameters of a query to the traffic history
+#[derive(Clone, Debug, Hash)]
+pub struct HistoryQuery {
+    /// Beginning of the time interval
+    pub from: String,
+    /// End of the time interval
+    pub to: String,
+    /// How to aggregate the results
+    pub group: HistoryGroup,
+    /// Local address the flows must involve (empty to not filter)
+    pub local_address: String,
+}
+
+impl Default for HistoryQuery {
+    fn default() -> Self {
+        let now = Local::now();
+        HistoryQuery {
+            from: (now - Duration::days(1))
+                .format(HISTORY_TIME_FORMAT)
+                .to_string(),
+            to: now.format(HISTORY_TIME_FORMAT).to_string(),
+            group: HistoryGroup::default(),
+            local_address: String::new(),
+        }
+    }
+}
+
+impl HistoryQuery {
+    /// Returns the queried time interval as Unix timestamps, or `None` if it's not valid
+    pub fn get_interval(&self) -> Option<(i64, i64)> {
+        let from = parse_local_time(&self.from)?;
+        let to = parse_local_time(&self.to)?;
+        if from >= to {
+            return None;
+        }
+        Some((from.timestamp(), to.timestamp()))
+    }
+}
+
+fn parse_local_time(time: &str) -> Option<DateTime<Local>> {
+    let naive = NaiveDateTime::parse_from_str(time.trim(), HISTORY_TIME_FORMAT).ok()?;
+    Local.from_local_datetime(&naive).earliest()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_query_interval() {
+        let mut query = HistoryQuery {
+            from: "2023-12-01 14:00".to_string(),
+            to: "2023-12-01 15:00".to_string(),
+            group: HistoryGroup::Hosts,
+            local_address: String::new(),
+        };
+        let (from, to) = query.get_interval().unwrap();
+        assert_eq!(to - from, 3600);
+
+        query.to = "2023-12-01 13:59".to_string();
+        assert_eq!(query.get_interval(), None);
+
+        query.to = "yesterday".to_string();
+        assert_eq!(query.get_interval(), None);
+    }
+
+    #[test]
+    fn test_history_query_default_is_valid() {
+        let (from, to) = HistoryQuery::default().get_interval().unwrap();
+        assert_eq!(to - from, 86400);
+    }
+}
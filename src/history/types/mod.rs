@@ -0,0 +1,3 @@
+pub mod history_entry;
+pub mod history_group;
+pub mod history_query;
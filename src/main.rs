@@ -2,6 +2,7 @@
 
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+use std::net::TcpListener;
 use std::sync::{Arc, Mutex};
 use std::{panic, process, thread};
 
@@ -30,16 +31,26 @@
 
 use crate::configs::types::config_window::{ConfigWindow, ToPosition};
 use crate::configs::types::configs::Configs;
+use crate::notifications::notification_log::get_notification_log_path;
 use crate::secondary_threads::check_updates::set_newer_release_status;
+use crate::secondary_threads::serve_api::{generate_api_token, serve_api};
+use crate::secondary_threads::write_events::STDOUT_OUTPUT;
+use crate::translations::translations_3::api_unavailable_translation;
 
+mod api;
+mod blocklists;
 mod chart;
 mod cli;
 mod configs;
 mod countries;
+mod events;
+mod flow_export;
 mod gui;
+mod history;
 mod mmdb;
 mod networking;
 mod notifications;
+mod oui;
 mod report;
 mod secondary_threads;
 mod translations;
@@ -54,6 +65,13 @@ pub fn main() -> iced::Result {
     parse_cli_args();
 
     let configs1 = Arc::new(Mutex::new(Configs::load()));
+    {
+        // the token to authenticate to the local API is generated once per installation
+        let settings = &mut configs1.lock().unwrap().settings;
+        if settings.api_token.is_empty() {
+            settings.api_token = generate_api_token();
+        }
+    }
     let configs2 = configs1.clone();
 
     let newer_release_available1 = Arc::new(Mutex::new(None));
@@ -81,7 +99,46 @@ pub fn main() -> iced::Result {
         })
         .unwrap();
 
-    print_cli_welcome_message();
+    let ConfigSettings {
+        language,
+        api,
+        api_address,
+        api_token,
+        events,
+        events_output,
+        ..
+    } = configs1.lock().unwrap().settings.clone();
+
+    // the standard output may be reserved to the stream of JSON events
+    if !events || events_output.ne(STDOUT_OUTPUT) {
+        print_cli_welcome_message();
+    }
+
+    let mut sniffer = Sniffer::new(
+        &configs1,
+        newer_release_available1,
+        get_notification_log_path(),
+    );
+
+    // serve the local API, if enabled
+    if api {
+        match TcpListener::bind(&api_address) {
+            Ok(listener) => {
+                let api_state = sniffer.api_state.clone();
+                let info_traffic = sniffer.info_traffic.clone();
+                thread::Builder::new()
+                    .name("thread_serve_api".to_string())
+                    .spawn(move || {
+                        serve_api(&listener, &api_token, &api_state, &info_traffic);
+                    })
+                    .unwrap();
+            }
+            Err(error) => sniffer.show_error(format!(
+                "{}\n{api_address}: {error}",
+                api_unavailable_translation(language)
+            )),
+        }
+    }
 
     let ConfigWindow { size, position } = configs1.lock().unwrap().window;
 
@@ -104,7 +161,7 @@ pub fn main() -> iced::Result {
             },
             ..Default::default()
         },
-        flags: Sniffer::new(&configs1, newer_release_available1),
+        flags: sniffer,
         default_font: Font::with_name("Sarasa Mono SC"),
         default_text_size: FONT_SIZE_BODY,
         antialiasing: false,
@@ -0,0 +1,421 @@
+//! Module containing functions to passively learn the names of the hosts in the local network,
+//! reading the content of DHCP, mDNS, LLMNR, and NetBIOS name service packets.
+//!
+//! Hosts in the local network usually don't have a PTR record, so that their rDNS would just be
+//! equal to their IP address; the names harvested here are used in its place.
+
+use std::net::{IpAddr, Ipv4Addr};
+
+use crate::networking::types::address_port_pair::AddressPortPair;
+use crate::networking::types::dns_message::{DnsMessage, ResourceRecord, RR_TYPE_NB};
+use crate::networking::types::host::Host;
+use crate::{AppProtocol, InfoTraffic, Protocol};
+
+/// UDP port used by the NetBIOS name service
+const NETBIOS_NAME_SERVICE_PORT: u16 = 137;
+/// Magic cookie preceding the options of a DHCP message
+const DHCP_MAGIC_COOKIE: [u8; 4] = [99, 130, 83, 99];
+/// Length of the fixed part of a DHCP message (excluding the magic cookie)
+const DHCP_FIXED_LEN: usize = 236;
+/// DHCP option carrying the client hostname
+const DHCP_OPTION_HOSTNAME: u8 = 12;
+/// DHCP option carrying the IP address requested by the client
+const DHCP_OPTION_REQUESTED_IP: u8 = 50;
+
+/// Returns the pairs of addresses and names announced in the payload of the given packet, if any.
+pub fn harvest_names(
+    key: &AddressPortPair,
+    app_protocol: AppProtocol,
+    payload: &[u8],
+) -> Vec<(IpAddr, String)> {
+    if key.protocol.ne(&Protocol::UDP) {
+        return Vec::new();
+    }
+    match app_protocol {
+        AppProtocol::DHCP => harvest_dhcp(payload).into_iter().collect(),
+        AppProtocol::mDNS | AppProtocol::LLMNR => harvest_dns(payload),
+        AppProtocol::NetBIOS
+            if [key.port1, key.port2].contains(&Some(NETBIOS_NAME_SERVICE_PORT)) =>
+        {
+            harvest_netbios(payload)
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Saves the name harvested for the given address.
+///
+/// If the address was already resolved to a local host without a proper name,
+/// the host is renamed preserving the data it exchanged so far.
+pub fn update_local_name(info_traffic: &mut InfoTraffic, address: String, name: String) {
+    if info_traffic.local_names.get(&address) == Some(&name) {
+        return;
+    }
+    info_traffic
+        .local_names
+        .insert(address.clone(), name.clone());
+
+    let Some((r_dns, old_host)) = info_traffic.addresses_resolved.get(&address).cloned() else {
+        // the name will be used when the rDNS lookup of this address completes
+        return;
+    };
+    // only rename hosts still identified by their IP address
+    if old_host.domain.ne(&address) {
+        return;
+    }
+    let Some(old_data_info_host) = info_traffic.hosts.get(&old_host).cloned() else {
+        return;
+    };
+    if !old_data_info_host.is_local {
+        return;
+    }
+
+    let new_host = Host {
+        domain: name,
+        ..old_host.clone()
+    };
+    info_traffic.hosts.remove(&old_host);
+    info_traffic
+        .hosts
+        .entry(new_host.clone())
+        .and_modify(|data_info_host| {
+            data_info_host.data_info += old_data_info_host.data_info;
+            data_info_host.is_favorite |= old_data_info_host.is_favorite;
+            if data_info_host.mac_address.is_none() {
+                data_info_host.mac_address = old_data_info_host.mac_address.clone();
+            }
+        })
+        .or_insert(old_data_info_host);
+    info_traffic
+        .addresses_resolved
+        .insert(address, (r_dns, new_host.clone()));
+    if info_traffic.favorite_hosts.remove(&old_host) {
+        info_traffic.favorite_hosts.insert(new_host.clone());
+    }
+    if info_traffic.favorites_last_interval.remove(&old_host) {
+        info_traffic.favorites_last_interval.insert(new_host);
+    }
+}
+
+/// Extracts the hostname (option 12) announced by a DHCP client, together with its address.
+fn harvest_dhcp(payload: &[u8]) -> Option<(IpAddr, String)> {
+    if payload.get(DHCP_FIXED_LEN..DHCP_FIXED_LEN + 4)? != DHCP_MAGIC_COOKIE {
+        return None;
+    }
+    let client_address = ipv4_from_slice(payload.get(12..16)?);
+    let your_address = ipv4_from_slice(payload.get(16..20)?);
+
+    let mut hostname = None;
+    let mut requested_address = None;
+    let mut pos = DHCP_FIXED_LEN + 4;
+    while let Some(&code) = payload.get(pos) {
+        match code {
+            // padding
+            0 => {
+                pos += 1;
+                continue;
+            }
+            // end of options
+            255 => break,
+            _ => {}
+        }
+        let Some(len) = payload.get(pos + 1).map(|len| *len as usize) else {
+            break;
+        };
+        let Some(value) = payload.get(pos + 2..pos + 2 + len) else {
+            break;
+        };
+        match code {
+            DHCP_OPTION_HOSTNAME => hostname = sanitize_name(value),
+            DHCP_OPTION_REQUESTED_IP if len == 4 => requested_address = ipv4_from_slice(value),
+            _ => {}
+        }
+        pos += 2 + len;
+    }
+
+    let address = [client_address, requested_address, your_address]
+        .into_iter()
+        .flatten()
+        .find(|address| !address.is_unspecified())?;
+    Some((IpAddr::V4(address), hostname?))
+}
+
+/// Extracts the names associated with A and AAAA records of mDNS and LLMNR messages.
+fn harvest_dns(payload: &[u8]) -> Vec<(IpAddr, String)> {
+    get_all_records(payload)
+        .into_iter()
+        .filter_map(|record| {
+            let address = record.ip_address()?;
+            let name = record.name.trim_end_matches('.');
+            let name = name.strip_suffix(".local").unwrap_or(name);
+            Some((address, sanitize_name(name.as_bytes())?))
+        })
+        .collect()
+}
+
+/// Extracts the unique workstation and server names of NetBIOS name service messages.
+fn harvest_netbios(payload: &[u8]) -> Vec<(IpAddr, String)> {
+    let mut names = Vec::new();
+    for record in get_all_records(payload) {
+        if record.rr_type != RR_TYPE_NB {
+            continue;
+        }
+        let Some(name) = decode_netbios_name(&record.name) else {
+            continue;
+        };
+        // each entry is composed by 2 bytes of flags followed by an IPv4 address
+        for entry in record.data.chunks_exact(6) {
+            let is_group_name = entry[0] & 0x80 != 0;
+            if let Some(address) = ipv4_from_slice(&entry[2..]) {
+                if !is_group_name && !address.is_unspecified() {
+                    names.push((IpAddr::V4(address), name.clone()));
+                }
+            }
+        }
+    }
+    names
+}
+
+/// Decodes a NetBIOS name in first-level encoding (RFC 1001, section 14.1).
+///
+/// Only workstation (0x00) and server (0x20) names are considered.
+fn decode_netbios_name(encoded: &str) -> Option<String> {
+    let encoded = encoded.split('.').next()?.as_bytes();
+    if encoded.len() != 32 {
+        return None;
+    }
+    let mut decoded = Vec::with_capacity(16);
+    for pair in encoded.chunks_exact(2) {
+        if !pair.iter().all(|c| (b'A'..=b'P').contains(c)) {
+            return None;
+        }
+        decoded.push(((pair[0] - b'A') << 4) | (pair[1] - b'A'));
+    }
+    let suffix = decoded.pop()?;
+    if suffix != 0x00 && suffix != 0x20 {
+        return None;
+    }
+    sanitize_name(&decoded)
+}
+
+/// Returns the answer, authority, and additional records of a DNS-like message.
+fn get_all_records(payload: &[u8]) -> Vec<ResourceRecord> {
+    DnsMessage::parse(payload)
+        .map(|message| {
+            let mut records = message.answers;
+            records.extend(message.other_records);
+            records
+        })
+        .unwrap_or_default()
+}
+
+fn ipv4_from_slice(slice: &[u8]) -> Option<Ipv4Addr> {
+    let octets: [u8; 4] = slice.try_into().ok()?;
+    Some(Ipv4Addr::from(octets))
+}
+
+/// Returns the given name without trailing NUL and whitespace characters,
+/// or `None` if it's empty or contains control characters.
+fn sanitize_name(bytes: &[u8]) -> Option<String> {
+    let name = String::from_utf8_lossy(bytes);
+    let name = name.trim_end_matches(|c: char| c == '\0' || c.is_whitespace());
+    if name.is_empty() || name.chars().any(char::is_control) {
+        None
+    } else {
+        Some(name.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv6Addr;
+
+    use crate::networking::types::data_info::DataInfo;
+    use crate::networking::types::data_info_host::DataInfoHost;
+    use crate::networking::types::traffic_direction::TrafficDirection;
+
+    use super::*;
+
+    fn udp_key(port1: u16, port2: u16) -> AddressPortPair {
+        AddressPortPair::new(
+            "192.168.1.10".to_string(),
+            Some(port1),
+            "192.168.1.255".to_string(),
+            Some(port2),
+            Protocol::UDP,
+            "eth0".to_string(),
+        )
+    }
+
+    fn dns_header(flags: u16, answers: u16) -> Vec<u8> {
+        let mut header = vec![0, 0];
+        header.extend_from_slice(&flags.to_be_bytes());
+        header.extend_from_slice(&[0, 0]);
+        header.extend_from_slice(&answers.to_be_bytes());
+        header.extend_from_slice(&[0, 0, 0, 0]);
+        header
+    }
+
+    fn encode_netbios_name(name: &str, suffix: u8) -> Vec<u8> {
+        let mut raw = format!("{name:<15}").into_bytes();
+        raw.push(suffix);
+        let mut encoded = vec![32];
+        for byte in raw {
+            encoded.push(b'A' + (byte >> 4));
+            encoded.push(b'A' + (byte & 0x0F));
+        }
+        encoded.push(0);
+        encoded
+    }
+
+    #[test]
+    fn test_harvest_dhcp_request() {
+        let mut payload = vec![0; DHCP_FIXED_LEN];
+        payload[0] = 1;
+        payload.extend_from_slice(&DHCP_MAGIC_COOKIE);
+        payload.extend_from_slice(&[53, 1, 3]);
+        payload.extend_from_slice(&[DHCP_OPTION_REQUESTED_IP, 4, 192, 168, 1, 23]);
+        payload.extend_from_slice(&[DHCP_OPTION_HOSTNAME, 6]);
+        payload.extend_from_slice(b"laptop");
+        payload.push(255);
+
+        assert_eq!(
+            harvest_names(&udp_key(68, 67), AppProtocol::DHCP, &payload),
+            vec![(
+                IpAddr::V4(Ipv4Addr::new(192, 168, 1, 23)),
+                "laptop".to_string()
+            )]
+        );
+
+        // truncated options don't make the parser panic
+        assert!(harvest_names(&udp_key(68, 67), AppProtocol::DHCP, &payload[..250]).is_empty());
+        // payload without the magic cookie
+        assert!(harvest_names(&udp_key(68, 67), AppProtocol::DHCP, &[1; 300]).is_empty());
+    }
+
+    #[test]
+    fn test_harvest_mdns_response_with_compression() {
+        let mut payload = dns_header(0x8400, 2);
+        payload.extend_from_slice(b"\x07printer\x05local\x00");
+        payload.extend_from_slice(&[0, 1, 0x80, 1, 0, 0, 0, 120, 0, 4, 192, 168, 1, 50]);
+        // AAAA record whose name is a pointer to the previous one
+        payload.extend_from_slice(&[0xC0, 12, 0, 28, 0x80, 1, 0, 0, 0, 120, 0, 16]);
+        payload.extend_from_slice(&[0xfe, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+
+        assert_eq!(
+            harvest_names(&udp_key(5353, 5353), AppProtocol::mDNS, &payload),
+            vec![
+                (
+                    IpAddr::V4(Ipv4Addr::new(192, 168, 1, 50)),
+                    "printer".to_string()
+                ),
+                (
+                    IpAddr::V6(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1)),
+                    "printer".to_string()
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn test_harvest_llmnr_response() {
+        let mut payload = dns_header(0x8000, 1);
+        // one question
+        payload[5] = 1;
+        payload.extend_from_slice(b"\x03nas\x00");
+        payload.extend_from_slice(&[0, 1, 0, 1]);
+        // answer
+        payload.extend_from_slice(b"\x03nas\x00");
+        payload.extend_from_slice(&[0, 1, 0, 1, 0, 0, 0, 30, 0, 4, 10, 0, 0, 7]);
+
+        assert_eq!(
+            harvest_names(&udp_key(5355, 50000), AppProtocol::LLMNR, &payload),
+            vec![(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 7)), "nas".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_dns_pointer_loop_is_rejected() {
+        let mut payload = dns_header(0x8400, 1);
+        payload.extend_from_slice(&[0xC0, 12, 0, 1, 0, 1, 0, 0, 0, 120, 0, 4, 1, 2, 3, 4]);
+        assert!(harvest_names(&udp_key(5353, 5353), AppProtocol::mDNS, &payload).is_empty());
+    }
+
+    #[test]
+    fn test_harvest_netbios_name_service() {
+        let mut payload = dns_header(0x8500, 3);
+        for (suffix, flags) in [(0x00, 0x00), (0x1D, 0x00), (0x00, 0x80)] {
+            payload.extend(encode_netbios_name("DESKTOP-1", suffix));
+            payload.extend_from_slice(&[0, 32, 0, 1, 0, 0, 0, 0, 0, 6, flags, 0]);
+            payload.extend_from_slice(&[192, 168, 1, 60]);
+        }
+
+        // only the unique workstation name is considered
+        assert_eq!(
+            harvest_names(&udp_key(137, 137), AppProtocol::NetBIOS, &payload),
+            vec![(
+                IpAddr::V4(Ipv4Addr::new(192, 168, 1, 60)),
+                "DESKTOP-1".to_string()
+            )]
+        );
+        // NetBIOS datagram and session services are not parsed
+        assert!(harvest_names(&udp_key(138, 138), AppProtocol::NetBIOS, &payload).is_empty());
+    }
+
+    #[test]
+    fn test_update_local_name_renames_resolved_host() {
+        let address = "192.168.1.60".to_string();
+        let old_host = Host {
+            domain: address.clone(),
+            ..Host::default()
+        };
+        let mut info_traffic = InfoTraffic::new();
+        info_traffic
+            .addresses_resolved
+            .insert(address.clone(), (address.clone(), old_host.clone()));
+        info_traffic.hosts.insert(
+            old_host.clone(),
+            DataInfoHost {
+                data_info: DataInfo::new_with_first_packet(100, TrafficDirection::Incoming),
+                is_favorite: true,
+                is_local: true,
+                ..DataInfoHost::default()
+            },
+        );
+        info_traffic.favorite_hosts.insert(old_host.clone());
+
+        update_local_name(&mut info_traffic, address.clone(), "DESKTOP-1".to_string());
+
+        let new_host = Host {
+            domain: "DESKTOP-1".to_string(),
+            ..Host::default()
+        };
+        assert_eq!(
+            info_traffic.local_names.get(&address),
+            Some(&"DESKTOP-1".to_string())
+        );
+        assert_eq!(
+            info_traffic.addresses_resolved.get(&address),
+            Some(&(address.clone(), new_host.clone()))
+        );
+        assert!(!info_traffic.hosts.contains_key(&old_host));
+        assert_eq!(
+            info_traffic
+                .hosts
+                .get(&new_host)
+                .unwrap()
+                .data_info
+                .tot_bytes(),
+            100
+        );
+        assert!(info_traffic.favorite_hosts.contains(&new_host));
+        assert!(!info_traffic.favorite_hosts.contains(&old_host));
+
+        // hosts that already have a name are not renamed again
+        update_local_name(&mut info_traffic, address.clone(), "OTHER".to_string());
+        assert_eq!(
+            info_traffic.addresses_resolved.get(&address),
+            Some(&(address, new_host))
+        );
+    }
+}
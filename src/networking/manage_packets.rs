@@ -3,17 +3,21 @@
 use std::str::FromStr;
 use std::sync::{Arc, Mutex};
 
-use chrono::Local;
+use chrono::{DateTime, Local};
 use dns_lookup::lookup_addr;
 use etherparse::{Ethernet2Header, IpHeader, PacketHeaders, TransportHeader};
 use pcap::{Active, Address, Capture, Device};
 
+use crate::blocklists::blocklist_check::check_blocklists;
+use crate::blocklists::types::blocklists::Blocklists;
 use crate::mmdb::asn::get_asn;
 use crate::mmdb::country::get_country;
 use crate::mmdb::types::mmdb_reader::MmdbReader;
 use crate::networking::types::address_port_pair::AddressPortPair;
 use crate::networking::types::app_protocol::from_port_to_application_protocol;
 use crate::networking::types::data_info_host::DataInfoHost;
+use crate::networking::types::dns_message::{DnsMessage, ResourceRecord};
+use crate::networking::types::dns_query::{DnsQuery, DnsResponse};
 use crate::networking::types::host::Host;
 use crate::networking::types::icmp_type::{IcmpType, IcmpTypeV4, IcmpTypeV6};
 use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
@@ -33,6 +37,7 @@ pub fn analyze_headers(
     exchanged_bytes: &mut u128,
     icmp_type: &mut IcmpType,
     packet_filters_fields: &mut PacketFiltersFields,
+    interface: &str,
 ) -> Option<AddressPortPair> {
     analyze_link_header(headers.link, &mut mac_addresses.0, &mut mac_addresses.1);
 
@@ -62,6 +67,7 @@ pub fn analyze_headers(
         packet_filters_fields.dest.to_string(),
         packet_filters_fields.dport,
         packet_filters_fields.protocol,
+        interface.to_string(),
     ))
 }
 
@@ -231,6 +237,7 @@ pub fn modify_or_insert_in_map(
             } else {
                 HashMap::new()
             },
+            interface: my_device.name.clone(),
         })
         .clone();
 
@@ -247,13 +254,27 @@ pub fn modify_or_insert_in_map(
     new_info
 }
 
+/// Returns the direction of a packet exchanged by the given device, without inserting it in the map
+pub fn get_packet_direction(key: &AddressPortPair, my_device: &MyDevice) -> TrafficDirection {
+    get_traffic_direction(
+        &key.address1,
+        &key.address2,
+        key.port1,
+        key.port2,
+        &my_device.addresses.lock().unwrap(),
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn reverse_dns_lookup(
     info_traffic: &Arc<Mutex<InfoTraffic>>,
     key: &AddressPortPair,
     traffic_direction: TrafficDirection,
+    mac_address: Option<String>,
     my_device: &MyDevice,
     country_db_reader: &Arc<MmdbReader>,
     asn_db_reader: &Arc<MmdbReader>,
+    blocklists: &Arc<Mutex<Blocklists>>,
 ) {
     let address_to_lookup = get_address_to_lookup(key, traffic_direction);
     let my_interface_addresses = my_device.addresses.lock().unwrap().clone();
@@ -271,6 +292,8 @@ pub fn reverse_dns_lookup(
     let is_local = is_local_connection(&address_to_lookup, &my_interface_addresses);
     let country = get_country(&address_to_lookup, country_db_reader);
     let asn = get_asn(&address_to_lookup, asn_db_reader);
+    // the MAC address is only meaningful for hosts in the same network (otherwise it's the gateway)
+    let mac_address = if is_local { mac_address } else { None };
     let r_dns = if let Ok(result) = lookup_result {
         if result.is_empty() {
             address_to_lookup.clone()
@@ -280,13 +303,27 @@ pub fn reverse_dns_lookup(
     } else {
         address_to_lookup.clone()
     };
+
+    let mut info_traffic_lock = info_traffic.lock().unwrap();
+    // local hosts rarely have a PTR record: in that case use the name they announced, if any
+    let domain = match info_traffic_lock.local_names.get(&address_to_lookup) {
+        Some(name) if is_local && r_dns.eq(&address_to_lookup) => name.clone(),
+        _ => get_domain_from_r_dns(r_dns.clone()),
+    };
     let new_host = Host {
-        domain: get_domain_from_r_dns(r_dns.clone()),
+        domain,
         asn,
         country,
     };
-
-    let mut info_traffic_lock = info_traffic.lock().unwrap();
+    // the address itself was already checked when it was first observed
+    if r_dns.ne(&address_to_lookup) {
+        check_blocklists(
+            &mut info_traffic_lock,
+            &blocklists.lock().unwrap(),
+            &address_to_lookup,
+            Some(&r_dns),
+        );
+    }
     // collect the data exchanged from the same address so far and remove the address from the collection of addresses waiting a rDNS
     let other_data = info_traffic_lock
         .addresses_waiting_resolution
@@ -301,6 +338,9 @@ pub fn reverse_dns_lookup(
         .entry(new_host.clone())
         .and_modify(|data_info_host| {
             data_info_host.data_info += other_data;
+            if data_info_host.mac_address.is_none() {
+                data_info_host.mac_address = mac_address.clone();
+            }
         })
         .or_insert_with(|| DataInfoHost {
             data_info: other_data,
@@ -308,6 +348,7 @@ pub fn reverse_dns_lookup(
             is_loopback,
             is_local,
             traffic_type,
+            mac_address,
         });
     // check if the newly resolved host was featured in the favorites (possible in case of already existing host)
     if info_traffic_lock.favorite_hosts.contains(&new_host) {
@@ -317,6 +358,71 @@ pub fn reverse_dns_lookup(
     drop(info_traffic_lock);
 }
 
+/// Maximum number of DNS queries kept in the log
+const MAX_DNS_QUERIES: usize = 10_000;
+/// Seconds after which a DNS query is not waiting for a response anymore
+const DNS_RESPONSE_TIMEOUT: i64 = 10;
+
+/// Adds a DNS query to the log, or completes a logged query with the data of its response.
+///
+/// Only the most recent queries are kept, and queries not answered in time are left without response.
+pub fn log_dns_message(
+    info_traffic: &mut InfoTraffic,
+    key: &AddressPortPair,
+    message: &DnsMessage,
+) {
+    let now = Local::now();
+    if message.is_response {
+        // the client is the destination of the response
+        let pending_key = (key.address2.clone(), key.port2, message.id);
+        if let Some(position) = info_traffic.dns_queries_pending.remove(&pending_key) {
+            let index = position.checked_sub(info_traffic.dns_queries_discarded);
+            if let Some(query) = index.and_then(|index| info_traffic.dns_queries.get_mut(index)) {
+                query.response = Some(DnsResponse {
+                    response_code: message.response_code,
+                    answers: message
+                        .answers
+                        .iter()
+                        .map(ResourceRecord::value_string)
+                        .collect(),
+                    latency: now - query.timestamp,
+                });
+            }
+        }
+    } else if let Some((name, query_type)) = message.questions.first() {
+        if info_traffic.dns_queries.len() >= MAX_DNS_QUERIES {
+            info_traffic.dns_queries.pop_front();
+            info_traffic.dns_queries_discarded += 1;
+        }
+        expire_pending_dns_queries(info_traffic, now);
+        let pending_key = (key.address1.clone(), key.port1, message.id);
+        let position = info_traffic.dns_queries_discarded + info_traffic.dns_queries.len();
+        info_traffic
+            .dns_queries_pending
+            .insert(pending_key, position);
+        info_traffic.dns_queries.push_back(DnsQuery {
+            timestamp: now,
+            client: key.address1.clone(),
+            server: key.address2.clone(),
+            name: name.clone(),
+            query_type: *query_type,
+            response: None,
+        });
+    }
+}
+
+/// Stops waiting for the responses to the queries discarded from the log or sent too long ago
+fn expire_pending_dns_queries(info_traffic: &mut InfoTraffic, now: DateTime<Local>) {
+    let discarded = info_traffic.dns_queries_discarded;
+    let dns_queries = &info_traffic.dns_queries;
+    info_traffic.dns_queries_pending.retain(|_, position| {
+        position
+            .checked_sub(discarded)
+            .and_then(|index| dns_queries.get(index))
+            .is_some_and(|query| (now - query.timestamp).num_seconds() < DNS_RESPONSE_TIMEOUT)
+    });
+}
+
 /// Returns the traffic direction observed (incoming or outgoing)
 fn get_traffic_direction(
     source_ip: &String,
@@ -546,17 +652,35 @@ pub fn get_address_to_lookup(key: &AddressPortPair, traffic_direction: TrafficDi
     }
 }
 
+/// Returns the MAC address of the remote host, given the source and destination MAC addresses
+pub fn get_mac_to_lookup(
+    mac_addresses: &(Option<String>, Option<String>),
+    traffic_direction: TrafficDirection,
+) -> Option<String> {
+    match traffic_direction {
+        TrafficDirection::Outgoing => mac_addresses.1.clone(),
+        TrafficDirection::Incoming => mac_addresses.0.clone(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::net::IpAddr;
 
+    use etherparse::{PacketBuilder, PacketHeaders};
     use pcap::Address;
 
     use crate::networking::manage_packets::{
-        get_traffic_direction, get_traffic_type, is_local_connection, mac_from_dec_to_hex,
+        analyze_headers, get_traffic_direction, get_traffic_type, is_local_connection,
+        log_dns_message, mac_from_dec_to_hex, DNS_RESPONSE_TIMEOUT,
     };
+    use crate::networking::types::address_port_pair::AddressPortPair;
+    use crate::networking::types::dns_message::DnsMessage;
+    use crate::networking::types::icmp_type::IcmpType;
+    use crate::networking::types::packet_filters_fields::PacketFiltersFields;
     use crate::networking::types::traffic_direction::TrafficDirection;
     use crate::networking::types::traffic_type::TrafficType;
+    use crate::{InfoTraffic, Protocol};
 
     #[test]
     fn mac_simple_test() {
@@ -1021,4 +1145,153 @@ fn is_local_connection_ipv6_link_local_test() {
         let result3 = is_local_connection("fe70::8b1:1234:5678:d065", &address_vec);
         assert_eq!(result3, false);
     }
+
+    #[test]
+    fn analyze_headers_interface_test() {
+        let mut packet = Vec::new();
+        PacketBuilder::ethernet2([1; 6], [2; 6])
+            .ipv4([10, 0, 0, 1], [10, 0, 0, 2], 64)
+            .udp(50000, 53)
+            .write(&mut packet, &[0; 10])
+            .unwrap();
+        let key = |interface: &str| {
+            analyze_headers(
+                PacketHeaders::from_ethernet_slice(&packet).unwrap(),
+                &mut (None, None),
+                &mut 0,
+                &mut IcmpType::default(),
+                &mut PacketFiltersFields::default(),
+                interface,
+            )
+            .unwrap()
+        };
+
+        // the same pair observed on two adapters is not merged
+        assert_eq!(key("eth0"), key("eth0"));
+        assert_ne!(key("eth0"), key("tun0"));
+        assert_eq!(key("tun0").interface, "tun0");
+    }
+
+    #[test]
+    fn log_dns_query_and_response_test() {
+        let mut info_traffic = InfoTraffic::new();
+        let query_key = AddressPortPair::new(
+            "192.168.1.10".to_string(),
+            Some(50000),
+            "1.1.1.1".to_string(),
+            Some(53),
+            Protocol::UDP,
+            "eth0".to_string(),
+        );
+        let response_key = AddressPortPair::new(
+            "1.1.1.1".to_string(),
+            Some(53),
+            "192.168.1.10".to_string(),
+            Some(50000),
+            Protocol::UDP,
+            "eth0".to_string(),
+        );
+        let mut query = vec![0xAB, 0xCD, 0x01, 0x00, 0, 1, 0, 0, 0, 0, 0, 0];
+        query.extend_from_slice(b"\x07example\x03com\x00");
+        query.extend_from_slice(&[0, 1, 0, 1]);
+        let mut response = query.clone();
+        response[2] = 0x81;
+        response[3] = 0x83; // NXDOMAIN
+
+        log_dns_message(
+            &mut info_traffic,
+            &query_key,
+            &DnsMessage::parse(&query).unwrap(),
+        );
+        assert_eq!(info_traffic.dns_queries.len(), 1);
+        assert_eq!(info_traffic.dns_queries_pending.len(), 1);
+        assert!(info_traffic.dns_queries[0].response.is_none());
+
+        // a response with a different transaction ID is ignored
+        response[0] = 0x00;
+        log_dns_message(
+            &mut info_traffic,
+            &response_key,
+            &DnsMessage::parse(&response).unwrap(),
+        );
+        assert!(info_traffic.dns_queries[0].response.is_none());
+
+        response[0] = 0xAB;
+        log_dns_message(
+            &mut info_traffic,
+            &response_key,
+            &DnsMessage::parse(&response).unwrap(),
+        );
+        let logged_query = &info_traffic.dns_queries[0];
+        assert_eq!(logged_query.client, "192.168.1.10");
+        assert_eq!(logged_query.server, "1.1.1.1");
+        assert_eq!(logged_query.name, "example.com");
+        assert_eq!(logged_query.get_query_type_str(), "A");
+        assert_eq!(logged_query.get_response_code_str(), "NXDOMAIN");
+        assert!(logged_query.response.as_ref().unwrap().answers.is_empty());
+        assert!(info_traffic.dns_queries_pending.is_empty());
+    }
+
+    #[test]
+    fn log_dns_expired_query_test() {
+        let mut info_traffic = InfoTraffic::new();
+        let query_key = AddressPortPair::new(
+            "192.168.1.10".to_string(),
+            Some(50000),
+            "1.1.1.1".to_string(),
+            Some(53),
+            Protocol::UDP,
+            "eth0".to_string(),
+        );
+        let response_key = AddressPortPair::new(
+            "1.1.1.1".to_string(),
+            Some(53),
+            "192.168.1.10".to_string(),
+            Some(50000),
+            Protocol::UDP,
+            "eth0".to_string(),
+        );
+        let mut query = vec![0xAB, 0xCD, 0x01, 0x00, 0, 1, 0, 0, 0, 0, 0, 0];
+        query.extend_from_slice(b"\x07example\x03com\x00");
+        query.extend_from_slice(&[0, 1, 0, 1]);
+        let mut response = query.clone();
+        response[2] = 0x81;
+        response[3] = 0x80;
+
+        log_dns_message(
+            &mut info_traffic,
+            &query_key,
+            &DnsMessage::parse(&query).unwrap(),
+        );
+        info_traffic.dns_queries[0].timestamp -= chrono::Duration::seconds(DNS_RESPONSE_TIMEOUT);
+        // the first query is not waiting for a response anymore when the second one is logged
+        query[1] = 0xEF;
+        log_dns_message(
+            &mut info_traffic,
+            &query_key,
+            &DnsMessage::parse(&query).unwrap(),
+        );
+        assert_eq!(info_traffic.dns_queries.len(), 2);
+        assert_eq!(info_traffic.dns_queries_pending.len(), 1);
+
+        log_dns_message(
+            &mut info_traffic,
+            &response_key,
+            &DnsMessage::parse(&response).unwrap(),
+        );
+        assert!(info_traffic.dns_queries[0].response.is_none());
+
+        // the second query is still answered after the first one was discarded from the log
+        info_traffic.dns_queries.pop_front();
+        info_traffic.dns_queries_discarded += 1;
+        response[1] = 0xEF;
+        log_dns_message(
+            &mut info_traffic,
+            &response_key,
+            &DnsMessage::parse(&response).unwrap(),
+        );
+        assert_eq!(info_traffic.dns_queries.len(), 1);
+        assert!(info_traffic.dns_queries[0].response.is_some());
+        assert!(info_traffic.dns_queries_pending.is_empty());
+    }
 }
@@ -1,2 +1,3 @@
+pub mod local_names;
 pub mod manage_packets;
 pub mod types;
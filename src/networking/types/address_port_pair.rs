@@ -2,10 +2,12 @@
 
 use std::fmt;
 
+use serde::{Deserialize, Serialize};
+
 use crate::Protocol;
 
 /// Struct representing a network address:port pair.
-#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+#[derive(PartialEq, Eq, Hash, Clone, Debug, Serialize, Deserialize)]
 pub struct AddressPortPair {
     /// Network layer IPv4 or IPv6 source address.
     pub address1: String,
@@ -17,6 +19,10 @@ pub struct AddressPortPair {
     pub port2: Option<u16>,
     ///  Transport layer protocol carried through the associate address:port pair (TCP or UPD).
     pub protocol: Protocol,
+    /// Name of the network adapter through which the packets were captured
+    /// (the same pair can be observed on more than one adapter).
+    #[serde(default)]
+    pub interface: String,
 }
 
 impl AddressPortPair {
@@ -27,12 +33,15 @@ impl AddressPortPair {
     /// * `address` - A string representing the network layer IPv4 or IPv6 address.
     ///
     /// * `port` - An integer representing the transport layer port number (in the range 0..=65535).
+    ///
+    /// * `interface` - The name of the network adapter through which the packets were captured.
     pub fn new(
         address1: String,
         port1: Option<u16>,
         address2: String,
         port2: Option<u16>,
         protocol: Protocol,
+        interface: String,
     ) -> Self {
         AddressPortPair {
             address1,
@@ -40,6 +49,7 @@ pub fn new(
             address2,
             port2,
             protocol,
+            interface,
         }
     }
 }
@@ -1,7 +1,9 @@
 use std::fmt;
 
+use serde::{Deserialize, Serialize};
+
 /// Enum representing the possible observed values of application layer protocol.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 #[allow(clippy::upper_case_acronyms)]
 pub enum AppProtocol {
     /// File Transfer Protocol
@@ -53,6 +55,8 @@ pub enum AppProtocol {
     SSDP,
     /// Extensible Messaging and Presence Protocol |
     XMPP,
+    /// Link-Local Multicast Name Resolution
+    LLMNR,
     /// Not identified
     #[default]
     Unknown,
@@ -108,6 +112,7 @@ pub fn from_port_to_application_protocol(port: Option<u16>) -> AppProtocol {
             1900 => AppProtocol::SSDP,
             5222 => AppProtocol::XMPP,
             5353 => AppProtocol::mDNS,
+            5355 => AppProtocol::LLMNR,
             _ => AppProtocol::Unknown,
         }
     } else {
@@ -129,7 +134,7 @@ fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 }
 
 // impl AppProtocol {
-// pub(crate) const ALL: [AppProtocol; 25] = [
+// pub(crate) const ALL: [AppProtocol; 26] = [
 //     AppProtocol::Unknown,
 //     AppProtocol::BGP,
 //     AppProtocol::DHCP,
@@ -142,6 +147,7 @@ fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 //     AppProtocol::IMAPS,
 //     AppProtocol::LDAP,
 //     AppProtocol::LDAPS,
+//     AppProtocol::LLMNR,
 //     AppProtocol::mDNS,
 //     AppProtocol::NetBIOS,
 //     AppProtocol::NTP,
@@ -2,10 +2,12 @@
 
 use std::ops::AddAssign;
 
+use serde::{Deserialize, Serialize};
+
 use crate::networking::types::traffic_direction::TrafficDirection;
 
 /// Amount of exchanged data (packets and bytes) incoming and outgoing
-#[derive(Clone, Default, Copy)]
+#[derive(Clone, Default, Copy, Serialize, Deserialize)]
 pub struct DataInfo {
     /// Incoming packets
     pub incoming_packets: u128,
@@ -1,10 +1,12 @@
 //! Module defining the `DataInfoHost` struct related to hosts.
 
+use serde::{Deserialize, Serialize};
+
 use crate::networking::types::data_info::DataInfo;
 use crate::networking::types::traffic_type::TrafficType;
 
 /// Host-related information.
-#[derive(Clone, Default)]
+#[derive(Clone, Default, Serialize, Deserialize)]
 pub struct DataInfoHost {
     /// Incoming and outgoing packets and bytes
     pub data_info: DataInfo,
@@ -16,4 +18,6 @@ pub struct DataInfoHost {
     pub is_local: bool,
     /// Determine if the connection with this host is unicast, multicast, or broadcast
     pub traffic_type: TrafficType,
+    /// MAC address of the host (only available for hosts in the local network)
+    pub mac_address: Option<String>,
 }
@@ -0,0 +1,263 @@
+//! Module defining the `DnsMessage` struct, used to parse the content of DNS-like messages
+//! (DNS, mDNS, LLMNR, and NetBIOS name service share the same wire format).
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// Resource record type of IPv4 addresses
+pub const RR_TYPE_A: u16 = 1;
+/// Resource record type of name servers
+const RR_TYPE_NS: u16 = 2;
+/// Resource record type of canonical names
+const RR_TYPE_CNAME: u16 = 5;
+/// Resource record type of domain name pointers
+const RR_TYPE_PTR: u16 = 12;
+/// Resource record type of IPv6 addresses
+pub const RR_TYPE_AAAA: u16 = 28;
+/// Resource record type of NetBIOS general name service records
+pub const RR_TYPE_NB: u16 = 32;
+
+/// Parsed DNS-like message
+pub struct DnsMessage<'a> {
+    /// Transaction identifier, used to match queries and responses
+    pub id: u16,
+    /// Whether this message is a response
+    pub is_response: bool,
+    /// Response code (RCODE)
+    pub response_code: u8,
+    /// Names and types of the questions
+    pub questions: Vec<(String, u16)>,
+    /// Records of the answer section
+    pub answers: Vec<ResourceRecord<'a>>,
+    /// Records of the authority and additional sections
+    pub other_records: Vec<ResourceRecord<'a>>,
+}
+
+/// Resource record contained in a DNS-like message
+pub struct ResourceRecord<'a> {
+    /// Owner name of the record
+    pub name: String,
+    /// Type of the record
+    pub rr_type: u16,
+    /// Raw record data
+    pub data: &'a [u8],
+    /// Domain name carried by the record data (for NS, CNAME, and PTR records)
+    pub data_name: Option<String>,
+}
+
+impl<'a> DnsMessage<'a> {
+    /// Parses the given message, returning `None` if it's malformed or truncated.
+    pub fn parse(message: &'a [u8]) -> Option<Self> {
+        let read_u16 = |pos: usize| -> Option<u16> {
+            Some(u16::from_be_bytes(
+                message.get(pos..pos + 2)?.try_into().ok()?,
+            ))
+        };
+
+        let id = read_u16(0)?;
+        let flags = read_u16(2)?;
+        let questions_count = read_u16(4)?;
+        let answers_count = read_u16(6)? as usize;
+        let other_count = read_u16(8)? as usize + read_u16(10)? as usize;
+
+        let mut pos = 12;
+        let mut questions = Vec::with_capacity(questions_count.into());
+        for _ in 0..questions_count {
+            let (name, name_end) = read_name(message, pos)?;
+            questions.push((name, read_u16(name_end)?));
+            // skip type and class
+            pos = name_end + 4;
+        }
+
+        let mut records = Vec::with_capacity(answers_count + other_count);
+        for _ in 0..answers_count + other_count {
+            let (name, name_end) = read_name(message, pos)?;
+            let rr_type = read_u16(name_end)?;
+            let data_len = read_u16(name_end + 8)? as usize;
+            let data_start = name_end + 10;
+            let data = message.get(data_start..data_start + data_len)?;
+            let data_name = if [RR_TYPE_NS, RR_TYPE_CNAME, RR_TYPE_PTR].contains(&rr_type) {
+                read_name(message, data_start).map(|(name, _)| name)
+            } else {
+                None
+            };
+            records.push(ResourceRecord {
+                name,
+                rr_type,
+                data,
+                data_name,
+            });
+            pos = data_start + data_len;
+        }
+        let other_records = records.split_off(answers_count);
+        #[allow(clippy::cast_possible_truncation)]
+        let response_code = (flags & 0x000F) as u8;
+
+        Some(DnsMessage {
+            id,
+            is_response: flags & 0x8000 != 0,
+            response_code,
+            questions,
+            answers: records,
+            other_records,
+        })
+    }
+}
+
+impl ResourceRecord<'_> {
+    /// Returns the IP address carried by A and AAAA records
+    pub fn ip_address(&self) -> Option<IpAddr> {
+        match self.rr_type {
+            RR_TYPE_A => {
+                let octets: [u8; 4] = self.data.try_into().ok()?;
+                Some(IpAddr::V4(Ipv4Addr::from(octets)))
+            }
+            RR_TYPE_AAAA => {
+                let octets: [u8; 16] = self.data.try_into().ok()?;
+                Some(IpAddr::V6(Ipv6Addr::from(octets)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns a human-readable representation of the record data
+    pub fn value_string(&self) -> String {
+        if let Some(address) = self.ip_address() {
+            address.to_string()
+        } else if let Some(name) = &self.data_name {
+            name.clone()
+        } else {
+            format!(
+                "{} ({} B)",
+                get_record_type_str(self.rr_type),
+                self.data.len()
+            )
+        }
+    }
+}
+
+/// Returns the mnemonic of the given resource record type
+pub fn get_record_type_str(rr_type: u16) -> String {
+    match rr_type {
+        RR_TYPE_A => "A".to_string(),
+        RR_TYPE_NS => "NS".to_string(),
+        RR_TYPE_CNAME => "CNAME".to_string(),
+        6 => "SOA".to_string(),
+        RR_TYPE_PTR => "PTR".to_string(),
+        15 => "MX".to_string(),
+        16 => "TXT".to_string(),
+        RR_TYPE_AAAA => "AAAA".to_string(),
+        33 => "SRV".to_string(),
+        64 => "SVCB".to_string(),
+        65 => "HTTPS".to_string(),
+        255 => "ANY".to_string(),
+        _ => format!("TYPE{rr_type}"),
+    }
+}
+
+/// Returns the mnemonic of the given response code
+pub fn get_response_code_str(response_code: u8) -> String {
+    match response_code {
+        0 => "NOERROR".to_string(),
+        1 => "FORMERR".to_string(),
+        2 => "SERVFAIL".to_string(),
+        3 => "NXDOMAIN".to_string(),
+        4 => "NOTIMP".to_string(),
+        5 => "REFUSED".to_string(),
+        _ => format!("RCODE{response_code}"),
+    }
+}
+
+/// Reads a (possibly compressed) domain name starting at the given position.
+///
+/// Returns the name and the position immediately after it.
+fn read_name(message: &[u8], mut pos: usize) -> Option<(String, usize)> {
+    let mut labels = Vec::new();
+    let mut end = None;
+    let mut jumps = 0;
+    loop {
+        let len = *message.get(pos)? as usize;
+        if len == 0 {
+            pos += 1;
+            break;
+        }
+        if len & 0xC0 == 0xC0 {
+            // compression pointer: limit the number of jumps to avoid loops
+            jumps += 1;
+            if jumps > 16 {
+                return None;
+            }
+            let offset = ((len & 0x3F) << 8) | *message.get(pos + 1)? as usize;
+            end.get_or_insert(pos + 2);
+            pos = offset;
+            continue;
+        }
+        let label = message.get(pos + 1..pos + 1 + len)?;
+        labels.push(String::from_utf8_lossy(label).to_string());
+        pos += 1 + len;
+    }
+    Some((labels.join("."), end.unwrap_or(pos)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_query() {
+        let mut message = vec![0x12, 0x34, 0x01, 0x00, 0, 1, 0, 0, 0, 0, 0, 0];
+        message.extend_from_slice(b"\x07example\x03com\x00");
+        message.extend_from_slice(&[0, 28, 0, 1]);
+
+        let dns_message = DnsMessage::parse(&message).unwrap();
+        assert_eq!(dns_message.id, 0x1234);
+        assert!(!dns_message.is_response);
+        assert_eq!(
+            dns_message.questions,
+            vec![("example.com".to_string(), RR_TYPE_AAAA)]
+        );
+        assert!(dns_message.answers.is_empty());
+    }
+
+    #[test]
+    fn test_parse_response_with_compression() {
+        let mut message = vec![0x12, 0x34, 0x81, 0x80, 0, 1, 0, 2, 0, 0, 0, 0];
+        message.extend_from_slice(b"\x03www\x07example\x03com\x00");
+        message.extend_from_slice(&[0, 1, 0, 1]);
+        // CNAME pointing to "example.com" (offset 16)
+        message.extend_from_slice(&[0xC0, 12, 0, 5, 0, 1, 0, 0, 0, 60, 0, 2, 0xC0, 16]);
+        message.extend_from_slice(&[0xC0, 16, 0, 1, 0, 1, 0, 0, 0, 60, 0, 4, 93, 184, 216, 34]);
+
+        let dns_message = DnsMessage::parse(&message).unwrap();
+        assert!(dns_message.is_response);
+        assert_eq!(dns_message.response_code, 0);
+        let answers: Vec<String> = dns_message
+            .answers
+            .iter()
+            .map(ResourceRecord::value_string)
+            .collect();
+        assert_eq!(answers, vec!["example.com", "93.184.216.34"]);
+        assert_eq!(dns_message.answers[1].name, "example.com");
+    }
+
+    #[test]
+    fn test_parse_malformed() {
+        // truncated header
+        assert!(DnsMessage::parse(&[0, 1, 2]).is_none());
+        // pointer loop
+        let mut message = vec![0, 0, 0x84, 0, 0, 0, 0, 1, 0, 0, 0, 0];
+        message.extend_from_slice(&[0xC0, 12, 0, 1, 0, 1, 0, 0, 0, 120, 0, 4, 1, 2, 3, 4]);
+        assert!(DnsMessage::parse(&message).is_none());
+        // record data exceeding the message length
+        let mut message = vec![0, 0, 0x84, 0, 0, 0, 0, 1, 0, 0, 0, 0];
+        message.extend_from_slice(&[0, 0, 1, 0, 1, 0, 0, 0, 120, 0, 4, 1, 2]);
+        assert!(DnsMessage::parse(&message).is_none());
+    }
+
+    #[test]
+    fn test_mnemonics() {
+        assert_eq!(get_record_type_str(RR_TYPE_AAAA), "AAAA");
+        assert_eq!(get_record_type_str(1234), "TYPE1234");
+        assert_eq!(get_response_code_str(3), "NXDOMAIN");
+        assert_eq!(get_response_code_str(9), "RCODE9");
+    }
+}
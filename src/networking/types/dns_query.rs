@@ -0,0 +1,97 @@
+//! Module defining the `DnsQuery` struct, representing an entry of the DNS queries log.
+
+use std::fmt;
+
+use chrono::{DateTime, Duration, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::networking::types::dns_message::{get_record_type_str, get_response_code_str};
+
+/// A DNS query observed in the sniffed traffic, with its response (if any)
+#[derive(Clone, Serialize, Deserialize)]
+pub struct DnsQuery {
+    /// Time at which the query was observed
+    pub timestamp: DateTime<Local>,
+    /// Address of the host performing the query
+    pub client: String,
+    /// Address of the DNS server
+    pub server: String,
+    /// Queried name
+    pub name: String,
+    /// Queried record type
+    pub query_type: u16,
+    /// Response to this query; `None` if not observed yet
+    pub response: Option<DnsResponse>,
+}
+
+/// Response to a DNS query
+#[derive(Clone, Serialize, Deserialize)]
+pub struct DnsResponse {
+    /// Response code (RCODE)
+    pub response_code: u8,
+    /// Values of the records contained in the answer section
+    pub answers: Vec<String>,
+    /// Time elapsed between the query and the response
+    #[serde(with = "latency_microseconds")]
+    pub latency: Duration,
+}
+
+/// (De)serializes the latency of DNS responses as a number of microseconds
+mod latency_microseconds {
+    use chrono::Duration;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(latency: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(latency.num_microseconds().unwrap_or_default())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        Ok(Duration::microseconds(i64::deserialize(deserializer)?))
+    }
+}
+
+impl DnsQuery {
+    pub fn get_query_type_str(&self) -> String {
+        get_record_type_str(self.query_type)
+    }
+
+    /// Returns the response code mnemonic, or "-" if the response is missing
+    pub fn get_response_code_str(&self) -> String {
+        self.response.as_ref().map_or("-".to_string(), |response| {
+            get_response_code_str(response.response_code)
+        })
+    }
+}
+
+impl fmt::Display for DnsQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let client = if self.client.len() > 24 {
+            format!("{}…", &self.client[..23])
+        } else {
+            self.client.clone()
+        };
+        let (latency, answers) = match &self.response {
+            Some(response) => {
+                #[allow(clippy::cast_precision_loss)]
+                let millis =
+                    response.latency.num_microseconds().unwrap_or_default() as f64 / 1000.0;
+                (
+                    format!("{millis:.1} ms"),
+                    response.answers.len().to_string(),
+                )
+            }
+            None => ("-".to_string(), "-".to_string()),
+        };
+        write!(
+            f,
+            "{:<12}  {:<24}  {:<6}{:<9}{:>10}{:>9}   {}",
+            self.timestamp.format("%H:%M:%S%.3f"),
+            client,
+            self.get_query_type_str(),
+            self.get_response_code_str(),
+            latency,
+            answers,
+            self.name
+        )
+    }
+}
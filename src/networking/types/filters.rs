@@ -2,13 +2,15 @@
 
 use std::collections::HashSet;
 
+use serde::{Deserialize, Serialize};
+
 use crate::networking::types::ip_collection::AddressCollection;
 use crate::networking::types::packet_filters_fields::PacketFiltersFields;
 use crate::networking::types::port_collection::PortCollection;
 use crate::{IpVersion, Protocol};
 
 /// Possible filters applicable to network traffic
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Filters {
     /// Internet Protocol versions
     pub ip_versions: HashSet<IpVersion>,
@@ -2,8 +2,9 @@
 use std::fmt::{Display, Formatter};
 
 use etherparse::{Icmpv4Type, Icmpv6Type};
+use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum IcmpType {
     V4(IcmpTypeV4),
     V6(IcmpTypeV6),
@@ -46,7 +47,7 @@ fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
     }
 }
 
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 #[allow(clippy::module_name_repetitions)]
 pub enum IcmpTypeV4 {
     EchoReply,
@@ -162,7 +163,7 @@ fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
     }
 }
 
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 #[allow(clippy::module_name_repetitions)]
 pub enum IcmpTypeV6 {
     DestinationUnreachable,
@@ -5,6 +5,7 @@
 use std::fmt;
 
 use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
 
 use crate::networking::types::icmp_type::IcmpType;
 use crate::networking::types::traffic_direction::TrafficDirection;
@@ -14,7 +15,7 @@
 /// Struct useful to format the output report file and to keep track of statistics about the sniffed traffic.
 ///
 /// Each `InfoAddressPortPair` struct is associated to a single address:port pair.
-#[derive(Clone, Default)]
+#[derive(Clone, Default, Serialize, Deserialize)]
 pub struct InfoAddressPortPair {
     /// Source MAC address
     pub mac_address1: Option<String>,
@@ -34,6 +35,8 @@ pub struct InfoAddressPortPair {
     pub traffic_direction: TrafficDirection,
     /// Types of the ICMP messages exchanged, with the relative count (this is empty if not ICMP)
     pub icmp_types: HashMap<IcmpType, usize>,
+    /// Name of the network adapter on which the connection was observed
+    pub interface: String,
 }
 
 impl fmt::Display for InfoAddressPortPair {
@@ -1,17 +1,23 @@
 //! Module defining the `ReportInfo` struct, useful to format the output report file and
 //! to keep track of statistics about the sniffed traffic.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 
+use serde::{Deserialize, Serialize};
+
+use crate::blocklists::types::blocklist_match::BlocklistMatch;
 use crate::networking::types::address_port_pair::AddressPortPair;
 use crate::networking::types::data_info::DataInfo;
 use crate::networking::types::data_info_host::DataInfoHost;
+use crate::networking::types::dns_query::DnsQuery;
 use crate::networking::types::host::Host;
 use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
+use crate::networking::types::scan_detector::ScanDetector;
 use crate::networking::types::traffic_direction::TrafficDirection;
 use crate::AppProtocol;
 
 /// Struct to be shared between the threads in charge of parsing packets and update reports.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct InfoTraffic {
     /// Total amount of filtered bytes received.
     pub tot_received_bytes: u128,
@@ -27,20 +33,43 @@ pub struct InfoTraffic {
     pub all_bytes: u128,
     /// Number of dropped packets
     pub dropped_packets: u32,
+    /// Map of the network adapters with all the data they exchanged (including packets not filtered)
+    pub adapters: HashMap<String, DataInfo>,
     /// Map of the filtered traffic
     pub map: HashMap<AddressPortPair, InfoAddressPortPair>,
     /// Collection of the favorite hosts
     pub favorite_hosts: HashSet<Host>,
     /// Collection of favorite hosts that exchanged data in the last interval
+    #[serde(skip)]
     pub favorites_last_interval: HashSet<Host>,
     /// Map of the application layer protocols with their data info
     pub app_protocols: HashMap<AppProtocol, DataInfo>,
     /// Map of the addresses waiting for a rDNS resolution; used to NOT send multiple rDNS for the same address
+    #[serde(skip)]
     pub addresses_waiting_resolution: HashMap<String, DataInfo>,
     /// Map of the resolved addresses with their full rDNS value and the corresponding host
     pub addresses_resolved: HashMap<String, (String, Host)>,
     /// Map of the hosts with their data info
     pub hosts: HashMap<Host, DataInfoHost>,
+    /// Map of the addresses with the names announced via DHCP, mDNS, LLMNR, or NetBIOS
+    pub local_names: HashMap<String, String>,
+    /// Log of the observed DNS queries, in chronological order (only the most recent ones are kept)
+    pub dns_queries: VecDeque<DnsQuery>,
+    /// Number of DNS queries discarded from the beginning of the log
+    #[serde(skip)]
+    pub dns_queries_discarded: usize,
+    /// Map of the DNS queries waiting for a response (client address, client port, and transaction ID),
+    /// with the corresponding position in the log (discarded queries included)
+    #[serde(skip)]
+    pub dns_queries_pending: HashMap<(String, Option<u16>, u16), usize>,
+    /// Map of the remote addresses matching a blocklist
+    pub blocklisted: HashMap<String, BlocklistMatch>,
+    /// Remote addresses which matched a blocklist in the last interval
+    #[serde(skip)]
+    pub blocklisted_last_interval: Vec<BlocklistMatch>,
+    /// Connection attempts used to detect port scans, host sweeps, and SYN floods
+    #[serde(skip)]
+    pub scan_detector: ScanDetector,
 }
 
 impl InfoTraffic {
@@ -54,6 +83,7 @@ pub fn new() -> Self {
             all_packets: 0,
             all_bytes: 0,
             dropped_packets: 0,
+            adapters: HashMap::new(),
             map: HashMap::new(),
             favorite_hosts: HashSet::new(),
             favorites_last_interval: HashSet::new(),
@@ -61,6 +91,13 @@ pub fn new() -> Self {
             addresses_waiting_resolution: HashMap::new(),
             addresses_resolved: HashMap::new(),
             hosts: HashMap::new(),
+            local_names: HashMap::new(),
+            dns_queries: VecDeque::new(),
+            dns_queries_discarded: 0,
+            dns_queries_pending: HashMap::new(),
+            blocklisted: HashMap::new(),
+            blocklisted_last_interval: Vec::new(),
+            scan_detector: ScanDetector::default(),
         }
     }
 
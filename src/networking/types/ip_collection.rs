@@ -2,7 +2,9 @@
 use std::ops::RangeInclusive;
 use std::str::FromStr;
 
-#[derive(Debug, Eq, PartialEq, Clone)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize)]
 pub(crate) struct AddressCollection {
     pub(crate) ips: Vec<IpAddr>,
     pub(crate) ranges: Vec<RangeInclusive<IpAddr>>,
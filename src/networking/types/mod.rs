@@ -4,6 +4,8 @@
 pub mod byte_multiple;
 pub mod data_info;
 pub mod data_info_host;
+pub mod dns_message;
+pub mod dns_query;
 pub mod filters;
 pub mod host;
 pub mod icmp_type;
@@ -16,6 +18,8 @@
 pub mod packet_filters_fields;
 pub mod port_collection;
 pub mod protocol;
+pub mod scan_detector;
+pub mod scan_probe;
 pub mod search_parameters;
 pub mod traffic_direction;
 pub mod traffic_type;
@@ -1,7 +1,9 @@
 use std::ops::RangeInclusive;
 use std::str::FromStr;
 
-#[derive(Debug, Eq, PartialEq, Clone)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize)]
 pub(crate) struct PortCollection {
     pub(crate) ports: Vec<u16>,
     pub(crate) ranges: Vec<RangeInclusive<u16>>,
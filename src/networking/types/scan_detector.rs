@@ -0,0 +1,495 @@
+//! Module defining the `ScanDetector` struct, used to detect port scans, host sweeps,
+//! and SYN floods from the connection attempts observed.
+
+use std::collections::{HashMap, VecDeque};
+
+use chrono::{DateTime, Duration, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::networking::types::scan_probe::{ProbeKind, ScanProbe};
+use crate::Protocol;
+
+/// Seconds after which a connection attempt without reply is considered unanswered
+const REPLY_TIMEOUT: i64 = 3;
+/// Seconds during which failed connection attempts are taken into account
+const WINDOW: i64 = 60;
+/// Distinct ports of the same host to be probed unsuccessfully to detect a port scan
+const PORT_SCAN_PORTS: usize = 20;
+/// Distinct hosts to be probed unsuccessfully on the same port to detect a host sweep
+const HOST_SWEEP_HOSTS: usize = 20;
+/// Unanswered SYNs sent to the same port of a host, from any source, to detect a SYN flood
+const SYN_FLOOD_ATTEMPTS: usize = 100;
+/// Maximum number of connection attempts waiting for a reply
+const MAX_PENDING: usize = 10_000;
+/// Maximum number of failed connection attempts kept in the window
+const MAX_FAILED: usize = 10_000;
+
+/// Kind of scan detected
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+pub enum ScanKind {
+    /// Many ports probed on one host
+    PortScan,
+    /// One port probed on many hosts
+    HostSweep,
+    /// Many unanswered SYNs sent to a port of one host, possibly from spoofed sources
+    SynFlood,
+}
+
+/// Scan detected in the last window
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct ScanDetection {
+    pub kind: ScanKind,
+    /// Address of the host performing the scan
+    /// (for SYN floods, the source which sent most of the unanswered SYNs)
+    pub offender: String,
+    /// Host probed (port scans), port probed (host sweeps), or host and port flooded (SYN floods)
+    pub target: String,
+    /// Number of ports (port scans), hosts (host sweeps), or unanswered SYNs (SYN floods)
+    pub count: usize,
+    /// Time of the first failed probe considered
+    pub start: DateTime<Local>,
+    /// Time of the last failed probe considered
+    pub end: DateTime<Local>,
+    /// Number of distinct sources involved (more than one only for SYN floods)
+    #[serde(default)]
+    pub sources: usize,
+}
+
+impl ScanDetection {
+    /// Returns the offender, followed by the number of other sources involved (if any)
+    pub fn offenders(&self) -> String {
+        if self.sources > 1 {
+            format!("{} (+{})", self.offender, self.sources - 1)
+        } else {
+            self.offender.clone()
+        }
+    }
+}
+
+/// Connection attempt which was refused or not answered
+#[derive(Clone)]
+struct FailedProbe {
+    offender: String,
+    target: String,
+    port: u16,
+    protocol: Protocol,
+    attempts: usize,
+    unanswered: bool,
+    timestamp: DateTime<Local>,
+}
+
+/// Keeps track of the connection attempts and of their outcome
+#[derive(Clone, Default)]
+pub struct ScanDetector {
+    /// Connection attempts waiting for a reply (offender, target, and port),
+    /// with the time of the first attempt and the number of attempts
+    pending: HashMap<(String, String, u16), (DateTime<Local>, usize)>,
+    /// Connection attempts which failed during the last window, oldest first
+    failed: VecDeque<FailedProbe>,
+    /// Scans already notified, with the last time they were observed
+    reported: HashMap<(ScanKind, String, String), DateTime<Local>>,
+}
+
+impl ScanDetector {
+    /// Records a connection attempt or a reply to it
+    pub fn record(&mut self, probe: ScanProbe, now: DateTime<Local>) {
+        let ScanProbe {
+            kind,
+            offender,
+            target,
+            port,
+            protocol,
+        } = probe;
+        let key = (offender, target, port);
+        match kind {
+            ProbeKind::Attempt => {
+                if let Some(attempt) = self.pending.get_mut(&key) {
+                    attempt.1 += 1;
+                } else if self.pending.len() < MAX_PENDING {
+                    self.pending.insert(key, (now, 1));
+                }
+            }
+            ProbeKind::Accepted => {
+                self.pending.remove(&key);
+            }
+            ProbeKind::Refused => {
+                let (timestamp, attempts) = match self.pending.remove(&key) {
+                    Some(attempt) => attempt,
+                    // UDP probes are only known when refused
+                    None if protocol.eq(&Protocol::UDP) => (now, 1),
+                    // resets not related to a connection attempt
+                    None => return,
+                };
+                let (offender, target, port) = key;
+                self.push_failed(FailedProbe {
+                    offender,
+                    target,
+                    port,
+                    protocol,
+                    attempts,
+                    unanswered: false,
+                    timestamp,
+                });
+            }
+        }
+    }
+
+    /// Returns the scans observed in the last window which weren't notified yet
+    pub fn detect(&mut self, now: DateTime<Local>) -> Vec<ScanDetection> {
+        let reply_deadline = now - Duration::seconds(REPLY_TIMEOUT);
+        let window_start = now - Duration::seconds(WINDOW);
+
+        let (timed_out, pending): (HashMap<_, _>, HashMap<_, _>) =
+            std::mem::take(&mut self.pending)
+                .into_iter()
+                .partition(|(_, (timestamp, _))| *timestamp < reply_deadline);
+        self.pending = pending;
+        let mut timed_out: Vec<_> = timed_out.into_iter().collect();
+        timed_out.sort_by_key(|(_, (timestamp, _))| *timestamp);
+        for ((offender, target, port), (timestamp, attempts)) in timed_out {
+            self.push_failed(FailedProbe {
+                offender,
+                target,
+                port,
+                protocol: Protocol::TCP,
+                attempts,
+                unanswered: true,
+                timestamp,
+            });
+        }
+        self.failed.retain(|probe| probe.timestamp >= window_start);
+        self.reported
+            .retain(|_, last_seen| *last_seen >= window_start);
+
+        // ports probed on each host, hosts probed on each port,
+        // and sources of the attempts to each port of each host
+        let mut ports: HashMap<(&str, &str), Aggregate<(Protocol, u16)>> = HashMap::new();
+        let mut hosts: HashMap<(&str, Protocol, u16), Aggregate<&str>> = HashMap::new();
+        let mut floods: HashMap<(&str, Protocol, u16), Aggregate<&str>> = HashMap::new();
+        for probe in &self.failed {
+            ports
+                .entry((probe.offender.as_str(), probe.target.as_str()))
+                .or_insert_with(|| Aggregate::new(probe.timestamp))
+                .add((probe.protocol, probe.port), probe);
+            hosts
+                .entry((probe.offender.as_str(), probe.protocol, probe.port))
+                .or_insert_with(|| Aggregate::new(probe.timestamp))
+                .add(probe.target.as_str(), probe);
+            if probe.unanswered {
+                floods
+                    .entry((probe.target.as_str(), probe.protocol, probe.port))
+                    .or_insert_with(|| Aggregate::new(probe.timestamp))
+                    .add(probe.offender.as_str(), probe);
+            }
+        }
+
+        let mut candidates = Vec::new();
+        for ((offender, target), aggregate) in ports {
+            if aggregate.items.len() >= PORT_SCAN_PORTS {
+                candidates.push(ScanDetection {
+                    kind: ScanKind::PortScan,
+                    offender: offender.to_string(),
+                    target: target.to_string(),
+                    count: aggregate.items.len(),
+                    start: aggregate.start,
+                    end: aggregate.end,
+                    sources: 1,
+                });
+            }
+        }
+        for ((offender, protocol, port), aggregate) in hosts {
+            if aggregate.items.len() >= HOST_SWEEP_HOSTS {
+                candidates.push(ScanDetection {
+                    kind: ScanKind::HostSweep,
+                    offender: offender.to_string(),
+                    target: format!("{protocol} {port}"),
+                    count: aggregate.items.len(),
+                    start: aggregate.start,
+                    end: aggregate.end,
+                    sources: 1,
+                });
+            }
+        }
+        for ((target, protocol, port), aggregate) in floods {
+            if aggregate.unanswered >= SYN_FLOOD_ATTEMPTS {
+                // the source which sent most SYNs (the lowest address among equals)
+                let offender = aggregate
+                    .items
+                    .iter()
+                    .max_by(|(a, a_attempts), (b, b_attempts)| {
+                        a_attempts.cmp(b_attempts).then(b.cmp(a))
+                    })
+                    .map(|(offender, _)| (*offender).to_string())
+                    .unwrap_or_default();
+                candidates.push(ScanDetection {
+                    kind: ScanKind::SynFlood,
+                    offender,
+                    target: format!("{target} {protocol} {port}"),
+                    count: aggregate.unanswered,
+                    start: aggregate.start,
+                    end: aggregate.end,
+                    sources: aggregate.items.len(),
+                });
+            }
+        }
+
+        // ongoing scans are notified only once
+        let mut detections = Vec::new();
+        for detection in candidates {
+            // the sources of a SYN flood may change while it is ongoing
+            let offender = if detection.kind.eq(&ScanKind::SynFlood) {
+                String::new()
+            } else {
+                detection.offender.clone()
+            };
+            let key = (detection.kind, offender, detection.target.clone());
+            if self.reported.insert(key, detection.end).is_none() {
+                detections.push(detection);
+            }
+        }
+        detections.sort_by_key(|detection| detection.start);
+        detections
+    }
+
+    /// Adds a failed connection attempt, discarding the oldest one if too many are kept
+    fn push_failed(&mut self, probe: FailedProbe) {
+        if self.failed.len() >= MAX_FAILED {
+            self.failed.pop_front();
+        }
+        self.failed.push_back(probe);
+    }
+}
+
+/// Failed probes grouped by offender and target (or port), or by target and port
+struct Aggregate<T> {
+    /// Items observed, with the number of unanswered attempts involving each of them
+    items: HashMap<T, usize>,
+    unanswered: usize,
+    start: DateTime<Local>,
+    end: DateTime<Local>,
+}
+
+impl<T: Eq + std::hash::Hash> Aggregate<T> {
+    fn new(timestamp: DateTime<Local>) -> Self {
+        Aggregate {
+            items: HashMap::new(),
+            unanswered: 0,
+            start: timestamp,
+            end: timestamp,
+        }
+    }
+
+    fn add(&mut self, item: T, probe: &FailedProbe) {
+        let unanswered = self.items.entry(item).or_default();
+        if probe.unanswered {
+            *unanswered += probe.attempts;
+            self.unanswered += probe.attempts;
+        }
+        self.start = self.start.min(probe.timestamp);
+        self.end = self.end.max(probe.timestamp);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn probe(kind: ProbeKind, offender: &str, target: &str, port: u16) -> ScanProbe {
+        ScanProbe {
+            kind,
+            offender: offender.to_string(),
+            target: target.to_string(),
+            port,
+            protocol: Protocol::TCP,
+        }
+    }
+
+    #[test]
+    fn test_port_scan() {
+        let mut detector = ScanDetector::default();
+        let start = Local::now();
+        for port in 1..=25 {
+            let now = start + Duration::milliseconds(i64::from(port) * 100);
+            detector.record(probe(ProbeKind::Attempt, "10.0.0.9", "10.0.0.2", port), now);
+            // open ports reply with a SYN-ACK, closed ports with a RST
+            let reply = if port == 22 {
+                ProbeKind::Accepted
+            } else {
+                ProbeKind::Refused
+            };
+            detector.record(probe(reply, "10.0.0.9", "10.0.0.2", port), now);
+        }
+        let detections = detector.detect(start + Duration::seconds(5));
+        assert_eq!(
+            detections,
+            vec![ScanDetection {
+                kind: ScanKind::PortScan,
+                offender: "10.0.0.9".to_string(),
+                target: "10.0.0.2".to_string(),
+                count: 24,
+                start: start + Duration::milliseconds(100),
+                end: start + Duration::milliseconds(2500),
+                sources: 1,
+            }]
+        );
+
+        // the same scan is not notified again while ongoing...
+        detector.record(
+            probe(ProbeKind::Attempt, "10.0.0.9", "10.0.0.2", 26),
+            start + Duration::seconds(6),
+        );
+        assert!(detector.detect(start + Duration::seconds(10)).is_empty());
+        // ...but it is after a window without probes
+        let later = start + Duration::seconds(100);
+        for port in 100..120 {
+            detector.record(
+                probe(ProbeKind::Attempt, "10.0.0.9", "10.0.0.2", port),
+                later,
+            );
+            detector.record(
+                probe(ProbeKind::Refused, "10.0.0.9", "10.0.0.2", port),
+                later,
+            );
+        }
+        assert_eq!(detector.detect(later + Duration::seconds(1)).len(), 1);
+    }
+
+    #[test]
+    fn test_host_sweep() {
+        let mut detector = ScanDetector::default();
+        let now = Local::now();
+        for host in 1..=30 {
+            let target = format!("192.168.1.{host}");
+            if host <= 10 {
+                detector.record(
+                    ScanProbe {
+                        protocol: Protocol::UDP,
+                        ..probe(ProbeKind::Refused, "192.168.1.50", &target, 161)
+                    },
+                    now,
+                );
+            }
+            // most hosts don't reply at all
+            detector.record(probe(ProbeKind::Attempt, "192.168.1.50", &target, 445), now);
+        }
+        let detections = detector.detect(now + Duration::seconds(4));
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].kind, ScanKind::HostSweep);
+        assert_eq!(detections[0].target, "TCP 445");
+        assert_eq!(detections[0].count, 30);
+    }
+
+    #[test]
+    fn test_syn_flood() {
+        let mut detector = ScanDetector::default();
+        let now = Local::now();
+        for _ in 0..150 {
+            detector.record(
+                probe(ProbeKind::Attempt, "203.0.113.4", "10.0.0.2", 80),
+                now,
+            );
+        }
+        // not unanswered yet
+        assert!(detector.detect(now + Duration::seconds(1)).is_empty());
+        let detections = detector.detect(now + Duration::seconds(4));
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].kind, ScanKind::SynFlood);
+        assert_eq!(detections[0].offender, "203.0.113.4");
+        assert_eq!(detections[0].target, "10.0.0.2 TCP 80");
+        assert_eq!(detections[0].count, 150);
+        assert_eq!(detections[0].sources, 1);
+    }
+
+    #[test]
+    fn test_syn_flood_from_spoofed_sources() {
+        let mut detector = ScanDetector::default();
+        let now = Local::now();
+        // a single SYN from each random source
+        for source in 0..120 {
+            let offender = format!("198.51.{}.{}", source / 256, source % 256);
+            detector.record(probe(ProbeKind::Attempt, &offender, "10.0.0.2", 443), now);
+        }
+        detector.record(
+            probe(ProbeKind::Attempt, "198.51.0.7", "10.0.0.2", 443),
+            now,
+        );
+        let detections = detector.detect(now + Duration::seconds(4));
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].kind, ScanKind::SynFlood);
+        assert_eq!(detections[0].offender, "198.51.0.7");
+        assert_eq!(detections[0].target, "10.0.0.2 TCP 443");
+        assert_eq!(detections[0].count, 121);
+        assert_eq!(detections[0].sources, 120);
+
+        // the flood is notified only once, even if the sources change
+        for source in 0..120 {
+            let offender = format!("192.0.2.{source}");
+            detector.record(
+                probe(ProbeKind::Attempt, &offender, "10.0.0.2", 443),
+                now + Duration::seconds(5),
+            );
+        }
+        assert!(detector.detect(now + Duration::seconds(10)).is_empty());
+    }
+
+    #[test]
+    fn test_tracked_probes_are_bounded() {
+        let mut detector = ScanDetector::default();
+        let now = Local::now();
+        for source in 0..MAX_PENDING + 500 {
+            let offender = format!(
+                "10.{}.{}.{}",
+                source / 65536,
+                source / 256 % 256,
+                source % 256
+            );
+            detector.record(probe(ProbeKind::Attempt, &offender, "10.0.0.2", 80), now);
+        }
+        assert_eq!(detector.pending.len(), MAX_PENDING);
+        // attempts already pending are still counted
+        detector.record(probe(ProbeKind::Attempt, "10.0.0.0", "10.0.0.2", 80), now);
+        assert_eq!(detector.pending.len(), MAX_PENDING);
+
+        let detections = detector.detect(now + Duration::seconds(4));
+        assert_eq!(detections.len(), 1);
+        assert_eq!(detections[0].offender, "10.0.0.0");
+        assert_eq!(detections[0].count, MAX_PENDING + 1);
+        assert!(detector.pending.is_empty());
+        assert_eq!(detector.failed.len(), MAX_FAILED);
+
+        let later = now + Duration::seconds(10);
+        for port in 0..100 {
+            detector.record(
+                probe(ProbeKind::Attempt, "10.0.0.9", "10.0.0.3", port),
+                later,
+            );
+            detector.record(
+                probe(ProbeKind::Refused, "10.0.0.9", "10.0.0.3", port),
+                later,
+            );
+        }
+        assert_eq!(detector.failed.len(), MAX_FAILED);
+        assert_eq!(detector.failed.back().unwrap().target, "10.0.0.3");
+        assert_eq!(detector.detect(later).len(), 1);
+    }
+
+    #[test]
+    fn test_no_detection_for_accepted_connections() {
+        let mut detector = ScanDetector::default();
+        let now = Local::now();
+        // a browser connecting to many hosts
+        for host in 1..=50 {
+            let target = format!("93.184.216.{host}");
+            detector.record(probe(ProbeKind::Attempt, "192.168.1.2", &target, 443), now);
+            detector.record(probe(ProbeKind::Accepted, "192.168.1.2", &target, 443), now);
+        }
+        // resets of established connections
+        detector.record(
+            probe(ProbeKind::Refused, "192.168.1.2", "1.1.1.1", 443),
+            now,
+        );
+        assert!(detector.detect(now + Duration::seconds(10)).is_empty());
+        assert!(detector.pending.is_empty());
+        assert!(detector.failed.is_empty());
+    }
+}
@@ -0,0 +1,190 @@
+//! Module defining the `ScanProbe` struct, representing a packet relevant to the detection
+//! of port scans and host sweeps.
+
+use std::net::IpAddr;
+
+use etherparse::{
+    icmpv4, icmpv6, Icmpv4Type, Icmpv6Type, IpHeader, PacketHeaders, TransportHeader,
+};
+
+use crate::Protocol;
+
+/// Connection attempt, or reply to a connection attempt
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScanProbe {
+    pub kind: ProbeKind,
+    /// Address of the host attempting the connection
+    pub offender: String,
+    /// Address of the host probed
+    pub target: String,
+    /// Port probed
+    pub port: u16,
+    pub protocol: Protocol,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProbeKind {
+    /// TCP SYN
+    Attempt,
+    /// TCP SYN-ACK
+    Accepted,
+    /// TCP RST, or ICMP port unreachable
+    Refused,
+}
+
+impl ScanProbe {
+    /// Extracts the probe carried by a packet, if any
+    pub fn from_headers(headers: &PacketHeaders) -> Option<Self> {
+        let (source, destination) = match &headers.ip {
+            Some(IpHeader::Version4(header, _)) => (
+                IpAddr::from(header.source),
+                IpAddr::from(header.destination),
+            ),
+            Some(IpHeader::Version6(header, _)) => (
+                IpAddr::from(header.source),
+                IpAddr::from(header.destination),
+            ),
+            None => return None,
+        };
+        match &headers.transport {
+            Some(TransportHeader::Tcp(tcp)) if tcp.syn && !tcp.ack => Some(ScanProbe {
+                kind: ProbeKind::Attempt,
+                offender: source.to_string(),
+                target: destination.to_string(),
+                port: tcp.destination_port,
+                protocol: Protocol::TCP,
+            }),
+            Some(TransportHeader::Tcp(tcp)) if tcp.syn || tcp.rst => Some(ScanProbe {
+                kind: if tcp.rst {
+                    ProbeKind::Refused
+                } else {
+                    ProbeKind::Accepted
+                },
+                offender: destination.to_string(),
+                target: source.to_string(),
+                port: tcp.source_port,
+                protocol: Protocol::TCP,
+            }),
+            Some(TransportHeader::Icmpv4(icmp))
+                if matches!(
+                    icmp.icmp_type,
+                    Icmpv4Type::DestinationUnreachable(icmpv4::DestUnreachableHeader::Port)
+                ) =>
+            {
+                from_port_unreachable(headers.payload)
+            }
+            Some(TransportHeader::Icmpv6(icmp))
+                if matches!(
+                    icmp.icmp_type,
+                    Icmpv6Type::DestinationUnreachable(icmpv6::DestUnreachableCode::Port)
+                ) =>
+            {
+                from_port_unreachable(headers.payload)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Builds the probe refused by an ICMP port unreachable message,
+/// based on the header of the datagram embedded in it
+fn from_port_unreachable(payload: &[u8]) -> Option<ScanProbe> {
+    let (source, destination, next_header, transport) = match payload.first()? >> 4 {
+        4 => {
+            let header_len = usize::from(payload[0] & 0x0f) * 4;
+            if header_len < 20 {
+                return None;
+            }
+            let source: [u8; 4] = payload.get(12..16)?.try_into().ok()?;
+            let destination: [u8; 4] = payload.get(16..20)?.try_into().ok()?;
+            (
+                IpAddr::from(source),
+                IpAddr::from(destination),
+                *payload.get(9)?,
+                payload.get(header_len..)?,
+            )
+        }
+        6 => {
+            let source: [u8; 16] = payload.get(8..24)?.try_into().ok()?;
+            let destination: [u8; 16] = payload.get(24..40)?.try_into().ok()?;
+            (
+                IpAddr::from(source),
+                IpAddr::from(destination),
+                *payload.get(6)?,
+                payload.get(40..)?,
+            )
+        }
+        _ => return None,
+    };
+    let protocol = match next_header {
+        6 => Protocol::TCP,
+        17 => Protocol::UDP,
+        _ => return None,
+    };
+    let port = u16::from_be_bytes(transport.get(2..4)?.try_into().ok()?);
+    Some(ScanProbe {
+        kind: ProbeKind::Refused,
+        offender: source.to_string(),
+        target: destination.to_string(),
+        port,
+        protocol,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_port_unreachable_ipv4() {
+        // IPv4 header (20 bytes) + UDP header of the datagram sent by 10.0.0.5 to 192.168.1.2:161
+        let payload = [
+            0x45, 0, 0, 36, 0, 0, 0, 0, 64, 17, 0, 0, 10, 0, 0, 5, 192, 168, 1, 2, 0xc3, 0x50, 0,
+            161, 0, 16, 0, 0,
+        ];
+        assert_eq!(
+            from_port_unreachable(&payload),
+            Some(ScanProbe {
+                kind: ProbeKind::Refused,
+                offender: "10.0.0.5".to_string(),
+                target: "192.168.1.2".to_string(),
+                port: 161,
+                protocol: Protocol::UDP,
+            })
+        );
+        // truncated before the destination port
+        assert_eq!(from_port_unreachable(&payload[..22]), None);
+        // ICMP datagram
+        let mut icmp = payload;
+        icmp[9] = 1;
+        assert_eq!(from_port_unreachable(&icmp), None);
+    }
+
+    #[test]
+    fn test_from_port_unreachable_ipv6() {
+        let mut payload = vec![0x60, 0, 0, 0, 0, 8, 17, 64];
+        payload.extend(
+            "2001:db8::5"
+                .parse::<std::net::Ipv6Addr>()
+                .unwrap()
+                .octets(),
+        );
+        payload.extend(
+            "2001:db8::1"
+                .parse::<std::net::Ipv6Addr>()
+                .unwrap()
+                .octets(),
+        );
+        payload.extend([0xc3, 0x50, 0, 53, 0, 8, 0, 0]);
+        assert_eq!(
+            from_port_unreachable(&payload),
+            Some(ScanProbe {
+                kind: ProbeKind::Refused,
+                offender: "2001:db8::5".to_string(),
+                target: "2001:db8::1".to_string(),
+                port: 53,
+                protocol: Protocol::UDP,
+            })
+        );
+    }
+}
@@ -11,6 +11,8 @@ pub struct SearchParameters {
     pub country: String,
     /// Autonomous System name
     pub as_name: String,
+    /// Network adapter
+    pub interface: String,
     /// Whether to display only favorites
     pub only_favorites: bool,
 }
@@ -23,6 +25,29 @@ pub fn is_some_filter_active(&self) -> bool {
             || !self.country.is_empty()
             || !self.as_name.is_empty()
             || !self.address.is_empty()
+            || !self.interface.is_empty()
+    }
+}
+
+/// Used to express the search filters applied to GUI DNS page
+#[derive(Clone, Debug, Default, Hash)]
+pub struct DnsSearchParameters {
+    /// Address of the client performing the queries
+    pub client: String,
+    /// Queried name
+    pub name: String,
+    /// Queried record type
+    pub query_type: String,
+    /// Response code
+    pub response_code: String,
+}
+
+impl DnsSearchParameters {
+    pub fn is_some_filter_active(&self) -> bool {
+        !self.client.is_empty()
+            || !self.name.is_empty()
+            || !self.query_type.is_empty()
+            || !self.response_code.is_empty()
     }
 }
 
@@ -32,4 +57,5 @@ pub enum FilterInputType {
     Country,
     AS,
     Address,
+    Interface,
 }
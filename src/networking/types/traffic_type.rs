@@ -1,5 +1,7 @@
+use serde::{Deserialize, Serialize};
+
 /// Enum representing the possible traffic type (unicast, multicast or broadcast).
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub enum TrafficType {
     /// Unicast traffic
     Unicast,
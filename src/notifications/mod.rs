@@ -1,2 +1,4 @@
+pub mod notification_log;
 pub mod notify_and_log;
+pub mod run_actions;
 pub mod types;
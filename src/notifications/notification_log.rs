@@ -0,0 +1,497 @@
+//! Module containing the functions to persist the emitted notifications to a rotating log file
+//! of JSON lines, to read it a page at a time, and to export it as CSV or JSON.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Local, SecondsFormat};
+use serde::{Deserialize, Serialize};
+
+use crate::notifications::types::logged_notification::LoggedNotification;
+use crate::notifications::types::notification_action::NotificationKind;
+use crate::notifications::types::notification_filter::NotificationFilter;
+use crate::SNIFFNET_LOWERCASE;
+
+/// Size in bytes beyond which the log file is rotated
+const MAX_LOG_SIZE: u64 = 5_000_000;
+/// Number of rotated log files kept besides the current one
+const ROTATED_LOGS: usize = 3;
+
+/// Line of the notifications log
+#[derive(Clone, Serialize, Deserialize)]
+pub struct NotificationRecord {
+    pub timestamp: DateTime<Local>,
+    pub kind: NotificationKind,
+    pub summary: String,
+    pub notification: LoggedNotification,
+}
+
+impl NotificationRecord {
+    pub fn new(notification: &LoggedNotification) -> Self {
+        NotificationRecord {
+            timestamp: notification.timestamp(),
+            kind: NotificationKind::of(notification),
+            summary: notification.summary(),
+            notification: notification.clone(),
+        }
+    }
+}
+
+/// Fields of a line of the log needed to filter it
+#[derive(Deserialize)]
+struct RecordSummary {
+    kind: NotificationKind,
+    summary: String,
+}
+
+/// Position and filterable fields of a line of the log
+struct LogLine {
+    /// Log file containing the line (0 is the current one, see `rotated_path`)
+    file: usize,
+    /// Offset of the line in its file
+    offset: u64,
+    kind: NotificationKind,
+    summary: String,
+}
+
+/// Log of the emitted notifications, with an in-memory index of its lines
+/// so that a page of it can be read without reading the whole log
+pub struct NotificationLog {
+    /// Path of the log (`None` if the notifications aren't persisted)
+    path: Option<PathBuf>,
+    /// Lines of the log, oldest first; built the first time a page is requested
+    index: Option<Vec<LogLine>>,
+    /// Number of lines of the index cleared from the view
+    cleared: usize,
+}
+
+impl NotificationLog {
+    pub fn new(path: Option<PathBuf>) -> Self {
+        NotificationLog {
+            path,
+            index: None,
+            cleared: 0,
+        }
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Appends the notifications to the log, rotating it if it grew too much
+    pub fn append(&mut self, notifications: &[LoggedNotification]) -> std::io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if notifications.is_empty() {
+            return Ok(());
+        }
+        if fs::metadata(path).is_ok_and(|metadata| metadata.len() >= MAX_LOG_SIZE) {
+            rotate_log(path)?;
+            if let Some(index) = &mut self.index {
+                for line in index.iter_mut() {
+                    line.file += 1;
+                }
+                let discarded = index
+                    .iter()
+                    .take_while(|line| line.file > ROTATED_LOGS)
+                    .count();
+                index.drain(..discarded);
+                self.cleared = self.cleared.saturating_sub(discarded);
+            }
+        } else if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        let mut offset = file.metadata()?.len();
+        for notification in notifications {
+            let record = NotificationRecord::new(notification);
+            let line = serde_json::to_string(&record)?;
+            writeln!(file, "{line}")?;
+            if let Some(index) = &mut self.index {
+                index.push(LogLine {
+                    file: 0,
+                    offset,
+                    kind: record.kind,
+                    summary: record.summary,
+                });
+            }
+            offset += line.len() as u64 + 1;
+        }
+        Ok(())
+    }
+
+    /// Clears the notifications logged so far from the view, without removing them from the log
+    pub fn clear_view(&mut self) {
+        let index = self
+            .index
+            .get_or_insert_with(|| self.path.as_deref().map(index_log).unwrap_or_default());
+        self.cleared = index.len();
+    }
+
+    /// Reads a page of 20 records of the log matching the filter, newest first,
+    /// together with the number of records matching the filter.
+    ///
+    /// Only the lines of the requested page are read from the log.
+    /// If the page doesn't exist anymore (e.g. the filter changed), the last one is returned
+    pub fn read_page(
+        &mut self,
+        filter: &NotificationFilter,
+        page_number: usize,
+    ) -> (Vec<NotificationRecord>, usize) {
+        let index = self
+            .index
+            .get_or_insert_with(|| self.path.as_deref().map(index_log).unwrap_or_default());
+        let matching: Vec<&LogLine> = index[self.cleared..]
+            .iter()
+            .rev()
+            .filter(|line| filter.matches_summary(line.kind, &line.summary))
+            .collect();
+        let results_number = matching.len();
+        let page_number = page_number.clamp(1, results_number.div_ceil(20).max(1));
+        let Some(path) = &self.path else {
+            return (Vec::new(), results_number);
+        };
+        // lines of a page are sorted by file
+        let mut reader: Option<(usize, BufReader<File>)> = None;
+        let page = matching
+            .into_iter()
+            .skip((page_number - 1) * 20)
+            .take(20)
+            .filter_map(|line| {
+                if !matches!(&reader, Some((file, _)) if *file == line.file) {
+                    let file = File::open(rotated_path(path, line.file)).ok()?;
+                    reader = Some((line.file, BufReader::new(file)));
+                }
+                let (_, reader) = reader.as_mut()?;
+                reader.seek(SeekFrom::Start(line.offset)).ok()?;
+                let mut content = String::new();
+                reader.read_line(&mut content).ok()?;
+                serde_json::from_str(&content).ok()
+            })
+            .collect();
+        (page, results_number)
+    }
+}
+
+/// Format of the exported notifications
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+/// Returns the path of the notifications log, in the same directory of the configuration files
+pub fn get_notification_log_path() -> Option<PathBuf> {
+    let path = confy::get_configuration_file_path(SNIFFNET_LOWERCASE, "notifications").ok()?;
+    Some(path.with_extension("jsonl"))
+}
+
+/// Returns the path of a notifications log in a new temporary directory
+#[cfg(test)]
+pub fn get_test_log_path() -> PathBuf {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static TEST_LOGS: AtomicUsize = AtomicUsize::new(0);
+    std::env::temp_dir()
+        .join(format!(
+            "sniffnet_test_{}_{}",
+            std::process::id(),
+            TEST_LOGS.fetch_add(1, Ordering::Relaxed)
+        ))
+        .join("notifications.jsonl")
+}
+
+/// Path of the n-th rotated log (n = 0 is the current one)
+fn rotated_path(path: &Path, n: usize) -> PathBuf {
+    if n == 0 {
+        path.to_path_buf()
+    } else {
+        let mut rotated = path.as_os_str().to_owned();
+        rotated.push(format!(".{n}"));
+        PathBuf::from(rotated)
+    }
+}
+
+fn rotate_log(path: &Path) -> std::io::Result<()> {
+    for n in (0..ROTATED_LOGS).rev() {
+        let from = rotated_path(path, n);
+        if from.exists() {
+            fs::rename(from, rotated_path(path, n + 1))?;
+        }
+    }
+    Ok(())
+}
+
+/// Reads the records of the log (rotated files included) matching the filter, oldest first.
+///
+/// Lines that can't be parsed are skipped
+pub fn read_log(path: &Path, filter: &NotificationFilter) -> Vec<NotificationRecord> {
+    let mut records = Vec::new();
+    for n in (0..=ROTATED_LOGS).rev() {
+        let Ok(file) = File::open(rotated_path(path, n)) else {
+            continue;
+        };
+        records.extend(
+            BufReader::new(file)
+                .lines()
+                .map_while(Result::ok)
+                .filter_map(|line| serde_json::from_str::<NotificationRecord>(&line).ok())
+                .filter(|record| filter.matches(&record.notification)),
+        );
+    }
+    records
+}
+
+/// Indexes the lines of the log (rotated files included), oldest first.
+///
+/// Lines that can't be parsed are skipped
+fn index_log(path: &Path) -> Vec<LogLine> {
+    let mut index = Vec::new();
+    for n in (0..=ROTATED_LOGS).rev() {
+        let Ok(file) = File::open(rotated_path(path, n)) else {
+            continue;
+        };
+        let mut reader = BufReader::new(file);
+        let mut offset = 0;
+        let mut line = String::new();
+        while let Ok(len @ 1..) = reader.read_line(&mut line) {
+            if let Ok(RecordSummary { kind, summary }) = serde_json::from_str(&line) {
+                index.push(LogLine {
+                    file: n,
+                    offset,
+                    kind,
+                    summary,
+                });
+            }
+            offset += len as u64;
+            line.clear();
+        }
+    }
+    index
+}
+
+/// Formats the records in the given format
+pub fn export_records(records: &[NotificationRecord], format: ExportFormat) -> String {
+    match format {
+        ExportFormat::Json => serde_json::to_string_pretty(records).unwrap_or_default(),
+        ExportFormat::Csv => {
+            let mut csv = String::from("timestamp,kind,summary\n");
+            for record in records {
+                csv.push_str(&format!(
+                    "{},{:?},{}\n",
+                    record
+                        .timestamp
+                        .to_rfc3339_opts(SecondsFormat::Millis, false),
+                    record.kind,
+                    escape_csv(&record.summary)
+                ));
+            }
+            csv
+        }
+    }
+}
+
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::configs::types::config_baseline::ListeningPort;
+    use crate::notifications::types::logged_notification::{
+        NewListeningPort, PacketsThresholdExceeded,
+    };
+    use crate::Protocol;
+
+    use super::*;
+
+    fn packets(incoming: u32) -> LoggedNotification {
+        LoggedNotification::PacketsThresholdExceeded(PacketsThresholdExceeded {
+            threshold: 100,
+            incoming,
+            outgoing: 0,
+            timestamp: Local::now(),
+        })
+    }
+
+    fn new_port(port: u16) -> LoggedNotification {
+        LoggedNotification::NewListeningPort(NewListeningPort {
+            listening_port: ListeningPort {
+                port,
+                protocol: Protocol::TCP,
+            },
+            remote_address: "10.0.0.5".to_string(),
+            timestamp: Local::now(),
+        })
+    }
+
+    fn remove_logs(path: &Path) {
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_append_and_read_log() {
+        let path = get_test_log_path();
+        let mut log = NotificationLog::new(Some(path.clone()));
+        log.append(&[packets(150), new_port(22)]).unwrap();
+        log.append(&[new_port(8080)]).unwrap();
+        // corrupted lines are skipped
+        OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(b"{not json\n")
+            .unwrap();
+
+        let all = read_log(&path, &NotificationFilter::default());
+        let ports = read_log(
+            &path,
+            &NotificationFilter {
+                kind: NotificationKind::NewListeningPort,
+                ..NotificationFilter::default()
+            },
+        );
+        let searched = read_log(
+            &path,
+            &NotificationFilter {
+                search: "tcp 8080".to_string(),
+                ..NotificationFilter::default()
+            },
+        );
+        let line = fs::read_to_string(&path).unwrap();
+        remove_logs(&path);
+
+        assert_eq!(all.len(), 3);
+        assert_eq!(all[0].kind, NotificationKind::Packets);
+        assert_eq!(ports.len(), 2);
+        assert_eq!(searched.len(), 1);
+        assert_eq!(
+            searched[0].summary,
+            "New listening port: TCP 8080 <- 10.0.0.5"
+        );
+        // full RFC 3339 timestamps
+        let record: serde_json::Value = serde_json::from_str(line.lines().next().unwrap()).unwrap();
+        assert!(DateTime::parse_from_rfc3339(record["timestamp"].as_str().unwrap()).is_ok());
+        assert_eq!(record["kind"], "Packets");
+    }
+
+    #[test]
+    fn test_rotate_log() {
+        let path = get_test_log_path();
+        let mut log = NotificationLog::new(Some(path.clone()));
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        for n in 1..=ROTATED_LOGS + 1 {
+            fs::write(&path, "x".repeat(usize::try_from(MAX_LOG_SIZE).unwrap())).unwrap();
+            log.append(&[new_port(u16::try_from(n).unwrap())]).unwrap();
+        }
+        let exists: Vec<bool> = (0..=ROTATED_LOGS + 1)
+            .map(|n| rotated_path(&path, n).exists())
+            .collect();
+        let current = fs::read_to_string(&path).unwrap();
+        remove_logs(&path);
+
+        // the oldest rotated log is discarded
+        assert_eq!(exists, vec![true, true, true, true, false]);
+        assert_eq!(current.lines().count(), 1);
+        assert!(current.contains("TCP 4"));
+    }
+
+    #[test]
+    fn test_read_log_page_and_clear_view() {
+        let path = get_test_log_path();
+        let mut log = NotificationLog::new(Some(path.clone()));
+        // more notifications than the ones kept in memory, split across rotated files
+        let notifications: Vec<LoggedNotification> = (1..=46).map(new_port).collect();
+        log.append(&notifications[..30]).unwrap();
+        rotate_log(&path).unwrap();
+        log.append(&notifications[30..40]).unwrap();
+        let ports = |page: &[NotificationRecord]| -> Vec<String> {
+            page.iter()
+                .map(|record| record.summary.split(' ').nth(4).unwrap().to_string())
+                .collect()
+        };
+
+        let (first_page, results_number) = log.read_page(&NotificationFilter::default(), 1);
+        assert_eq!(results_number, 40);
+        assert_eq!(ports(&first_page)[0], "40");
+        // the index is kept up to date, also when the log is rotated
+        OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(
+                format!("{}\n", "x".repeat(usize::try_from(MAX_LOG_SIZE).unwrap())).as_bytes(),
+            )
+            .unwrap();
+        log.append(&notifications[40..45]).unwrap();
+        assert!(rotated_path(&path, 2).exists());
+        let (first_page, results_number) = log.read_page(&NotificationFilter::default(), 1);
+        assert_eq!(results_number, 45);
+        assert_eq!(ports(&first_page)[0], "45");
+        assert_eq!(ports(&first_page)[19], "26");
+        let (last_page, _) = log.read_page(&NotificationFilter::default(), 3);
+        assert_eq!(ports(&last_page), vec!["5", "4", "3", "2", "1"]);
+        // pages beyond the last one show the last one
+        let (beyond_page, _) = log.read_page(&NotificationFilter::default(), 9);
+        assert_eq!(ports(&beyond_page), ports(&last_page));
+        let (searched, results_number) = log.read_page(
+            &NotificationFilter {
+                search: "tcp 45".to_string(),
+                ..NotificationFilter::default()
+            },
+            2,
+        );
+        assert_eq!(results_number, 1);
+        assert_eq!(ports(&searched), vec!["45"]);
+        // an index built from scratch gives the same pages
+        let (indexed_page, _) =
+            NotificationLog::new(Some(path.clone())).read_page(&NotificationFilter::default(), 1);
+        assert_eq!(ports(&indexed_page), ports(&first_page));
+
+        // the view is cleared, but the log is kept
+        log.clear_view();
+        assert_eq!(log.read_page(&NotificationFilter::default(), 1).1, 0);
+        log.append(&notifications[45..]).unwrap();
+        let (page, results_number) = log.read_page(&NotificationFilter::default(), 1);
+        assert_eq!(results_number, 1);
+        assert_eq!(ports(&page), vec!["46"]);
+        assert_eq!(read_log(&path, &NotificationFilter::default()).len(), 46);
+        remove_logs(&path);
+
+        // notifications aren't persisted without a path
+        let mut log = NotificationLog::new(None);
+        log.append(&notifications).unwrap();
+        assert_eq!(log.read_page(&NotificationFilter::default(), 1).1, 0);
+    }
+
+    #[test]
+    fn test_export_records() {
+        let mut records = vec![
+            NotificationRecord::new(&packets(150)),
+            NotificationRecord::new(&new_port(22)),
+        ];
+        records[1].summary = "a \"quoted\", summary".to_string();
+
+        let csv = export_records(&records, ExportFormat::Csv);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "timestamp,kind,summary");
+        assert!(
+            lines[1].contains(",Packets,\"Packets threshold exceeded: 150 incoming, 0 outgoing")
+        );
+        assert!(lines[2].ends_with(",NewListeningPort,\"a \"\"quoted\"\", summary\""));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&export_records(&records, ExportFormat::Json)).unwrap();
+        assert_eq!(json.as_array().unwrap().len(), 2);
+        assert_eq!(
+            json[1]["notification"]["NewListeningPort"]["listening_port"]["port"],
+            22
+        );
+    }
+}
@@ -2,80 +2,304 @@
 
 use chrono::Local;
 
+use crate::configs::types::config_baseline::ConfigBaseline;
+use crate::configs::types::config_usage::ConfigUsage;
+use crate::networking::types::data_info::DataInfo;
+use crate::notifications::types::alert_trigger::{AlertState, AlertTransition};
 use crate::notifications::types::logged_notification::{
-    BytesThresholdExceeded, FavoriteTransmitted, LoggedNotification, PacketsThresholdExceeded,
+    AlertEnded, BlocklistMatched, BytesThresholdExceeded, FavoriteTransmitted, FirstSeen,
+    LoggedNotification, NewListeningPort, PacketsThresholdExceeded, QuotaReached,
+    RuleThresholdExceeded, ScanDetected, ThresholdAlert,
 };
+use crate::notifications::types::notification_rule::NotificationRule;
 use crate::notifications::types::notifications::Notifications;
 use crate::notifications::types::sound::{play, Sound};
 use crate::{InfoTraffic, RunTimeData};
 
 /// Checks if one or more notifications have to be emitted and logs them.
 ///
-/// It returns the number of new notifications emitted
+/// Thresholds are evaluated according to the alert trigger: a notification is logged when
+/// the condition starts, and another one when it ends.
+///
+/// The hosts, countries, ASNs, and listening ports observed are added to the baseline,
+/// and the ones not part of it yet are notified once the learning window is over.
+///
+/// The data exchanged by each adapter in the billing cycle is compared to the data cap,
+/// and the levels of the cap reached are notified once per cycle.
+///
+/// Blocklist matches have the highest priority: their sound is the one emitted,
+/// followed by port scans, host sweeps, and SYN floods, and by the data cap.
+///
+/// It returns the new notifications emitted, in chronological order
 pub fn notify_and_log(
     runtime_data: &mut RunTimeData,
     notifications: Notifications,
+    notification_rules: &[NotificationRule],
+    baseline: &mut ConfigBaseline,
+    usage: &mut ConfigUsage,
     info_traffic: &Arc<Mutex<InfoTraffic>>,
-) -> usize {
+) -> Vec<LoggedNotification> {
     let mut already_emitted_sound = false;
-    let mut emitted_notifications = 0;
+    let mut emitted = Vec::new();
+    let trigger = notifications.trigger;
+    // blocklists
+    let blocklisted = std::mem::take(&mut info_traffic.lock().unwrap().blocklisted_last_interval);
+    if notifications.blocklist_notification.notify_on_match && !blocklisted.is_empty() {
+        for blocklist_match in blocklisted {
+            //log this notification
+            emitted.push(LoggedNotification::BlocklistMatched(BlocklistMatched {
+                blocklist_match,
+                timestamp: Local::now(),
+            }));
+        }
+        if notifications.blocklist_notification.sound.ne(&Sound::None) {
+            // emit sound
+            play(
+                notifications.blocklist_notification.sound,
+                notifications.volume,
+            );
+            already_emitted_sound = true;
+        }
+    }
+    // scans
+    let scans = info_traffic
+        .lock()
+        .unwrap()
+        .scan_detector
+        .detect(Local::now());
+    if notifications.scan_notification.notify_on_scan && !scans.is_empty() {
+        for scan in scans {
+            //log this notification
+            emitted.push(LoggedNotification::ScanDetected(ScanDetected {
+                scan,
+                timestamp: Local::now(),
+            }));
+        }
+        if !already_emitted_sound && notifications.scan_notification.sound.ne(&Sound::None) {
+            // emit sound
+            play(notifications.scan_notification.sound, notifications.volume);
+            already_emitted_sound = true;
+        }
+    }
+    // data cap
+    if let Some(cap) = notifications.quota_notification.cap {
+        let cycle_start_day = notifications.quota_notification.cycle_start_day;
+        let today = Local::now().date_naive();
+        let mut adapters: Vec<&String> = runtime_data.adapters_accounted.keys().collect();
+        adapters.sort();
+        let mut reached = Vec::new();
+        for adapter in adapters {
+            if let Some((level, adapter_usage)) =
+                usage.check_quota(adapter, cap, cycle_start_day, today)
+            {
+                reached.push(QuotaReached {
+                    adapter: adapter.clone(),
+                    level,
+                    used: adapter_usage.tot_bytes(),
+                    cap,
+                    cycle_start: adapter_usage.cycle_start,
+                    timestamp: Local::now(),
+                });
+            }
+        }
+        if !reached.is_empty() {
+            for quota_reached in reached {
+                //log this notification
+                emitted.push(LoggedNotification::QuotaReached(quota_reached));
+            }
+            if !already_emitted_sound && notifications.quota_notification.sound.ne(&Sound::None) {
+                // emit sound
+                play(notifications.quota_notification.sound, notifications.volume);
+                already_emitted_sound = true;
+            }
+        }
+    }
     // packets threshold
-    if notifications.packets_notification.threshold.is_some() {
+    if let Some(threshold) = notifications.packets_notification.threshold {
         let sent_packets_entry = runtime_data.tot_sent_packets - runtime_data.tot_sent_packets_prev;
         let received_packets_entry =
             runtime_data.tot_received_packets - runtime_data.tot_received_packets_prev;
-        if received_packets_entry + sent_packets_entry
-            > u128::from(notifications.packets_notification.threshold.unwrap())
-        {
-            // log this notification
-            emitted_notifications += 1;
-            if runtime_data.logged_notifications.len() >= 30 {
-                runtime_data.logged_notifications.pop_back();
+        match runtime_data.packets_alert.update(
+            received_packets_entry + sent_packets_entry,
+            u128::from(threshold),
+            trigger,
+        ) {
+            Some(AlertTransition::Started) => {
+                // log this notification
+                emitted.push(LoggedNotification::PacketsThresholdExceeded(
+                    PacketsThresholdExceeded {
+                        threshold: notifications.packets_notification.previous_threshold,
+                        incoming: received_packets_entry.try_into().unwrap(),
+                        outgoing: sent_packets_entry.try_into().unwrap(),
+                        timestamp: Local::now(),
+                    },
+                ));
+                if !already_emitted_sound
+                    && notifications.packets_notification.sound.ne(&Sound::None)
+                {
+                    // emit sound
+                    play(
+                        notifications.packets_notification.sound,
+                        notifications.volume,
+                    );
+                    already_emitted_sound = true;
+                }
             }
-            runtime_data.logged_notifications.push_front(
-                LoggedNotification::PacketsThresholdExceeded(PacketsThresholdExceeded {
-                    threshold: notifications.packets_notification.previous_threshold,
-                    incoming: received_packets_entry.try_into().unwrap(),
-                    outgoing: sent_packets_entry.try_into().unwrap(),
-                    timestamp: Local::now().to_string().get(11..19).unwrap().to_string(),
-                }),
-            );
-            if notifications.packets_notification.sound.ne(&Sound::None) {
-                // emit sound
-                play(
-                    notifications.packets_notification.sound,
-                    notifications.volume,
-                );
-                already_emitted_sound = true;
+            Some(AlertTransition::Ended) => {
+                emitted.push(alert_ended(ThresholdAlert::Packets { threshold }));
             }
+            None => {}
         }
+    } else {
+        runtime_data.packets_alert = AlertState::default();
     }
     // bytes threshold
-    if notifications.bytes_notification.threshold.is_some() {
+    if let Some(threshold) = notifications.bytes_notification.threshold {
         let sent_bytes_entry = runtime_data.tot_sent_bytes - runtime_data.tot_sent_bytes_prev;
         let received_bytes_entry =
             runtime_data.tot_received_bytes - runtime_data.tot_received_bytes_prev;
-        if received_bytes_entry + sent_bytes_entry
-            > u128::from(notifications.bytes_notification.threshold.unwrap())
-        {
-            //log this notification
-            emitted_notifications += 1;
-            if runtime_data.logged_notifications.len() >= 30 {
-                runtime_data.logged_notifications.pop_back();
+        match runtime_data.bytes_alert.update(
+            received_bytes_entry + sent_bytes_entry,
+            u128::from(threshold),
+            trigger,
+        ) {
+            Some(AlertTransition::Started) => {
+                //log this notification
+                emitted.push(LoggedNotification::BytesThresholdExceeded(
+                    BytesThresholdExceeded {
+                        threshold: notifications.bytes_notification.previous_threshold,
+                        incoming: received_bytes_entry.try_into().unwrap(),
+                        outgoing: sent_bytes_entry.try_into().unwrap(),
+                        timestamp: Local::now(),
+                    },
+                ));
+                if !already_emitted_sound && notifications.bytes_notification.sound.ne(&Sound::None)
+                {
+                    // emit sound
+                    play(notifications.bytes_notification.sound, notifications.volume);
+                    already_emitted_sound = true;
+                }
             }
-            runtime_data.logged_notifications.push_front(
-                LoggedNotification::BytesThresholdExceeded(BytesThresholdExceeded {
-                    threshold: notifications.bytes_notification.previous_threshold,
-                    incoming: received_bytes_entry.try_into().unwrap(),
-                    outgoing: sent_bytes_entry.try_into().unwrap(),
-                    timestamp: Local::now().to_string().get(11..19).unwrap().to_string(),
-                }),
-            );
-            if !already_emitted_sound && notifications.bytes_notification.sound.ne(&Sound::None) {
-                // emit sound
-                play(notifications.bytes_notification.sound, notifications.volume);
-                already_emitted_sound = true;
+            Some(AlertTransition::Ended) => {
+                emitted.push(alert_ended(ThresholdAlert::Bytes { threshold }));
             }
+            None => {}
+        }
+    } else {
+        runtime_data.bytes_alert = AlertState::default();
+    }
+    // custom rules
+    let info_traffic_lock = info_traffic.lock().unwrap();
+    let rules_data: Vec<(NotificationRule, DataInfo)> = notification_rules
+        .iter()
+        .map(|rule| (rule.clone(), rule.get_data_info(&info_traffic_lock)))
+        .collect();
+    drop(info_traffic_lock);
+    let mut rules_state = Vec::new();
+    for (rule, data_info) in rules_data {
+        // a rule is evaluated starting from the interval after it was added
+        let Some((_, data_info_prev, mut alert_state)) = runtime_data
+            .rules_state
+            .iter()
+            .find(|(rule_prev, _, _)| rule_prev.eq(&rule))
+            .cloned()
+        else {
+            rules_state.push((rule, data_info, AlertState::default()));
+            continue;
+        };
+        let data_info_entry = DataInfo {
+            incoming_bytes: data_info
+                .incoming_bytes
+                .saturating_sub(data_info_prev.incoming_bytes),
+            outgoing_bytes: data_info
+                .outgoing_bytes
+                .saturating_sub(data_info_prev.outgoing_bytes),
+            ..DataInfo::default()
+        };
+        match alert_state.update(
+            rule.get_bytes(&data_info_entry),
+            u128::from(rule.threshold),
+            trigger,
+        ) {
+            Some(AlertTransition::Started) => {
+                //log this notification
+                emitted.push(LoggedNotification::RuleThresholdExceeded(
+                    RuleThresholdExceeded {
+                        rule: rule.clone(),
+                        incoming: data_info_entry
+                            .incoming_bytes
+                            .try_into()
+                            .unwrap_or(u64::MAX),
+                        outgoing: data_info_entry
+                            .outgoing_bytes
+                            .try_into()
+                            .unwrap_or(u64::MAX),
+                        timestamp: Local::now(),
+                    },
+                ));
+                if !already_emitted_sound && rule.sound.ne(&Sound::None) {
+                    // emit sound
+                    play(rule.sound, notifications.volume);
+                    already_emitted_sound = true;
+                }
+            }
+            Some(AlertTransition::Ended) => {
+                emitted.push(alert_ended(ThresholdAlert::Rule(rule.clone())));
+            }
+            None => {}
+        }
+        rules_state.push((rule, data_info, alert_state));
+    }
+    runtime_data.rules_state = rules_state;
+    // novelties with respect to the baseline
+    let info_traffic_lock = info_traffic.lock().unwrap();
+    let first_seen_targets = baseline.learn_targets(&info_traffic_lock);
+    let new_listening_ports = baseline.learn_listening_ports(&info_traffic_lock);
+    drop(info_traffic_lock);
+    // everything is new to an empty baseline: novelties are learned silently at first
+    let is_learning = baseline.is_learning();
+    baseline.advance_learning();
+    if notifications.first_seen_notification.notify_on_first_seen
+        && !is_learning
+        && !first_seen_targets.is_empty()
+    {
+        for (target, host, data_info_host) in first_seen_targets {
+            //log this notification
+            emitted.push(LoggedNotification::FirstSeen(FirstSeen {
+                target,
+                host,
+                data_info_host,
+                timestamp: Local::now(),
+            }));
+        }
+        if !already_emitted_sound && notifications.first_seen_notification.sound.ne(&Sound::None) {
+            // emit sound
+            play(
+                notifications.first_seen_notification.sound,
+                notifications.volume,
+            );
+            already_emitted_sound = true;
+        }
+    }
+    if notifications.new_port_notification.notify_on_new_port
+        && !is_learning
+        && !new_listening_ports.is_empty()
+    {
+        for (listening_port, remote_address) in new_listening_ports {
+            //log this notification
+            emitted.push(LoggedNotification::NewListeningPort(NewListeningPort {
+                listening_port,
+                remote_address,
+                timestamp: Local::now(),
+            }));
+        }
+        if !already_emitted_sound && notifications.new_port_notification.sound.ne(&Sound::None) {
+            // emit sound
+            play(
+                notifications.new_port_notification.sound,
+                notifications.volume,
+            );
+            already_emitted_sound = true;
         }
     }
     // from favorites
@@ -89,20 +313,13 @@ pub fn notify_and_log(
         let info_traffic_lock = info_traffic.lock().unwrap();
         for host in &info_traffic_lock.favorites_last_interval.clone() {
             //log this notification
-            emitted_notifications += 1;
-            if runtime_data.logged_notifications.len() >= 30 {
-                runtime_data.logged_notifications.pop_back();
-            }
-
-            runtime_data
-                .logged_notifications
-                .push_front(LoggedNotification::FavoriteTransmitted(
-                    FavoriteTransmitted {
-                        host: host.clone(),
-                        data_info_host: info_traffic_lock.hosts.get(host).unwrap().clone(),
-                        timestamp: Local::now().to_string().get(11..19).unwrap().to_string(),
-                    },
-                ));
+            emitted.push(LoggedNotification::FavoriteTransmitted(
+                FavoriteTransmitted {
+                    host: host.clone(),
+                    data_info_host: info_traffic_lock.hosts.get(host).unwrap().clone(),
+                    timestamp: Local::now(),
+                },
+            ));
         }
         drop(info_traffic_lock);
         if !already_emitted_sound && notifications.favorite_notification.sound.ne(&Sound::None) {
@@ -114,5 +331,23 @@ pub fn notify_and_log(
         }
     }
 
-    emitted_notifications
+    for notification in &emitted {
+        log_notification(runtime_data, notification.clone());
+    }
+    emitted
+}
+
+/// Logs a notification, keeping only the last 30 ones
+pub fn log_notification(runtime_data: &mut RunTimeData, notification: LoggedNotification) {
+    if runtime_data.logged_notifications.len() >= 30 {
+        runtime_data.logged_notifications.pop_back();
+    }
+    runtime_data.logged_notifications.push_front(notification);
+}
+
+fn alert_ended(alert: ThresholdAlert) -> LoggedNotification {
+    LoggedNotification::AlertEnded(AlertEnded {
+        alert,
+        timestamp: Local::now(),
+    })
 }
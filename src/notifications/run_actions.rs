@@ -0,0 +1,316 @@
+//! Module containing the functions to run the commands and to call the webhooks
+//! configured for the emitted notifications.
+
+use std::io::{Error, ErrorKind, Write};
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Local};
+
+use crate::events::types::event::Event;
+use crate::notifications::types::logged_notification::{ActionFailed, LoggedNotification};
+use crate::notifications::types::notification_action::{NotificationAction, NotificationKind};
+use crate::utils::formatted_strings::APP_VERSION;
+use crate::SNIFFNET_LOWERCASE;
+
+/// Seconds after which a webhook not responding is abandoned
+const WEBHOOK_TIMEOUT: u64 = 10;
+
+/// Seconds after which a command still running is killed
+const COMMAND_TIMEOUT: u64 = 30;
+
+/// Actions to run for a notification, submitted to the actions worker
+pub struct ActionsJob {
+    actions: Vec<NotificationAction>,
+    kind: NotificationKind,
+    /// The notification, described by the same JSON object of the events stream
+    payload: String,
+}
+
+/// Spawns the thread in charge of running the notification actions one after the other,
+/// returning the channel used to submit them.
+///
+/// The actions failed are sent back through `failures_sender`, to be reported in the log.
+/// The thread lasts until the returned sender is dropped.
+pub fn spawn_actions_worker(failures_sender: Sender<ActionFailed>) -> Sender<ActionsJob> {
+    let (jobs_sender, jobs_receiver) = mpsc::channel();
+    thread::Builder::new()
+        .name("thread_notification_actions".to_string())
+        .spawn(move || {
+            run_jobs(&jobs_receiver, &failures_sender);
+        })
+        .unwrap();
+    jobs_sender
+}
+
+/// Submits to the actions worker the actions applying to the notification
+pub fn run_actions(
+    jobs_sender: &Sender<ActionsJob>,
+    actions: &[NotificationAction],
+    notification: &LoggedNotification,
+    timestamp: DateTime<Local>,
+) {
+    let actions: Vec<NotificationAction> = actions
+        .iter()
+        .filter(|action| action.applies_to(notification))
+        .cloned()
+        .collect();
+    if actions.is_empty() {
+        return;
+    }
+    let payload = serde_json::to_string(&Event::Notification {
+        timestamp,
+        notification: notification.clone(),
+    })
+    .unwrap_or_default();
+    // the worker is gone only if it panicked, which already terminated the app
+    let _ = jobs_sender.send(ActionsJob {
+        actions,
+        kind: NotificationKind::of(notification),
+        payload,
+    });
+}
+
+fn run_jobs(jobs_receiver: &Receiver<ActionsJob>, failures_sender: &Sender<ActionFailed>) {
+    let report = |action: &str, error: String| {
+        // the receiver is gone only if the app is closing
+        let _ = failures_sender.send(ActionFailed {
+            action: action.to_string(),
+            error,
+            timestamp: Local::now(),
+        });
+    };
+    for job in jobs_receiver {
+        for action in job.actions {
+            let command = action.command.trim();
+            if !command.is_empty() {
+                match run_command(
+                    command,
+                    job.kind,
+                    &job.payload,
+                    Duration::from_secs(COMMAND_TIMEOUT),
+                ) {
+                    Ok(status) if status.success() => {}
+                    Ok(status) => report(command, status.to_string()),
+                    Err(error) => report(command, error.to_string()),
+                }
+            }
+            let webhook = action.webhook.trim();
+            if !webhook.is_empty() {
+                match post_webhook(webhook, &job.payload) {
+                    Ok(status) if status.is_success() => {}
+                    Ok(status) => report(webhook, status.to_string()),
+                    Err(error) => report(webhook, error.to_string()),
+                }
+            }
+        }
+    }
+}
+
+/// Runs the command with the system shell, passing the notification as JSON on its standard input
+/// and in the `SNIFFNET_EVENT` environment variable, and its kind in `SNIFFNET_NOTIFICATION`
+///
+/// The command is killed if still running after the timeout
+fn run_command(
+    command: &str,
+    kind: NotificationKind,
+    payload: &str,
+    timeout: Duration,
+) -> std::io::Result<ExitStatus> {
+    let mut child = shell(command)
+        .env("SNIFFNET_NOTIFICATION", format!("{kind:?}"))
+        .env("SNIFFNET_EVENT", payload)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // commands are free to not read their input
+        let _ = stdin.write_all(payload.as_bytes());
+    }
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(status);
+        }
+        if Instant::now() >= deadline {
+            child.kill()?;
+            child.wait()?;
+            return Err(Error::new(
+                ErrorKind::TimedOut,
+                format!("killed after {timeout:?}"),
+            ));
+        }
+        thread::sleep(Duration::from_millis(50));
+    }
+}
+
+#[cfg(windows)]
+fn shell(command: &str) -> Command {
+    let mut shell = Command::new("cmd");
+    shell.args(["/C", command]);
+    shell
+}
+
+#[cfg(not(windows))]
+fn shell(command: &str) -> Command {
+    let mut shell = Command::new("sh");
+    shell.args(["-c", command]);
+    shell
+}
+
+/// Posts the notification as JSON to the webhook
+fn post_webhook(url: &str, payload: &str) -> reqwest::Result<reqwest::StatusCode> {
+    reqwest::blocking::Client::builder()
+        .timeout(Duration::from_secs(WEBHOOK_TIMEOUT))
+        .build()?
+        .post(url)
+        .header("User-agent", format!("{SNIFFNET_LOWERCASE}-{APP_VERSION}"))
+        .header("Content-Type", "application/json")
+        .body(payload.to_string())
+        .send()
+        .map(|response| response.status())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read};
+    use std::net::TcpListener;
+
+    use super::*;
+    use crate::notifications::types::logged_notification::{AlertEnded, ThresholdAlert};
+
+    const PAYLOAD: &str = r#"{"event":"notification"}"#;
+
+    #[test]
+    #[cfg(not(windows))]
+    fn test_run_command() {
+        let path = format!(
+            "{}/test_notification_action.txt",
+            env!("CARGO_MANIFEST_DIR")
+        );
+        let status = run_command(
+            &format!("cat > {path} && echo \"$SNIFFNET_NOTIFICATION\" >> {path}"),
+            NotificationKind::Scan,
+            PAYLOAD,
+            Duration::from_secs(COMMAND_TIMEOUT),
+        )
+        .unwrap();
+        let output = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(status.success());
+        assert_eq!(output, format!("{PAYLOAD}Scan\n"));
+
+        // commands not reading their input are fine too
+        assert!(run_command(
+            "exit 0",
+            NotificationKind::Scan,
+            PAYLOAD,
+            Duration::from_secs(COMMAND_TIMEOUT)
+        )
+        .unwrap()
+        .success());
+
+        // commands not terminating are killed
+        let start = Instant::now();
+        let error = run_command(
+            "sleep 10",
+            NotificationKind::Scan,
+            PAYLOAD,
+            Duration::from_millis(200),
+        )
+        .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::TimedOut);
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn test_failures_reported() {
+        let (failures_sender, failures_receiver) = mpsc::channel();
+        let jobs_sender = spawn_actions_worker(failures_sender);
+        let notification = LoggedNotification::AlertEnded(AlertEnded {
+            alert: ThresholdAlert::Packets { threshold: 3 },
+            timestamp: Local::now(),
+        });
+        let actions = [
+            NotificationAction {
+                kind: NotificationKind::All,
+                command: "exit 3".to_string(),
+                webhook: "http://127.0.0.1:1/hook".to_string(),
+            },
+            // not applying to the notification
+            NotificationAction {
+                kind: NotificationKind::Scan,
+                command: "exit 4".to_string(),
+                webhook: String::new(),
+            },
+        ];
+        run_actions(&jobs_sender, &actions, &notification, Local::now());
+
+        let failures: Vec<ActionFailed> = (0..2)
+            .map(|_| {
+                failures_receiver
+                    .recv_timeout(Duration::from_secs(WEBHOOK_TIMEOUT + 5))
+                    .unwrap()
+            })
+            .collect();
+        assert_eq!(failures[0].action, "exit 3");
+        assert!(failures[0].error.contains('3'));
+        assert_eq!(failures[1].action, "http://127.0.0.1:1/hook");
+        assert!(failures_receiver
+            .recv_timeout(Duration::from_millis(500))
+            .is_err());
+
+        // failures never trigger other actions
+        let failure = LoggedNotification::ActionFailed(failures[0].clone());
+        run_actions(&jobs_sender, &actions, &failure, Local::now());
+        assert!(failures_receiver
+            .recv_timeout(Duration::from_millis(500))
+            .is_err());
+    }
+
+    #[test]
+    fn test_post_webhook() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut content_length = 0;
+            let mut content_type = String::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let line = line.trim_end().to_ascii_lowercase();
+                if line.is_empty() {
+                    break;
+                }
+                if let Some(value) = line.strip_prefix("content-length:") {
+                    content_length = value.trim().parse().unwrap();
+                }
+                if let Some(value) = line.strip_prefix("content-type:") {
+                    content_type = value.trim().to_string();
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            reader
+                .get_mut()
+                .write_all(b"HTTP/1.1 204 No Content\r\nContent-Length: 0\r\n\r\n")
+                .unwrap();
+            (request_line, content_type, String::from_utf8(body).unwrap())
+        });
+
+        let status = post_webhook(&url, PAYLOAD).unwrap();
+        let (request_line, content_type, body) = server.join().unwrap();
+        assert_eq!(status, reqwest::StatusCode::NO_CONTENT);
+        assert_eq!(request_line.trim_end(), "POST /hook HTTP/1.1");
+        assert_eq!(content_type, "application/json");
+        assert_eq!(body, PAYLOAD);
+    }
+}
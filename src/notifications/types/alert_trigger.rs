@@ -0,0 +1,211 @@
+//! Module defining how thresholds are evaluated over time, so that an ongoing condition
+//! produces a single notification when it starts and a single one when it ends.
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use crate::translations::translations_3::{
+    consecutive_seconds_translation, seconds_on_average_translation,
+};
+use crate::Language;
+
+/// How the values of the last seconds are compared with a threshold
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TriggerMode {
+    /// The threshold has to be exceeded in each of the last seconds
+    Consecutive,
+    /// The average of the last seconds has to exceed the threshold
+    Average,
+}
+
+impl TriggerMode {
+    pub(crate) const ALL: [TriggerMode; 2] = [TriggerMode::Consecutive, TriggerMode::Average];
+
+    pub fn all_strings(language: Language) -> Vec<&'static str> {
+        TriggerMode::ALL
+            .iter()
+            .map(|mode| mode.get_picklist_label(language))
+            .collect()
+    }
+
+    pub fn get_picklist_label(self, language: Language) -> &'static str {
+        match self {
+            TriggerMode::Consecutive => consecutive_seconds_translation(language),
+            TriggerMode::Average => seconds_on_average_translation(language),
+        }
+    }
+}
+
+/// Used to contain how thresholds are evaluated, as set by the user
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlertTrigger {
+    /// How the values of the window are compared with a threshold
+    pub mode: TriggerMode,
+    /// Number of seconds over which a threshold is evaluated
+    pub window: u8,
+    /// Seconds after the end of an alert during which the same alert can't start again
+    pub cooldown: u16,
+}
+
+impl Default for AlertTrigger {
+    fn default() -> Self {
+        AlertTrigger {
+            mode: TriggerMode::Consecutive,
+            window: 1,
+            cooldown: 0,
+        }
+    }
+}
+
+/// Change of state of an alert
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertTransition {
+    Started,
+    Ended,
+}
+
+/// State of an alert, used to notify only when the condition starts and ends
+#[derive(Clone, Default, Debug)]
+pub struct AlertState {
+    /// Values of the last seconds, the most recent at the back
+    values: VecDeque<u128>,
+    /// Whether the threshold is currently exceeded
+    is_active: bool,
+    /// Seconds left before the alert can start again
+    cooldown_left: u16,
+}
+
+impl AlertState {
+    /// Records the value of the last second, and returns the transition it caused, if any.
+    ///
+    /// An alert starts when the window exceeds the threshold, and ends when the window
+    /// is entirely below it again (for the average mode, when the average is below it).
+    pub fn update(
+        &mut self,
+        value: u128,
+        threshold: u128,
+        trigger: AlertTrigger,
+    ) -> Option<AlertTransition> {
+        let window = usize::from(trigger.window.max(1));
+        self.values.push_back(value);
+        while self.values.len() > window {
+            self.values.pop_front();
+        }
+        let is_cooling_down = self.cooldown_left > 0;
+        self.cooldown_left = self.cooldown_left.saturating_sub(1);
+        if self.values.len() < window {
+            return None;
+        }
+
+        let (is_exceeded, is_below) = match trigger.mode {
+            TriggerMode::Consecutive => (
+                self.values.iter().all(|v| *v > threshold),
+                self.values.iter().all(|v| *v <= threshold),
+            ),
+            TriggerMode::Average => {
+                let average = self.values.iter().sum::<u128>() / u128::from(trigger.window.max(1));
+                let is_exceeded = average > threshold;
+                (is_exceeded, !is_exceeded)
+            }
+        };
+
+        if !self.is_active && is_exceeded && !is_cooling_down {
+            self.is_active = true;
+            Some(AlertTransition::Started)
+        } else if self.is_active && is_below {
+            self.is_active = false;
+            self.cooldown_left = trigger.cooldown;
+            Some(AlertTransition::Ended)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transitions(values: &[u128], trigger: AlertTrigger) -> Vec<Option<AlertTransition>> {
+        let mut state = AlertState::default();
+        values
+            .iter()
+            .map(|value| state.update(*value, 10, trigger))
+            .collect()
+    }
+
+    #[test]
+    fn test_single_second_window() {
+        let started = Some(AlertTransition::Started);
+        let ended = Some(AlertTransition::Ended);
+        // an ongoing condition is notified once when it starts and once when it ends
+        assert_eq!(
+            transitions(&[5, 20, 30, 40, 5, 20], AlertTrigger::default()),
+            vec![None, started, None, None, ended, started]
+        );
+    }
+
+    #[test]
+    fn test_consecutive_window() {
+        let trigger = AlertTrigger {
+            window: 3,
+            ..AlertTrigger::default()
+        };
+        // a single burst is ignored
+        assert_eq!(
+            transitions(&[50, 5, 5, 20, 20, 20, 5, 20, 5, 5, 5], trigger),
+            vec![
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(AlertTransition::Started),
+                None,
+                None,
+                None,
+                None,
+                Some(AlertTransition::Ended)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_average_window() {
+        let trigger = AlertTrigger {
+            mode: TriggerMode::Average,
+            window: 2,
+            cooldown: 0,
+        };
+        assert_eq!(
+            transitions(&[30, 0, 0, 30, 10, 5], trigger),
+            vec![
+                None,
+                Some(AlertTransition::Started),
+                Some(AlertTransition::Ended),
+                Some(AlertTransition::Started),
+                None,
+                Some(AlertTransition::Ended)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cooldown() {
+        let trigger = AlertTrigger {
+            cooldown: 2,
+            ..AlertTrigger::default()
+        };
+        assert_eq!(
+            transitions(&[20, 5, 20, 20, 20], trigger),
+            vec![
+                Some(AlertTransition::Started),
+                Some(AlertTransition::Ended),
+                None,
+                None,
+                Some(AlertTransition::Started)
+            ]
+        );
+    }
+}
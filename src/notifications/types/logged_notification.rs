@@ -1,7 +1,16 @@
+use chrono::{DateTime, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::blocklists::types::blocklist_match::BlocklistMatch;
+use crate::configs::types::config_baseline::ListeningPort;
 use crate::networking::types::data_info_host::DataInfoHost;
 use crate::networking::types::host::Host;
+use crate::networking::types::scan_detector::{ScanDetection, ScanKind};
+use crate::notifications::types::notification_rule::NotificationRule;
+use crate::utils::formatted_strings::get_formatted_bytes_string_with_b;
 
 /// Enum representing the possible notification events.
+#[derive(Clone, Serialize, Deserialize)]
 pub enum LoggedNotification {
     /// Packets threshold exceeded
     PacketsThresholdExceeded(PacketsThresholdExceeded),
@@ -9,27 +18,243 @@ pub enum LoggedNotification {
     BytesThresholdExceeded(BytesThresholdExceeded),
     /// Favorite connection exchanged data
     FavoriteTransmitted(FavoriteTransmitted),
+    /// Threshold of a custom rule exceeded
+    RuleThresholdExceeded(RuleThresholdExceeded),
+    /// Threshold not exceeded anymore
+    AlertEnded(AlertEnded),
+    /// Host, country, or ASN never seen before exchanged data
+    FirstSeen(FirstSeen),
+    /// Local port never seen before accepted incoming connections
+    NewListeningPort(NewListeningPort),
+    /// Remote address or its name matched a blocklist
+    BlocklistMatched(BlocklistMatched),
+    /// Port scan, host sweep, or SYN flood detected
+    ScanDetected(ScanDetected),
+    /// Data usage of a network adapter reached a level of the billing cycle cap
+    QuotaReached(QuotaReached),
+    /// Command or webhook of a notification action failed
+    ActionFailed(ActionFailed),
+}
+
+impl LoggedNotification {
+    /// Time at which the notification was emitted
+    pub fn timestamp(&self) -> DateTime<Local> {
+        match self {
+            LoggedNotification::PacketsThresholdExceeded(n) => n.timestamp,
+            LoggedNotification::BytesThresholdExceeded(n) => n.timestamp,
+            LoggedNotification::FavoriteTransmitted(n) => n.timestamp,
+            LoggedNotification::RuleThresholdExceeded(n) => n.timestamp,
+            LoggedNotification::AlertEnded(n) => n.timestamp,
+            LoggedNotification::FirstSeen(n) => n.timestamp,
+            LoggedNotification::NewListeningPort(n) => n.timestamp,
+            LoggedNotification::BlocklistMatched(n) => n.timestamp,
+            LoggedNotification::ScanDetected(n) => n.timestamp,
+            LoggedNotification::QuotaReached(n) => n.timestamp,
+            LoggedNotification::ActionFailed(n) => n.timestamp,
+        }
+    }
+
+    /// One-line description of the notification, used to search and export the log
+    pub fn summary(&self) -> String {
+        match self {
+            LoggedNotification::PacketsThresholdExceeded(n) => format!(
+                "Packets threshold exceeded: {} incoming, {} outgoing (threshold {} per second)",
+                n.incoming, n.outgoing, n.threshold
+            ),
+            LoggedNotification::BytesThresholdExceeded(n) => format!(
+                "Bytes threshold exceeded: {} incoming, {} outgoing (threshold {} per second)",
+                get_formatted_bytes_string_with_b(u128::from(n.incoming)),
+                get_formatted_bytes_string_with_b(u128::from(n.outgoing)),
+                get_formatted_bytes_string_with_b(u128::from(n.threshold))
+            ),
+            LoggedNotification::FavoriteTransmitted(n) => {
+                format!(
+                    "Favorite connection exchanged data: {}",
+                    host_summary(&n.host)
+                )
+            }
+            LoggedNotification::RuleThresholdExceeded(n) => format!(
+                "Rule threshold exceeded: {} incoming, {} outgoing ({} {} {} > {} per second)",
+                get_formatted_bytes_string_with_b(u128::from(n.incoming)),
+                get_formatted_bytes_string_with_b(u128::from(n.outgoing)),
+                n.rule.scope,
+                n.rule.value,
+                n.rule.direction,
+                get_formatted_bytes_string_with_b(u128::from(n.rule.threshold))
+            ),
+            LoggedNotification::AlertEnded(n) => match &n.alert {
+                ThresholdAlert::Packets { threshold } => {
+                    format!("Alert ended: packets threshold ({threshold} per second)")
+                }
+                ThresholdAlert::Bytes { threshold } => format!(
+                    "Alert ended: bytes threshold ({} per second)",
+                    get_formatted_bytes_string_with_b(u128::from(*threshold))
+                ),
+                ThresholdAlert::Rule(rule) => format!(
+                    "Alert ended: rule threshold ({} {} {} > {} per second)",
+                    rule.scope,
+                    rule.value,
+                    rule.direction,
+                    get_formatted_bytes_string_with_b(u128::from(rule.threshold))
+                ),
+            },
+            LoggedNotification::FirstSeen(n) => {
+                let target = match n.target {
+                    FirstSeenTarget::Host => "host",
+                    FirstSeenTarget::Country => "country",
+                    FirstSeenTarget::Asn => "ASN",
+                };
+                format!("New {target}: {}", host_summary(&n.host))
+            }
+            LoggedNotification::NewListeningPort(n) => format!(
+                "New listening port: {} {} <- {}",
+                n.listening_port.protocol, n.listening_port.port, n.remote_address
+            ),
+            LoggedNotification::BlocklistMatched(n) => {
+                let blocklist_match = &n.blocklist_match;
+                let address = match &blocklist_match.name {
+                    Some(name) => format!("{} ({name})", blocklist_match.address),
+                    None => blocklist_match.address.clone(),
+                };
+                format!(
+                    "Blocklist match: {address} (list {}, entry {})",
+                    blocklist_match.list, blocklist_match.entry
+                )
+            }
+            LoggedNotification::ScanDetected(n) => {
+                let scan = &n.scan;
+                let (kind, count) = match scan.kind {
+                    ScanKind::PortScan => ("Port scan", "ports"),
+                    ScanKind::HostSweep => ("Host sweep", "hosts"),
+                    ScanKind::SynFlood => ("SYN flood", "unanswered SYNs"),
+                };
+                format!(
+                    "{kind}: {} -> {} ({} {count} in {} s)",
+                    scan.offenders(),
+                    scan.target,
+                    scan.count,
+                    (scan.end - scan.start).num_seconds()
+                )
+            }
+            LoggedNotification::QuotaReached(n) => format!(
+                "Data cap {}% reached: {} {} of {} since {}",
+                n.level,
+                n.adapter,
+                get_formatted_bytes_string_with_b(u128::from(n.used)),
+                get_formatted_bytes_string_with_b(u128::from(n.cap)),
+                n.cycle_start
+            ),
+            LoggedNotification::ActionFailed(n) => {
+                format!("Action failed: {} ({})", n.action, n.error)
+            }
+        }
+    }
 }
 
-#[derive(Clone)]
+/// Describes a host as "domain (country, ASN)"
+fn host_summary(host: &Host) -> String {
+    format!(
+        "{} ({}, AS{} {})",
+        host.domain, host.country, host.asn.number, host.asn.name
+    )
+}
+
+#[derive(Clone, Serialize, Deserialize)]
 pub struct PacketsThresholdExceeded {
     pub(crate) threshold: u32,
     pub(crate) incoming: u32,
     pub(crate) outgoing: u32,
-    pub(crate) timestamp: String,
+    pub(crate) timestamp: DateTime<Local>,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct BytesThresholdExceeded {
     pub(crate) threshold: u64,
     pub(crate) incoming: u32,
     pub(crate) outgoing: u32,
-    pub(crate) timestamp: String,
+    pub(crate) timestamp: DateTime<Local>,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct FavoriteTransmitted {
     pub(crate) host: Host,
     pub(crate) data_info_host: DataInfoHost,
-    pub(crate) timestamp: String,
+    pub(crate) timestamp: DateTime<Local>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct RuleThresholdExceeded {
+    pub(crate) rule: NotificationRule,
+    pub(crate) incoming: u64,
+    pub(crate) outgoing: u64,
+    pub(crate) timestamp: DateTime<Local>,
+}
+
+/// Threshold whose alert has ended
+#[derive(Clone, Serialize, Deserialize)]
+pub enum ThresholdAlert {
+    Packets { threshold: u32 },
+    Bytes { threshold: u64 },
+    Rule(NotificationRule),
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct AlertEnded {
+    pub(crate) alert: ThresholdAlert,
+    pub(crate) timestamp: DateTime<Local>,
+}
+
+/// Kind of target seen for the first time
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum FirstSeenTarget {
+    Host,
+    Country,
+    Asn,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FirstSeen {
+    pub(crate) target: FirstSeenTarget,
+    pub(crate) host: Host,
+    pub(crate) data_info_host: DataInfoHost,
+    pub(crate) timestamp: DateTime<Local>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct NewListeningPort {
+    pub(crate) listening_port: ListeningPort,
+    pub(crate) remote_address: String,
+    pub(crate) timestamp: DateTime<Local>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BlocklistMatched {
+    pub(crate) blocklist_match: BlocklistMatch,
+    pub(crate) timestamp: DateTime<Local>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ScanDetected {
+    pub(crate) scan: ScanDetection,
+    pub(crate) timestamp: DateTime<Local>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct QuotaReached {
+    pub(crate) adapter: String,
+    /// Percentage of the cap reached
+    pub(crate) level: u8,
+    /// Bytes exchanged by the adapter in the billing cycle
+    pub(crate) used: u64,
+    pub(crate) cap: u64,
+    pub(crate) cycle_start: NaiveDate,
+    pub(crate) timestamp: DateTime<Local>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ActionFailed {
+    /// Command or webhook URL of the action
+    pub(crate) action: String,
+    pub(crate) error: String,
+    pub(crate) timestamp: DateTime<Local>,
 }
@@ -1,3 +1,7 @@
+pub mod alert_trigger;
 pub mod logged_notification;
+pub mod notification_action;
+pub mod notification_filter;
+pub mod notification_rule;
 pub mod notifications;
 pub mod sound;
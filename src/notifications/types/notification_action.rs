@@ -0,0 +1,158 @@
+//! Module defining the `NotificationAction` struct, which allows to run a command
+//! or to call a webhook when a notification is emitted.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::notifications::types::logged_notification::LoggedNotification;
+
+/// Kind of notifications an action applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NotificationKind {
+    All,
+    Packets,
+    Bytes,
+    Favorite,
+    Rule,
+    AlertEnded,
+    FirstSeen,
+    NewListeningPort,
+    Blocklist,
+    Scan,
+    Quota,
+    ActionFailed,
+}
+
+impl NotificationKind {
+    pub(crate) const ALL: [NotificationKind; 12] = [
+        NotificationKind::All,
+        NotificationKind::Packets,
+        NotificationKind::Bytes,
+        NotificationKind::Favorite,
+        NotificationKind::Rule,
+        NotificationKind::AlertEnded,
+        NotificationKind::FirstSeen,
+        NotificationKind::NewListeningPort,
+        NotificationKind::Blocklist,
+        NotificationKind::Scan,
+        NotificationKind::Quota,
+        NotificationKind::ActionFailed,
+    ];
+
+    /// Kinds of notifications actions can be run for
+    pub(crate) const ACTIONABLE: [NotificationKind; 11] = [
+        NotificationKind::All,
+        NotificationKind::Packets,
+        NotificationKind::Bytes,
+        NotificationKind::Favorite,
+        NotificationKind::Rule,
+        NotificationKind::AlertEnded,
+        NotificationKind::FirstSeen,
+        NotificationKind::NewListeningPort,
+        NotificationKind::Blocklist,
+        NotificationKind::Scan,
+        NotificationKind::Quota,
+    ];
+
+    /// Returns the kind of the given notification
+    pub fn of(notification: &LoggedNotification) -> Self {
+        match notification {
+            LoggedNotification::PacketsThresholdExceeded(_) => NotificationKind::Packets,
+            LoggedNotification::BytesThresholdExceeded(_) => NotificationKind::Bytes,
+            LoggedNotification::FavoriteTransmitted(_) => NotificationKind::Favorite,
+            LoggedNotification::RuleThresholdExceeded(_) => NotificationKind::Rule,
+            LoggedNotification::AlertEnded(_) => NotificationKind::AlertEnded,
+            LoggedNotification::FirstSeen(_) => NotificationKind::FirstSeen,
+            LoggedNotification::NewListeningPort(_) => NotificationKind::NewListeningPort,
+            LoggedNotification::BlocklistMatched(_) => NotificationKind::Blocklist,
+            LoggedNotification::ScanDetected(_) => NotificationKind::Scan,
+            LoggedNotification::QuotaReached(_) => NotificationKind::Quota,
+            LoggedNotification::ActionFailed(_) => NotificationKind::ActionFailed,
+        }
+    }
+}
+
+impl fmt::Display for NotificationKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NotificationKind::All => write!(f, "*"),
+            NotificationKind::Packets => write!(f, "Packets"),
+            NotificationKind::Bytes => write!(f, "Bytes"),
+            NotificationKind::Favorite => write!(f, "Favorites"),
+            NotificationKind::Rule => write!(f, "Rules"),
+            NotificationKind::AlertEnded => write!(f, "Alerts ended"),
+            NotificationKind::FirstSeen => write!(f, "First seen"),
+            NotificationKind::NewListeningPort => write!(f, "Listening ports"),
+            NotificationKind::Blocklist => write!(f, "Blocklists"),
+            NotificationKind::Scan => write!(f, "Scans"),
+            NotificationKind::Quota => write!(f, "Data cap"),
+            NotificationKind::ActionFailed => write!(f, "Failed actions"),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct NotificationAction {
+    /// Kind of notifications the action applies to
+    pub kind: NotificationKind,
+    /// Command run by the system shell, receiving the notification as JSON on its standard input
+    pub command: String,
+    /// URL the notification is posted to as JSON
+    pub webhook: String,
+}
+
+impl Default for NotificationAction {
+    fn default() -> Self {
+        NotificationAction {
+            kind: NotificationKind::All,
+            command: String::new(),
+            webhook: String::new(),
+        }
+    }
+}
+
+impl NotificationAction {
+    /// Checks whether the action has something to do, and its webhook (if any) is an HTTP URL
+    pub fn is_valid(&self) -> bool {
+        let webhook = self.webhook.trim();
+        (!self.command.trim().is_empty() || !webhook.is_empty())
+            && (webhook.is_empty()
+                || webhook.starts_with("http://")
+                || webhook.starts_with("https://"))
+    }
+
+    /// Checks whether the action has to be run for the given notification
+    ///
+    /// Failures of the actions never trigger other actions, which could fail in turn
+    pub fn applies_to(&self, notification: &LoggedNotification) -> bool {
+        let kind = NotificationKind::of(notification);
+        kind.ne(&NotificationKind::ActionFailed)
+            && (self.kind.eq(&NotificationKind::All) || self.kind.eq(&kind))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_action_validity() {
+        let command = NotificationAction {
+            command: "notify-send Sniffnet".to_string(),
+            ..NotificationAction::default()
+        };
+        assert!(command.is_valid());
+        let webhook = NotificationAction {
+            webhook: " https://hooks.example.com/sniffnet ".to_string(),
+            ..NotificationAction::default()
+        };
+        assert!(webhook.is_valid());
+        assert!(!NotificationAction::default().is_valid());
+        assert!(!NotificationAction {
+            webhook: "hooks.example.com".to_string(),
+            ..command
+        }
+        .is_valid());
+    }
+}
@@ -0,0 +1,39 @@
+//! Module defining the `NotificationFilter` struct, used to search the logged notifications.
+
+use crate::notifications::types::logged_notification::LoggedNotification;
+use crate::notifications::types::notification_action::NotificationKind;
+
+/// Search and kind filter of the notifications shown and exported
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct NotificationFilter {
+    /// Text searched in the notification summary (case insensitive)
+    pub search: String,
+    /// Kind of the notifications
+    pub kind: NotificationKind,
+}
+
+impl Default for NotificationFilter {
+    fn default() -> Self {
+        NotificationFilter {
+            search: String::new(),
+            kind: NotificationKind::All,
+        }
+    }
+}
+
+impl NotificationFilter {
+    pub fn is_active(&self) -> bool {
+        !self.search.trim().is_empty() || self.kind.ne(&NotificationKind::All)
+    }
+
+    pub fn matches(&self, notification: &LoggedNotification) -> bool {
+        self.matches_summary(NotificationKind::of(notification), &notification.summary())
+    }
+
+    /// Checks the filter against the kind and the summary of a notification
+    pub fn matches_summary(&self, kind: NotificationKind, summary: &str) -> bool {
+        let search = self.search.trim().to_lowercase();
+        (self.kind.eq(&NotificationKind::All) || self.kind.eq(&kind))
+            && (search.is_empty() || summary.to_lowercase().contains(&search))
+    }
+}
@@ -0,0 +1,322 @@
+//! Module defining the `NotificationRule` struct, which allows to be notified
+//! about the traffic exchanged with a specific host, ASN, country, application, or port.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::countries::types::country::Country;
+use crate::networking::manage_packets::get_address_to_lookup;
+use crate::networking::types::data_info::DataInfo;
+use crate::networking::types::traffic_direction::TrafficDirection;
+use crate::notifications::types::sound::Sound;
+use crate::{ByteMultiple, InfoTraffic};
+
+/// Kind of traffic a rule applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RuleScope {
+    /// Traffic exchanged with an IP address or a domain (including its subdomains)
+    Host,
+    /// Traffic exchanged with an Autonomous System, identified by number or name
+    Asn,
+    /// Traffic exchanged with a country, identified by its ISO code
+    Country,
+    /// Traffic of an application protocol
+    App,
+    /// Traffic on a port, either local or remote
+    Port,
+}
+
+impl RuleScope {
+    pub(crate) const ALL: [RuleScope; 5] = [
+        RuleScope::Host,
+        RuleScope::Asn,
+        RuleScope::Country,
+        RuleScope::App,
+        RuleScope::Port,
+    ];
+}
+
+impl fmt::Display for RuleScope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RuleScope::Host => write!(f, "Host"),
+            RuleScope::Asn => write!(f, "ASN"),
+            RuleScope::Country => write!(f, "Country"),
+            RuleScope::App => write!(f, "App"),
+            RuleScope::Port => write!(f, "Port"),
+        }
+    }
+}
+
+/// Direction of the traffic counted by a rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RuleDirection {
+    Incoming,
+    Outgoing,
+    Both,
+}
+
+impl RuleDirection {
+    pub(crate) const ALL: [RuleDirection; 3] = [
+        RuleDirection::Both,
+        RuleDirection::Incoming,
+        RuleDirection::Outgoing,
+    ];
+}
+
+impl fmt::Display for RuleDirection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RuleDirection::Incoming => write!(f, "↓"),
+            RuleDirection::Outgoing => write!(f, "↑"),
+            RuleDirection::Both => write!(f, "↑↓"),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct NotificationRule {
+    /// Kind of traffic the rule applies to
+    pub scope: RuleScope,
+    /// Host, ASN, country, application, or port the rule applies to
+    pub value: String,
+    /// Direction of the traffic counted by the rule
+    pub direction: RuleDirection,
+    /// Threshold of bytes per second; if exceeded a notification is emitted
+    pub threshold: u64,
+    /// B, KB, MB or GB
+    pub byte_multiple: ByteMultiple,
+    /// The sound to emit
+    pub sound: Sound,
+}
+
+impl Default for NotificationRule {
+    fn default() -> Self {
+        NotificationRule {
+            scope: RuleScope::Host,
+            value: String::new(),
+            direction: RuleDirection::Both,
+            threshold: 0,
+            byte_multiple: ByteMultiple::B,
+            sound: Sound::Gulp,
+        }
+    }
+}
+
+impl NotificationRule {
+    /// Checks whether the value is meaningful for the scope of the rule
+    pub fn is_valid(&self) -> bool {
+        let value = self.value.trim();
+        match self.scope {
+            RuleScope::Port => value.parse::<u16>().is_ok(),
+            RuleScope::Country => Country::from_str(&value.to_ascii_uppercase()).ne(&Country::ZZ),
+            RuleScope::Host | RuleScope::Asn | RuleScope::App => !value.is_empty(),
+        }
+    }
+
+    /// Returns the data exchanged so far by the traffic the rule applies to
+    pub fn get_data_info(&self, info_traffic: &InfoTraffic) -> DataInfo {
+        let value = self.value.trim();
+        let mut data_info = DataInfo::default();
+        for (key, info) in &info_traffic.map {
+            let is_match = match self.scope {
+                RuleScope::Port => value
+                    .parse::<u16>()
+                    .is_ok_and(|port| key.port1 == Some(port) || key.port2 == Some(port)),
+                RuleScope::App => format!("{:?}", info.app_protocol).eq_ignore_ascii_case(value),
+                RuleScope::Host | RuleScope::Asn | RuleScope::Country => {
+                    let address = get_address_to_lookup(key, info.traffic_direction);
+                    let host = info_traffic
+                        .addresses_resolved
+                        .get(&address)
+                        .map(|(_, host)| host);
+                    match (self.scope, host) {
+                        (RuleScope::Host, _) if address.eq(value) => true,
+                        (RuleScope::Host, Some(host)) => {
+                            host.domain.eq_ignore_ascii_case(value)
+                                || host
+                                    .domain
+                                    .to_ascii_lowercase()
+                                    .ends_with(&format!(".{}", value.to_ascii_lowercase()))
+                        }
+                        (RuleScope::Asn, Some(host)) => {
+                            host.asn.number.to_string().eq(value)
+                                || (!host.asn.name.is_empty()
+                                    && host.asn.name.eq_ignore_ascii_case(value))
+                        }
+                        (RuleScope::Country, Some(host)) => {
+                            host.country.to_string().eq_ignore_ascii_case(value)
+                        }
+                        _ => false,
+                    }
+                }
+            };
+            if is_match {
+                data_info += match info.traffic_direction {
+                    TrafficDirection::Incoming => DataInfo {
+                        incoming_packets: info.transmitted_packets,
+                        incoming_bytes: info.transmitted_bytes,
+                        ..DataInfo::default()
+                    },
+                    TrafficDirection::Outgoing => DataInfo {
+                        outgoing_packets: info.transmitted_packets,
+                        outgoing_bytes: info.transmitted_bytes,
+                        ..DataInfo::default()
+                    },
+                };
+            }
+        }
+        data_info
+    }
+
+    /// Returns the bytes counted by the rule, according to its direction
+    pub fn get_bytes(&self, data_info: &DataInfo) -> u128 {
+        match self.direction {
+            RuleDirection::Incoming => data_info.incoming_bytes,
+            RuleDirection::Outgoing => data_info.outgoing_bytes,
+            RuleDirection::Both => data_info.tot_bytes(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::networking::types::address_port_pair::AddressPortPair;
+    use crate::networking::types::asn::Asn;
+    use crate::networking::types::host::Host;
+    use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
+    use crate::{AppProtocol, Protocol};
+
+    use super::*;
+
+    fn rule(scope: RuleScope, value: &str) -> NotificationRule {
+        NotificationRule {
+            scope,
+            value: value.to_string(),
+            ..NotificationRule::default()
+        }
+    }
+
+    fn info_traffic() -> InfoTraffic {
+        let mut info_traffic = InfoTraffic::new();
+        // outgoing HTTPS connection to a resolved host
+        info_traffic.map.insert(
+            AddressPortPair::new(
+                "192.168.1.2".to_string(),
+                Some(50000),
+                "1.1.1.1".to_string(),
+                Some(443),
+                Protocol::TCP,
+                "eth0".to_string(),
+            ),
+            InfoAddressPortPair {
+                transmitted_bytes: 300,
+                transmitted_packets: 3,
+                app_protocol: AppProtocol::HTTPS,
+                traffic_direction: TrafficDirection::Outgoing,
+                ..InfoAddressPortPair::default()
+            },
+        );
+        // incoming Telnet connection from a host not resolved yet
+        info_traffic.map.insert(
+            AddressPortPair::new(
+                "10.0.0.5".to_string(),
+                Some(40000),
+                "192.168.1.2".to_string(),
+                Some(23),
+                Protocol::TCP,
+                "eth0".to_string(),
+            ),
+            InfoAddressPortPair {
+                transmitted_bytes: 50,
+                transmitted_packets: 1,
+                app_protocol: AppProtocol::Telnet,
+                traffic_direction: TrafficDirection::Incoming,
+                ..InfoAddressPortPair::default()
+            },
+        );
+        info_traffic.addresses_resolved.insert(
+            "1.1.1.1".to_string(),
+            (
+                "one.one.one.one".to_string(),
+                Host {
+                    domain: "one.one".to_string(),
+                    asn: Asn {
+                        number: 13335,
+                        name: "CLOUDFLARENET".to_string(),
+                    },
+                    country: Country::US,
+                },
+            ),
+        );
+        info_traffic
+    }
+
+    #[test]
+    fn test_rule_validity() {
+        assert!(rule(RuleScope::Port, " 23 ").is_valid());
+        assert!(!rule(RuleScope::Port, "70000").is_valid());
+        assert!(rule(RuleScope::Country, "it").is_valid());
+        assert!(!rule(RuleScope::Country, "XX").is_valid());
+        assert!(rule(RuleScope::Asn, "13335").is_valid());
+        assert!(!rule(RuleScope::Host, " ").is_valid());
+    }
+
+    #[test]
+    fn test_rule_data_info() {
+        let info_traffic = info_traffic();
+        let outgoing = |scope, value| {
+            rule(scope, value)
+                .get_data_info(&info_traffic)
+                .outgoing_bytes
+        };
+
+        assert_eq!(outgoing(RuleScope::Host, "1.1.1.1"), 300);
+        assert_eq!(outgoing(RuleScope::Host, "one.one"), 300);
+        assert_eq!(outgoing(RuleScope::Host, "one"), 300);
+        assert_eq!(outgoing(RuleScope::Host, "ne.one"), 0);
+        assert_eq!(outgoing(RuleScope::Asn, "13335"), 300);
+        assert_eq!(outgoing(RuleScope::Asn, "cloudflarenet"), 300);
+        assert_eq!(outgoing(RuleScope::Country, "us"), 300);
+        assert_eq!(outgoing(RuleScope::Country, "IT"), 0);
+        assert_eq!(outgoing(RuleScope::App, "https"), 300);
+        assert_eq!(outgoing(RuleScope::Port, "443"), 300);
+
+        let telnet = rule(RuleScope::App, "Telnet").get_data_info(&info_traffic);
+        assert_eq!(telnet.incoming_bytes, 50);
+        assert_eq!(telnet.outgoing_bytes, 0);
+        assert_eq!(
+            rule(RuleScope::Port, "23")
+                .get_data_info(&info_traffic)
+                .incoming_packets,
+            1
+        );
+        // hosts waiting for the reverse DNS lookup are only matched by address
+        assert_eq!(
+            rule(RuleScope::Host, "10.0.0.5")
+                .get_data_info(&info_traffic)
+                .incoming_bytes,
+            50
+        );
+    }
+
+    #[test]
+    fn test_rule_bytes_by_direction() {
+        let data_info = DataInfo {
+            incoming_bytes: 100,
+            outgoing_bytes: 20,
+            ..DataInfo::default()
+        };
+        let bytes = |direction| {
+            NotificationRule {
+                direction,
+                ..NotificationRule::default()
+            }
+            .get_bytes(&data_info)
+        };
+        assert_eq!(bytes(RuleDirection::Incoming), 100);
+        assert_eq!(bytes(RuleDirection::Outgoing), 20);
+        assert_eq!(bytes(RuleDirection::Both), 120);
+    }
+}
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::networking::types::byte_multiple::from_char_to_multiple;
+use crate::notifications::types::alert_trigger::AlertTrigger;
 use crate::notifications::types::sound::Sound;
 use crate::ByteMultiple;
 
@@ -11,6 +12,12 @@ pub struct Notifications {
     pub packets_notification: PacketsNotification,
     pub bytes_notification: BytesNotification,
     pub favorite_notification: FavoriteNotification,
+    pub first_seen_notification: FirstSeenNotification,
+    pub new_port_notification: NewPortNotification,
+    pub blocklist_notification: BlocklistNotification,
+    pub scan_notification: ScanNotification,
+    pub quota_notification: QuotaNotification,
+    pub trigger: AlertTrigger,
 }
 
 impl Default for Notifications {
@@ -20,6 +27,12 @@ fn default() -> Self {
             packets_notification: PacketsNotification::default(),
             bytes_notification: BytesNotification::default(),
             favorite_notification: FavoriteNotification::default(),
+            first_seen_notification: FirstSeenNotification::default(),
+            new_port_notification: NewPortNotification::default(),
+            blocklist_notification: BlocklistNotification::default(),
+            scan_notification: ScanNotification::default(),
+            quota_notification: QuotaNotification::default(),
+            trigger: AlertTrigger::default(),
         }
     }
 }
@@ -33,6 +46,16 @@ pub enum Notification {
     Bytes(BytesNotification),
     /// Favorites notification
     Favorite(FavoriteNotification),
+    /// First seen host, country, or ASN notification
+    FirstSeen(FirstSeenNotification),
+    /// New listening port notification
+    NewPort(NewPortNotification),
+    /// Blocklist match notification
+    Blocklist(BlocklistNotification),
+    /// Port scan, host sweep, and SYN flood notification
+    Scan(ScanNotification),
+    /// Data cap of the billing cycle notification
+    Quota(QuotaNotification),
 }
 
 #[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug, Copy)]
@@ -172,6 +195,211 @@ pub fn off(sound: Sound) -> Self {
     }
 }
 
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug, Copy)]
+pub struct FirstSeenNotification {
+    /// Flag to determine if this notification is enabled
+    pub notify_on_first_seen: bool,
+    /// The sound to emit
+    pub sound: Sound,
+}
+
+impl Default for FirstSeenNotification {
+    fn default() -> Self {
+        FirstSeenNotification {
+            notify_on_first_seen: false,
+            sound: Sound::Pop,
+        }
+    }
+}
+
+impl FirstSeenNotification {
+    /// Constructor when the notification is in use
+    pub fn on(sound: Sound) -> Self {
+        FirstSeenNotification {
+            notify_on_first_seen: true,
+            sound,
+        }
+    }
+
+    /// Constructor when the notification is not in use
+    pub fn off(sound: Sound) -> Self {
+        FirstSeenNotification {
+            notify_on_first_seen: false,
+            sound,
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug, Copy)]
+pub struct NewPortNotification {
+    /// Flag to determine if this notification is enabled
+    pub notify_on_new_port: bool,
+    /// The sound to emit
+    pub sound: Sound,
+}
+
+impl Default for NewPortNotification {
+    fn default() -> Self {
+        NewPortNotification {
+            notify_on_new_port: false,
+            sound: Sound::Gulp,
+        }
+    }
+}
+
+impl NewPortNotification {
+    /// Constructor when the notification is in use
+    pub fn on(sound: Sound) -> Self {
+        NewPortNotification {
+            notify_on_new_port: true,
+            sound,
+        }
+    }
+
+    /// Constructor when the notification is not in use
+    pub fn off(sound: Sound) -> Self {
+        NewPortNotification {
+            notify_on_new_port: false,
+            sound,
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug, Copy)]
+pub struct BlocklistNotification {
+    /// Flag to determine if this notification is enabled
+    pub notify_on_match: bool,
+    /// The sound to emit
+    pub sound: Sound,
+}
+
+impl Default for BlocklistNotification {
+    fn default() -> Self {
+        // loading a blocklist is enough to be notified of its matches
+        BlocklistNotification {
+            notify_on_match: true,
+            sound: Sound::Swhoosh,
+        }
+    }
+}
+
+impl BlocklistNotification {
+    /// Constructor when the notification is in use
+    pub fn on(sound: Sound) -> Self {
+        BlocklistNotification {
+            notify_on_match: true,
+            sound,
+        }
+    }
+
+    /// Constructor when the notification is not in use
+    pub fn off(sound: Sound) -> Self {
+        BlocklistNotification {
+            notify_on_match: false,
+            sound,
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug, Copy)]
+pub struct ScanNotification {
+    /// Flag to determine if this notification is enabled
+    pub notify_on_scan: bool,
+    /// The sound to emit
+    pub sound: Sound,
+}
+
+impl Default for ScanNotification {
+    fn default() -> Self {
+        // scans are rare in normal traffic, and worth knowing about
+        ScanNotification {
+            notify_on_scan: true,
+            sound: Sound::Gulp,
+        }
+    }
+}
+
+impl ScanNotification {
+    /// Constructor when the notification is in use
+    pub fn on(sound: Sound) -> Self {
+        ScanNotification {
+            notify_on_scan: true,
+            sound,
+        }
+    }
+
+    /// Constructor when the notification is not in use
+    pub fn off(sound: Sound) -> Self {
+        ScanNotification {
+            notify_on_scan: false,
+            sound,
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug, Copy)]
+pub struct QuotaNotification {
+    /// Data cap of each network adapter in a billing cycle; notifications are emitted
+    /// when 80%, 90%, and 100% of it is used
+    pub cap: Option<u64>,
+    /// B, KB, MB or GB
+    pub byte_multiple: ByteMultiple,
+    /// Day of the month on which billing cycles start (from 1 to 28)
+    pub cycle_start_day: u8,
+    /// The sound to emit
+    pub sound: Sound,
+    /// The last used Some value for the cap field
+    pub previous_cap: u64,
+}
+
+impl Default for QuotaNotification {
+    fn default() -> Self {
+        QuotaNotification {
+            cap: None,
+            byte_multiple: ByteMultiple::GB,
+            cycle_start_day: 1,
+            sound: Sound::Pop,
+            previous_cap: 10_000_000_000,
+        }
+    }
+}
+
+impl QuotaNotification {
+    /// Arbitrary string constructor. Will fallback values to existing notification if set, default() otherwise
+    pub fn from(value: &str, existing: Option<Self>) -> Self {
+        let default = existing.unwrap_or_default();
+        // the cap is parsed like a bytes threshold
+        let bytes_notification = BytesNotification::from(
+            value,
+            Some(BytesNotification {
+                threshold: default.cap,
+                byte_multiple: default.byte_multiple,
+                sound: default.sound,
+                previous_threshold: default.previous_cap,
+            }),
+        );
+        Self {
+            cap: bytes_notification.threshold,
+            byte_multiple: bytes_notification.byte_multiple,
+            previous_cap: bytes_notification.previous_threshold,
+            ..default
+        }
+    }
+
+    /// Arbitrary string constructor for the day on which billing cycles start
+    pub fn with_cycle_start_day(self, value: &str) -> Self {
+        let cycle_start_day = match value.trim().parse::<u8>() {
+            Ok(day) if (1..=28).contains(&day) => day,
+            _ if value.trim().is_empty() => 1,
+            _ => self.cycle_start_day,
+        };
+        Self {
+            cycle_start_day,
+            ..self
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -272,4 +500,39 @@ fn test_can_instantiate_packet_notification_from_string(
     ) {
         assert_eq!(expected, PacketsNotification::from(input, None));
     }
+
+    #[test]
+    fn test_can_instantiate_quota_notification_from_string() {
+        let quota_notification = QuotaNotification::from("5 g", None);
+        assert_eq!(
+            quota_notification,
+            QuotaNotification {
+                cap: Some(5_000_000_000),
+                previous_cap: 5_000_000_000,
+                ..QuotaNotification::default()
+            }
+        );
+        assert_eq!(
+            QuotaNotification::from("foob@r", Some(quota_notification)),
+            quota_notification
+        );
+        assert_eq!(
+            QuotaNotification::from("750M", Some(quota_notification)).cap,
+            Some(750_000_000)
+        );
+
+        assert_eq!(
+            quota_notification
+                .with_cycle_start_day("15")
+                .cycle_start_day,
+            15
+        );
+        assert_eq!(
+            quota_notification.with_cycle_start_day("").cycle_start_day,
+            1
+        );
+        let fifteenth = quota_notification.with_cycle_start_day("15");
+        assert_eq!(fifteenth.with_cycle_start_day("31").cycle_start_day, 15);
+        assert_eq!(fifteenth.with_cycle_start_day("x").cycle_start_day, 15);
+    }
 }
@@ -0,0 +1 @@
+pub mod oui_reader;
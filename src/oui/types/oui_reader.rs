@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+/// Table of the IEEE assignments (MA-L, MA-M, MA-S) with their organization names.
+///
+/// Keys are uppercase hexadecimal prefixes of 6, 7, or 9 digits.
+pub enum OuiReader {
+    Default(HashMap<String, String>),
+    Custom(HashMap<String, String>),
+}
+
+impl OuiReader {
+    pub fn from(oui_path: &String, default_oui: &'static [u8]) -> OuiReader {
+        if !oui_path.is_empty() {
+            if let Ok(custom_oui) = std::fs::read(oui_path) {
+                let custom_assignments = parse_oui_csv(&custom_oui);
+                if !custom_assignments.is_empty() {
+                    return OuiReader::Custom(custom_assignments);
+                }
+            }
+        }
+        OuiReader::Default(parse_oui_csv(default_oui))
+    }
+
+    pub fn assignments(&self) -> &HashMap<String, String> {
+        match self {
+            OuiReader::Default(assignments) | OuiReader::Custom(assignments) => assignments,
+        }
+    }
+}
+
+/// Parses a file in the format distributed by the IEEE (`oui.csv`, `mam.csv`, `oui36.csv`):
+/// `Registry,Assignment,Organization Name,Organization Address`
+fn parse_oui_csv(content: &[u8]) -> HashMap<String, String> {
+    let mut assignments = HashMap::new();
+    for line in String::from_utf8_lossy(content).lines() {
+        let fields = split_csv_line(line);
+        if let (Some(assignment), Some(organization)) = (fields.get(1), fields.get(2)) {
+            let assignment = assignment.trim().to_uppercase();
+            if [6, 7, 9].contains(&assignment.len())
+                && assignment.chars().all(|c| c.is_ascii_hexdigit())
+            {
+                assignments.insert(assignment, organization.trim().to_string());
+            }
+        }
+    }
+    assignments
+}
+
+/// Splits a CSV line into its fields, taking care of double-quoted values
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek().eq(&Some(&'"')) => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_csv_line() {
+        assert_eq!(
+            split_csv_line(
+                r#"MA-L,F01898,"Apple, Inc.","1 Infinite Loop Cupertino CA US 95014 ""HQ""""#
+            ),
+            vec![
+                "MA-L".to_string(),
+                "F01898".to_string(),
+                "Apple, Inc.".to_string(),
+                r#"1 Infinite Loop Cupertino CA US 95014 "HQ""#.to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_oui_csv() {
+        let content = "Registry,Assignment,Organization Name,Organization Address\n\
+            MA-L,B827EB,Raspberry Pi Foundation,Cambridge\n\
+            MA-M,70B3D51,\"Acme, Ltd.\",\n\
+            MA-S,8C1F64ABC,Tiny Vendor,\n\
+            MA-L,XYZ,Broken Entry,\n";
+        let assignments = parse_oui_csv(content.as_bytes());
+        assert_eq!(assignments.len(), 3);
+        assert_eq!(
+            assignments.get("B827EB"),
+            Some(&"Raspberry Pi Foundation".to_string())
+        );
+        assert_eq!(assignments.get("70B3D51"), Some(&"Acme, Ltd.".to_string()));
+        assert_eq!(
+            assignments.get("8C1F64ABC"),
+            Some(&"Tiny Vendor".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_merged_registries() {
+        // the headers of the single registries may be repeated in the merged file
+        let content = "Registry,Assignment,Organization Name,Organization Address\r\n\
+            MA-L,70B3D5,IEEE Registration Authority,\r\n\
+            Registry,Assignment,Organization Name,Organization Address\r\n\
+            MA-M,70B3D51,Medium Vendor ,\r\n\
+            Registry,Assignment,Organization Name,Organization Address\r\n\
+            MA-S,70B3D5123,Small Vendor,\r\n";
+        let assignments = parse_oui_csv(content.as_bytes());
+        assert_eq!(assignments.len(), 3);
+        assert_eq!(
+            assignments.get("70B3D51"),
+            Some(&"Medium Vendor".to_string())
+        );
+        assert_eq!(
+            assignments.get("70B3D5123"),
+            Some(&"Small Vendor".to_string())
+        );
+    }
+
+    #[test]
+    fn test_custom_oui_fallback_to_default() {
+        let reader = OuiReader::from(&"/not/existing/oui.csv".to_string(), b"MA-L,B827EB,Pi,\n");
+        assert!(matches!(reader, OuiReader::Default(_)));
+        assert_eq!(reader.assignments().get("B827EB"), Some(&"Pi".to_string()));
+    }
+}
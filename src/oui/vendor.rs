@@ -0,0 +1,83 @@
+use crate::oui::types::oui_reader::OuiReader;
+
+pub const OUI_DB: &[u8] = include_bytes!("../../resources/DB/oui.csv");
+
+/// Returns the organization to which the IEEE assigned the prefix of the given MAC address
+pub fn get_vendor(mac_address: &str, oui_reader: &OuiReader) -> Option<String> {
+    let hex: String = mac_address
+        .chars()
+        .filter(char::is_ascii_hexdigit)
+        .collect::<String>()
+        .to_uppercase();
+    if hex.len() != 12 {
+        return None;
+    }
+    // locally administered addresses (e.g., randomized or virtual interfaces) have no vendor
+    let first_octet = u8::from_str_radix(&hex[..2], 16).unwrap_or_default();
+    if first_octet & 0b10 != 0 {
+        return None;
+    }
+    // look for the most specific assignment first (MA-S, MA-M, MA-L)
+    let assignments = oui_reader.assignments();
+    for prefix_len in [9, 7, 6] {
+        if let Some(organization) = assignments.get(&hex[..prefix_len]) {
+            return Some(organization.clone());
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::oui::types::oui_reader::OuiReader;
+    use crate::oui::vendor::{get_vendor, OUI_DB};
+
+    #[test]
+    fn test_get_vendor_default_db() {
+        let reader = OuiReader::from(&String::new(), OUI_DB);
+        assert_eq!(
+            get_vendor("b8:27:eb:12:34:56", &reader),
+            Some("Raspberry Pi Foundation".to_string())
+        );
+        assert_eq!(
+            get_vendor("24:0A:C4:00:00:01", &reader),
+            Some("Espressif Inc.".to_string())
+        );
+    }
+
+    #[test]
+    fn test_get_vendor_not_found() {
+        let reader = OuiReader::from(&String::new(), OUI_DB);
+        // locally administered
+        assert_eq!(get_vendor("02:42:ac:11:00:02", &reader), None);
+        // malformed
+        assert_eq!(get_vendor("b8:27:eb", &reader), None);
+        assert_eq!(get_vendor("", &reader), None);
+    }
+
+    #[test]
+    fn test_get_vendor_longest_prefix() {
+        let reader = OuiReader::Custom(HashMap::from([
+            (
+                "70B3D5".to_string(),
+                "IEEE Registration Authority".to_string(),
+            ),
+            ("70B3D51".to_string(), "Medium Vendor".to_string()),
+            ("70B3D5123".to_string(), "Small Vendor".to_string()),
+        ]));
+        assert_eq!(
+            get_vendor("70:b3:d5:12:34:56", &reader),
+            Some("Small Vendor".to_string())
+        );
+        assert_eq!(
+            get_vendor("70:b3:d5:1f:34:56", &reader),
+            Some("Medium Vendor".to_string())
+        );
+        assert_eq!(
+            get_vendor("70:b3:d5:f1:34:56", &reader),
+            Some("IEEE Registration Authority".to_string())
+        );
+    }
+}
@@ -7,6 +7,7 @@
 use crate::networking::types::address_port_pair::AddressPortPair;
 use crate::networking::types::data_info::DataInfo;
 use crate::networking::types::data_info_host::DataInfoHost;
+use crate::networking::types::dns_query::DnsQuery;
 use crate::networking::types::host::Host;
 use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
 use crate::report::types::report_entry::ReportEntry;
@@ -76,6 +77,13 @@ pub fn get_searched_entries(sniffer: &Sniffer) -> (Vec<ReportEntry>, usize) {
                     return false;
                 }
             }
+            // check network adapter filter
+            let searched_interface = &*sniffer.search.interface.to_lowercase();
+            if !searched_interface.is_empty()
+                && !value.interface.to_lowercase().contains(searched_interface)
+            {
+                return false;
+            }
             // check favorites filter
             if searched_only_fav
                 && !info_traffic_lock
@@ -128,6 +136,10 @@ pub fn get_searched_entries(sniffer: &Sniffer) -> (Vec<ReportEntry>, usize) {
                     key: key_val.0.clone(),
                     val: key_val.1.clone(),
                     tooltip: flag,
+                    blocklist: info_traffic_lock
+                        .blocklisted
+                        .get(&address_to_lookup)
+                        .map(|blocklist_match| blocklist_match.list.clone()),
                 }
             })
             .collect(),
@@ -135,20 +147,84 @@ pub fn get_searched_entries(sniffer: &Sniffer) -> (Vec<ReportEntry>, usize) {
     )
 }
 
+/// Returns the DNS queries which satisfy the search constraints and belong to the given page
+/// (most recent first), and the total number of queries which satisfy the search constraints
+pub fn get_searched_dns_queries(sniffer: &Sniffer) -> (Vec<DnsQuery>, usize) {
+    let searched_client = &*sniffer.dns_search.client.to_lowercase();
+    let searched_name = &*sniffer.dns_search.name.to_lowercase();
+    let searched_type = &*sniffer.dns_search.query_type.to_lowercase();
+    let searched_response_code = &*sniffer.dns_search.response_code.to_lowercase();
+
+    let info_traffic_lock = sniffer.info_traffic.lock().unwrap();
+    let all_results: Vec<&DnsQuery> = info_traffic_lock
+        .dns_queries
+        .iter()
+        .rev()
+        .filter(|query| {
+            // check client address filter
+            if !searched_client.is_empty() && !query.client.to_lowercase().contains(searched_client)
+            {
+                return false;
+            }
+            // check queried name filter
+            if !searched_name.is_empty() && !query.name.to_lowercase().contains(searched_name) {
+                return false;
+            }
+            // check record type filter
+            if !searched_type.is_empty()
+                && query.get_query_type_str().to_lowercase().ne(searched_type)
+            {
+                return false;
+            }
+            // check response code filter
+            if !searched_response_code.is_empty()
+                && !query
+                    .get_response_code_str()
+                    .to_lowercase()
+                    .starts_with(searched_response_code)
+            {
+                return false;
+            }
+            // if arrived at this point all filters are satisfied => return true
+            true
+        })
+        .collect();
+
+    let upper_bound = min(sniffer.dns_page_number * 20, all_results.len());
+
+    (
+        all_results
+            .get((sniffer.dns_page_number - 1) * 20..upper_bound)
+            .unwrap_or(&Vec::new())
+            .iter()
+            .map(|query| (*query).clone())
+            .collect(),
+        all_results.len(),
+    )
+}
+
 pub fn get_host_entries(
     info_traffic: &Arc<Mutex<InfoTraffic>>,
     chart_type: ChartType,
 ) -> Vec<(Host, DataInfoHost)> {
-    let info_traffic_lock = info_traffic.lock().unwrap();
-    let mut sorted_vec: Vec<(&Host, &DataInfoHost)> = info_traffic_lock.hosts.iter().collect();
+    let mut entries = get_all_host_entries(&info_traffic.lock().unwrap(), chart_type);
+    entries.truncate(30);
+    entries
+}
+
+/// Returns all the hosts, sorted by the data exchanged
+pub fn get_all_host_entries(
+    info_traffic: &InfoTraffic,
+    chart_type: ChartType,
+) -> Vec<(Host, DataInfoHost)> {
+    let mut sorted_vec: Vec<(&Host, &DataInfoHost)> = info_traffic.hosts.iter().collect();
 
     sorted_vec.sort_by(|&(_, a), &(_, b)| match chart_type {
         ChartType::Packets => b.data_info.tot_packets().cmp(&a.data_info.tot_packets()),
         ChartType::Bytes => b.data_info.tot_bytes().cmp(&a.data_info.tot_bytes()),
     });
 
-    let n_entry = min(sorted_vec.len(), 30);
-    sorted_vec[0..n_entry]
+    sorted_vec
         .iter()
         .map(|e| (e.0.clone(), e.1.clone()))
         .collect()
@@ -158,8 +234,15 @@ pub fn get_app_entries(
     info_traffic: &Arc<Mutex<InfoTraffic>>,
     chart_type: ChartType,
 ) -> Vec<(AppProtocol, DataInfo)> {
-    let info_traffic_lock = info_traffic.lock().unwrap();
-    let mut sorted_vec: Vec<(&AppProtocol, &DataInfo)> = info_traffic_lock
+    get_all_app_entries(&info_traffic.lock().unwrap(), chart_type)
+}
+
+/// Returns all the application protocols (except "not applicable"), sorted by the data exchanged
+pub fn get_all_app_entries(
+    info_traffic: &InfoTraffic,
+    chart_type: ChartType,
+) -> Vec<(AppProtocol, DataInfo)> {
+    let mut sorted_vec: Vec<(&AppProtocol, &DataInfo)> = info_traffic
         .app_protocols
         .iter()
         .filter(|(app_protocol, _)| app_protocol.ne(&&AppProtocol::NotApplicable))
@@ -1,2 +1,3 @@
 pub mod report_entry;
 pub mod report_sort_type;
+pub mod session_comparison;
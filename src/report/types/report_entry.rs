@@ -11,4 +11,6 @@ pub struct ReportEntry {
     pub key: AddressPortPair,
     pub val: InfoAddressPortPair,
     pub tooltip: Tooltip<'static, Message, Renderer<StyleType>>,
+    /// Name of the blocklist matched by the remote address, if any
+    pub blocklist: Option<String>,
 }
@@ -0,0 +1,276 @@
+//! Module defining the `SessionComparison` struct, which represents the differences
+//! between a baseline session and the current one.
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::countries::types::country::Country;
+use crate::networking::types::asn::Asn;
+use crate::networking::types::data_info_host::DataInfoHost;
+use crate::networking::types::host::Host;
+use crate::report::get_report_entries::{get_all_app_entries, get_all_host_entries};
+use crate::{AppProtocol, ChartType, InfoTraffic};
+
+/// Bytes exchanged by an item (e.g., an application protocol or a country) in the two compared sessions
+#[derive(Clone, Debug, PartialEq)]
+pub struct BytesDelta<T> {
+    pub item: T,
+    pub baseline_bytes: u128,
+    pub current_bytes: u128,
+}
+
+impl<T> BytesDelta<T> {
+    /// Returns the variation of the exchanged bytes in percentage, or `None` if the item is new
+    #[allow(clippy::cast_precision_loss)]
+    pub fn get_percentage(&self) -> Option<f64> {
+        if self.baseline_bytes == 0 {
+            return None;
+        }
+        let baseline = self.baseline_bytes as f64;
+        Some((self.current_bytes as f64 - baseline) / baseline * 100.0)
+    }
+
+    /// Returns the variation of the exchanged bytes in percentage, formatted as a string
+    pub fn get_percentage_string(&self) -> String {
+        match self.get_percentage() {
+            None => "NEW".to_string(),
+            Some(percentage) => format!("{percentage:+.1}%"),
+        }
+    }
+}
+
+/// Differences between a baseline session and the current one
+#[derive(Default)]
+pub struct SessionComparison {
+    /// Hosts which didn't exchange data in the baseline session, with the bytes exchanged now
+    pub new_hosts: Vec<(Host, u128)>,
+    /// Hosts of the baseline session which didn't exchange data anymore, with the bytes exchanged back then
+    pub disappeared_hosts: Vec<(Host, u128)>,
+    /// Autonomous Systems not contacted in the baseline session
+    pub new_asns: Vec<Asn>,
+    /// Autonomous Systems of the baseline session not contacted anymore
+    pub disappeared_asns: Vec<Asn>,
+    /// Bytes exchanged by each application protocol in the two sessions
+    pub apps: Vec<BytesDelta<AppProtocol>>,
+    /// Bytes exchanged with each country in the two sessions
+    pub countries: Vec<BytesDelta<Country>>,
+}
+
+impl SessionComparison {
+    pub fn new(baseline: &InfoTraffic, current: &InfoTraffic) -> Self {
+        let baseline_hosts = get_all_host_entries(baseline, ChartType::Bytes);
+        let current_hosts = get_all_host_entries(current, ChartType::Bytes);
+
+        let new_hosts = get_missing_hosts(&current_hosts, &baseline_hosts);
+        let disappeared_hosts = get_missing_hosts(&baseline_hosts, &current_hosts);
+
+        let baseline_asns = get_asns(&baseline_hosts);
+        let current_asns = get_asns(&current_hosts);
+        let new_asns = get_missing_asns(&current_asns, &baseline_asns);
+        let disappeared_asns = get_missing_asns(&baseline_asns, &current_asns);
+
+        let apps = get_deltas(
+            get_all_app_entries(baseline, ChartType::Bytes)
+                .into_iter()
+                .map(|(app, data_info)| (app, data_info.tot_bytes())),
+            get_all_app_entries(current, ChartType::Bytes)
+                .into_iter()
+                .map(|(app, data_info)| (app, data_info.tot_bytes())),
+        );
+
+        let countries = get_deltas(
+            baseline_hosts
+                .iter()
+                .map(|(host, data_info_host)| (host.country, data_info_host.data_info.tot_bytes())),
+            current_hosts
+                .iter()
+                .map(|(host, data_info_host)| (host.country, data_info_host.data_info.tot_bytes())),
+        );
+
+        SessionComparison {
+            new_hosts,
+            disappeared_hosts,
+            new_asns,
+            disappeared_asns,
+            apps,
+            countries,
+        }
+    }
+}
+
+/// Returns the hosts of `hosts` not included in `other_hosts`, with their exchanged bytes
+fn get_missing_hosts(
+    hosts: &[(Host, DataInfoHost)],
+    other_hosts: &[(Host, DataInfoHost)],
+) -> Vec<(Host, u128)> {
+    let other_hosts: HashSet<&Host> = other_hosts.iter().map(|(host, _)| host).collect();
+    hosts
+        .iter()
+        .filter(|(host, _)| !other_hosts.contains(host))
+        .map(|(host, data_info_host)| (host.clone(), data_info_host.data_info.tot_bytes()))
+        .collect()
+}
+
+/// Returns the known Autonomous Systems contacted by the given hosts, in order of appearance
+fn get_asns(hosts: &[(Host, DataInfoHost)]) -> Vec<Asn> {
+    let mut seen = HashSet::new();
+    hosts
+        .iter()
+        .map(|(host, _)| &host.asn)
+        .filter(|asn| asn.number != 0 && seen.insert(asn.number))
+        .cloned()
+        .collect()
+}
+
+fn get_missing_asns(asns: &[Asn], other_asns: &[Asn]) -> Vec<Asn> {
+    let other_asns: HashSet<u32> = other_asns.iter().map(|asn| asn.number).collect();
+    asns.iter()
+        .filter(|asn| !other_asns.contains(&asn.number))
+        .cloned()
+        .collect()
+}
+
+/// Sums the bytes of each item in the two sessions, sorting the results by the bigger of the two values
+fn get_deltas<T: Copy + Eq + Hash>(
+    baseline: impl Iterator<Item = (T, u128)>,
+    current: impl Iterator<Item = (T, u128)>,
+) -> Vec<BytesDelta<T>> {
+    let mut map: HashMap<T, BytesDelta<T>> = HashMap::new();
+    for (item, bytes) in baseline {
+        map.entry(item)
+            .or_insert(BytesDelta {
+                item,
+                baseline_bytes: 0,
+                current_bytes: 0,
+            })
+            .baseline_bytes += bytes;
+    }
+    for (item, bytes) in current {
+        map.entry(item)
+            .or_insert(BytesDelta {
+                item,
+                baseline_bytes: 0,
+                current_bytes: 0,
+            })
+            .current_bytes += bytes;
+    }
+    let mut deltas: Vec<BytesDelta<T>> = map.into_values().collect();
+    deltas.sort_by(|a, b| {
+        b.baseline_bytes
+            .max(b.current_bytes)
+            .cmp(&a.baseline_bytes.max(a.current_bytes))
+    });
+    deltas
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::networking::types::data_info::DataInfo;
+
+    use super::*;
+
+    fn host(domain: &str, asn_number: u32, country: Country) -> Host {
+        Host {
+            domain: domain.to_string(),
+            asn: Asn {
+                number: asn_number,
+                name: format!("AS{asn_number}"),
+            },
+            country,
+        }
+    }
+
+    fn data_info_host(bytes: u128) -> DataInfoHost {
+        DataInfoHost {
+            data_info: DataInfo {
+                incoming_packets: 1,
+                outgoing_packets: 0,
+                incoming_bytes: bytes,
+                outgoing_bytes: 0,
+            },
+            ..DataInfoHost::default()
+        }
+    }
+
+    fn data_info(bytes: u128) -> DataInfo {
+        data_info_host(bytes).data_info
+    }
+
+    #[test]
+    fn test_session_comparison() {
+        let mut baseline = InfoTraffic::new();
+        baseline.hosts = HashMap::from([
+            (host("example.com", 1, Country::US), data_info_host(1000)),
+            (host("example.ru", 2, Country::RU), data_info_host(500)),
+            (host("example.it", 3, Country::IT), data_info_host(200)),
+        ]);
+        baseline.app_protocols = HashMap::from([
+            (AppProtocol::HTTPS, data_info(1500)),
+            (AppProtocol::DNS, data_info(200)),
+        ]);
+
+        let mut current = InfoTraffic::new();
+        current.hosts = HashMap::from([
+            (host("example.com", 1, Country::US), data_info_host(3000)),
+            (host("example.it", 3, Country::IT), data_info_host(100)),
+            (host("example.de", 4, Country::DE), data_info_host(50)),
+        ]);
+        current.app_protocols = HashMap::from([
+            (AppProtocol::HTTPS, data_info(3000)),
+            (AppProtocol::SSH, data_info(150)),
+        ]);
+
+        let comparison = SessionComparison::new(&baseline, &current);
+
+        assert_eq!(
+            comparison.new_hosts,
+            vec![(host("example.de", 4, Country::DE), 50)]
+        );
+        assert_eq!(
+            comparison.disappeared_hosts,
+            vec![(host("example.ru", 2, Country::RU), 500)]
+        );
+        assert_eq!(comparison.new_asns.len(), 1);
+        assert_eq!(comparison.new_asns[0].number, 4);
+        assert_eq!(comparison.disappeared_asns.len(), 1);
+        assert_eq!(comparison.disappeared_asns[0].number, 2);
+
+        assert_eq!(
+            comparison.apps,
+            vec![
+                BytesDelta {
+                    item: AppProtocol::HTTPS,
+                    baseline_bytes: 1500,
+                    current_bytes: 3000
+                },
+                BytesDelta {
+                    item: AppProtocol::DNS,
+                    baseline_bytes: 200,
+                    current_bytes: 0
+                },
+                BytesDelta {
+                    item: AppProtocol::SSH,
+                    baseline_bytes: 0,
+                    current_bytes: 150
+                },
+            ]
+        );
+        assert_eq!(comparison.apps[0].get_percentage_string(), "+100.0%");
+        assert_eq!(comparison.apps[1].get_percentage_string(), "-100.0%");
+        assert_eq!(comparison.apps[2].get_percentage(), None);
+
+        let russia = comparison
+            .countries
+            .iter()
+            .find(|delta| delta.item == Country::RU)
+            .unwrap();
+        assert_eq!(russia.current_bytes, 0);
+        assert_eq!(russia.get_percentage(), Some(-100.0));
+        let italy = comparison
+            .countries
+            .iter()
+            .find(|delta| delta.item == Country::IT)
+            .unwrap();
+        assert_eq!(italy.get_percentage_string(), "-50.0%");
+    }
+}
@@ -0,0 +1,121 @@
+//! Module containing the function executed by the thread in charge of exporting
+//! the observed flows to a NetFlow v9 or IPFIX collector.
+
+use std::net::{SocketAddr, UdpSocket};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use chrono::Local;
+
+use crate::flow_export::flow_exporter::FlowExporter;
+use crate::InfoTraffic;
+
+/// Returns a UDP socket connected to the collector at the given address
+pub fn connect_to_collector(collector: &str) -> std::io::Result<UdpSocket> {
+    let collector: SocketAddr = collector
+        .parse()
+        .map_err(|_| std::io::Error::from(std::io::ErrorKind::InvalidInput))?;
+    let local_address = if collector.is_ipv4() {
+        "0.0.0.0:0"
+    } else {
+        "[::]:0"
+    };
+    let socket = UdpSocket::bind(local_address)?;
+    socket.connect(collector)?;
+    Ok(socket)
+}
+
+/// The calling thread enters in a loop in which, every second, it sends to the collector
+/// the flows exceeding the active or inactive timeout.
+///
+/// The loop terminates (after sending all the flows not exported yet) when the capture is interrupted.
+pub fn export_flows(
+    current_capture_id: &Arc<Mutex<usize>>,
+    info_traffic_mutex: &Arc<Mutex<InfoTraffic>>,
+    mut exporter: FlowExporter,
+    socket: &UdpSocket,
+) {
+    let capture_id = *current_capture_id.lock().unwrap();
+
+    let mut is_capture_over = false;
+    while !is_capture_over {
+        thread::sleep(Duration::from_secs(1));
+        is_capture_over = *current_capture_id.lock().unwrap() != capture_id;
+
+        let now = Local::now();
+        let records = exporter.get_expired_records(
+            &info_traffic_mutex.lock().unwrap().map,
+            now,
+            is_capture_over,
+        );
+        for packet in exporter.encode(&records, now) {
+            // the collector may be temporarily unreachable: the records are lost, as with any flow probe
+            let _ = socket.send(&packet);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flow_export::types::flow_export_settings::FlowExportSettings;
+    use crate::networking::types::address_port_pair::AddressPortPair;
+    use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
+    use crate::Protocol;
+
+    use super::*;
+
+    #[test]
+    fn test_export_flows_to_local_collector() {
+        let collector = UdpSocket::bind("127.0.0.1:0").unwrap();
+        collector
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        let socket = connect_to_collector(&collector.local_addr().unwrap().to_string()).unwrap();
+
+        let current_capture_id = Arc::new(Mutex::new(0));
+        let mut info_traffic = InfoTraffic::new();
+        info_traffic.map.insert(
+            AddressPortPair::new(
+                "192.168.1.2".to_string(),
+                Some(55555),
+                "1.1.1.1".to_string(),
+                Some(53),
+                Protocol::UDP,
+                "eth0".to_string(),
+            ),
+            InfoAddressPortPair {
+                transmitted_bytes: 120,
+                transmitted_packets: 2,
+                initial_timestamp: Local::now(),
+                final_timestamp: Local::now(),
+                ..InfoAddressPortPair::default()
+            },
+        );
+        let info_traffic_mutex = Arc::new(Mutex::new(info_traffic));
+        let exporter = FlowExporter::new(&FlowExportSettings::default());
+
+        let capture_id = current_capture_id.clone();
+        let handle = thread::spawn(move || {
+            export_flows(&capture_id, &info_traffic_mutex, exporter, &socket);
+        });
+
+        // the flow is still active: it's exported when the capture is interrupted
+        thread::sleep(Duration::from_millis(200));
+        *current_capture_id.lock().unwrap() += 1;
+        handle.join().unwrap();
+
+        let mut buffer = [0; 1500];
+        let size = collector.recv(&mut buffer).unwrap();
+        // NetFlow v9 header with two templates and one data record
+        assert_eq!(&buffer[0..4], &[0, 9, 0, 3]);
+        assert!(size > 20);
+    }
+
+    #[test]
+    fn test_connect_to_invalid_collector() {
+        assert!(connect_to_collector("collector").is_err());
+        assert!(connect_to_collector("127.0.0.1").is_err());
+        assert!(connect_to_collector("127.0.0.1:2055").is_ok());
+    }
+}
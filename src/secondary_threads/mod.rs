@@ -1,2 +1,8 @@
 pub mod check_updates;
+pub mod export_flows;
 pub mod parse_packets;
+pub mod serve_api;
+pub mod serve_metrics;
+pub mod store_configs;
+pub mod store_history;
+pub mod write_events;
@@ -5,37 +5,48 @@
 use std::sync::{Arc, Mutex};
 use std::thread;
 
+use chrono::Local;
 use etherparse::{PacketHeaders, ReadError};
 use pcap::{Active, Capture, Packet};
 
+use crate::blocklists::blocklist_check::check_blocklists;
+use crate::blocklists::types::blocklists::Blocklists;
 use crate::mmdb::types::mmdb_reader::MmdbReader;
+use crate::networking::local_names::{harvest_names, update_local_name};
 use crate::networking::manage_packets::{
-    analyze_headers, get_address_to_lookup, get_app_protocol, modify_or_insert_in_map,
-    reverse_dns_lookup,
+    analyze_headers, get_address_to_lookup, get_app_protocol, get_mac_to_lookup,
+    get_packet_direction, log_dns_message, modify_or_insert_in_map, reverse_dns_lookup,
 };
 use crate::networking::types::data_info::DataInfo;
+use crate::networking::types::dns_message::DnsMessage;
 use crate::networking::types::filters::Filters;
 use crate::networking::types::icmp_type::IcmpType;
 use crate::networking::types::info_address_port_pair::InfoAddressPortPair;
 use crate::networking::types::my_device::MyDevice;
 use crate::networking::types::my_link_type::MyLinkType;
 use crate::networking::types::packet_filters_fields::PacketFiltersFields;
-use crate::InfoTraffic;
+use crate::networking::types::scan_probe::ScanProbe;
+use crate::{AppProtocol, InfoTraffic, Protocol};
 
 /// The calling thread enters in a loop in which it waits for network packets, parses them according
 /// to the user specified filters, and inserts them into the shared map variable.
+#[allow(clippy::too_many_arguments)]
 pub fn parse_packets(
     current_capture_id: &Arc<Mutex<usize>>,
+    paused: &Arc<Mutex<bool>>,
     device: &MyDevice,
     mut cap: Capture<Active>,
-    filters: &Filters,
+    filters: &Arc<Mutex<Filters>>,
     info_traffic_mutex: &Arc<Mutex<InfoTraffic>>,
     country_mmdb_reader: &Arc<MmdbReader>,
     asn_mmdb_reader: &Arc<MmdbReader>,
+    blocklists: &Arc<Mutex<Blocklists>>,
 ) {
     let capture_id = *current_capture_id.lock().unwrap();
 
     let my_link_type = MyLinkType::from_pcap_link_type(cap.get_datalink());
+    // number of packets dropped by this capture (other adapters may be captured at the same time)
+    let mut dropped_packets = 0;
 
     loop {
         match cap.next_packet() {
@@ -49,11 +60,14 @@ pub fn parse_packets(
                 if *current_capture_id.lock().unwrap() != capture_id {
                     return;
                 }
+                let is_paused = *paused.lock().unwrap();
                 if let Ok(headers) = get_sniffable_headers(&packet, my_link_type) {
                     let mut exchanged_bytes = 0;
                     let mut mac_addresses = (None, None);
                     let mut icmp_type = IcmpType::default();
                     let mut packet_filters_fields = PacketFiltersFields::default();
+                    let payload = headers.payload;
+                    let scan_probe = ScanProbe::from_headers(&headers);
 
                     let key_option = analyze_headers(
                         headers,
@@ -61,22 +75,50 @@ pub fn parse_packets(
                         &mut exchanged_bytes,
                         &mut icmp_type,
                         &mut packet_filters_fields,
+                        &device.name,
                     );
                     if key_option.is_none() {
                         continue;
                     }
 
                     let key = key_option.unwrap();
+
+                    // packets received while the capture is paused are discarded,
+                    // but they're still accounted in the data usage of the adapter
+                    if is_paused {
+                        let traffic_direction = get_packet_direction(&key, device);
+                        let mut info_traffic = info_traffic_mutex
+                            .lock()
+                            .expect("Error acquiring mutex\n\r");
+                        info_traffic
+                            .adapters
+                            .entry(device.name.clone())
+                            .or_default()
+                            .add_packet(exchanged_bytes, traffic_direction);
+                        update_dropped_packets(&mut cap, &mut info_traffic, &mut dropped_packets);
+                        continue;
+                    }
+
                     let application_protocol = get_app_protocol(key.port1, key.port2);
+                    let local_names = harvest_names(&key, application_protocol, payload);
+                    // DNS messages over TCP are preceded by a 2 bytes length field
+                    let dns_message = match (application_protocol, key.protocol) {
+                        (AppProtocol::DNS, Protocol::UDP) => DnsMessage::parse(payload),
+                        (AppProtocol::DNS, Protocol::TCP) => {
+                            payload.get(2..).and_then(DnsMessage::parse)
+                        }
+                        _ => None,
+                    };
                     let mut new_info = InfoAddressPortPair::default();
 
-                    let passed_filters = filters.matches(&packet_filters_fields);
+                    // filters can be changed while the capture is running
+                    let passed_filters = filters.lock().unwrap().matches(&packet_filters_fields);
                     if passed_filters {
                         new_info = modify_or_insert_in_map(
                             info_traffic_mutex,
                             &key,
                             device,
-                            mac_addresses,
+                            mac_addresses.clone(),
                             icmp_type,
                             exchanged_bytes,
                             application_protocol,
@@ -89,14 +131,33 @@ pub fn parse_packets(
                     //increment number of sniffed packets and bytes
                     info_traffic.all_packets += 1;
                     info_traffic.all_bytes += exchanged_bytes;
-                    // update dropped packets number
-                    if let Ok(stats) = cap.stats() {
-                        info_traffic.dropped_packets = stats.dropped;
+                    // the data usage of the adapter includes the packets not filtered
+                    let traffic_direction = if passed_filters {
+                        new_info.traffic_direction
+                    } else {
+                        get_packet_direction(&key, device)
+                    };
+                    info_traffic
+                        .adapters
+                        .entry(device.name.clone())
+                        .or_default()
+                        .add_packet(exchanged_bytes, traffic_direction);
+                    // passively learn the names of the hosts in the local network
+                    for (address, name) in local_names {
+                        update_local_name(&mut info_traffic, address.to_string(), name);
                     }
 
                     if passed_filters {
                         info_traffic.add_packet(exchanged_bytes, new_info.traffic_direction);
 
+                        if let Some(message) = &dns_message {
+                            log_dns_message(&mut info_traffic, &key, message);
+                        }
+
+                        if let Some(probe) = scan_probe {
+                            info_traffic.scan_detector.record(probe, Local::now());
+                        }
+
                         // check the rDNS status of this address and act accordingly
                         let address_to_lookup =
                             get_address_to_lookup(&key, new_info.traffic_direction);
@@ -114,6 +175,13 @@ pub fn parse_packets(
                             (false, false) => {
                                 // rDNS not requested yet (first occurrence of this address to lookup)
 
+                                check_blocklists(
+                                    &mut info_traffic,
+                                    &blocklists.lock().unwrap(),
+                                    &address_to_lookup,
+                                    None,
+                                );
+
                                 // Add this address to the map of addresses waiting for a resolution
                                 // Useful to NOT perform again a rDNS lookup for this entry
                                 info_traffic.addresses_waiting_resolution.insert(
@@ -126,10 +194,13 @@ pub fn parse_packets(
 
                                 // launch new thread to resolve host name
                                 let key2 = key.clone();
+                                let mac_address2 =
+                                    get_mac_to_lookup(&mac_addresses, new_info.traffic_direction);
                                 let info_traffic2 = info_traffic_mutex.clone();
                                 let device2 = device.clone();
                                 let country_db_reader_2 = country_mmdb_reader.clone();
                                 let asn_db_reader_2 = asn_mmdb_reader.clone();
+                                let blocklists_2 = blocklists.clone();
                                 thread::Builder::new()
                                     .name("thread_reverse_dns_lookup".to_string())
                                     .spawn(move || {
@@ -137,9 +208,11 @@ pub fn parse_packets(
                                             &info_traffic2,
                                             &key2,
                                             new_info.traffic_direction,
+                                            mac_address2,
                                             &device2,
                                             &country_db_reader_2,
                                             &asn_db_reader_2,
+                                            &blocklists_2,
                                         );
                                     })
                                     .unwrap();
@@ -188,12 +261,27 @@ pub fn parse_packets(
                                 )
                             });
                     }
+
+                    update_dropped_packets(&mut cap, &mut info_traffic, &mut dropped_packets);
                 }
             }
         }
     }
 }
 
+/// Updates the number of dropped packets with the ones dropped by this capture since the last call
+/// (the packet mustn't be borrowed anymore)
+fn update_dropped_packets(
+    cap: &mut Capture<Active>,
+    info_traffic: &mut InfoTraffic,
+    dropped_packets: &mut u32,
+) {
+    if let Ok(stats) = cap.stats() {
+        info_traffic.dropped_packets += stats.dropped.saturating_sub(*dropped_packets);
+        *dropped_packets = stats.dropped;
+    }
+}
+
 fn get_sniffable_headers<'a>(
     packet: &'a Packet,
     my_link_type: MyLinkType,
@@ -0,0 +1,469 @@
+//! Module containing the function executed by the thread in charge of serving the local API,
+//! used to control the captures and to read the collected data as JSON.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{IpAddr, SocketAddr, TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use tungstenite::handshake::derive_accept_key;
+use tungstenite::protocol::Role;
+use tungstenite::WebSocket;
+
+use crate::api::routes::{handle_request, ApiResponse, Totals, Update, UPDATES_PATH};
+use crate::api::types::api_state::ApiState;
+use crate::InfoTraffic;
+
+/// Default address of the local API
+pub const DEFAULT_API_ADDRESS: &str = "127.0.0.1:9285";
+
+/// Maximum size of the body of a request
+const MAX_BODY_SIZE: usize = 64 * 1024;
+
+/// Generates a random token to authenticate the requests to the local API
+pub fn generate_api_token() -> String {
+    let bytes: [u8; 32] = rand::random();
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Headers of a request relevant to decide whether it has to be served
+#[derive(Default)]
+struct RequestHeaders {
+    host: Option<String>,
+    origin: Option<String>,
+    authorization: Option<String>,
+    content_type: Option<String>,
+    content_length: usize,
+    websocket_key: Option<String>,
+}
+
+/// The calling thread enters in a loop in which it accepts the connections to the local API,
+/// each of them handled in a dedicated thread.
+///
+/// The loop lasts as long as the application.
+pub fn serve_api(
+    listener: &TcpListener,
+    api_token: &str,
+    api_state: &Arc<Mutex<ApiState>>,
+    info_traffic_mutex: &Arc<Mutex<InfoTraffic>>,
+) {
+    let Ok(local_address) = listener.local_addr() else {
+        return;
+    };
+    for stream in listener.incoming().flatten() {
+        let api_token = api_token.to_string();
+        let api_state = api_state.clone();
+        let info_traffic_mutex = info_traffic_mutex.clone();
+        thread::Builder::new()
+            .name("thread_api_connection".to_string())
+            .spawn(move || {
+                // a misbehaving client must not stop the API
+                let _ = handle_connection(
+                    stream,
+                    local_address,
+                    &api_token,
+                    &api_state,
+                    &info_traffic_mutex,
+                );
+            })
+            .unwrap();
+    }
+}
+
+fn handle_connection(
+    mut stream: TcpStream,
+    local_address: SocketAddr,
+    api_token: &str,
+    api_state: &Arc<Mutex<ApiState>>,
+    info_traffic_mutex: &Arc<Mutex<InfoTraffic>>,
+) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut request_parts = request_line.split_whitespace();
+    let method = request_parts.next().unwrap_or_default().to_string();
+    let target = request_parts.next().unwrap_or_default().to_string();
+    let (path, query) = target.split_once('?').unwrap_or((&target, ""));
+
+    let mut headers = RequestHeaders::default();
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            let value = value.trim().to_string();
+            match name.trim().to_ascii_lowercase().as_str() {
+                "host" => headers.host = Some(value),
+                "origin" => headers.origin = Some(value),
+                "authorization" => headers.authorization = Some(value),
+                "content-type" => headers.content_type = Some(value),
+                "content-length" => headers.content_length = value.parse().unwrap_or_default(),
+                "sec-websocket-key" => headers.websocket_key = Some(value),
+                _ => {}
+            }
+        }
+    }
+
+    if let Some(rejection) = check_request(&method, path, query, &headers, local_address, api_token)
+    {
+        return write_response(&mut stream, &rejection);
+    }
+
+    if path == UPDATES_PATH {
+        return match headers.websocket_key {
+            Some(key) if method == "GET" => {
+                let totals = Totals::new(&info_traffic_mutex.lock().unwrap());
+                write!(
+                    stream,
+                    "HTTP/1.1 101 Switching Protocols\r\n\
+                    Upgrade: websocket\r\n\
+                    Connection: Upgrade\r\n\
+                    Sec-WebSocket-Accept: {}\r\n\r\n",
+                    derive_accept_key(key.as_bytes())
+                )?;
+                stream.set_read_timeout(None)?;
+                let websocket = WebSocket::from_raw_socket(stream, Role::Server, None);
+                stream_updates(websocket, totals, info_traffic_mutex);
+                Ok(())
+            }
+            _ => write_response(
+                &mut stream,
+                &ApiResponse {
+                    status: 400,
+                    body: "{\"error\":\"Expected a WebSocket handshake\"}".to_string(),
+                },
+            ),
+        };
+    }
+
+    let mut body = Vec::new();
+    reader
+        .take(u64::try_from(headers.content_length.min(MAX_BODY_SIZE)).unwrap_or_default())
+        .read_to_end(&mut body)?;
+    let response = handle_request(
+        &method,
+        path,
+        &String::from_utf8_lossy(&body),
+        api_state,
+        info_traffic_mutex,
+    );
+    write_response(&mut stream, &response)
+}
+
+/// Returns the response to send in place of serving the request, if the request isn't allowed.
+///
+/// Since the API is reachable by any web page open in a browser, requests are refused if:
+/// - the `Host` doesn't name the bound address (protection against DNS rebinding)
+/// - the `Origin` is present and not a loopback one (protection against cross-origin requests)
+/// - the token isn't provided in the `Authorization` header
+///   (or in the query string, when opening the WebSocket, since browsers can't set headers there)
+/// - a `POST` body isn't declared as JSON (protection against "simple" cross-origin requests)
+fn check_request(
+    method: &str,
+    path: &str,
+    query: &str,
+    headers: &RequestHeaders,
+    local_address: SocketAddr,
+    api_token: &str,
+) -> Option<ApiResponse> {
+    if !headers
+        .host
+        .as_deref()
+        .is_some_and(|host| is_allowed_host(host, local_address))
+    {
+        return Some(ApiResponse::error(403, "Host not allowed"));
+    }
+
+    if headers
+        .origin
+        .as_deref()
+        .is_some_and(|origin| !is_loopback_origin(origin))
+    {
+        return Some(ApiResponse::error(403, "Origin not allowed"));
+    }
+
+    let provided_token = headers
+        .authorization
+        .as_deref()
+        .and_then(|authorization| authorization.strip_prefix("Bearer "))
+        .or_else(|| {
+            if path == UPDATES_PATH {
+                query
+                    .split('&')
+                    .find_map(|parameter| parameter.strip_prefix("token="))
+            } else {
+                None
+            }
+        });
+    if !provided_token.is_some_and(|token| tokens_match(token.trim(), api_token)) {
+        return Some(ApiResponse::error(401, "Missing or invalid token"));
+    }
+
+    if method == "POST"
+        && !headers.content_type.as_deref().is_some_and(|content_type| {
+            content_type
+                .split(';')
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .eq_ignore_ascii_case("application/json")
+        })
+    {
+        return Some(ApiResponse::error(415, "Expected a JSON body"));
+    }
+
+    None
+}
+
+/// Checks that the `Host` of a request is the address the API is bound to
+fn is_allowed_host(host: &str, local_address: SocketAddr) -> bool {
+    let port = local_address.port();
+    if let Ok(host) = host.parse::<SocketAddr>() {
+        host.port() == port
+            && (host.ip() == local_address.ip() || local_address.ip().is_unspecified())
+    } else {
+        local_address.ip().is_loopback() && host.eq_ignore_ascii_case(&format!("localhost:{port}"))
+    }
+}
+
+/// Checks that the `Origin` of a request is a page served by the local host
+fn is_loopback_origin(origin: &str) -> bool {
+    let Some((_, authority)) = origin.split_once("://") else {
+        // e.g. "null", sent by sandboxed pages and local files
+        return false;
+    };
+    let authority = authority.split('/').next().unwrap_or_default();
+    let host = if let Some(ipv6) = authority.strip_prefix('[') {
+        ipv6.split(']').next().unwrap_or_default()
+    } else {
+        authority.split(':').next().unwrap_or_default()
+    };
+    host.eq_ignore_ascii_case("localhost")
+        || host.parse::<IpAddr>().is_ok_and(|ip| ip.is_loopback())
+}
+
+/// Compares the tokens in constant time, to not reveal how much of the provided one is correct
+fn tokens_match(provided: &str, expected: &str) -> bool {
+    provided.len() == expected.len()
+        && provided
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0, |difference, (a, b)| difference | (a ^ b))
+            == 0
+}
+
+fn write_response(stream: &mut TcpStream, response: &ApiResponse) -> std::io::Result<()> {
+    let reason = match response.status {
+        200 => "OK",
+        202 => "Accepted",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        409 => "Conflict",
+        415 => "Unsupported Media Type",
+        _ => "Internal Server Error",
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {} {reason}\r\n\
+        Content-Type: application/json\r\n\
+        Content-Length: {}\r\n\
+        Connection: close\r\n\r\n{}",
+        response.status,
+        response.body.len(),
+        response.body
+    )?;
+    stream.flush()
+}
+
+/// Sends every second the traffic exchanged in the last second, until the client disconnects
+fn stream_updates(
+    mut websocket: WebSocket<TcpStream>,
+    mut previous: Totals,
+    info_traffic_mutex: &Arc<Mutex<InfoTraffic>>,
+) {
+    loop {
+        thread::sleep(Duration::from_secs(1));
+        let current = Totals::new(&info_traffic_mutex.lock().unwrap());
+        let Ok(update) = serde_json::to_string(&Update::new(&previous, current)) else {
+            return;
+        };
+        if websocket.send(tungstenite::Message::Text(update)).is_err() {
+            return;
+        }
+        previous = current;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOKEN: &str = "secret";
+
+    fn start_server() -> (String, Arc<Mutex<ApiState>>, Arc<Mutex<InfoTraffic>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let api_state = Arc::new(Mutex::new(ApiState::default()));
+        let info_traffic_mutex = Arc::new(Mutex::new(InfoTraffic::new()));
+        let (api_state_2, info_traffic_mutex_2) = (api_state.clone(), info_traffic_mutex.clone());
+        thread::spawn(move || serve_api(&listener, TOKEN, &api_state_2, &info_traffic_mutex_2));
+        (address, api_state, info_traffic_mutex)
+    }
+
+    fn request(address: &str, request: &str) -> String {
+        let mut stream = TcpStream::connect(address).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn test_serve_api() {
+        let (address, api_state, info_traffic_mutex) = start_server();
+        info_traffic_mutex.lock().unwrap().tot_sent_bytes = 42;
+
+        let response = request(
+            &address,
+            &format!(
+                "GET /api/totals HTTP/1.1\r\nHost: {address}\r\nAuthorization: Bearer {TOKEN}\r\n\r\n"
+            ),
+        );
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("\"sent_bytes\":42"));
+
+        let body = "{\"protocols\": [\"UDP\"]}";
+        let response = request(
+            &address,
+            &format!(
+                "POST /api/capture/start HTTP/1.1\r\nHost: {address}\r\nAuthorization: Bearer {TOKEN}\r\n\
+                Content-Type: application/json\r\nContent-Length: {}\r\n\r\n{body}",
+                body.len()
+            ),
+        );
+        assert!(response.starts_with("HTTP/1.1 202 Accepted\r\n"));
+        assert_eq!(api_state.lock().unwrap().pending_requests.len(), 1);
+
+        let response = request(
+            &address,
+            &format!(
+                "GET /api/updates HTTP/1.1\r\nHost: {address}\r\nAuthorization: Bearer {TOKEN}\r\n\r\n"
+            ),
+        );
+        assert!(response.starts_with("HTTP/1.1 400 Bad Request\r\n"));
+    }
+
+    #[test]
+    fn test_rejected_requests() {
+        let (address, api_state, _) = start_server();
+        let port = address.rsplit_once(':').unwrap().1;
+        let body = "{\"protocols\": [\"UDP\"]}";
+        let post = |headers: &str| {
+            request(
+                &address,
+                &format!(
+                    "POST /api/capture/start HTTP/1.1\r\n{headers}Content-Length: {}\r\n\r\n{body}",
+                    body.len()
+                ),
+            )
+        };
+
+        // missing or wrong token
+        let response = post(&format!(
+            "Host: {address}\r\nContent-Type: application/json\r\n"
+        ));
+        assert!(response.starts_with("HTTP/1.1 401 Unauthorized\r\n"));
+        let response = post(&format!(
+            "Host: {address}\r\nAuthorization: Bearer secreT\r\nContent-Type: application/json\r\n"
+        ));
+        assert!(response.starts_with("HTTP/1.1 401 Unauthorized\r\n"));
+
+        // cross-origin "simple" request
+        let response = post(&format!(
+            "Host: {address}\r\nOrigin: https://example.com\r\nAuthorization: Bearer {TOKEN}\r\n\
+            Content-Type: text/plain\r\n"
+        ));
+        assert!(response.starts_with("HTTP/1.1 403 Forbidden\r\n"));
+        let response = post(&format!(
+            "Host: {address}\r\nAuthorization: Bearer {TOKEN}\r\nContent-Type: text/plain\r\n"
+        ));
+        assert!(response.starts_with("HTTP/1.1 415 Unsupported Media Type\r\n"));
+
+        // DNS rebinding
+        let response = post(&format!(
+            "Host: attacker.example.com:{port}\r\nAuthorization: Bearer {TOKEN}\r\n\
+            Content-Type: application/json\r\n"
+        ));
+        assert!(response.starts_with("HTTP/1.1 403 Forbidden\r\n"));
+
+        assert!(api_state.lock().unwrap().pending_requests.is_empty());
+
+        // requests from local pages are served
+        let response = post(&format!(
+            "Host: localhost:{port}\r\nOrigin: http://localhost:3000\r\n\
+            Authorization: Bearer {TOKEN}\r\nContent-Type: application/json; charset=utf-8\r\n"
+        ));
+        assert!(response.starts_with("HTTP/1.1 202 Accepted\r\n"));
+        assert_eq!(api_state.lock().unwrap().pending_requests.len(), 1);
+    }
+
+    #[test]
+    fn test_is_allowed_host() {
+        let loopback: SocketAddr = "127.0.0.1:9285".parse().unwrap();
+        assert!(is_allowed_host("127.0.0.1:9285", loopback));
+        assert!(is_allowed_host("localhost:9285", loopback));
+        assert!(!is_allowed_host("127.0.0.1:9286", loopback));
+        assert!(!is_allowed_host("127.0.0.1", loopback));
+        assert!(!is_allowed_host("rebind.example.com:9285", loopback));
+
+        let unspecified: SocketAddr = "0.0.0.0:9285".parse().unwrap();
+        assert!(is_allowed_host("192.168.1.10:9285", unspecified));
+        assert!(!is_allowed_host("localhost:9285", unspecified));
+    }
+
+    #[test]
+    fn test_is_loopback_origin() {
+        assert!(is_loopback_origin("http://localhost:3000"));
+        assert!(is_loopback_origin("http://127.0.0.1"));
+        assert!(is_loopback_origin("http://[::1]:8080"));
+        assert!(!is_loopback_origin("https://example.com"));
+        assert!(!is_loopback_origin("http://localhost.example.com"));
+        assert!(!is_loopback_origin("null"));
+    }
+
+    #[test]
+    fn test_stream_updates() {
+        let (address, _, info_traffic_mutex) = start_server();
+
+        let (mut websocket, _) =
+            tungstenite::connect(format!("ws://{address}{UPDATES_PATH}?token={TOKEN}")).unwrap();
+        info_traffic_mutex.lock().unwrap().tot_received_bytes = 1000;
+        let update = websocket.read().unwrap().into_text().unwrap();
+        let update: serde_json::Value = serde_json::from_str(&update).unwrap();
+        assert_eq!(update["received_bytes"], 1000);
+        assert_eq!(update["totals"]["received_bytes"], 1000);
+
+        info_traffic_mutex.lock().unwrap().tot_received_bytes = 1500;
+        let update = websocket.read().unwrap().into_text().unwrap();
+        let update: serde_json::Value = serde_json::from_str(&update).unwrap();
+        assert_eq!(update["received_bytes"], 500);
+
+        // the WebSocket can't be opened by pages of other origins, nor without the token
+        assert!(tungstenite::connect(format!("ws://{address}{UPDATES_PATH}")).is_err());
+        let mut request = tungstenite::client::IntoClientRequest::into_client_request(format!(
+            "ws://{address}{UPDATES_PATH}?token={TOKEN}"
+        ))
+        .unwrap();
+        request
+            .headers_mut()
+            .insert("Origin", "https://example.com".parse().unwrap());
+        assert!(tungstenite::connect(request).is_err());
+    }
+}
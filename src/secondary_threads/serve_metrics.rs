@@ -0,0 +1,312 @@
+//! Module containing the function executed by the thread in charge of exposing
+//! the traffic counters on a local HTTP endpoint, in the Prometheus text format.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::io::{BufRead, BufReader, ErrorKind, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use crate::networking::types::data_info::DataInfo;
+use crate::report::get_report_entries::get_all_app_entries;
+use crate::{ChartType, InfoTraffic};
+
+/// Default address of the metrics endpoint
+pub const DEFAULT_METRICS_ADDRESS: &str = "127.0.0.1:9284";
+
+/// Path of the metrics endpoint
+const METRICS_PATH: &str = "/metrics";
+
+/// Milliseconds between two consecutive checks for incoming connections
+const POLL_INTERVAL: u64 = 100;
+
+/// The calling thread enters in a loop in which it answers the requests to the metrics endpoint,
+/// reading the current counters from the shared `InfoTraffic`.
+///
+/// The loop terminates when the capture is interrupted,
+/// while the listener can be shared with the thread serving the following capture.
+pub fn serve_metrics(
+    current_capture_id: &Arc<Mutex<usize>>,
+    info_traffic_mutex: &Arc<Mutex<InfoTraffic>>,
+    listener: &TcpListener,
+) {
+    let capture_id = *current_capture_id.lock().unwrap();
+    if listener.set_nonblocking(true).is_err() {
+        return;
+    }
+
+    while *current_capture_id.lock().unwrap() == capture_id {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                // a misbehaving client must not stop the endpoint
+                let _ = answer_request(stream, info_traffic_mutex);
+            }
+            Err(e) if e.kind() == ErrorKind::WouldBlock => {
+                thread::sleep(Duration::from_millis(POLL_INTERVAL));
+            }
+            Err(_) => return,
+        }
+    }
+}
+
+fn answer_request(
+    mut stream: TcpStream,
+    info_traffic_mutex: &Arc<Mutex<InfoTraffic>>,
+) -> std::io::Result<()> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(Duration::from_secs(1)))?;
+
+    let mut request_line = String::new();
+    BufReader::new(&stream).read_line(&mut request_line)?;
+    let mut request_parts = request_line.split_whitespace();
+    let method = request_parts.next().unwrap_or_default();
+    let path = request_parts.next().unwrap_or_default();
+
+    let (status, body) = if method != "GET" {
+        ("405 Method Not Allowed", String::new())
+    } else if path == METRICS_PATH {
+        let body = get_prometheus_metrics(&info_traffic_mutex.lock().unwrap());
+        ("200 OK", body)
+    } else {
+        ("404 Not Found", String::new())
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\n\
+        Content-Type: text/plain; version=0.0.4; charset=utf-8\r\n\
+        Content-Length: {}\r\n\
+        Connection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()
+}
+
+/// Returns the traffic counters formatted according to the Prometheus text exposition format
+pub fn get_prometheus_metrics(info_traffic: &InfoTraffic) -> String {
+    let mut metrics = String::new();
+
+    for (name, help, value) in [
+        (
+            "sniffnet_bytes_total",
+            "Bytes observed, including the ones not matching the filters",
+            info_traffic.all_bytes,
+        ),
+        (
+            "sniffnet_packets_total",
+            "Packets observed, including the ones not matching the filters",
+            info_traffic.all_packets,
+        ),
+        (
+            "sniffnet_sent_bytes_total",
+            "Sent bytes matching the filters",
+            info_traffic.tot_sent_bytes,
+        ),
+        (
+            "sniffnet_received_bytes_total",
+            "Received bytes matching the filters",
+            info_traffic.tot_received_bytes,
+        ),
+        (
+            "sniffnet_sent_packets_total",
+            "Sent packets matching the filters",
+            info_traffic.tot_sent_packets,
+        ),
+        (
+            "sniffnet_received_packets_total",
+            "Received packets matching the filters",
+            info_traffic.tot_received_packets,
+        ),
+        (
+            "sniffnet_dropped_packets_total",
+            "Packets dropped by the capture",
+            u128::from(info_traffic.dropped_packets),
+        ),
+    ] {
+        write_header(&mut metrics, name, help);
+        let _ = writeln!(metrics, "{name} {value}");
+    }
+
+    let apps: Vec<(String, DataInfo)> = get_all_app_entries(info_traffic, ChartType::Bytes)
+        .into_iter()
+        .map(|(app, data_info)| (format!("{app:?}"), data_info))
+        .collect();
+    write_labelled_bytes(
+        &mut metrics,
+        "sniffnet_app_bytes_total",
+        "Bytes exchanged by each application protocol",
+        "app",
+        &apps,
+    );
+
+    let mut countries: BTreeMap<String, DataInfo> = BTreeMap::new();
+    for (host, data_info_host) in &info_traffic.hosts {
+        *countries.entry(format!("{:?}", host.country)).or_default() += data_info_host.data_info;
+    }
+    write_labelled_bytes(
+        &mut metrics,
+        "sniffnet_country_bytes_total",
+        "Bytes exchanged with each country",
+        "country",
+        &countries.into_iter().collect::<Vec<_>>(),
+    );
+
+    metrics
+}
+
+fn write_header(metrics: &mut String, name: &str, help: &str) {
+    let _ = writeln!(metrics, "# HELP {name} {help}");
+    let _ = writeln!(metrics, "# TYPE {name} counter");
+}
+
+fn write_labelled_bytes(
+    metrics: &mut String,
+    name: &str,
+    help: &str,
+    label: &str,
+    entries: &[(String, DataInfo)],
+) {
+    write_header(metrics, name, help);
+    for (value, data_info) in entries {
+        for (direction, bytes) in [
+            ("sent", data_info.outgoing_bytes),
+            ("received", data_info.incoming_bytes),
+        ] {
+            let _ = writeln!(
+                metrics,
+                "{name}{{{label}=\"{value}\",direction=\"{direction}\"}} {bytes}"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::countries::types::country::Country;
+    use crate::networking::types::asn::Asn;
+    use crate::networking::types::data_info_host::DataInfoHost;
+    use crate::networking::types::host::Host;
+    use crate::AppProtocol;
+
+    use super::*;
+
+    fn data_info(incoming_bytes: u128, outgoing_bytes: u128) -> DataInfo {
+        DataInfo {
+            incoming_packets: 1,
+            outgoing_packets: 1,
+            incoming_bytes,
+            outgoing_bytes,
+        }
+    }
+
+    fn host(domain: &str, country: Country) -> Host {
+        Host {
+            domain: domain.to_string(),
+            asn: Asn::default(),
+            country,
+        }
+    }
+
+    #[test]
+    fn test_get_prometheus_metrics() {
+        let mut info_traffic = InfoTraffic::new();
+        info_traffic.all_bytes = 1500;
+        info_traffic.all_packets = 12;
+        info_traffic.tot_sent_bytes = 400;
+        info_traffic.tot_received_bytes = 900;
+        info_traffic.tot_sent_packets = 4;
+        info_traffic.tot_received_packets = 6;
+        info_traffic.dropped_packets = 3;
+        info_traffic.app_protocols = HashMap::from([
+            (AppProtocol::HTTPS, data_info(800, 300)),
+            (AppProtocol::NotApplicable, data_info(100, 100)),
+        ]);
+        info_traffic.hosts = HashMap::from([
+            (
+                host("example.com", Country::US),
+                DataInfoHost {
+                    data_info: data_info(500, 200),
+                    ..DataInfoHost::default()
+                },
+            ),
+            (
+                host("example.org", Country::US),
+                DataInfoHost {
+                    data_info: data_info(300, 100),
+                    ..DataInfoHost::default()
+                },
+            ),
+            (
+                host("example.it", Country::IT),
+                DataInfoHost {
+                    data_info: data_info(100, 100),
+                    ..DataInfoHost::default()
+                },
+            ),
+        ]);
+
+        let metrics = get_prometheus_metrics(&info_traffic);
+        let lines: Vec<&str> = metrics.lines().collect();
+
+        for expected in [
+            "# TYPE sniffnet_bytes_total counter",
+            "sniffnet_bytes_total 1500",
+            "sniffnet_packets_total 12",
+            "sniffnet_sent_bytes_total 400",
+            "sniffnet_received_bytes_total 900",
+            "sniffnet_sent_packets_total 4",
+            "sniffnet_received_packets_total 6",
+            "sniffnet_dropped_packets_total 3",
+            "sniffnet_app_bytes_total{app=\"HTTPS\",direction=\"sent\"} 300",
+            "sniffnet_app_bytes_total{app=\"HTTPS\",direction=\"received\"} 800",
+            "sniffnet_country_bytes_total{country=\"US\",direction=\"sent\"} 300",
+            "sniffnet_country_bytes_total{country=\"US\",direction=\"received\"} 800",
+            "sniffnet_country_bytes_total{country=\"IT\",direction=\"received\"} 100",
+        ] {
+            assert!(lines.contains(&expected), "missing line: {expected}");
+        }
+        // traffic without an application protocol is not reported per app
+        assert!(!metrics.contains("NotApplicable"));
+        // every line is either a comment or a sample
+        assert!(lines
+            .iter()
+            .all(|line| line.starts_with("# ") || line.starts_with("sniffnet_")));
+    }
+
+    #[test]
+    fn test_serve_metrics() {
+        let current_capture_id = Arc::new(Mutex::new(0));
+        let mut info_traffic = InfoTraffic::new();
+        info_traffic.all_packets = 7;
+        let info_traffic_mutex = Arc::new(Mutex::new(info_traffic));
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let capture_id = current_capture_id.clone();
+        let handle = thread::spawn(move || {
+            serve_metrics(&capture_id, &info_traffic_mutex, &listener);
+        });
+
+        let request = |path: &str| {
+            let mut stream = TcpStream::connect(address).unwrap();
+            write!(stream, "GET {path} HTTP/1.1\r\nHost: localhost\r\n\r\n").unwrap();
+            let mut response = String::new();
+            std::io::Read::read_to_string(&mut stream, &mut response).unwrap();
+            response
+        };
+
+        let response = request("/metrics");
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("\nsniffnet_packets_total 7\n"));
+        assert!(request("/").starts_with("HTTP/1.1 404 Not Found\r\n"));
+
+        // the endpoint is closed as soon as the capture is interrupted
+        *current_capture_id.lock().unwrap() += 1;
+        handle.join().unwrap();
+        assert!(TcpStream::connect(address).is_err());
+    }
+}
@@ -0,0 +1,33 @@
+//! Module containing the function executed by the thread in charge of periodically storing
+//! the configurations updated during the captures.
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use crate::configs::types::configs::Configs;
+
+/// Number of seconds between two consecutive stores of the configurations
+const CONFIGS_INTERVAL: u64 = 60;
+
+/// The calling thread enters in a loop in which, every minute, it stores the baseline
+/// of the observed targets and the data usage of the adapters,
+/// so that they aren't lost if the app is not closed gracefully.
+///
+/// The loop terminates when the capture is interrupted.
+pub fn store_configs(current_capture_id: &Arc<Mutex<usize>>, configs: &Arc<Mutex<Configs>>) {
+    let capture_id = *current_capture_id.lock().unwrap();
+    loop {
+        for _ in 0..CONFIGS_INTERVAL {
+            thread::sleep(Duration::from_secs(1));
+            if *current_capture_id.lock().unwrap() != capture_id {
+                return;
+            }
+        }
+        let configs_lock = configs.lock().unwrap();
+        let (baseline, usage) = (configs_lock.baseline.clone(), configs_lock.usage.clone());
+        drop(configs_lock);
+        baseline.store();
+        usage.store();
+    }
+}
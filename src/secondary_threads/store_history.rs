@@ -0,0 +1,154 @@
+//! Module containing the function executed by the thread in charge of periodically storing
+//! the traffic history on disk.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use chrono::Local;
+
+use crate::history::history_db::HistoryDb;
+use crate::networking::manage_packets::get_address_to_lookup;
+use crate::networking::types::data_info::DataInfo;
+use crate::networking::types::host::Host;
+use crate::{AppProtocol, InfoTraffic};
+
+/// Number of seconds between two consecutive writes of the traffic history
+const HISTORY_INTERVAL: u64 = 60;
+
+/// The calling thread enters in a loop in which, every minute, it stores in the history database
+/// the flows updated and the data exchanged by each host and application protocol in the last minute,
+/// and it deletes the data older than the retention period (in days).
+///
+/// The loop terminates (after a last write) when the capture is interrupted.
+pub fn store_history(
+    current_capture_id: &Arc<Mutex<usize>>,
+    info_traffic_mutex: &Arc<Mutex<InfoTraffic>>,
+    mut history_db: HistoryDb,
+    retention_days: u16,
+) {
+    let capture_id = *current_capture_id.lock().unwrap();
+    let session = Local::now().timestamp();
+    let mut last_store = Local::now();
+    let mut last_hosts = HashMap::new();
+    let mut last_apps = HashMap::new();
+
+    let mut is_capture_over = false;
+    while !is_capture_over {
+        for _ in 0..HISTORY_INTERVAL {
+            thread::sleep(Duration::from_secs(1));
+            if *current_capture_id.lock().unwrap() != capture_id {
+                is_capture_over = true;
+                break;
+            }
+        }
+
+        let now = Local::now();
+        let info_traffic = info_traffic_mutex.lock().unwrap();
+        let hosts: HashMap<Host, DataInfo> = info_traffic
+            .hosts
+            .iter()
+            .map(|(host, data_info_host)| (host.clone(), data_info_host.data_info))
+            .collect();
+        let apps: HashMap<AppProtocol, DataInfo> = info_traffic.app_protocols.clone();
+        let flows: Vec<_> = info_traffic
+            .map
+            .iter()
+            .filter(|(_, val)| val.final_timestamp >= last_store)
+            .map(|(key, val)| {
+                let host = info_traffic
+                    .addresses_resolved
+                    .get(&get_address_to_lookup(key, val.traffic_direction))
+                    .map(|(_, host)| host.clone());
+                (key.clone(), val.clone(), host)
+            })
+            .collect();
+        drop(info_traffic);
+
+        history_db
+            .store(
+                session,
+                now,
+                &flows,
+                &get_deltas(&hosts, &last_hosts),
+                &get_deltas(&apps, &last_apps),
+            )
+            .unwrap_or(());
+
+        history_db
+            .prune((now - chrono::Duration::days(retention_days.into())).timestamp())
+            .unwrap_or(());
+
+        last_store = now;
+        last_hosts = hosts;
+        last_apps = apps;
+    }
+}
+
+/// Returns the data exchanged since the previous snapshot, omitting the entries which didn't exchange any data
+fn get_deltas<K: Clone + Eq + Hash>(
+    current: &HashMap<K, DataInfo>,
+    previous: &HashMap<K, DataInfo>,
+) -> HashMap<K, DataInfo> {
+    current
+        .iter()
+        .filter_map(|(key, data_info)| {
+            let previous = previous.get(key).copied().unwrap_or_default();
+            let delta = DataInfo {
+                incoming_packets: data_info
+                    .incoming_packets
+                    .saturating_sub(previous.incoming_packets),
+                outgoing_packets: data_info
+                    .outgoing_packets
+                    .saturating_sub(previous.outgoing_packets),
+                incoming_bytes: data_info
+                    .incoming_bytes
+                    .saturating_sub(previous.incoming_bytes),
+                outgoing_bytes: data_info
+                    .outgoing_bytes
+                    .saturating_sub(previous.outgoing_bytes),
+            };
+            if delta.tot_packets() == 0 {
+                None
+            } else {
+                Some((key.clone(), delta))
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data_info(packets: u128, bytes: u128) -> DataInfo {
+        DataInfo {
+            incoming_packets: packets,
+            outgoing_packets: 0,
+            incoming_bytes: bytes,
+            outgoing_bytes: 0,
+        }
+    }
+
+    #[test]
+    fn test_get_deltas() {
+        let previous = HashMap::from([
+            (AppProtocol::HTTPS, data_info(10, 1000)),
+            (AppProtocol::DNS, data_info(2, 100)),
+        ]);
+        let current = HashMap::from([
+            (AppProtocol::HTTPS, data_info(15, 1600)),
+            (AppProtocol::DNS, data_info(2, 100)),
+            (AppProtocol::SSH, data_info(1, 60)),
+        ]);
+
+        let deltas = get_deltas(&current, &previous);
+        assert_eq!(deltas.len(), 2);
+        assert_eq!(deltas[&AppProtocol::HTTPS].tot_packets(), 5);
+        assert_eq!(deltas[&AppProtocol::HTTPS].tot_bytes(), 600);
+        assert_eq!(deltas[&AppProtocol::SSH].tot_bytes(), 60);
+        assert!(!deltas.contains_key(&AppProtocol::DNS));
+    }
+}
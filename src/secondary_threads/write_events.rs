@@ -0,0 +1,100 @@
+//! Module containing the function executed by the thread in charge of writing
+//! the events of the capture as a stream of JSON lines.
+
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::sync::mpsc::Receiver;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use chrono::Local;
+
+use crate::events::event_tracker::EventTracker;
+use crate::notifications::types::logged_notification::LoggedNotification;
+use crate::InfoTraffic;
+
+/// Output meaning that the events have to be written to the standard output
+pub const STDOUT_OUTPUT: &str = "-";
+
+/// Returns the destination of the events: the standard output, or the file at the given path
+/// (new events are appended to the file if it already exists)
+pub fn open_events_output(output: &str) -> std::io::Result<Box<dyn Write + Send>> {
+    if output == STDOUT_OUTPUT {
+        Ok(Box::new(std::io::stdout()))
+    } else {
+        let file = OpenOptions::new().create(true).append(true).open(output)?;
+        Ok(Box::new(file))
+    }
+}
+
+/// The calling thread enters in a loop in which, every second, it writes a JSON line
+/// for each event occurred in the last interval.
+///
+/// The loop terminates (after reporting the end of all the connections) when the capture is interrupted.
+pub fn write_events(
+    current_capture_id: &Arc<Mutex<usize>>,
+    info_traffic_mutex: &Arc<Mutex<InfoTraffic>>,
+    notifications_receiver: &Receiver<LoggedNotification>,
+    mut output: Box<dyn Write + Send>,
+) {
+    let capture_id = *current_capture_id.lock().unwrap();
+    let mut tracker = EventTracker::default();
+
+    let mut is_capture_over = false;
+    while !is_capture_over {
+        thread::sleep(Duration::from_secs(1));
+        is_capture_over = *current_capture_id.lock().unwrap() != capture_id;
+
+        let notifications = notifications_receiver.try_iter().collect();
+        let events = tracker.get_events(
+            &info_traffic_mutex.lock().unwrap(),
+            notifications,
+            Local::now(),
+            is_capture_over,
+        );
+        for event in events {
+            if let Ok(line) = serde_json::to_string(&event) {
+                // the reader may have gone away: this must not stop the capture
+                let _ = writeln!(output, "{line}");
+            }
+        }
+        let _ = output.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+
+    use super::*;
+
+    #[test]
+    fn test_write_events_to_file() {
+        let path = std::env::temp_dir().join("sniffnet_test_events.jsonl");
+        let _ = std::fs::remove_file(&path);
+        let output = open_events_output(path.to_str().unwrap()).unwrap();
+
+        let current_capture_id = Arc::new(Mutex::new(0));
+        let mut info_traffic = InfoTraffic::new();
+        info_traffic.tot_received_bytes = 1000;
+        let info_traffic_mutex = Arc::new(Mutex::new(info_traffic));
+        let (_sender, receiver) = mpsc::channel();
+
+        let capture_id = current_capture_id.clone();
+        let handle = thread::spawn(move || {
+            write_events(&capture_id, &info_traffic_mutex, &receiver, output);
+        });
+        thread::sleep(Duration::from_millis(200));
+        *current_capture_id.lock().unwrap() += 1;
+        handle.join().unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 1);
+        let event: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(event["event"], "totals");
+        assert_eq!(event["received_bytes"], 1000);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
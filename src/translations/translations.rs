@@ -1916,27 +1916,3 @@ pub fn no_notifications_received_translation(
         }
     })
 }
-
-pub fn only_last_30_translation(language: Language) -> &'static str {
-    match language {
-        Language::EN => "Only the last 30 notifications are displayed",
-        Language::IT => "Solo le ultime 30 notifiche sono mostrate",
-        Language::FR => "Seulement les 30 dernières notifications sont affichées",
-        Language::ES => "Sólo se muestran las últimas 30 notificaciones",
-        Language::PL => "Wyświetlane jest tylko 30 ostatnich powiadomień",
-        Language::DE => "Nur die letzten 30 Benachrichtigungen werden angezeigt",
-        Language::UK => "Можеш побачити лише 30 останніх повідомлень",
-        Language::ZH => "仅显示最近 30 条通知",
-        Language::RO => "Sunt afișate doar ultimele 30 de notificări",
-        Language::KO => "최근 30개의 알림만 표시됩니다",
-        Language::TR => "Sadece son 30 bildirim gösterilmektedir",
-        Language::RU => "Тут показываются только последние 30 уведомлений",
-        Language::PT => "São mostradas apenas as últimas 30 notificações",
-        Language::EL => "Μόνο οι τελευταίες 30 ειδοποιήσεις απεικονίζονται",
-        // Language::FA => "تنها ۳۰ اعلان آخر نمایش داده شده اند",
-        Language::SV => "Endast de senaste 30 notifikationerna visas",
-        Language::FI => "Vain viimeiset 30 ilmoitusta näytetään",
-        Language::JA => "最新の通知 30 件のみ表示されます",
-        Language::UZ => "Faqat oxirgi 30 ta bildirishnoma ko'rsatiladi",
-    }
-}
@@ -3,6 +3,7 @@
 use iced::widget::Text;
 use iced::Renderer;
 
+use crate::networking::types::scan_detector::ScanKind;
 use crate::translations::translations::network_adapter_translation;
 use crate::{Language, StyleType};
 
@@ -24,9 +25,9 @@ pub fn zoom_translation(language: Language) -> &'static str {
 
 pub fn mmdb_files_translation(language: Language) -> &'static str {
     match language {
-        Language::EN => "Database files (MMDB format)",
-        Language::IT => "File di database (formato MMDB)",
-        _ => "Database files (MMDB format)",
+        Language::EN => "Database files",
+        Language::IT => "File di database",
+        _ => "Database files",
     }
 }
 
@@ -124,3 +125,761 @@ pub fn database_from_file_translation(language: Language) -> &'static str {
         _ => "Select database file",
     }
 }
+
+pub fn mac_vendors_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "MAC vendors",
+        Language::IT => "Produttori MAC",
+        _ => "MAC vendors",
+    }
+}
+
+pub fn vendor_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Vendor",
+        Language::IT => "Produttore",
+        _ => "Vendor",
+    }
+}
+
+pub fn client_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Client",
+        Language::IT => "Client",
+        _ => "Client",
+    }
+}
+
+pub fn record_type_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Type",
+        Language::IT => "Tipo",
+        _ => "Type",
+    }
+}
+
+pub fn response_code_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Response code",
+        Language::IT => "Codice di risposta",
+        _ => "Response code",
+    }
+}
+
+pub fn answers_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Answers",
+        Language::IT => "Risposte",
+        _ => "Answers",
+    }
+}
+
+pub fn time_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Time",
+        Language::IT => "Ora",
+        _ => "Time",
+    }
+}
+
+pub fn rcode_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "RCODE",
+        Language::IT => "Codice",
+        _ => "RCODE",
+    }
+}
+
+pub fn latency_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Latency",
+        Language::IT => "Latenza",
+        _ => "Latency",
+    }
+}
+
+pub fn edit_filters_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Edit filters",
+        Language::IT => "Modifica filtri",
+        _ => "Edit filters",
+    }
+}
+
+pub fn apply_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Apply",
+        Language::IT => "Applica",
+        _ => "Apply",
+    }
+}
+
+pub fn filters_changed_translation(language: Language, time: &str) -> String {
+    match language {
+        Language::EN => format!("Filters changed during the capture (at {time})"),
+        Language::IT => format!("Filtri modificati durante la cattura (alle {time})"),
+        _ => format!("Filters changed during the capture (at {time})"),
+    }
+}
+
+pub fn pause_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Pause the analysis",
+        Language::IT => "Metti in pausa l'analisi",
+        _ => "Pause the analysis",
+    }
+}
+
+pub fn resume_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Resume the analysis",
+        Language::IT => "Riprendi l'analisi",
+        _ => "Resume the analysis",
+    }
+}
+
+pub fn analysis_paused_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Analysis paused",
+        Language::IT => "Analisi in pausa",
+        _ => "Analysis paused",
+    }
+}
+
+pub fn also_analyze_adapter_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Analyze this adapter as well",
+        Language::IT => "Analizza anche questo adattatore",
+        _ => "Analyze this adapter as well",
+    }
+}
+
+pub fn other_adapters_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Other network adapters",
+        Language::IT => "Altri adattatori di rete",
+        _ => "Other network adapters",
+    }
+}
+
+pub fn traffic_history_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Traffic history",
+        Language::IT => "Storico del traffico",
+        _ => "Traffic history",
+    }
+}
+
+pub fn store_history_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Store the traffic history on disk",
+        Language::IT => "Salva lo storico del traffico su disco",
+        _ => "Store the traffic history on disk",
+    }
+}
+
+pub fn history_retention_translation(language: Language, days: u16) -> String {
+    match language {
+        Language::EN => format!("Keep it for {days} days"),
+        Language::IT => format!("Conservalo per {days} giorni"),
+        _ => format!("Keep it for {days} days"),
+    }
+}
+
+pub fn flows_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Connections",
+        Language::IT => "Connessioni",
+        _ => "Connections",
+    }
+}
+
+pub fn local_address_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Local address",
+        Language::IT => "Indirizzo locale",
+        _ => "Local address",
+    }
+}
+
+pub fn from_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "From",
+        Language::IT => "Da",
+        _ => "From",
+    }
+}
+
+pub fn to_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "To",
+        Language::IT => "A",
+        _ => "To",
+    }
+}
+
+pub fn group_by_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Group by",
+        Language::IT => "Raggruppa per",
+        _ => "Group by",
+    }
+}
+
+pub fn search_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Search",
+        Language::IT => "Cerca",
+        _ => "Search",
+    }
+}
+
+pub fn invalid_time_interval_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Invalid time interval (expected format: YYYY-MM-DD HH:MM)",
+        Language::IT => "Intervallo di tempo non valido (formato atteso: AAAA-MM-GG HH:MM)",
+        _ => "Invalid time interval (expected format: YYYY-MM-DD HH:MM)",
+    }
+}
+
+pub fn history_unavailable_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "The traffic history can't be read",
+        Language::IT => "Lo storico del traffico non può essere letto",
+        _ => "The traffic history can't be read",
+    }
+}
+
+pub fn session_from_file_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Open a saved session",
+        Language::IT => "Apri una sessione salvata",
+        _ => "Open a saved session",
+    }
+}
+
+pub fn save_session_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Save a snapshot of the session",
+        Language::IT => "Salva un'istantanea della sessione",
+        _ => "Save a snapshot of the session",
+    }
+}
+
+pub fn session_file_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Session file",
+        Language::IT => "File di sessione",
+        _ => "Session file",
+    }
+}
+
+pub fn read_only_session_translation(language: Language, time: &str) -> String {
+    match language {
+        Language::EN => format!("Read-only session (saved at {time})"),
+        Language::IT => format!("Sessione in sola lettura (salvata alle {time})"),
+        _ => format!("Read-only session (saved at {time})"),
+    }
+}
+
+pub fn compare_sessions_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Compare with a saved session",
+        Language::IT => "Confronta con una sessione salvata",
+        _ => "Compare with a saved session",
+    }
+}
+
+pub fn comparison_with_translation(language: Language, file_name: &str) -> String {
+    match language {
+        Language::EN => format!("Comparison with {file_name}"),
+        Language::IT => format!("Confronto con {file_name}"),
+        _ => format!("Comparison with {file_name}"),
+    }
+}
+
+pub fn autonomous_system_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Autonomous System",
+        Language::IT => "Sistema autonomo",
+        _ => "Autonomous System",
+    }
+}
+
+pub fn expose_metrics_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Expose Prometheus metrics at",
+        Language::IT => "Esponi le metriche Prometheus su",
+        _ => "Expose Prometheus metrics at",
+    }
+}
+
+pub fn export_flows_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Export flows to",
+        Language::IT => "Esporta i flussi verso",
+        _ => "Export flows to",
+    }
+}
+
+pub fn enable_api_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Enable the local API at",
+        Language::IT => "Abilita l'API locale su",
+        _ => "Enable the local API at",
+    }
+}
+
+pub fn applied_at_next_launch_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "(applied at next launch)",
+        Language::IT => "(applicato al prossimo avvio)",
+        _ => "(applied at next launch)",
+    }
+}
+
+pub fn write_events_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Write events as JSON lines to",
+        Language::IT => "Scrivi gli eventi come righe JSON su",
+        _ => "Write events as JSON lines to",
+    }
+}
+
+pub fn events_stdout_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "(- for standard output)",
+        Language::IT => "(- per lo standard output)",
+        _ => "(- for standard output)",
+    }
+}
+
+pub fn notification_rules_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => {
+            "Notify when the traffic of a host, ASN, country, app, or port exceeds a threshold"
+        }
+        Language::IT => {
+            "Notifica quando il traffico di un host, ASN, paese, app o porta supera una soglia"
+        }
+        _ => "Notify when the traffic of a host, ASN, country, app, or port exceeds a threshold",
+    }
+}
+
+pub fn rule_exceeded_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Rule threshold exceeded",
+        Language::IT => "Soglia di una regola superata",
+        _ => "Rule threshold exceeded",
+    }
+}
+
+pub fn notify_when_exceeded_for_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Notify when thresholds are exceeded for",
+        Language::IT => "Notifica quando le soglie sono superate per",
+        _ => "Notify when thresholds are exceeded for",
+    }
+}
+
+pub fn cooldown_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Cooldown:",
+        Language::IT => "Pausa:",
+        _ => "Cooldown:",
+    }
+}
+
+pub fn alert_ended_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Threshold no longer exceeded",
+        Language::IT => "Soglia non più superata",
+        _ => "Threshold no longer exceeded",
+    }
+}
+
+pub fn new_host_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "New host",
+        Language::IT => "Nuovo host",
+        _ => "New host",
+    }
+}
+
+pub fn new_country_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "New country",
+        Language::IT => "Nuovo paese",
+        _ => "New country",
+    }
+}
+
+pub fn new_asn_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "New ASN",
+        Language::IT => "Nuovo ASN",
+        _ => "New ASN",
+    }
+}
+
+pub fn new_listening_port_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "New listening port",
+        Language::IT => "Nuova porta in ascolto",
+        _ => "New listening port",
+    }
+}
+
+pub fn first_seen_notification_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Notify me when a host, country, or ASN never seen before exchanges data",
+        Language::IT => "Notificami quando un host, paese o ASN mai visto prima scambia dati",
+        _ => "Notify me when a host, country, or ASN never seen before exchanges data",
+    }
+}
+
+pub fn new_port_notification_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => {
+            "Notify me when a local port never seen before accepts incoming connections"
+        }
+        Language::IT => {
+            "Notificami quando una porta locale mai vista prima accetta connessioni in ingresso"
+        }
+        _ => "Notify me when a local port never seen before accepts incoming connections",
+    }
+}
+
+pub fn blocklists_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Blocklists",
+        Language::IT => "Blocklist",
+        _ => "Blocklists",
+    }
+}
+
+pub fn blocklist_from_file_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Select blocklist file",
+        Language::IT => "Seleziona file blocklist",
+        _ => "Select blocklist file",
+    }
+}
+
+pub fn blocklist_entries_translation(language: Language, entries: usize) -> String {
+    match language {
+        Language::EN => format!("({entries} entries)"),
+        Language::IT => format!("({entries} voci)"),
+        _ => format!("({entries} entries)"),
+    }
+}
+
+pub fn blocklist_matched_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Blocklist match",
+        Language::IT => "Corrispondenza in blocklist",
+        _ => "Blocklist match",
+    }
+}
+
+pub fn blocklist_notification_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Notify me when a remote address or its name matches a blocklist",
+        Language::IT => "Notificami quando un indirizzo remoto o il suo nome è in una blocklist",
+        _ => "Notify me when a remote address or its name matches a blocklist",
+    }
+}
+
+pub fn scan_notification_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Notify me when a port scan, host sweep, or SYN flood is detected",
+        Language::IT => {
+            "Notificami quando viene rilevata una scansione di porte, di host o un SYN flood"
+        }
+        _ => "Notify me when a port scan, host sweep, or SYN flood is detected",
+    }
+}
+
+pub fn port_scan_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Port scan",
+        Language::IT => "Scansione di porte",
+        _ => "Port scan",
+    }
+}
+
+pub fn host_sweep_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Host sweep",
+        Language::IT => "Scansione di host",
+        _ => "Host sweep",
+    }
+}
+
+pub fn syn_flood_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "SYN flood",
+        Language::IT => "SYN flood",
+        _ => "SYN flood",
+    }
+}
+
+pub fn scan_targets_translation(
+    language: Language,
+    kind: ScanKind,
+    count: usize,
+    seconds: i64,
+) -> String {
+    match (language, kind) {
+        (Language::IT, ScanKind::PortScan) => format!("{count} porte sondate in {seconds} s"),
+        (Language::IT, ScanKind::HostSweep) => format!("{count} host sondati in {seconds} s"),
+        (Language::IT, ScanKind::SynFlood) => {
+            format!("{count} SYN senza risposta in {seconds} s")
+        }
+        (_, ScanKind::PortScan) => format!("{count} ports probed in {seconds} s"),
+        (_, ScanKind::HostSweep) => format!("{count} hosts probed in {seconds} s"),
+        (_, ScanKind::SynFlood) => format!("{count} unanswered SYNs in {seconds} s"),
+    }
+}
+
+pub fn notification_actions_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => {
+            "When a notification is emitted, run a command or post it to a webhook as JSON"
+        }
+        Language::IT => {
+            "Quando viene emessa una notifica, esegui un comando o inviala a un webhook in JSON"
+        }
+        _ => "When a notification is emitted, run a command or post it to a webhook as JSON",
+    }
+}
+
+pub fn command_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Command",
+        Language::IT => "Comando",
+        _ => "Command",
+    }
+}
+
+pub fn webhook_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Webhook URL",
+        Language::IT => "URL del webhook",
+        _ => "Webhook URL",
+    }
+}
+
+pub fn export_notifications_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Export the notifications log",
+        Language::IT => "Esporta il registro delle notifiche",
+        _ => "Export the notifications log",
+    }
+}
+
+pub fn quota_notification_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => {
+            "Data cap of each network adapter in a billing cycle (notified at 80%, 90%, and 100%)"
+        }
+        Language::IT => {
+            "Limite di dati di ogni scheda di rete in un ciclo di fatturazione (notificato all'80%, al 90% e al 100%)"
+        }
+        _ => "Data cap of each network adapter in a billing cycle (notified at 80%, 90%, and 100%)",
+    }
+}
+
+pub fn data_cap_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Data cap",
+        Language::IT => "Limite di dati",
+        _ => "Data cap",
+    }
+}
+
+pub fn cycle_start_day_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "billing cycle starting on day",
+        Language::IT => "ciclo di fatturazione che inizia il giorno",
+        _ => "billing cycle starting on day",
+    }
+}
+
+pub fn quota_reached_translation(language: Language, level: u8) -> String {
+    match language {
+        Language::EN => format!("{level}% of the data cap reached"),
+        Language::IT => format!("Raggiunto il {level}% del limite di dati"),
+        _ => format!("{level}% of the data cap reached"),
+    }
+}
+
+pub fn data_usage_translation(language: Language, cycle_start: &str) -> String {
+    match language {
+        Language::EN => format!("Data used since {cycle_start}"),
+        Language::IT => format!("Dati usati dal {cycle_start}"),
+        _ => format!("Data used since {cycle_start}"),
+    }
+}
+
+pub fn show_in_chart_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Show traffic in chart",
+        Language::IT => "Mostra il traffico nel grafico",
+        _ => "Show traffic in chart",
+    }
+}
+
+pub fn show_total_traffic_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Show total traffic",
+        Language::IT => "Mostra il traffico totale",
+        _ => "Show total traffic",
+    }
+}
+
+pub fn export_chart_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Export the chart as an image",
+        Language::IT => "Esporta il grafico come immagine",
+        _ => "Export the chart as an image",
+    }
+}
+
+pub fn map_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Map",
+        Language::IT => "Mappa",
+        _ => "Map",
+    }
+}
+
+pub fn traffic_by_country_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Traffic by country",
+        Language::IT => "Traffico per paese",
+        _ => "Traffic by country",
+    }
+}
+
+pub fn favorite_countries_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Countries with favorite hosts",
+        Language::IT => "Paesi con host preferiti",
+        _ => "Countries with favorite hosts",
+    }
+}
+
+pub fn inspect_country_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Click on a country to inspect its hosts",
+        Language::IT => "Clicca su un paese per ispezionarne gli host",
+        _ => "Click on a country to inspect its hosts",
+    }
+}
+
+pub fn error_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Error",
+        Language::IT => "Errore",
+        _ => "Error",
+    }
+}
+
+pub fn file_not_saved_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "The file couldn't be saved",
+        Language::IT => "Impossibile salvare il file",
+        _ => "The file couldn't be saved",
+    }
+}
+
+pub fn metrics_unavailable_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "The metrics endpoint couldn't be started",
+        Language::IT => "Impossibile avviare l'endpoint delle metriche",
+        _ => "The metrics endpoint couldn't be started",
+    }
+}
+
+pub fn api_unavailable_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "The local API couldn't be started",
+        Language::IT => "Impossibile avviare l'API locale",
+        _ => "The local API couldn't be started",
+    }
+}
+
+pub fn api_token_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Token",
+        Language::IT => "Token",
+        _ => "Token",
+    }
+}
+
+pub fn action_failed_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Notification action failed",
+        Language::IT => "Azione di notifica non riuscita",
+        _ => "Notification action failed",
+    }
+}
+
+pub fn baseline_translation(
+    language: Language,
+    hosts: usize,
+    countries: usize,
+    asns: usize,
+    ports: usize,
+) -> String {
+    match language {
+        Language::EN => format!(
+            "Already seen: {hosts} hosts, {countries} countries, {asns} ASNs, {ports} listening ports"
+        ),
+        Language::IT => format!(
+            "Già visti: {hosts} host, {countries} paesi, {asns} ASN, {ports} porte in ascolto"
+        ),
+        _ => format!(
+            "Already seen: {hosts} hosts, {countries} countries, {asns} ASNs, {ports} listening ports"
+        ),
+    }
+}
+
+pub fn learning_baseline_translation(language: Language, minutes: u64) -> String {
+    match language {
+        Language::EN => {
+            format!("Learning: novelties will be notified after {minutes} more minutes of capture")
+        }
+        Language::IT => format!(
+            "Apprendimento: le novità saranno notificate dopo altri {minutes} minuti di cattura"
+        ),
+        _ => {
+            format!("Learning: novelties will be notified after {minutes} more minutes of capture")
+        }
+    }
+}
+
+pub fn reset_baseline_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "Forget what was seen and learn it again",
+        Language::IT => "Dimentica ciò che è stato visto e imparalo di nuovo",
+        _ => "Forget what was seen and learn it again",
+    }
+}
+
+pub fn consecutive_seconds_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "consecutive s",
+        Language::IT => "s consecutivi",
+        _ => "consecutive s",
+    }
+}
+
+pub fn seconds_on_average_translation(language: Language) -> &'static str {
+    match language {
+        Language::EN => "s on average",
+        Language::IT => "s in media",
+        _ => "s on average",
+    }
+}
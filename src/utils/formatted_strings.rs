@@ -1,6 +1,8 @@
 use std::cmp::min;
 use std::net::IpAddr;
 
+use chrono::{DateTime, Local};
+
 use crate::networking::types::filters::Filters;
 use crate::translations::translations::{
     address_translation, ip_version_translation, protocol_translation,
@@ -223,3 +225,12 @@ pub fn get_path_termination_string(full_path: &str, i: usize) -> String {
     let suspensions = if tot_len > i { "..." } else { "" };
     [suspensions, &full_path[tot_len - slice_len..], " "].concat()
 }
+
+/// Formats the time of an event, showing its date only if it isn't today
+pub fn get_formatted_timestamp(timestamp: DateTime<Local>) -> String {
+    if timestamp.date_naive() == Local::now().date_naive() {
+        timestamp.format("%H:%M:%S").to_string()
+    } else {
+        timestamp.format("%Y-%m-%d %H:%M:%S").to_string()
+    }
+}
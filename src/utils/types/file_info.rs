@@ -1,5 +1,6 @@
 use crate::translations::translations_3::{
-    database_from_file_translation, style_from_file_translation,
+    blocklist_from_file_translation, database_from_file_translation, export_chart_translation,
+    export_notifications_translation, session_from_file_translation, style_from_file_translation,
 };
 use crate::translations::types::language::Language;
 
@@ -7,6 +8,13 @@
 pub enum FileInfo {
     Style,
     Database,
+    OuiDatabase,
+    Session,
+    Blocklist,
+    NotificationsCsv,
+    NotificationsJson,
+    ChartPng,
+    ChartSvg,
 }
 
 impl FileInfo {
@@ -14,13 +22,41 @@ pub fn get_extension(&self) -> &'static str {
         match self {
             FileInfo::Style => "toml",
             FileInfo::Database => "mmdb",
+            FileInfo::OuiDatabase => "csv",
+            FileInfo::Session => "ron",
+            FileInfo::Blocklist => "txt",
+            FileInfo::NotificationsCsv => "csv",
+            FileInfo::NotificationsJson => "json",
+            FileInfo::ChartPng => "png",
+            FileInfo::ChartSvg => "svg",
+        }
+    }
+
+    /// Extensions of the files that can be picked
+    pub fn get_open_extensions(&self) -> &'static [&'static str] {
+        match self {
+            FileInfo::Style => &["toml"],
+            FileInfo::Database => &["mmdb"],
+            FileInfo::OuiDatabase => &["csv"],
+            FileInfo::Session => &["ron"],
+            FileInfo::Blocklist => &["txt", "csv", "netset", "ipset", "list"],
+            FileInfo::NotificationsCsv => &["csv"],
+            FileInfo::NotificationsJson => &["json"],
+            FileInfo::ChartPng => &["png"],
+            FileInfo::ChartSvg => &["svg"],
         }
     }
 
     pub fn action_info(&self, language: Language) -> &'static str {
         match self {
             FileInfo::Style => style_from_file_translation(language),
-            FileInfo::Database => database_from_file_translation(language),
+            FileInfo::Database | FileInfo::OuiDatabase => database_from_file_translation(language),
+            FileInfo::Session => session_from_file_translation(language),
+            FileInfo::Blocklist => blocklist_from_file_translation(language),
+            FileInfo::NotificationsCsv | FileInfo::NotificationsJson => {
+                export_notifications_translation(language)
+            }
+            FileInfo::ChartPng | FileInfo::ChartSvg => export_chart_translation(language),
         }
     }
 }